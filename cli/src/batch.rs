@@ -0,0 +1,643 @@
+extern crate regex;
+
+use self::regex::{Captures, Regex};
+
+use crate::command_executor::CommandExecutor;
+use crate::utils::output;
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+// Nesting limit of included scripts that also protects from recursive includes
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+lazy_static! {
+    static ref VARIABLE_REFERENCE_RE: Regex = Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}").unwrap();
+    static ref VARIABLE_NAME_RE: Regex = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap();
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Command(String),
+    Defined(String),
+    Equal(String, String),
+    NotEqual(String, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Statement {
+    Command { line: String, force: bool },
+    Set { name: String, value: String },
+    Capture { names: Vec<String>, command: String },
+    If { condition: Condition, negate: bool, then_block: Vec<Line>, else_block: Vec<Line> },
+    OnError(Vec<Line>),
+    Include(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Line {
+    num: usize,
+    text: String,
+    statement: Statement,
+}
+
+pub struct BatchExecutor<'a> {
+    command_executor: &'a CommandExecutor,
+    variables: HashMap<String, String>,
+    on_error: Option<Vec<Line>>,
+    scripts: Vec<PathBuf>,
+    failed_line: Option<usize>,
+}
+
+impl<'a> BatchExecutor<'a> {
+    pub fn new(command_executor: &'a CommandExecutor) -> BatchExecutor<'a> {
+        BatchExecutor {
+            command_executor,
+            variables: HashMap::new(),
+            on_error: None,
+            scripts: Vec::new(),
+            failed_line: None,
+        }
+    }
+
+    // Number of the line of the executed (not included) script where execution failed
+    pub fn failed_line(&self) -> Option<usize> {
+        self.failed_line
+    }
+
+    // Statements are executed as soon as they are read, so only lines of an open block are kept in memory
+    pub fn execute<T>(&mut self, reader: T) -> Result<(), ()> where T: BufRead {
+        let mut parser = Parser::new(reader.lines());
+
+        while !self.command_executor.ctx().is_exit() {
+            match parser.next_statement() {
+                Ok(Some(line)) => self._execute_line(&line)?,
+                Ok(None) => break,
+                Err(err) => {
+                    println_err!("{}", err);
+                    return self._handle_error();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn execute_file(&mut self, path: &Path) -> Result<(), ()> {
+        let file = File::open(path)
+            .map_err(|err| println_err!("Can't open script file {}\nError: {}", path.display(), err))?;
+
+        self.scripts.push(path.to_path_buf());
+        let res = self.execute(BufReader::new(file));
+        self.scripts.pop();
+        res
+    }
+
+    fn _execute_block(&mut self, block: &[Line]) -> Result<(), ()> {
+        for line in block {
+            if self.command_executor.ctx().is_exit() {
+                break;
+            }
+            self._execute_line(line)?;
+        }
+        Ok(())
+    }
+
+    fn _execute_line(&mut self, line: &Line) -> Result<(), ()> {
+        match line.statement {
+            Statement::Command { line: ref command, force } => {
+                let res = self._execute_command(line, command);
+                if res.is_err() && !force {
+                    return self._fail(line);
+                }
+            }
+            Statement::Set { ref name, ref value } => {
+                match self._substitute(value) {
+                    Ok(value) => { self.variables.insert(name.to_string(), value); }
+                    Err(()) => return self._fail(line)
+                }
+            }
+            Statement::Capture { ref names, ref command } => {
+                let (res, values) = output::capture(|| self._execute_command(line, command));
+                if res.is_err() {
+                    return self._fail(line);
+                }
+
+                if values.len() < names.len() {
+                    println_err!("Command returned {} values, but {} expected", values.len(), names.len());
+                    return self._fail(line);
+                }
+
+                for (name, value) in names.iter().zip(values) {
+                    self.variables.insert(name.to_string(), value);
+                }
+            }
+            Statement::If { ref condition, negate, ref then_block, ref else_block } => {
+                let res = match self._check_condition(line, condition) {
+                    Ok(res) => res,
+                    Err(()) => return self._fail(line)
+                };
+
+                if res != negate {
+                    self._execute_block(then_block)?;
+                } else {
+                    self._execute_block(else_block)?;
+                }
+            }
+            Statement::OnError(ref block) => {
+                self.on_error = Some(block.clone());
+            }
+            Statement::Include(ref path) => {
+                if self.scripts.len() >= MAX_INCLUDE_DEPTH {
+                    println_err!("Scripts are included too deeply (maximum is {})", MAX_INCLUDE_DEPTH);
+                    return self._fail(line);
+                }
+
+                let path = match self._substitute(path) {
+                    Ok(path) => self._resolve_path(path.trim_matches('"')),
+                    Err(()) => return self._fail(line)
+                };
+
+                // Included script has its own on-error block, the one of the including script is restored after it
+                let on_error = self.on_error.take();
+                let res = self.execute_file(&path);
+                self.on_error = on_error;
+
+                // Failure inside of included script is already reported
+                if res.is_err() {
+                    self._set_failed_line(line);
+                    return self._handle_error();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn _execute_command(&self, line: &Line, command: &str) -> Result<(), ()> {
+        let command = self._substitute_command(command)?;
+
+        // Original line is echoed, so values of variables (like wallet keys) aren't printed.
+        // In json mode only the command name is reported in the output object.
+        if !output::is_json() {
+            println!("{}", line.text);
+        }
+        let res = self.command_executor.execute(&command);
+        if !output::is_json() {
+            println!();
+        }
+        res
+    }
+
+    fn _check_condition(&self, line: &Line, condition: &Condition) -> Result<bool, ()> {
+        match *condition {
+            Condition::Command(ref command) => Ok(self._execute_command(line, command).is_ok()),
+            Condition::Defined(ref name) => Ok(self._get_variable(name).is_some()),
+            Condition::Equal(ref left, ref right) => Ok(self._substitute(left)? == self._substitute(right)?),
+            Condition::NotEqual(ref left, ref right) => Ok(self._substitute(left)? != self._substitute(right)?),
+        }
+    }
+
+    fn _fail(&mut self, line: &Line) -> Result<(), ()> {
+        self._set_failed_line(line);
+
+        match self.scripts.last() {
+            Some(script) => println_err!("Batch execution failed at line #{} of \"{}\"", line.num, script.display()),
+            None => println_err!("Batch execution failed at line #{}", line.num)
+        }
+
+        self._handle_error()
+    }
+
+    fn _set_failed_line(&mut self, line: &Line) {
+        if self.scripts.is_empty() {
+            self.failed_line.get_or_insert(line.num);
+        }
+    }
+
+    // On-error block is executed only once, its failure just stops it
+    fn _handle_error(&mut self) -> Result<(), ()> {
+        if let Some(block) = self.on_error.take() {
+            self._execute_block(&block).ok();
+        }
+
+        Err(())
+    }
+
+    fn _get_variable(&self, name: &str) -> Option<String> {
+        self.variables.get(name).cloned()
+            .or_else(|| env::var(name).ok())
+    }
+
+    // Replaces ${name} with value of script variable or environment variable with the same name
+    fn _substitute(&self, text: &str) -> Result<String, ()> {
+        self._substitute_values(text, false)
+    }
+
+    // Values are quoted if needed, so a value containing whitespaces or quotes stays a single param
+    fn _substitute_command(&self, command: &str) -> Result<String, ()> {
+        self._substitute_values(command, true)
+    }
+
+    fn _substitute_values(&self, text: &str, quote: bool) -> Result<String, ()> {
+        let mut undefined = None;
+        let res = VARIABLE_REFERENCE_RE.replace_all(text, |caps: &Captures| {
+            match self._get_variable(&caps[1]) {
+                Some(ref value) if quote => CommandExecutor::quote_value(value),
+                Some(value) => value,
+                None => {
+                    undefined.get_or_insert_with(|| caps[1].to_string());
+                    String::new()
+                }
+            }
+        }).to_string();
+
+        match undefined {
+            Some(name) => {
+                println_err!("Variable \"{}\" is not defined", name);
+                Err(())
+            }
+            None => Ok(res)
+        }
+    }
+
+    // Relative paths of included scripts are resolved against directory of the including script
+    fn _resolve_path(&self, path: &str) -> PathBuf {
+        let path = Path::new(path);
+
+        match self.scripts.last().and_then(|script| script.parent()) {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path.to_path_buf()
+        }
+    }
+}
+
+struct Parser<I> where I: Iterator<Item=io::Result<String>> {
+    lines: I,
+    num: usize,
+}
+
+impl<I> Parser<I> where I: Iterator<Item=io::Result<String>> {
+    fn new(lines: I) -> Parser<I> {
+        Parser { lines, num: 0 }
+    }
+
+    // Reads the next top-level statement together with all lines of its block
+    fn next_statement(&mut self) -> Result<Option<Line>, String> {
+        match self.next_line()? {
+            Some((num, text)) => self.parse_statement(num, text).map(Some),
+            None => Ok(None)
+        }
+    }
+
+    // Skips blank lines and lines starting with #
+    fn next_line(&mut self) -> Result<Option<(usize, String)>, String> {
+        for line in &mut self.lines {
+            self.num += 1;
+
+            let line = line.map_err(|_| format!("Can't parse line #{}", self.num))?;
+            let text = line.trim();
+
+            if !text.starts_with('#') && !text.is_empty() {
+                return Ok(Some((self.num, text.to_string())));
+            }
+        }
+        Ok(None)
+    }
+
+    // Returns parsed statements and the terminator which finished the block
+    fn parse_block(&mut self, terminators: &[&'static str]) -> Result<(Vec<Line>, Option<&'static str>), String> {
+        let mut block = Vec::new();
+
+        while let Some((num, text)) = self.next_line()? {
+            if let Some(terminator) = terminators.iter().find(|terminator| **terminator == text) {
+                return Ok((block, Some(*terminator)));
+            }
+
+            block.push(self.parse_statement(num, text)?);
+        }
+
+        Ok((block, None))
+    }
+
+    fn parse_statement(&mut self, num: usize, text: String) -> Result<Line, String> {
+        let statement = if let Some(condition) = _strip_keyword(&text, "if") {
+            let (negate, condition) = _parse_condition(condition)
+                .map_err(|err| format!("Line #{}: {}", num, err))?;
+
+            let (then_block, terminator) = self.parse_block(&["else", "end"])?;
+            let else_block = match terminator {
+                Some("else") => self.parse_block(&["end"])?,
+                terminator => (Vec::new(), terminator)
+            };
+
+            if else_block.1.is_none() {
+                return Err(format!("Line #{}: \"if\" block isn't closed with \"end\"", num));
+            }
+
+            Statement::If { condition, negate, then_block, else_block: else_block.0 }
+        } else if text == "on-error" {
+            let (block, terminator) = self.parse_block(&["end"])?;
+
+            if terminator.is_none() {
+                return Err(format!("Line #{}: \"on-error\" block isn't closed with \"end\"", num));
+            }
+
+            Statement::OnError(block)
+        } else if let Some(path) = _strip_keyword(&text, "include") {
+            Statement::Include(path.to_string())
+        } else if let Some(assignment) = _strip_keyword(&text, "set") {
+            _parse_assignment(assignment).map_err(|err| format!("Line #{}: {}", num, err))?
+        } else if text == "else" || text == "end" || text == "if" || text == "include" || text == "set" {
+            return Err(format!("Line #{}: unexpected \"{}\"", num, text));
+        } else if text.starts_with('-') {
+            Statement::Command { line: text[1..].to_string(), force: true }
+        } else {
+            Statement::Command { line: text.to_string(), force: false }
+        };
+
+        Ok(Line { num, text, statement })
+    }
+}
+
+fn _strip_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    if text.starts_with(keyword) && text[keyword.len()..].starts_with(char::is_whitespace) {
+        Some(text[keyword.len()..].trim())
+    } else {
+        None
+    }
+}
+
+fn _parse_condition(condition: &str) -> Result<(bool, Condition), String> {
+    let (negate, condition) = if condition.starts_with('!') {
+        (true, condition[1..].trim())
+    } else {
+        (false, condition)
+    };
+
+    if condition.is_empty() {
+        return Err("condition is empty".to_string());
+    }
+
+    let condition = if let Some(name) = _strip_keyword(condition, "defined") {
+        if !_is_variable_name(name) {
+            return Err(format!("invalid variable name \"{}\"", name));
+        }
+        Condition::Defined(name.to_string())
+    } else if let Some(pos) = condition.find(" == ") {
+        Condition::Equal(condition[..pos].trim().to_string(), condition[pos + 4..].trim().to_string())
+    } else if let Some(pos) = condition.find(" != ") {
+        Condition::NotEqual(condition[..pos].trim().to_string(), condition[pos + 4..].trim().to_string())
+    } else {
+        Condition::Command(condition.to_string())
+    };
+
+    Ok((negate, condition))
+}
+
+fn _parse_assignment(assignment: &str) -> Result<Statement, String> {
+    let pos = assignment.find('=')
+        .ok_or_else(|| "assignment must have form \"set <name> = <value>\"".to_string())?;
+
+    let names = assignment[..pos].split(',')
+        .map(str::trim)
+        .map(String::from)
+        .collect::<Vec<String>>();
+
+    if let Some(name) = names.iter().find(|name| !_is_variable_name(name)) {
+        return Err(format!("invalid variable name \"{}\"", name));
+    }
+
+    let value = assignment[pos + 1..].trim();
+
+    if value.starts_with("$(") && value.ends_with(')') {
+        let command = value[2..value.len() - 1].trim();
+
+        if command.is_empty() {
+            return Err("command is empty".to_string());
+        }
+
+        return Ok(Statement::Capture { names, command: command.to_string() });
+    }
+
+    if names.len() != 1 {
+        return Err("only command output can be assigned to several variables".to_string());
+    }
+
+    Ok(Statement::Set { name: names[0].to_string(), value: value.trim_matches('"').to_string() })
+}
+
+fn _is_variable_name(name: &str) -> bool {
+    VARIABLE_NAME_RE.is_match(name)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::common;
+    use crate::utils::environment::EnvironmentUtils;
+
+    use std::fs;
+
+    fn parse_script(script: &str) -> Result<Vec<Line>, String> {
+        let mut parser = Parser::new(script.as_bytes().lines());
+        let mut block = Vec::new();
+        while let Some(line) = parser.next_statement()? {
+            block.push(line);
+        }
+        Ok(block)
+    }
+
+    fn command(line: &str) -> Statement {
+        Statement::Command { line: line.to_string(), force: false }
+    }
+
+    fn executor() -> CommandExecutor {
+        CommandExecutor::build()
+            .add_command(common::prompt_command::new())
+            .finalize()
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn parse_works_for_commands() {
+            let block = parse_script("# comment\n\nwallet list\n-wallet close").unwrap();
+
+            assert_eq!(2, block.len());
+            assert_eq!(3, block[0].num);
+            assert_eq!(command("wallet list"), block[0].statement);
+            assert_eq!(Statement::Command { line: "wallet close".to_string(), force: true }, block[1].statement);
+        }
+
+        #[test]
+        fn parse_works_for_assignments() {
+            let block = parse_script("set name = \"my wallet\"\nset did, verkey = $(did new)").unwrap();
+
+            assert_eq!(Statement::Set { name: "name".to_string(), value: "my wallet".to_string() }, block[0].statement);
+            assert_eq!(Statement::Capture { names: vec!["did".to_string(), "verkey".to_string()], command: "did new".to_string() },
+                       block[1].statement);
+        }
+
+        #[test]
+        fn parse_works_for_if_else_block() {
+            let block = parse_script("if !defined seed\n  did new\nelse\n  did new seed=${seed}\nend").unwrap();
+
+            assert_eq!(1, block.len());
+            match block[0].statement {
+                Statement::If { ref condition, negate, ref then_block, ref else_block } => {
+                    assert_eq!(Condition::Defined("seed".to_string()), *condition);
+                    assert!(negate);
+                    assert_eq!(command("did new"), then_block[0].statement);
+                    assert_eq!(command("did new seed=${seed}"), else_block[0].statement);
+                }
+                _ => panic!("unexpected statement")
+            }
+        }
+
+        #[test]
+        fn parse_works_for_conditions() {
+            assert_eq!((false, Condition::Equal("${role}".to_string(), "TRUSTEE".to_string())), _parse_condition("${role} == TRUSTEE").unwrap());
+            assert_eq!((false, Condition::NotEqual("${role}".to_string(), "TRUSTEE".to_string())), _parse_condition("${role} != TRUSTEE").unwrap());
+            assert_eq!((true, Condition::Command("wallet open w key".to_string())), _parse_condition("!wallet open w key").unwrap());
+        }
+
+        #[test]
+        fn parse_works_for_on_error_and_include() {
+            let block = parse_script("on-error\n  wallet close\nend\ninclude common.txt").unwrap();
+
+            assert_eq!(Statement::OnError(vec![Line { num: 2, text: "wallet close".to_string(), statement: command("wallet close") }]),
+                       block[0].statement);
+            assert_eq!(Statement::Include("common.txt".to_string()), block[1].statement);
+        }
+
+        #[test]
+        fn parse_works_for_not_closed_block() {
+            assert!(parse_script("if defined seed\n  did new").is_err());
+            assert!(parse_script("on-error\n  wallet close").is_err());
+        }
+
+        #[test]
+        fn parse_works_for_unexpected_keyword() {
+            assert!(parse_script("wallet list\nend").is_err());
+            assert!(parse_script("on-error\nelse\nend").is_err());
+        }
+
+        #[test]
+        fn parse_works_for_invalid_assignment() {
+            assert!(parse_script("set 1name = value").is_err());
+            assert!(parse_script("set name value").is_err());
+            assert!(parse_script("set name, other = value").is_err());
+            assert!(parse_script("set name = $()").is_err());
+        }
+    }
+
+    mod execute {
+        use super::*;
+
+        #[test]
+        fn execute_works_for_variables() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            batch.execute("set name = cli\nprompt ${name}\nset captured = $(prompt ${name}-new)".as_bytes()).unwrap();
+
+            assert_eq!("cli-new> ", command_executor.ctx().get_prompt());
+            assert_eq!(Some("cli-new".to_string()), batch._get_variable("captured"));
+        }
+
+        #[test]
+        fn execute_works_for_statements_before_syntax_error() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            batch.execute("on-error\n prompt recovered\nend\nprompt executed\nend".as_bytes()).unwrap_err();
+            assert_eq!("recovered> ", command_executor.ctx().get_prompt());
+
+            batch.execute("prompt executed\nif defined name\n prompt not-closed".as_bytes()).unwrap_err();
+            assert_eq!("executed> ", command_executor.ctx().get_prompt());
+        }
+
+        #[test]
+        fn execute_works_for_undefined_variable() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            batch.execute("prompt ${undefined_cli_variable}".as_bytes()).unwrap_err();
+        }
+
+        #[test]
+        fn execute_works_for_if_block() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            batch.execute("set role = TRUSTEE\nif ${role} == TRUSTEE\n prompt trustee\nelse\n prompt other\nend".as_bytes()).unwrap();
+            assert_eq!("trustee> ", command_executor.ctx().get_prompt());
+
+            batch.execute("if unknown-command\n prompt failed\nelse\n prompt succeeded\nend".as_bytes()).unwrap();
+            assert_eq!("succeeded> ", command_executor.ctx().get_prompt());
+        }
+
+        #[test]
+        fn execute_works_for_on_error_block() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            batch.execute("on-error\n prompt recovered\nend\n-unknown-command\nprompt continued".as_bytes()).unwrap();
+            assert_eq!("continued> ", command_executor.ctx().get_prompt());
+
+            batch.execute("unknown-command\nprompt unreachable".as_bytes()).unwrap_err();
+            assert_eq!("recovered> ", command_executor.ctx().get_prompt());
+        }
+
+        #[test]
+        fn execute_works_for_failed_line() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            batch.execute("prompt first\n-unknown-command\nif defined undefined_cli_variable\nelse\n unknown-command\nend".as_bytes()).unwrap_err();
+            assert_eq!(Some(5), batch.failed_line());
+        }
+
+        #[test]
+        fn execute_works_for_value_with_spaces_and_quotes() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            batch.execute("set name = my cli\nprompt ${name}".as_bytes()).unwrap();
+            assert_eq!("my cli> ", command_executor.ctx().get_prompt());
+
+            env::set_var("INDY_CLI_BATCH_TEST_QUOTED", "my \"cli\"");
+            batch.execute("prompt ${INDY_CLI_BATCH_TEST_QUOTED}".as_bytes()).unwrap();
+            assert_eq!("my \"cli\"> ", command_executor.ctx().get_prompt());
+        }
+
+        #[test]
+        fn execute_works_for_on_error_block_of_included_script() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            let path = EnvironmentUtils::tmp_file_path("batch_on_error_included.txt");
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, "on-error\n prompt included\nend\nprompt included-done").unwrap();
+
+            let script = format!("on-error\n prompt including\nend\ninclude {}\nunknown-command", path.display());
+            batch.execute(script.as_bytes()).unwrap_err();
+
+            assert_eq!("including> ", command_executor.ctx().get_prompt());
+        }
+
+        #[test]
+        fn execute_works_for_environment_variable() {
+            let command_executor = executor();
+            let mut batch = BatchExecutor::new(&command_executor);
+
+            env::set_var("INDY_CLI_BATCH_TEST_PROMPT", "from-env");
+            batch.execute("prompt ${INDY_CLI_BATCH_TEST_PROMPT}".as_bytes()).unwrap();
+
+            assert_eq!("from-env> ", command_executor.ctx().get_prompt());
+        }
+    }
+}
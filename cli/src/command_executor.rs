@@ -11,6 +11,8 @@ use std::error::Error;
 
 use linefeed::{Reader, ReadResult};
 
+use crate::utils::output;
+
 #[derive(Debug)]
 pub struct ParamMetadata {
     name: &'static str,
@@ -399,6 +401,13 @@ impl CommandExecutor {
     }
 
     pub fn execute(&self, line: &str) -> Result<(), ()> {
+        output::begin(&self._command_name(line));
+        let res = self._execute(line);
+        output::finish(&res);
+        res
+    }
+
+    fn _execute(&self, line: &str) -> Result<(), ()> {
         let (cmd, params) = CommandExecutor::_split_first_word(line);
 
         if cmd == "help" {
@@ -415,14 +424,80 @@ impl CommandExecutor {
         }
 
         println_err!("Unknown group or command \"{}\"", cmd);
-        println!("Type \"help\" to display the help");
+        println_out!("Type \"help\" to display the help");
         Err(())
     }
 
+    // Params (like wallet keys and seeds) may be secret, so only group and command names are reported
+    fn _command_name(&self, line: &str) -> String {
+        let (cmd, params) = CommandExecutor::_split_first_word(line);
+
+        if let Some(&(_, ref commands)) = self.grouped_commands.get(cmd) {
+            let (sub_cmd, _) = CommandExecutor::_split_first_word(params);
+
+            if commands.contains_key(sub_cmd) {
+                return format!("{} {}", cmd, sub_cmd);
+            }
+        }
+
+        cmd.to_string()
+    }
+
     pub fn ctx(&self) -> &CommandContext {
         &self.ctx
     }
 
+    pub fn has_command(&self, name: &str) -> bool {
+        name == "help" || self.commands.contains_key(name) || self.grouped_commands.contains_key(name)
+    }
+
+    // Splits line into commands separated by `;` which isn't a part of quoted value
+    pub fn split_commands(line: &str) -> Vec<&str> {
+        let mut commands = Vec::new();
+        let mut is_quote_escape = false;
+        let mut is_quoted = false;
+        let mut start = 0;
+
+        for (pos, ch) in line.char_indices() {
+            if ch == ';' && !is_quoted {
+                commands.push(&line[start..pos]);
+                start = pos + 1;
+            }
+
+            if !is_quote_escape && ch == '"' {
+                is_quoted = !is_quoted;
+            }
+
+            is_quote_escape = ch == '\\';
+        }
+
+        commands.push(&line[start..]);
+
+        commands
+            .into_iter()
+            .map(str::trim)
+            .filter(|command| !command.is_empty())
+            .collect()
+    }
+
+    // Quotes value containing whitespaces, quotes or `;` so it is parsed as a single param of the same command
+    pub fn quote_value(value: &str) -> String {
+        if !value.contains(|ch: char| ch.is_whitespace() || ch == ';' || ch == '"') {
+            return value.to_string();
+        }
+
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    // Quotes command line argument keeping the name of `name=value` param unquoted
+    pub fn quote_param(arg: &str) -> String {
+        match arg.find('=') {
+            Some(pos) if arg[..pos].chars().all(|ch| ch.is_alphanumeric() || ch == '_') =>
+                format!("{}={}", &arg[..pos], CommandExecutor::quote_value(&arg[pos + 1..])),
+            _ => CommandExecutor::quote_value(arg)
+        }
+    }
+
     fn _get_dynamic_completions(&self, dynamic_completion_type: DynamicCompletionType, word: &str) -> Vec<(String, char)> {
         let completions = match dynamic_completion_type {
             DynamicCompletionType::Wallet => crate::commands::wallet::wallet_names(),
@@ -653,7 +728,7 @@ impl CommandExecutor {
         }
 
         println_err!("Unknown command \"{} {}\"", group.metadata().name(), cmd);
-        println!("Type \"{} help\" to display the help for \"{}\" group", group.metadata().name(), group.metadata().name());
+        println_out!("Type \"{} help\" to display the help for \"{}\" group", group.metadata().name(), group.metadata().name());
         Err(())
     }
 
@@ -670,11 +745,11 @@ impl CommandExecutor {
             Err(ref err) => {
                 println_err!("{}", err);
                 if group.is_some() {
-                    println!("Type \"{} {} help\" to display the help for \"{} {}\" command",
+                    println_out!("Type \"{} {} help\" to display the help for \"{} {}\" command",
                              group.unwrap().metadata().name(), command.metadata().name(),
                              group.unwrap().metadata().name(), command.metadata().name());
                 } else {
-                    println!("Type \"{} help\" to display the help for \"{}\" command",
+                    println_out!("Type \"{} help\" to display the help for \"{}\" command",
                              command.metadata().name(),
                              command.metadata().name());
                 }
@@ -685,116 +760,116 @@ impl CommandExecutor {
 
     fn _print_help(&self) {
         println_acc!("Hyperledger Indy CLI");
-        println!();
+        println_out!();
         println_acc!("Usage:");
-        println!("\t[<command-group>] <command> [[<main-param-name>=]<main-param-value>] [<param_name-1>=<param_value-1>]...[<param_name-n>=<param_value-n>]");
-        println!();
+        println_out!("\t[<command-group>] <command> [[<main-param-name>=]<main-param-value>] [<param_name-1>=<param_value-1>]...[<param_name-n>=<param_value-n>]");
+        println_out!();
         println_acc!("Getting help:");
-        println!("\thelp - Display this help");
-        println!("\t<command-group> help - Display the help for the specific command group");
-        println!("\t[<command-group>] <command> help - Display the help for the specific command");
-        println!();
+        println_out!("\thelp - Display this help");
+        println_out!("\t<command-group> help - Display the help for the specific command group");
+        println_out!("\t[<command-group>] <command> help - Display the help for the specific command");
+        println_out!();
         println_acc!("Command groups are:");
 
         for &(ref group, _) in self.grouped_commands.values() {
-            println!("\t{} - {}", group.metadata().name(), group.metadata().help())
+            println_out!("\t{} - {}", group.metadata().name(), group.metadata().help())
         }
 
-        println!();
+        println_out!();
         println_acc!("Top level commands are:");
 
         for command in self.commands.values() {
-            println!("\t{} - {}", command.metadata().name(), command.metadata().help())
+            println_out!("\t{} - {}", command.metadata().name(), command.metadata().help())
         }
 
-        println!();
+        println_out!();
     }
 
     fn _print_group_help(&self, group: &CommandGroup, commands: &HashMap<&'static str, Command>) {
         println_acc!("Group:");
-        println!("\t{} - {}", group.metadata().name(), group.metadata().help());
-        println!();
+        println_out!("\t{} - {}", group.metadata().name(), group.metadata().help());
+        println_out!();
         println_acc!("Usage:");
-        println!("\t{} <command> [[<main-param-name>=]<main-param-value>] [<param_name-1>=<param_value-1>]...[<param_name-n>=<param_value-n>]", group.metadata().name());
-        println!();
+        println_out!("\t{} <command> [[<main-param-name>=]<main-param-value>] [<param_name-1>=<param_value-1>]...[<param_name-n>=<param_value-n>]", group.metadata().name());
+        println_out!();
         println_acc!("Getting help:");
-        println!("\t{} <command> help - Display the help for the specific command", group.metadata().name());
-        println!();
+        println_out!("\t{} <command> help - Display the help for the specific command", group.metadata().name());
+        println_out!();
         println_acc!("Group commands are:");
 
         for command in commands.values() {
-            println!("\t{} - {}", command.metadata().name(), command.metadata().help())
+            println_out!("\t{} - {}", command.metadata().name(), command.metadata().help())
         }
 
-        println!();
+        println_out!();
     }
 
     fn _print_command_help(&self, group: Option<&CommandGroup>, command: &Command) {
         println_acc!("Command:");
 
         if let Some(group) = group {
-            println!("\t{} {} - {}", group.metadata().name(), command.metadata().name(), command.metadata().help());
+            println_out!("\t{} {} - {}", group.metadata().name(), command.metadata().name(), command.metadata().help());
         } else {
-            println!("\t{} - {}", command.metadata().name(), command.metadata().help());
+            println_out!("\t{} - {}", command.metadata().name(), command.metadata().help());
         }
 
-        println!();
+        println_out!();
         println_acc!("Usage:");
 
         if let Some(group) = group {
-            print!("\t{} {}", group.metadata().name(), command.metadata().name());
+            print_out!("\t{} {}", group.metadata().name(), command.metadata().name());
         } else {
-            print!("\t{}", command.metadata().name());
+            print_out!("\t{}", command.metadata().name());
         }
 
         if let Some(ref main_param) = command.metadata().main_param() {
-            print!(" <{}-value>", main_param.name());
+            print_out!(" <{}-value>", main_param.name());
         }
 
         for param in command.metadata().params() {
             match (param.is_optional(), param.is_deferred()) {
-                (true, true) => print!(" [{}[=<{}-value>]]", param.name(), param.name()),
-                (true, false) => print!(" [{}=<{}-value>]", param.name(), param.name()),
-                (false, true) => print!(" {}[=<{}-value>]", param.name(), param.name()),
-                (false, false) => print!(" {}=<{}-value>", param.name(), param.name())
+                (true, true) => print_out!(" [{}[=<{}-value>]]", param.name(), param.name()),
+                (true, false) => print_out!(" [{}=<{}-value>]", param.name(), param.name()),
+                (false, true) => print_out!(" {}[=<{}-value>]", param.name(), param.name()),
+                (false, false) => print_out!(" {}=<{}-value>", param.name(), param.name())
             }
         }
 
-        println!();
+        println_out!();
 
         if command.metadata().main_param().is_some() || !command.metadata().params().is_empty() {
-            println!();
+            println_out!();
             println_acc!("Parameters are:");
 
             if let Some(ref main_param) = command.metadata().main_param() {
-                println!("\t{} - {}", main_param.name(), main_param.help())
+                println_out!("\t{} - {}", main_param.name(), main_param.help())
             }
 
             for param in command.metadata().params() {
-                print!("\t{} - ", param.name());
+                print_out!("\t{} - ", param.name());
 
                 if param.is_optional() {
-                    print!("(optional) ")
+                    print_out!("(optional) ")
                 }
 
                 if param.is_deferred() {
-                    print!("(leave empty for deferred input) ")
+                    print_out!("(leave empty for deferred input) ")
                 }
 
-                println!("{}", param.help());
+                println_out!("{}", param.help());
             }
         }
 
         if !command.metadata().examples().is_empty() {
-            println!();
+            println_out!();
             println_acc!("Examples:");
 
             for example in command.metadata().examples() {
-                println!("\t{}", example);
+                println_out!("\t{}", example);
             }
         }
 
-        println!();
+        println_out!();
     }
 
     fn _parse_params(command: &CommandMetadata, params: &str) -> Result<CommandParams, String> {
@@ -855,13 +930,13 @@ impl CommandExecutor {
         }
 
         for param in deferred_params {
-            println!("Enter value for {}:", param);
+            println_prompt!("Enter value for {}:", param);
             let val;
             loop {
                 match rpassword::read_password() {
                     Ok(v) => {
                         if v.is_empty() {
-                            println!("Please enter value for {}:", param);
+                            println_prompt!("Please enter value for {}:", param);
                         } else {
                             val = v;
                             break;
@@ -869,7 +944,7 @@ impl CommandExecutor {
                     }
                     Err(err) => {
                         println_err!("{}", err.description().to_string());
-                        println!("Please enter value for {}:", param);
+                        println_prompt!("Please enter value for {}:", param);
                     }
                 }
             }
@@ -1054,6 +1129,20 @@ mod tests {
         cmd_executor.execute("test_group test_command \"main param\" param1=\"param1 value\" param2=param2-value").unwrap();
     }
 
+    #[test]
+    pub fn _command_name_works() {
+        let cmd_executor = CommandExecutor::build()
+            .add_group(test_group::new())
+            .add_command(test_command::new())
+            .finalize_group()
+            .add_command(test_command::new())
+            .finalize();
+        assert_eq!("test_group test_command", cmd_executor._command_name("test_group test_command main param1=secret"));
+        assert_eq!("test_command", cmd_executor._command_name("test_command main param1=secret"));
+        assert_eq!("test_group", cmd_executor._command_name("test_group secret"));
+        assert_eq!("unknown_command", cmd_executor._command_name("unknown_command param=secret"));
+    }
+
     #[test]
     pub fn _trim_quites_works() {
         assert_eq!(CommandExecutor::_trim_quotes(""), "");
@@ -1066,4 +1155,55 @@ mod tests {
     pub fn _unescape_works() {
         assert_eq!(unescape("123\\\"456"), Some("123\"456".to_owned()));
     }
+
+    #[test]
+    pub fn has_command_works() {
+        let cmd_executor = CommandExecutor::build()
+            .add_group(test_group::new())
+            .add_command(test_command::new())
+            .finalize_group()
+            .finalize();
+        assert!(cmd_executor.has_command("test_group"));
+        assert!(cmd_executor.has_command("help"));
+        assert!(!cmd_executor.has_command("test_command"));
+        assert!(!cmd_executor.has_command("script.txt"));
+    }
+
+    #[test]
+    pub fn split_commands_works() {
+        assert_eq!(CommandExecutor::split_commands("wallet open w1 key=k ; did list;"), vec!["wallet open w1 key=k", "did list"]);
+        assert_eq!(CommandExecutor::split_commands(r#"ledger custom {"a":"b;c"}; exit"#), vec![r#"ledger custom {"a":"b;c"}"#, "exit"]);
+        assert!(CommandExecutor::split_commands(" ; ").is_empty());
+    }
+
+    #[test]
+    pub fn quote_param_works() {
+        assert_eq!(CommandExecutor::quote_param("key=value"), "key=value");
+        assert_eq!(CommandExecutor::quote_param("key=value with spaces"), "key=\"value with spaces\"");
+        assert_eq!(CommandExecutor::quote_param("{\"a\": 1}"), "\"{\\\"a\\\": 1}\"");
+        assert_eq!(CommandExecutor::quote_param("key=a;b"), "key=\"a;b\"");
+        assert_eq!(CommandExecutor::quote_param("key=a\"b"), "key=\"a\\\"b\"");
+        assert_eq!(CommandExecutor::quote_param("{\"a\":\"b=c\"}"), "\"{\\\"a\\\":\\\"b=c\\\"}\"");
+    }
+
+    #[test]
+    pub fn quote_param_works_for_split_commands() {
+        let line = ["test_command", "main", "param1=value; did list"].iter()
+            .map(|arg| CommandExecutor::quote_param(arg))
+            .collect::<Vec<String>>()
+            .join(" ");
+        assert_eq!(vec![line.as_str()], CommandExecutor::split_commands(&line));
+    }
+
+    #[test]
+    pub fn execute_works_for_quoted_param() {
+        let cmd_executor = CommandExecutor::build()
+            .add_command(test_command::new())
+            .finalize();
+        let line = ["test_command", "main", "param1=param1 value"].iter()
+            .map(|arg| CommandExecutor::quote_param(arg))
+            .collect::<Vec<String>>()
+            .join(" ");
+        cmd_executor.execute(&line).unwrap();
+    }
 }
\ No newline at end of file
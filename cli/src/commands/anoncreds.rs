@@ -0,0 +1,904 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+
+use indy::{ErrorCode, IndyError};
+use crate::libindy::anoncreds::Anoncreds;
+
+use serde_json::Value as JSONValue;
+use serde_json::Map as JSONMap;
+
+use crate::utils::table::print_list_table;
+use crate::utils::file::{read_file, write_file};
+use crate::utils::output::{self, print_json};
+
+pub const CREDENTIALS_SEARCH_COUNT: usize = 100;
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("anoncreds", "Anoncreds management commands"));
+}
+
+pub mod create_schema_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create-schema", "Create Schema by the active DID without sending it to the Ledger.")
+                .add_required_param("name", "Schema name")
+                .add_required_param("version", "Schema version")
+                .add_required_param("attr_names", "Schema attributes split by comma")
+                .add_optional_param("file", "The path to file to store the created Schema json")
+                .add_example("anoncreds create-schema name=gvt version=1.0 attr_names=name,age")
+                .add_example("anoncreds create-schema name=gvt version=1.0 attr_names=name,age file=/home/gvt_schema.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let issuer_did = ensure_active_did(&ctx)?;
+
+        let name = get_str_param("name", params).map_err(error_err!())?;
+        let version = get_str_param("version", params).map_err(error_err!())?;
+        let attr_names = get_str_array_param("attr_names", params).map_err(error_err!())?;
+
+        let attr_names = JSONValue::from(attr_names).to_string();
+
+        let res = match Anoncreds::issuer_create_schema(&issuer_did, name, version, &attr_names) {
+            Ok((schema_id, schema_json)) => {
+                println_succ!("Schema \"{}\" has been created", schema_id);
+                output::add_result(&schema_id);
+                output_json(params, &schema_json)
+            }
+            Err(err) => Err(handle_anoncreds_error(err, Some(&issuer_did), None))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod create_cred_def_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create-cred-def", "Create Credential Definition and store its private keys into the opened wallet.")
+                .add_required_param("schema", "Schema json or the path to file containing it")
+                .add_required_param("tag", "Allows to distinct between credential definitions for the same issuer and schema")
+                .add_optional_param("signature_type", "Signature type (only CL supported now)")
+                .add_optional_param("support_revocation", "Whether the Credential Definition supports revocation (False by default)")
+                .add_optional_param("file", "The path to file to store the created Credential Definition json")
+                .add_example("anoncreds create-cred-def schema=/home/gvt_schema.json tag=1")
+                .add_example("anoncreds create-cred-def schema=/home/gvt_schema.json tag=1 support_revocation=true file=/home/gvt_cred_def.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let issuer_did = ensure_active_did(&ctx)?;
+
+        let schema = get_json_param("schema", params)?;
+        let tag = get_str_param("tag", params).map_err(error_err!())?;
+        let signature_type = get_opt_str_param("signature_type", params).map_err(error_err!())?;
+        let support_revocation = get_opt_bool_param("support_revocation", params).map_err(error_err!())?.unwrap_or(false);
+
+        let config = json!({ "support_revocation": support_revocation }).to_string();
+
+        let res = match Anoncreds::issuer_create_and_store_credential_def(wallet_handle, &issuer_did, &schema, tag, signature_type, &config) {
+            Ok((cred_def_id, cred_def_json)) => {
+                println_succ!("Credential Definition \"{}\" has been created", cred_def_id);
+                output::add_result(&cred_def_id);
+                output_json(params, &cred_def_json)
+            }
+            Err(err) => Err(handle_anoncreds_error(err, Some(&issuer_did), Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod create_revoc_reg_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create-revoc-reg", "Create Revocation Registry for the Credential Definition stored in the opened wallet and generate its tails file.")
+                .add_required_param("cred_def_id", "Identifier of Credential Definition stored in the wallet")
+                .add_required_param("tag", "Allows to distinct between revocation registries for the same Credential Definition")
+                .add_required_param("tails_dir", "The path to directory to store the tails file")
+                .add_optional_param("max_cred_num", "Maximum number of credentials the Registry can serve")
+                .add_optional_param("issuance_type", "ISSUANCE_BY_DEFAULT or ISSUANCE_ON_DEMAND (ISSUANCE_ON_DEMAND by default)")
+                .add_optional_param("def_file", "The path to file to store the created Revocation Registry Definition json")
+                .add_optional_param("entry_file", "The path to file to store the initial Revocation Registry Entry json")
+                .add_example("anoncreds create-revoc-reg cred_def_id=VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1 tag=1 tails_dir=/home/tails")
+                .add_example("anoncreds create-revoc-reg cred_def_id=VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1 tag=1 tails_dir=/home/tails max_cred_num=100 def_file=/home/rev_reg_def.json entry_file=/home/rev_reg_entry.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let issuer_did = ensure_active_did(&ctx)?;
+
+        let cred_def_id = get_str_param("cred_def_id", params).map_err(error_err!())?;
+        let tag = get_str_param("tag", params).map_err(error_err!())?;
+        let tails_dir = get_str_param("tails_dir", params).map_err(error_err!())?;
+        let max_cred_num = get_opt_number_param::<u32>("max_cred_num", params).map_err(error_err!())?;
+        let issuance_type = get_opt_str_param("issuance_type", params).map_err(error_err!())?;
+        let def_file = get_opt_str_param("def_file", params).map_err(error_err!())?;
+        let entry_file = get_opt_str_param("entry_file", params).map_err(error_err!())?;
+
+        let config = {
+            let mut json = JSONMap::new();
+            update_json_map_opt_key!(json, "max_cred_num", max_cred_num);
+            update_json_map_opt_key!(json, "issuance_type", issuance_type);
+            JSONValue::from(json).to_string()
+        };
+
+        let tails_writer_handle = Anoncreds::open_tails_writer(tails_dir)
+            .map_err(|err| handle_anoncreds_error(err, None, None))?;
+
+        let res = match Anoncreds::issuer_create_and_store_revoc_reg(wallet_handle, &issuer_did, None, tag, cred_def_id, &config, tails_writer_handle) {
+            Ok((rev_reg_id, rev_reg_def_json, rev_reg_entry_json)) => {
+                println_succ!("Revocation Registry \"{}\" has been created", rev_reg_id);
+                output::add_result(&rev_reg_id);
+                println_succ!("Revocation Registry Definition:");
+                print_json(&rev_reg_def_json);
+                println_succ!("Revocation Registry Entry:");
+                print_json(&rev_reg_entry_json);
+
+                if let Some(def_file) = def_file {
+                    store_json(def_file, &rev_reg_def_json)?;
+                }
+                if let Some(entry_file) = entry_file {
+                    store_json(entry_file, &rev_reg_entry_json)?;
+                }
+                Ok(())
+            }
+            Err(err) => Err(handle_anoncreds_error(err, Some(&issuer_did), Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod create_master_secret_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create-master-secret", "Create Master Secret and store it into the opened wallet.")
+                .add_optional_param("id", "Identifier of Master Secret (random UUID by default)")
+                .add_example("anoncreds create-master-secret")
+                .add_example("anoncreds create-master-secret id=my_master_secret")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let id = get_opt_str_param("id", params).map_err(error_err!())?;
+
+        let res = match Anoncreds::prover_create_master_secret(wallet_handle, id) {
+            Ok(id) => {
+                println_succ!("Master Secret \"{}\" has been created", id);
+                output::add_result(&id);
+                Ok(())
+            }
+            Err(err) => Err(handle_anoncreds_error(err, None, Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod create_cred_offer_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create-cred-offer", "Create Credential Offer for the Credential Definition stored in the opened wallet.")
+                .add_required_param("cred_def_id", "Identifier of Credential Definition stored in the wallet")
+                .add_optional_param("file", "The path to file to store the created Credential Offer json")
+                .add_example("anoncreds create-cred-offer cred_def_id=VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1")
+                .add_example("anoncreds create-cred-offer cred_def_id=VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1 file=/home/cred_offer.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let cred_def_id = get_str_param("cred_def_id", params).map_err(error_err!())?;
+
+        let res = match Anoncreds::issuer_create_credential_offer(wallet_handle, cred_def_id) {
+            Ok(cred_offer_json) => {
+                println_succ!("Credential Offer has been created");
+                output_json(params, &cred_offer_json)
+            }
+            Err(err) => Err(handle_anoncreds_error(err, None, Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod create_cred_req_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create-cred-req", "Create Credential Request for the Credential Offer by the active DID.")
+                .add_required_param("offer", "Credential Offer json or the path to file containing it")
+                .add_required_param("cred_def", "Credential Definition json or the path to file containing it")
+                .add_required_param("master_secret_id", "Identifier of Master Secret stored in the wallet")
+                .add_optional_param("file", "The path to file to store the created Credential Request json")
+                .add_optional_param("metadata_file", "The path to file to store the Credential Request metadata json needed to store the Credential")
+                .add_example("anoncreds create-cred-req offer=/home/cred_offer.json cred_def=/home/gvt_cred_def.json master_secret_id=my_master_secret")
+                .add_example("anoncreds create-cred-req offer=/home/cred_offer.json cred_def=/home/gvt_cred_def.json master_secret_id=my_master_secret file=/home/cred_req.json metadata_file=/home/cred_req_metadata.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let prover_did = ensure_active_did(&ctx)?;
+
+        let offer = get_json_param("offer", params)?;
+        let cred_def = get_json_param("cred_def", params)?;
+        let master_secret_id = get_str_param("master_secret_id", params).map_err(error_err!())?;
+        let metadata_file = get_opt_str_param("metadata_file", params).map_err(error_err!())?;
+
+        let res = match Anoncreds::prover_create_credential_req(wallet_handle, &prover_did, &offer, &cred_def, master_secret_id) {
+            Ok((cred_req_json, cred_req_metadata_json)) => {
+                println_succ!("Credential Request has been created");
+                output_json(params, &cred_req_json)?;
+
+                println_succ!("Credential Request metadata:");
+                print_json(&cred_req_metadata_json);
+                match metadata_file {
+                    Some(metadata_file) => store_json(metadata_file, &cred_req_metadata_json),
+                    None => Ok(())
+                }
+            }
+            Err(err) => Err(handle_anoncreds_error(err, Some(&prover_did), Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod create_cred_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create-cred", "Issue Credential for the Credential Request.")
+                .add_required_param("offer", "Credential Offer json or the path to file containing it")
+                .add_required_param("request", "Credential Request json or the path to file containing it")
+                .add_required_param("values", r#"Credential attribute values json: {"attr": "raw value"} or {"attr": {"raw": "raw value", "encoded": "encoded value"}}"#)
+                .add_optional_param("rev_reg_id", "Identifier of Revocation Registry stored in the wallet")
+                .add_optional_param("tails_dir", "The path to directory containing the tails file (required with rev_reg_id)")
+                .add_optional_param("file", "The path to file to store the issued Credential json")
+                .add_example(r#"anoncreds create-cred offer=/home/cred_offer.json request=/home/cred_req.json values={"name":"Alex","age":"28"}"#)
+                .add_example(r#"anoncreds create-cred offer=/home/cred_offer.json request=/home/cred_req.json values={"name":"Alex","age":"28"} rev_reg_id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1 tails_dir=/home/tails file=/home/cred.json"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let offer = get_json_param("offer", params)?;
+        let request = get_json_param("request", params)?;
+        let values = get_object_param("values", params).map_err(error_err!())?;
+        let rev_reg_id = get_opt_str_param("rev_reg_id", params).map_err(error_err!())?;
+        let tails_dir = get_opt_str_param("tails_dir", params).map_err(error_err!())?;
+
+        let values = encode_values(values)?;
+
+        let blob_storage_reader_handle = match (rev_reg_id, tails_dir) {
+            (Some(_), Some(tails_dir)) =>
+                Anoncreds::open_tails_reader(tails_dir)
+                    .map_err(|err| handle_anoncreds_error(err, None, None))?,
+            (Some(_), None) => {
+                println_err!("\"tails_dir\" parameter must be specified together with \"rev_reg_id\"");
+                return Err(());
+            }
+            (None, _) => -1
+        };
+
+        let res = match Anoncreds::issuer_create_credential(wallet_handle, &offer, &request, &values, rev_reg_id, blob_storage_reader_handle) {
+            Ok((cred_json, cred_revoc_id, revoc_reg_delta_json)) => {
+                println_succ!("Credential has been issued");
+                if let Some(cred_revoc_id) = cred_revoc_id {
+                    println_succ!("Credential revocation id: \"{}\"", cred_revoc_id);
+                }
+                if let Some(revoc_reg_delta_json) = revoc_reg_delta_json {
+                    println_succ!("Revocation Registry delta:");
+                    print_json(&revoc_reg_delta_json);
+                }
+                output_json(params, &cred_json)
+            }
+            Err(err) => Err(handle_anoncreds_error(err, None, Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+
+    fn encode_values(values: JSONValue) -> Result<String, ()> {
+        let values = match values {
+            JSONValue::Object(values) =>
+                values.into_iter()
+                    .map(|(attr, value)| {
+                        let value = match value {
+                            JSONValue::String(raw) => json!({ "raw": raw }),
+                            JSONValue::Object(_) => value,
+                            value => json!({ "raw": value.to_string() })
+                        };
+                        (attr, value)
+                    })
+                    .collect::<JSONMap<String, JSONValue>>(),
+            _ => {
+                println_err!("Credential values must be a json object");
+                return Err(());
+            }
+        };
+
+        Anoncreds::encode_credential_values(&JSONValue::from(values).to_string())
+            .map_err(|err| handle_anoncreds_error(err, None, None))
+    }
+}
+
+pub mod store_cred_command {
+    use super::*;
+
+    command!(CommandMetadata::build("store-cred", "Store issued Credential into the opened wallet.")
+                .add_required_param("cred", "Credential json or the path to file containing it")
+                .add_required_param("metadata", "Credential Request metadata json or the path to file containing it")
+                .add_required_param("cred_def", "Credential Definition json or the path to file containing it")
+                .add_optional_param("rev_reg_def", "Revocation Registry Definition json or the path to file containing it")
+                .add_optional_param("id", "Identifier by which Credential will be stored in the wallet (random UUID by default)")
+                .add_example("anoncreds store-cred cred=/home/cred.json metadata=/home/cred_req_metadata.json cred_def=/home/gvt_cred_def.json")
+                .add_example("anoncreds store-cred cred=/home/cred.json metadata=/home/cred_req_metadata.json cred_def=/home/gvt_cred_def.json id=my_cred")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let cred = get_json_param("cred", params)?;
+        let metadata = get_json_param("metadata", params)?;
+        let cred_def = get_json_param("cred_def", params)?;
+        let rev_reg_def = get_opt_json_param("rev_reg_def", params)?;
+        let id = get_opt_str_param("id", params).map_err(error_err!())?;
+
+        let res = match Anoncreds::prover_store_credential(wallet_handle, id, &metadata, &cred, &cred_def, rev_reg_def.as_ref().map(String::as_str)) {
+            Ok(id) => Ok(println_succ!("Credential \"{}\" has been stored", id)),
+            Err(err) => Err(handle_anoncreds_error(err, None, Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod list_creds_command {
+    use super::*;
+
+    command!(CommandMetadata::build("list-creds", "List Credentials stored in the opened wallet.")
+                .add_optional_param("filter", r#"Filter json: {"schema_id", "schema_issuer_did", "schema_name", "schema_version", "issuer_did", "cred_def_id"}"#)
+                .add_example("anoncreds list-creds")
+                .add_example(r#"anoncreds list-creds filter={"schema_name":"gvt"}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let filter = get_opt_object_param("filter", params).map_err(error_err!())?
+            .map(|filter| filter.to_string());
+
+        let res = match Anoncreds::prover_get_credentials(wallet_handle, filter.as_ref().map(String::as_str)) {
+            Ok(credentials) => print_credentials(&credentials),
+            Err(err) => Err(handle_anoncreds_error(err, None, Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod search_creds_command {
+    use super::*;
+
+    command!(CommandMetadata::build("search-creds", "Search Credentials stored in the opened wallet with WQL query.")
+                .add_optional_param("query", r#"WQL query json over Credential tags: {"schema_name": "gvt", "attr::name::value": "Alex"}"#)
+                .add_optional_param("count", "The maximum number of Credentials to show (100 by default)")
+                .add_example("anoncreds search-creds")
+                .add_example(r#"anoncreds search-creds query={"attr::name::value":"Alex"} count=10"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let query = get_opt_object_param("query", params).map_err(error_err!())?
+            .map(|query| query.to_string());
+        let count = get_opt_number_param::<usize>("count", params).map_err(error_err!())?.unwrap_or(CREDENTIALS_SEARCH_COUNT);
+
+        let (search_handle, total_count) = Anoncreds::prover_search_credentials(wallet_handle, query.as_ref().map(String::as_str))
+            .map_err(|err| handle_anoncreds_error(err, None, Some(&wallet_name)))?;
+
+        let credentials = Anoncreds::prover_fetch_credentials(search_handle, count)
+            .map_err(|err| handle_anoncreds_error(err, None, Some(&wallet_name)));
+
+        Anoncreds::prover_close_credentials_search(search_handle)
+            .map_err(|err| handle_anoncreds_error(err, None, Some(&wallet_name)))?;
+
+        let res = print_credentials(&credentials?);
+
+        if total_count > count {
+            println_warn!("Shown {} of {} found Credentials", count, total_count);
+        }
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod create_proof_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create-proof", "Create Proof for the Proof Request using Credentials stored in the opened wallet.")
+                .add_required_param("proof_req", "Proof Request json or the path to file containing it")
+                .add_required_param("requested_creds", "Requested Credentials json or the path to file containing it")
+                .add_required_param("master_secret_id", "Identifier of Master Secret stored in the wallet")
+                .add_required_param("schemas", "Json map of Schemas participating in the Proof (schema id -> schema) or the path to file containing it")
+                .add_required_param("cred_defs", "Json map of Credential Definitions participating in the Proof (cred def id -> cred def) or the path to file containing it")
+                .add_optional_param("rev_states", "Json map of Revocation States participating in the Proof or the path to file containing it")
+                .add_optional_param("file", "The path to file to store the created Proof json")
+                .add_example("anoncreds create-proof proof_req=/home/proof_req.json requested_creds=/home/requested_creds.json master_secret_id=my_master_secret schemas=/home/schemas.json cred_defs=/home/cred_defs.json file=/home/proof.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let proof_req = get_json_param("proof_req", params)?;
+        let requested_creds = get_json_param("requested_creds", params)?;
+        let master_secret_id = get_str_param("master_secret_id", params).map_err(error_err!())?;
+        let schemas = get_json_param("schemas", params)?;
+        let cred_defs = get_json_param("cred_defs", params)?;
+        let rev_states = get_opt_json_param("rev_states", params)?.unwrap_or_else(|| "{}".to_string());
+
+        let res = match Anoncreds::prover_create_proof(wallet_handle, &proof_req, &requested_creds, master_secret_id, &schemas, &cred_defs, &rev_states) {
+            Ok(proof_json) => {
+                println_succ!("Proof has been created");
+                output_json(params, &proof_json)
+            }
+            Err(err) => Err(handle_anoncreds_error(err, None, Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod verify_proof_command {
+    use super::*;
+
+    command!(CommandMetadata::build("verify-proof", "Verify Proof for the Proof Request.")
+                .add_required_param("proof_req", "Proof Request json or the path to file containing it")
+                .add_required_param("proof", "Proof json or the path to file containing it")
+                .add_required_param("schemas", "Json map of Schemas participating in the Proof (schema id -> schema) or the path to file containing it")
+                .add_required_param("cred_defs", "Json map of Credential Definitions participating in the Proof (cred def id -> cred def) or the path to file containing it")
+                .add_optional_param("rev_reg_defs", "Json map of Revocation Registry Definitions participating in the Proof or the path to file containing it")
+                .add_optional_param("rev_regs", "Json map of Revocation Registries participating in the Proof or the path to file containing it")
+                .add_example("anoncreds verify-proof proof_req=/home/proof_req.json proof=/home/proof.json schemas=/home/schemas.json cred_defs=/home/cred_defs.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let proof_req = get_json_param("proof_req", params)?;
+        let proof = get_json_param("proof", params)?;
+        let schemas = get_json_param("schemas", params)?;
+        let cred_defs = get_json_param("cred_defs", params)?;
+        let rev_reg_defs = get_opt_json_param("rev_reg_defs", params)?.unwrap_or_else(|| "{}".to_string());
+        let rev_regs = get_opt_json_param("rev_regs", params)?.unwrap_or_else(|| "{}".to_string());
+
+        let res = match Anoncreds::verifier_verify_proof(&proof_req, &proof, &schemas, &cred_defs, &rev_reg_defs, &rev_regs) {
+            Ok(true) => Ok(println_succ!("Proof is valid")),
+            Ok(false) => {
+                println_err!("Proof is invalid");
+                Err(())
+            }
+            Err(err) => Err(handle_anoncreds_error(err, None, None))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub fn get_json_param(name: &str, params: &CommandParams) -> Result<String, ()> {
+    let value = get_str_param(name, params).map_err(error_err!())?;
+    read_json(name, value)
+}
+
+fn get_opt_json_param(name: &str, params: &CommandParams) -> Result<Option<String>, ()> {
+    match get_opt_str_param(name, params).map_err(error_err!())? {
+        Some(value) => read_json(name, value).map(Some),
+        None => Ok(None)
+    }
+}
+
+// Parameter value is either inline json or the path to file containing it
+fn read_json(name: &str, value: &str) -> Result<String, ()> {
+    let trimmed = value.trim_start();
+
+    let json = if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        value.to_string()
+    } else {
+        read_file(value)
+            .map_err(|err| println_err!("Can't read \"{}\" parameter from the file \"{}\": {}", name, value, err))?
+    };
+
+    serde_json::from_str::<JSONValue>(&json)
+        .map_err(|err| println_err!("Can't parse object parameter \"{}\": err {}", name, err))?;
+
+    Ok(json)
+}
+
+pub fn output_json(params: &CommandParams, json: &str) -> Result<(), ()> {
+    print_json(&json);
+
+    match get_opt_str_param("file", params).map_err(error_err!())? {
+        Some(file) => store_json(file, json),
+        None => Ok(())
+    }
+}
+
+fn store_json(file: &str, json: &str) -> Result<(), ()> {
+    write_file(file, json)
+        .map_err(|err| println_err!("Cannot store json into the file: {}", err))?;
+
+    Ok(println_succ!("Json has been saved into the file \"{}\"", file))
+}
+
+fn print_credentials(credentials: &str) -> Result<(), ()> {
+    let credentials: Vec<JSONValue> = serde_json::from_str(credentials)
+        .map_err(|_| println_err!("Wrong data has been received"))?;
+
+    print_list_table(&credentials,
+                     &[("referent", "Referent"),
+                         ("schema_id", "Schema Id"),
+                         ("cred_def_id", "Cred Def Id"),
+                         ("rev_reg_id", "Rev Reg Id"),
+                         ("attrs", "Attributes")],
+                     "There are no credentials");
+
+    Ok(())
+}
+
+fn handle_anoncreds_error(err: IndyError, did: Option<&str>, wallet_name: Option<&str>) {
+    output::set_error_code(err.error_code);
+
+    match err.error_code {
+        ErrorCode::AnoncredsCredDefAlreadyExistsError => println_err!("Credential Definition already exists"),
+        ErrorCode::AnoncredsMasterSecretDuplicateNameError => println_err!("Master Secret already exists"),
+        ErrorCode::AnoncredsRevocationRegistryFullError => println_err!("Revocation Registry is full"),
+        ErrorCode::AnoncredsProofRejected => println_err!("Proof rejected: {}", err.message),
+        ErrorCode::WalletItemNotFound => println_err!("Requested item not found in the wallet: {}", err.message),
+        _ => handle_indy_error(err, did, None, wallet_name)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::{new_did, use_did, SEED_MY1, DID_MY1};
+    use crate::utils::environment::EnvironmentUtils;
+
+    const MASTER_SECRET_ID: &str = "master_secret";
+    const CRED_ID: &str = "cred_1";
+    const CRED_VALUES: &str = r#"{"name":"Alex","age":"28"}"#;
+
+    fn tmp_file(name: &str) -> String {
+        EnvironmentUtils::tmp_file_path(name).to_str().unwrap().to_string()
+    }
+
+    mod create_schema {
+        use super::*;
+
+        #[test]
+        pub fn create_schema_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            {
+                let cmd = create_schema_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", "gvt".to_string());
+                params.insert("version", "1.0".to_string());
+                params.insert("attr_names", "name,age".to_string());
+                params.insert("file", tmp_file("schema.json"));
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let schema: JSONValue = serde_json::from_str(&read_file(tmp_file("schema.json")).unwrap()).unwrap();
+            assert_eq!(schema["id"].as_str().unwrap(), format!("{}:2:gvt:1.0", DID_MY1));
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_schema_works_for_no_active_did() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = create_schema_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", "gvt".to_string());
+                params.insert("version", "1.0".to_string());
+                params.insert("attr_names", "name,age".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod create_cred_def {
+        use super::*;
+
+        #[test]
+        pub fn create_cred_def_works_for_inline_schema() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            let (_, schema) = Anoncreds::issuer_create_schema(DID_MY1, "gvt", "1.0", r#"["name","age"]"#).unwrap();
+            {
+                let cmd = create_cred_def_command::new();
+                let mut params = CommandParams::new();
+                params.insert("schema", schema);
+                params.insert("tag", "1".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_cred_def_works_for_unknown_file() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            {
+                let cmd = create_cred_def_command::new();
+                let mut params = CommandParams::new();
+                params.insert("schema", tmp_file("unknown_schema.json"));
+                params.insert("tag", "1".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod issuance {
+        use super::*;
+
+        #[test]
+        pub fn issue_store_and_prove_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+
+            let cred_def_id = create_cred_def(&ctx);
+            issue_and_store_credential(&ctx, &cred_def_id);
+
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            let credentials = Anoncreds::prover_get_credentials(wallet_handle, None).unwrap();
+            let credentials: Vec<JSONValue> = serde_json::from_str(&credentials).unwrap();
+            assert_eq!(1, credentials.len());
+            assert_eq!(credentials[0]["referent"].as_str().unwrap(), CRED_ID);
+            assert_eq!(credentials[0]["attrs"]["name"].as_str().unwrap(), "Alex");
+
+            let proof_req = json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": { "attr1_referent": { "name": "name" } },
+                "requested_predicates": {}
+            }).to_string();
+            let requested_creds = json!({
+                "self_attested_attributes": {},
+                "requested_attributes": { "attr1_referent": { "cred_id": CRED_ID, "revealed": true } },
+                "requested_predicates": {}
+            }).to_string();
+            let schema: JSONValue = serde_json::from_str(&read_file(tmp_file("schema.json")).unwrap()).unwrap();
+            let cred_def: JSONValue = serde_json::from_str(&read_file(tmp_file("cred_def.json")).unwrap()).unwrap();
+            let schema_id = schema["id"].as_str().unwrap().to_string();
+            let schemas = json!({ schema_id: schema }).to_string();
+            let cred_defs = json!({ cred_def_id.as_str(): cred_def }).to_string();
+            {
+                let cmd = create_proof_command::new();
+                let mut params = CommandParams::new();
+                params.insert("proof_req", proof_req.clone());
+                params.insert("requested_creds", requested_creds);
+                params.insert("master_secret_id", MASTER_SECRET_ID.to_string());
+                params.insert("schemas", schemas.clone());
+                params.insert("cred_defs", cred_defs.clone());
+                params.insert("file", tmp_file("proof.json"));
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = verify_proof_command::new();
+                let mut params = CommandParams::new();
+                params.insert("proof_req", proof_req);
+                params.insert("proof", tmp_file("proof.json"));
+                params.insert("schemas", schemas);
+                params.insert("cred_defs", cred_defs);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_cred_works_for_rev_reg_id_without_tails_dir() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            {
+                let cmd = create_cred_command::new();
+                let mut params = CommandParams::new();
+                params.insert("offer", "{}".to_string());
+                params.insert("request", "{}".to_string());
+                params.insert("values", CRED_VALUES.to_string());
+                params.insert("rev_reg_id", "VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_master_secret_works_for_duplicate() {
+            let ctx = setup_with_wallet();
+            create_master_secret(&ctx);
+            {
+                let cmd = create_master_secret_command::new();
+                let mut params = CommandParams::new();
+                params.insert("id", MASTER_SECRET_ID.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod list_creds {
+        use super::*;
+
+        #[test]
+        pub fn list_creds_works_for_empty_result() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = list_creds_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn list_and_search_creds_work() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+
+            let cred_def_id = create_cred_def(&ctx);
+            issue_and_store_credential(&ctx, &cred_def_id);
+            {
+                let cmd = list_creds_command::new();
+                let mut params = CommandParams::new();
+                params.insert("filter", json!({ "cred_def_id": cred_def_id }).to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = search_creds_command::new();
+                let mut params = CommandParams::new();
+                params.insert("query", r#"{"attr::name::value":"Alex"}"#.to_string());
+                params.insert("count", "10".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn list_creds_works_for_no_opened_wallet() {
+            let ctx = setup();
+            {
+                let cmd = list_creds_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    fn create_master_secret(ctx: &CommandContext) {
+        let cmd = create_master_secret_command::new();
+        let mut params = CommandParams::new();
+        params.insert("id", MASTER_SECRET_ID.to_string());
+        cmd.execute(&ctx, &params).unwrap();
+    }
+
+    pub fn create_cred_def(ctx: &CommandContext) -> String {
+        {
+            let cmd = create_schema_command::new();
+            let mut params = CommandParams::new();
+            params.insert("name", "gvt".to_string());
+            params.insert("version", "1.0".to_string());
+            params.insert("attr_names", "name,age".to_string());
+            params.insert("file", tmp_file("schema.json"));
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        {
+            let cmd = create_cred_def_command::new();
+            let mut params = CommandParams::new();
+            params.insert("schema", tmp_file("schema.json"));
+            params.insert("tag", "1".to_string());
+            params.insert("file", tmp_file("cred_def.json"));
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        let cred_def: JSONValue = serde_json::from_str(&read_file(tmp_file("cred_def.json")).unwrap()).unwrap();
+        cred_def["id"].as_str().unwrap().to_string()
+    }
+
+    pub fn issue_and_store_credential(ctx: &CommandContext, cred_def_id: &str) {
+        create_master_secret(ctx);
+        {
+            let cmd = create_cred_offer_command::new();
+            let mut params = CommandParams::new();
+            params.insert("cred_def_id", cred_def_id.to_string());
+            params.insert("file", tmp_file("cred_offer.json"));
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        {
+            let cmd = create_cred_req_command::new();
+            let mut params = CommandParams::new();
+            params.insert("offer", tmp_file("cred_offer.json"));
+            params.insert("cred_def", tmp_file("cred_def.json"));
+            params.insert("master_secret_id", MASTER_SECRET_ID.to_string());
+            params.insert("file", tmp_file("cred_req.json"));
+            params.insert("metadata_file", tmp_file("cred_req_metadata.json"));
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        {
+            let cmd = create_cred_command::new();
+            let mut params = CommandParams::new();
+            params.insert("offer", tmp_file("cred_offer.json"));
+            params.insert("request", tmp_file("cred_req.json"));
+            params.insert("values", CRED_VALUES.to_string());
+            params.insert("file", tmp_file("cred.json"));
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        {
+            let cmd = store_cred_command::new();
+            let mut params = CommandParams::new();
+            params.insert("cred", tmp_file("cred.json"));
+            params.insert("metadata", tmp_file("cred_req_metadata.json"));
+            params.insert("cred_def", tmp_file("cred_def.json"));
+            params.insert("id", CRED_ID.to_string());
+            cmd.execute(&ctx, &params).unwrap();
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::commands::get_str_param;
 
 use crate::utils::logger;
 use crate::utils::file::read_file;
+use crate::utils::output::{self, OutputFormat};
 
 pub mod about_command {
     use super::*;
@@ -17,15 +18,15 @@ pub mod about_command {
         trace!("execute >> _ctx: params: {:?}", _params);
 
         println_succ!("Hyperledger Indy CLI (https://github.com/hyperledger/indy-sdk)");
-        println!();
+        println_out!();
         println_succ!("This is the official CLI tool for Hyperledger Indy (https://www.hyperledger.org/projects),");
         println_succ!("which provides a distributed-ledger-based foundation for");
         println_succ!("self-sovereign identity (https://sovrin.org/).");
-        println!();
+        println_out!();
         println_succ!("Version: {}", env!("CARGO_PKG_VERSION"));
         println_succ!("Apache License Version 2.0");
         println_succ!("Copyright 2017 Sovrin Foundation");
-        println!();
+        println_out!();
 
         let res = Ok(());
 
@@ -50,7 +51,7 @@ pub mod show_command {
         let content = read_file(file)
             .map_err(|err| println_err!("{}", err))?;
 
-        println!("{}", content);
+        println_out!("{}", content);
         let res = Ok(());
 
         trace!("execute << {:?}", res);
@@ -73,6 +74,33 @@ pub mod prompt_command {
 
         ctx.set_main_prompt(prompt.to_owned());
         println_succ!("Command prompt has been set to \"{}\"", prompt);
+        output::add_result(prompt);
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod output_command {
+    use super::*;
+
+    command!(CommandMetadata::build("output", "Change output format of commands")
+                            .add_main_param("format", "Output format: text (default) or json")
+                            .add_example("output json")
+                            .add_example("output text")
+                            .finalize());
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> CommandResult {
+        trace!("execute >> ctx: {:?}, params: {:?}", ctx, params);
+
+        let format = get_str_param("format", params).map_err(error_err!())?;
+
+        let format = OutputFormat::from_str(format)
+            .ok_or_else(|| println_err!("Unsupported output format \"{}\"", format))?;
+
+        println_succ!("Output format has been set to \"{:?}\"", format);
+        output::set_format(format);
         let res = Ok(());
 
         trace!("execute << {:?}", res);
@@ -190,6 +218,36 @@ pub mod tests {
     #[cfg(any(windows))]
     pub const NULL_PAYMENT_PLUGIN: &'static str = "nullpay.dll";
 
+    mod output_format {
+        use super::*;
+
+        #[test]
+        pub fn output_works() {
+            let ctx = CommandContext::new();
+
+            let cmd = output_command::new();
+            let mut params = CommandParams::new();
+            params.insert("format", "json".to_string());
+            cmd.execute(&ctx, &params).unwrap();
+            assert_eq!(OutputFormat::Json, output::get_format());
+
+            params.insert("format", "text".to_string());
+            cmd.execute(&ctx, &params).unwrap();
+            assert_eq!(OutputFormat::Text, output::get_format());
+        }
+
+        #[test]
+        pub fn output_works_for_unknown_format() {
+            let ctx = CommandContext::new();
+
+            let cmd = output_command::new();
+            let mut params = CommandParams::new();
+            params.insert("format", "xml".to_string());
+            cmd.execute(&ctx, &params).unwrap_err();
+            assert_eq!(OutputFormat::Text, output::get_format());
+        }
+    }
+
     mod load {
         use super::*;
         use crate::utils::test::TestUtils;
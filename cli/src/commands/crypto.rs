@@ -0,0 +1,599 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+
+use indy::{ErrorCode, IndyError};
+use crate::libindy::crypto::Crypto;
+use crate::libindy::did::Did;
+
+use rust_base58::{FromBase58, ToBase58};
+use serde_json::Value as JSONValue;
+
+use crate::utils::table::print_table;
+use crate::utils::output::{self, print_json};
+
+use std::fs;
+use std::path::Path;
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("crypto", "Cryptographic commands using keys of the opened wallet"));
+}
+
+pub mod sign_command {
+    use super::*;
+
+    command!(CommandMetadata::build("sign", "Sign message or file content with key of the active or specified DID.")
+                .add_optional_param("message", "Message to sign")
+                .add_optional_param("file", "Path to the file which content to sign")
+                .add_optional_param("signer", "DID or verkey of the signer (active DID is used by default)")
+                .add_example("crypto sign message=hello")
+                .add_example("crypto sign file=/home/message.txt signer=VsKV7grR1BUE29mG2Fm2kX")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let message = get_message_param(params)?;
+        let signer = get_key_param(ctx, wallet_handle, &wallet_name, "signer", params)?;
+
+        let res = match Crypto::sign(wallet_handle, &signer, &message) {
+            Ok(signature) => {
+                println_succ!("Signature \"{}\" has been created with \"{}\" key", signature.to_base58(), signer);
+                Ok(())
+            }
+            Err(err) => Err(handle_crypto_error(err, &signer, &wallet_name))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod verify_command {
+    use super::*;
+
+    command!(CommandMetadata::build("verify", "Verify signature of message or file content.")
+                .add_required_param("signature", "Base58 encoded signature")
+                .add_optional_param("message", "Signed message")
+                .add_optional_param("file", "Path to the file which content is signed")
+                .add_optional_param("signer", "DID or verkey of the signer (active DID is used by default). \
+                                               DID must be stored in the opened wallet")
+                .add_example("crypto verify signature=3dS5w5YLkFQfGqb8d8MnUd1Xv5VEABfMWyuVRD7LNKwT9AzWDUEhsbU3D4rktGnUFRsuXfp5xXJn7Y7bnBQw9awR \
+                              message=hello signer=GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let signature = get_str_param("signature", params).map_err(error_err!())?;
+        let signature = signature.from_base58()
+            .map_err(|_| println_err!("Signature must be base58 encoded"))?;
+
+        let message = get_message_param(params)?;
+
+        // Verification doesn't need the wallet if verkey of signer is specified explicitly
+        let signer = match get_opt_str_param("signer", params).map_err(error_err!())? {
+            Some(signer) if !is_did(signer) => signer.to_string(),
+            _ => {
+                let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+                get_key_param(ctx, wallet_handle, &wallet_name, "signer", params)?
+            }
+        };
+
+        let res = match Crypto::verify(&signer, &message, &signature) {
+            Ok(true) => {
+                println_succ!("Signature is valid for \"{}\" key", signer);
+                Ok(())
+            }
+            Ok(false) => {
+                println_err!("Signature is invalid for \"{}\" key", signer);
+                Err(())
+            }
+            Err(err) => Err(handle_indy_error(err, None, None, None))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod pack_command {
+    use super::*;
+
+    command!(CommandMetadata::build("pack", "Pack message or file content for recipients (authcrypted with key of the active DID by default).")
+                .add_required_param("recipients", "DIDs or verkeys of recipients separated by comma. DIDs must be stored in the opened wallet")
+                .add_optional_param("message", "Message to pack")
+                .add_optional_param("file", "Path to the file which content to pack")
+                .add_optional_param("sender", "DID or verkey of the sender (active DID is used by default)")
+                .add_optional_param("anoncrypt", "Pack message without sender authentication (false by default)")
+                .add_optional_param("out_file", "Path to the file to store packed message")
+                .add_example("crypto pack recipients=GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa message=hello")
+                .add_example("crypto pack recipients=VsKV7grR1BUE29mG2Fm2kX,CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW file=/home/message.json anoncrypt=true out_file=/home/packed.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let recipients = get_str_array_param("recipients", params).map_err(error_err!())?
+            .into_iter()
+            .map(|recipient| resolve_key(wallet_handle, &wallet_name, recipient))
+            .collect::<Result<Vec<String>, ()>>()?;
+        let message = get_message_param(params)?;
+        let anoncrypt = get_opt_bool_param("anoncrypt", params).map_err(error_err!())?.unwrap_or(false);
+        let out_file = get_opt_str_param("out_file", params).map_err(error_err!())?;
+
+        let sender = if anoncrypt { None } else { Some(get_key_param(ctx, wallet_handle, &wallet_name, "sender", params)?) };
+
+        let res = match Crypto::pack_message(wallet_handle, &message, &json!(recipients).to_string(), sender.as_ref().map(String::as_str)) {
+            Ok(jwe) => {
+                let jwe = String::from_utf8_lossy(&jwe).to_string();
+
+                match out_file {
+                    Some(out_file) => {
+                        write_bytes(out_file, jwe.as_bytes())?;
+                        println_succ!("Packed message has been saved into the file \"{}\"", out_file);
+                    }
+                    None => print_json(&jwe)
+                }
+
+                match sender {
+                    Some(sender) => println_succ!("Message has been packed for {} recipient(s) with \"{}\" sender key", recipients.len(), sender),
+                    None => println_succ!("Message has been anonymously packed for {} recipient(s)", recipients.len())
+                }
+                Ok(())
+            }
+            Err(err) => Err(handle_crypto_error(err, "", &wallet_name))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod unpack_command {
+    use super::*;
+
+    command!(CommandMetadata::build("unpack", "Unpack message packed for a key of the opened wallet.")
+                .add_optional_param("message", "Packed message (JWE json)")
+                .add_optional_param("file", "Path to the file containing packed message")
+                .add_optional_param("out_file", "Path to the file to store unpacked message")
+                .add_example(r#"crypto unpack message={"protected":"eyJlbmMi...","iv":"...","ciphertext":"...","tag":"..."}"#)
+                .add_example("crypto unpack file=/home/packed.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let jwe = get_message_param(params)?;
+        let out_file = get_opt_str_param("out_file", params).map_err(error_err!())?;
+
+        let res = match Crypto::unpack_message(wallet_handle, &jwe) {
+            Ok(unpacked) => {
+                let unpacked = serde_json::from_slice::<JSONValue>(&unpacked)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                if let Some(out_file) = out_file {
+                    let message = unpacked["message"].as_str().unwrap_or("");
+                    write_bytes(out_file, message.as_bytes())?;
+                    println_succ!("Unpacked message has been saved into the file \"{}\"", out_file);
+                }
+
+                print_table(&unpacked, &[("sender_verkey", "Sender verkey"),
+                                         ("recipient_verkey", "Recipient verkey"),
+                                         ("message", "Message")]);
+
+                if unpacked["sender_verkey"].is_null() {
+                    println_warn!("Message has been anonymously packed, sender is unknown");
+                }
+                Ok(())
+            }
+            Err(err) => Err(handle_crypto_error(err, "", &wallet_name))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod anoncrypt_command {
+    use super::*;
+
+    command!(CommandMetadata::build("anoncrypt", "Encrypt message or file content for the recipient anonymously (sealed box).")
+                .add_required_param("recipient", "DID or verkey of the recipient. DID must be stored in the opened wallet")
+                .add_optional_param("message", "Message to encrypt")
+                .add_optional_param("file", "Path to the file which content to encrypt")
+                .add_optional_param("out_file", "Path to the file to store encrypted bytes (base58 encoded message is printed by default)")
+                .add_example("crypto anoncrypt recipient=GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa message=hello")
+                .add_example("crypto anoncrypt recipient=VsKV7grR1BUE29mG2Fm2kX file=/home/message.txt out_file=/home/message.enc")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let recipient = get_str_param("recipient", params).map_err(error_err!())?;
+        let recipient = if is_did(recipient) {
+            let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+            resolve_key(wallet_handle, &wallet_name, recipient)?
+        } else {
+            recipient.to_string()
+        };
+
+        let message = get_message_param(params)?;
+        let out_file = get_opt_str_param("out_file", params).map_err(error_err!())?;
+
+        let res = match Crypto::anon_crypt(&recipient, &message) {
+            Ok(encrypted) => {
+                match out_file {
+                    Some(out_file) => {
+                        write_bytes(out_file, &encrypted)?;
+                        println_succ!("Encrypted message has been saved into the file \"{}\"", out_file);
+                    }
+                    None => println_succ!("Message \"{}\" has been encrypted for \"{}\" key", encrypted.to_base58(), recipient)
+                }
+                Ok(())
+            }
+            Err(err) => Err(handle_indy_error(err, None, None, None))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod anondecrypt_command {
+    use super::*;
+
+    command!(CommandMetadata::build("anondecrypt", "Decrypt anonymously encrypted message with key of the active or specified DID.")
+                .add_optional_param("message", "Base58 encoded encrypted message")
+                .add_optional_param("file", "Path to the file containing encrypted bytes")
+                .add_optional_param("recipient", "DID or verkey of the recipient (active DID is used by default)")
+                .add_optional_param("out_file", "Path to the file to store decrypted message")
+                .add_example("crypto anondecrypt message=3mhgTUSKRPe7ubqQ6ZzFs1BQdP8ZN8VPYyMz4s3Z7fYpz8Kr4Rd")
+                .add_example("crypto anondecrypt file=/home/message.enc recipient=VsKV7grR1BUE29mG2Fm2kX out_file=/home/message.txt")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let encrypted = match get_opt_str_param("message", params).map_err(error_err!())? {
+            Some(message) => message.from_base58()
+                .map_err(|_| println_err!("Encrypted message must be base58 encoded"))?,
+            None => get_message_param(params)?
+        };
+        let recipient = get_key_param(ctx, wallet_handle, &wallet_name, "recipient", params)?;
+        let out_file = get_opt_str_param("out_file", params).map_err(error_err!())?;
+
+        let res = match Crypto::anon_decrypt(wallet_handle, &recipient, &encrypted) {
+            Ok(message) => {
+                match out_file {
+                    Some(out_file) => {
+                        write_bytes(out_file, &message)?;
+                        println_succ!("Decrypted message has been saved into the file \"{}\"", out_file);
+                    }
+                    None => println_succ!("Message \"{}\" has been decrypted with \"{}\" key", String::from_utf8_lossy(&message), recipient)
+                }
+                Ok(())
+            }
+            Err(err) => Err(handle_crypto_error(err, &recipient, &wallet_name))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+// Message is passed either inline or as the path to file
+fn get_message_param(params: &CommandParams) -> Result<Vec<u8>, ()> {
+    let message = get_opt_str_param("message", params).map_err(error_err!())?;
+    let file = get_opt_str_param("file", params).map_err(error_err!())?;
+
+    match (message, file) {
+        (Some(message), None) => Ok(message.as_bytes().to_vec()),
+        (None, Some(file)) => fs::read(file)
+            .map_err(|err| println_err!("Can't read the file \"{}\": {}", file, err)),
+        _ => {
+            println_err!("Either \"message\" or \"file\" parameter must be specified");
+            Err(())
+        }
+    }
+}
+
+// Returns verkey specified by the parameter or verkey of the active DID
+fn get_key_param(ctx: &CommandContext, wallet_handle: i32, wallet_name: &str, name: &str, params: &CommandParams) -> Result<String, ()> {
+    match get_opt_str_param(name, params).map_err(error_err!())? {
+        Some(key) => resolve_key(wallet_handle, wallet_name, key),
+        None => {
+            let did = ensure_active_did(&ctx)?;
+            resolve_key(wallet_handle, wallet_name, &did)
+        }
+    }
+}
+
+fn resolve_key(wallet_handle: i32, wallet_name: &str, key: &str) -> Result<String, ()> {
+    if !is_did(key) {
+        return Ok(key.to_string());
+    }
+
+    Did::key_for_local_did(wallet_handle, key)
+        .map_err(|err| handle_crypto_error(err, key, wallet_name))
+}
+
+// Base58 encoded ed25519 verkeys contain 43-44 symbols, so shorter values (except abbreviated verkeys) are DIDs
+fn is_did(value: &str) -> bool {
+    value.starts_with("did:") || (value.len() <= 22 && !value.starts_with('~'))
+}
+
+fn write_bytes(file: &str, content: &[u8]) -> Result<(), ()> {
+    if let Some(parent) = Path::new(file).parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| println_err!("Can't create the file \"{}\": {}", file, err))?;
+    }
+
+    fs::write(file, content)
+        .map_err(|err| println_err!("Can't write the file \"{}\": {}", file, err))
+}
+
+fn handle_crypto_error(err: IndyError, key: &str, wallet_name: &str) {
+    output::set_error_code(err.error_code);
+
+    match err.error_code {
+        ErrorCode::WalletItemNotFound => println_err!("Key or DID \"{}\" not found in the wallet \"{}\"", key, wallet_name),
+        ErrorCode::CommonInvalidStructure => println_err!("Invalid message or key: {}", err.message),
+        _ => handle_indy_error(err, None, None, Some(wallet_name))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::{new_did, use_did, SEED_MY1, DID_MY1, VERKEY_MY1, SEED_MY3, DID_MY3, VERKEY_MY3};
+    use crate::utils::environment::EnvironmentUtils;
+
+    const MESSAGE: &str = "hello";
+
+    fn sign(ctx: &CommandContext) -> String {
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        Crypto::sign(wallet_handle, VERKEY_MY1, MESSAGE.as_bytes()).unwrap().to_base58()
+    }
+
+    mod sign {
+        use super::*;
+
+        #[test]
+        pub fn sign_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            {
+                let cmd = sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("message", MESSAGE.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn sign_works_for_file() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+
+            let path = EnvironmentUtils::tmp_file_path("crypto_sign_message");
+            let path = path.to_str().unwrap();
+            write_bytes(path, MESSAGE.as_bytes()).unwrap();
+            {
+                let cmd = sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path.to_string());
+                params.insert("signer", DID_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn sign_works_for_no_active_did() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("message", MESSAGE.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn sign_works_for_message_and_file() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            {
+                let cmd = sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("message", MESSAGE.to_string());
+                params.insert("file", "/tmp/message".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod verify {
+        use super::*;
+
+        #[test]
+        pub fn verify_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            let signature = sign(&ctx);
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("signature", signature);
+                params.insert("message", MESSAGE.to_string());
+                params.insert("signer", DID_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn verify_works_for_other_message() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            let signature = sign(&ctx);
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("signature", signature);
+                params.insert("message", "other".to_string());
+                params.insert("signer", VERKEY_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod pack {
+        use super::*;
+
+        #[test]
+        pub fn pack_unpack_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            new_did(&ctx, SEED_MY3);
+            use_did(&ctx, DID_MY1);
+
+            let path = EnvironmentUtils::tmp_file_path("crypto_packed_message");
+            let path = path.to_str().unwrap();
+            {
+                let cmd = pack_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipients", format!("{},{}", DID_MY3, VERKEY_MY1));
+                params.insert("message", MESSAGE.to_string());
+                params.insert("out_file", path.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = unpack_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            let unpacked = Crypto::unpack_message(wallet_handle, &fs::read(path).unwrap()).unwrap();
+            let unpacked = serde_json::from_slice::<JSONValue>(&unpacked).unwrap();
+            assert_eq!(MESSAGE, unpacked["message"].as_str().unwrap());
+            assert_eq!(VERKEY_MY1, unpacked["sender_verkey"].as_str().unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn pack_works_for_anoncrypt() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = pack_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipients", VERKEY_MY3.to_string());
+                params.insert("message", MESSAGE.to_string());
+                params.insert("anoncrypt", "true".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn pack_works_for_unknown_recipient_did() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            {
+                let cmd = pack_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipients", DID_MY3.to_string());
+                params.insert("message", MESSAGE.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn unpack_works_for_invalid_message() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = unpack_command::new();
+                let mut params = CommandParams::new();
+                params.insert("message", r#"{"protected":"invalid"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod anoncrypt {
+        use super::*;
+
+        #[test]
+        pub fn anoncrypt_anondecrypt_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+
+            let path = EnvironmentUtils::tmp_file_path("crypto_anoncrypted_message");
+            let path = path.to_str().unwrap();
+            {
+                let cmd = anoncrypt_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipient", DID_MY1.to_string());
+                params.insert("message", MESSAGE.to_string());
+                params.insert("out_file", path.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = anondecrypt_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn anondecrypt_works_for_other_recipient() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            new_did(&ctx, SEED_MY3);
+
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            let encrypted = Crypto::anon_crypt(VERKEY_MY1, MESSAGE.as_bytes()).unwrap();
+            {
+                let cmd = anondecrypt_command::new();
+                let mut params = CommandParams::new();
+                params.insert("message", encrypted.to_base58());
+                params.insert("recipient", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+
+            assert_eq!(MESSAGE.as_bytes(), Crypto::anon_decrypt(wallet_handle, VERKEY_MY1, &encrypted).unwrap().as_slice());
+            tear_down_with_wallet(&ctx);
+        }
+    }
+}
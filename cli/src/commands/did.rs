@@ -1,6 +1,7 @@
 use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata, DynamicCompletionType};
 use crate::commands::*;
 use crate::utils::table::print_list_table;
+use crate::utils::output;
 
 use indy::ErrorCode;
 
@@ -74,6 +75,8 @@ pub mod new_command {
         let res = match res {
             Ok((did, vk)) => {
                 println_succ!("Did \"{}\" has been created with \"{}\" verkey", did, vk);
+                output::add_result(&did);
+                output::add_result(&vk);
                 Ok(did)
             }
             Err(err) => {
@@ -194,9 +197,11 @@ pub mod use_command {
             Ok(_) => {
                 set_active_did(ctx, Some(did.to_owned()));
                 println_succ!("Did \"{}\" has been set as active", did);
+                output::add_result(did);
                 Ok(())
             }
             Err(err) => {
+                output::set_error_code(err.error_code);
                 match err.error_code {
                     ErrorCode::WalletItemNotFound => {
                         println_err!("Requested DID not found");
@@ -315,6 +320,7 @@ pub mod rotate_key_command {
             let new_verkey = match Did::replace_keys_start(wallet_handle, &did, &identity_json) {
                 Ok(request) => Ok(request),
                 Err(err) => {
+                    output::set_error_code(err.error_code);
                     match err.error_code {
                         ErrorCode::WalletItemNotFound => {
                             println_err!("Active DID: \"{}\" not found", did);
@@ -341,6 +347,7 @@ pub mod rotate_key_command {
 
             let response_json = Ledger::sign_and_submit_request(pool_handle, wallet_handle, &did, &request)
                 .map_err(|err| {
+                    output::set_error_code(err.error_code);
                     match err.error_code {
                         ErrorCode::PoolLedgerTimeout => {
                             println_err!("Transaction response has not beed received");
@@ -363,9 +370,11 @@ pub mod rotate_key_command {
             Ok(vk) => {
                 println_succ!("Verkey for did \"{}\" has been updated", did);
                 println_succ!("New verkey is \"{}\"", vk);
+                output::add_result(&vk);
                 Ok(())
             },
             Err(err) => {
+                output::set_error_code(err.error_code);
                 match err.error_code {
                     ErrorCode::WalletItemNotFound => {
                         println_err!("Active DID: \"{}\" not found", did);
@@ -468,6 +477,7 @@ pub mod qualify_command {
         let res = match Did::qualify_did(wallet_handle, &did, &method) {
             Ok(full_qualified_did) => {
                 println_succ!("Fully qualified DID \"{}\"", full_qualified_did);
+                output::add_result(&full_qualified_did);
 
                 if let Some(active_did) = get_active_did(&ctx) {
                     if active_did == did {
@@ -479,6 +489,7 @@ pub mod qualify_command {
                 Ok(())
             }
             Err(err) => {
+                output::set_error_code(err.error_code);
                 match err.error_code {
                     ErrorCode::WalletItemNotFound => {
                         println_err!("Requested DID not found");
@@ -497,6 +508,55 @@ pub mod qualify_command {
     }
 }
 
+pub mod store_their_command {
+    use super::*;
+
+    command!(CommandMetadata::build("store-their", "Store DID of other party in the opened wallet to use it for pairwise and crypto commands.")
+                .add_main_param("did", "Their DID")
+                .add_optional_param("verkey", "Verkey of their DID (can be omitted if DID is cryptonym)")
+                .add_example("did store-their VsKV7grR1BUE29mG2Fm2kX verkey=GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa")
+                .finalize());
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?}, params {:?}", ctx, params);
+
+        let did = get_str_param("did", params).map_err(error_err!())?;
+        let verkey = get_opt_str_param("verkey", params).map_err(error_err!())?;
+
+        let wallet_handle = ensure_opened_wallet_handle(ctx)?;
+
+        let identity = {
+            let mut json = JSONMap::new();
+            json.insert("did".to_string(), JSONValue::from(did));
+            update_json_map_opt_key!(json, "verkey", verkey);
+            JSONValue::from(json).to_string()
+        };
+
+        let res = match Did::store_their_did(wallet_handle, &identity) {
+            Ok(()) => {
+                println_succ!("Their DID \"{}\" has been stored", did);
+                Ok(())
+            }
+            Err(err) => {
+                output::set_error_code(err.error_code);
+                match err.error_code {
+                    ErrorCode::CommonInvalidStructure => {
+                        println_err!("Invalid DID \"{}\" or verkey", did);
+                        Err(())
+                    },
+                    _ => {
+                        handle_indy_error(err, Some(&did), None, None);
+                        Err(())
+                    },
+                }
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 fn _list_dids(ctx: &CommandContext) -> Vec<serde_json::Value> {
     get_opened_wallet(ctx)
         .and_then(|(wallet_handle, _)|
@@ -1027,6 +1087,52 @@ pub mod tests {
         }
     }
 
+    mod did_store_their {
+        use super::*;
+
+        #[test]
+        pub fn store_their_works() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = store_their_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", DID_MY1.to_string());
+                params.insert("verkey", VERKEY_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            assert_eq!(VERKEY_MY1, Did::key_for_local_did(wallet_handle, DID_MY1).unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn store_their_works_for_invalid_verkey() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = store_their_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", DID_MY1.to_string());
+                params.insert("verkey", "invalid_base58_verkey".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn store_their_works_for_no_opened_wallet() {
+            let ctx = setup();
+            {
+                let cmd = store_their_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", DID_MY1.to_string());
+                params.insert("verkey", VERKEY_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
     fn get_did_info(ctx: &CommandContext, did: &str) -> serde_json::Value {
         let wallet_handle = ensure_opened_wallet_handle(ctx).unwrap();
         let did_info = Did::get_did_with_meta(wallet_handle, did).unwrap();
@@ -1056,4 +1162,14 @@ pub mod tests {
             cmd.execute(&ctx, &params).unwrap();
         }
     }
+
+    pub fn store_their_did(ctx: &CommandContext, did: &str, verkey: &str) {
+        {
+            let cmd = store_their_command::new();
+            let mut params = CommandParams::new();
+            params.insert("did", did.to_string());
+            params.insert("verkey", verkey.to_string());
+            cmd.execute(&ctx, &params).unwrap();
+        }
+    }
 }
@@ -4,10 +4,12 @@ extern crate chrono;
 use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata, DynamicCompletionType};
 use crate::commands::*;
 use crate::commands::payment_address::handle_payment_error;
+use crate::commands::anoncreds::{get_json_param, output_json};
 
 use indy::{ErrorCode, IndyError};
 use crate::libindy::ledger::Ledger;
 use crate::libindy::payment::Payment;
+use crate::libindy::crypto::Crypto;
 
 use serde_json::Value as JSONValue;
 use serde_json::Map as JSONMap;
@@ -16,13 +18,19 @@ use std::collections::{HashMap, BTreeMap};
 
 use crate::utils::table::{print_table, print_list_table};
 use crate::utils::file::{read_file, write_file};
+use crate::utils::output;
 
 use self::regex::Regex;
 use self::chrono::prelude::*;
 
+use rust_base58::{FromBase58, ToBase58};
+use sha2::{Digest, Sha256};
+
 pub const DELIMITER: &str = ":";
 pub const SCHEMA_MARKER: &str = "2";
 pub const CRED_DEF_MARKER: &str = "3";
+pub const REVOC_REG_TYPE: &str = "CL_ACCUM";
+pub const TXNS_MAX_COUNT: i32 = 1000;
 pub const SIGN_REQUEST: bool = true;
 pub const SEND_REQUEST: bool = true;
 
@@ -84,7 +92,7 @@ macro_rules! send_request {
             (response_json, response)
         } else {
             println_succ!("Transaction has been created:");
-            println!("     {}", $request);
+            println_out!("     {}", $request);
             set_transaction($ctx, Some($request.to_string()));
             return Ok(());
         }
@@ -96,20 +104,20 @@ macro_rules! get_transaction_to_use {
         if let Some(txn_) = $param_txn {
             txn_.to_string()
         } else if let Some(txn_) = get_transaction($ctx) {
-            println!("Transaction stored into context: {:?}.", txn_);
-            println!("Would you like to use it? (y/n)");
+            println_out!("Transaction stored into context: {:?}.", txn_);
+            println_prompt!("Would you like to use it? (y/n)");
 
             let use_transaction = crate::command_executor::wait_for_user_reply($ctx);
 
             if !use_transaction {
-                println!("No transaction has been used.");
+                println_out!("No transaction has been used.");
                 return Ok(());
             }
 
             txn_.to_string()
         } else {
             println_err!("There is not a transaction to use.");
-            println!("You either need to explicitly pass transaction as a parameter, or \
+            println_out!("You either need to explicitly pass transaction as a parameter, or \
                     load transaction using `ledger load-transaction`, or \
                     build a transaction (with passing either `send=false` or `endorser` parameter).");
             return Err(());
@@ -167,7 +175,7 @@ pub mod nym_command {
                     let change_nym = crate::command_executor::wait_for_user_reply(ctx);
 
                     if !change_nym {
-                        println!("The transaction has not been sent.");
+                        println_out!("The transaction has not been sent.");
                         return Ok(());
                     }
                 }
@@ -524,7 +532,7 @@ pub mod get_validator_info_command {
             };
         }
 
-        println!("{{\n{}\n}}", lines.join(",\n"));
+        println_out!("{{\n{}\n}}", lines.join(",\n"));
 
         let res = Ok(());
 
@@ -715,6 +723,347 @@ pub mod get_cred_def_command {
     }
 }
 
+pub mod revoc_reg_def_command {
+    use super::*;
+
+    command!(CommandMetadata::build("revoc-reg-def", r#"Send Revocation Registry Definition transaction to the Ledger.
+                One of the next parameter combinations must be specified to pay a transaction fee (if it is set on the ledger):
+                (source_payment_address, fee) - CLI automatically gets payment sources corresponded to the source payment address and prepares data
+                (fees_inputs, fees_outputs) - explicit specification of payment sources"#)
+                .add_main_param("def", "Revocation Registry Definition json or the path to file containing it (as created by `anoncreds create-revoc-reg`)")
+                .add_optional_param_with_dynamic_completion("source_payment_address","Payment address of sender.", DynamicCompletionType::PaymentAddress)
+                .add_optional_param("fee","Transaction fee set on the ledger.")
+                .add_optional_param("fees_inputs","The list of source inputs")
+                .add_optional_param("fees_outputs","The list of outputs in the following format: (recipient, amount)")
+                .add_optional_param("extra","Optional information for fees payment operation")
+                .add_optional_param("sign","Sign the request (True by default)")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_optional_param("endorser","DID of the Endorser that will submit the transaction to the ledger later. \
+                    Note that specifying of this parameter implies send=false so the transaction will be prepared to pass to the endorser instead of sending to the ledger.\
+                    The created request will be printed and stored into CLI context.")
+                .add_example("ledger revoc-reg-def /home/rev_reg_def.json")
+                .add_example("ledger revoc-reg-def /home/rev_reg_def.json send=false")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let revoc_reg_def = get_json_param("def", params)?;
+
+        let mut request = Ledger::build_revoc_reg_def_request(&submitter_did, &revoc_reg_def)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        set_author_agreement(ctx, &mut request)?;
+
+        let payment_method = set_request_fees(ctx, params, &mut request, wallet_handle, Some(&submitter_did))?;
+
+        let (response_json, response): (String, Response<serde_json::Value>) =
+            send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
+
+        handle_transaction_response(response)
+            .map(|result| print_transaction_response(result,
+                                                     "Revocation Registry Definition request has been sent to Ledger.",
+                                                     None,
+                                                     &[("id", "Id"),
+                                                         ("credDefId", "Cred Def Id"),
+                                                         ("revocDefType", "Type"),
+                                                         ("tag", "Tag")],
+                                                     true))?;
+
+        let receipts = parse_response_with_fees(&response_json, payment_method)?;
+
+        let res = print_response_receipts(receipts);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod revoc_reg_entry_command {
+    use super::*;
+
+    command!(CommandMetadata::build("revoc-reg-entry", r#"Send Revocation Registry Entry transaction to the Ledger.
+                One of the next parameter combinations must be specified to pay a transaction fee (if it is set on the ledger):
+                (source_payment_address, fee) - CLI automatically gets payment sources corresponded to the source payment address and prepares data
+                (fees_inputs, fees_outputs) - explicit specification of payment sources"#)
+                .add_required_param("id", "Identifier of Revocation Registry Definition the entry belongs to")
+                .add_required_param("entry", "Revocation Registry Entry (delta) json or the path to file containing it")
+                .add_optional_param("type", "Revocation Registry type (CL_ACCUM by default)")
+                .add_optional_param_with_dynamic_completion("source_payment_address","Payment address of sender.", DynamicCompletionType::PaymentAddress)
+                .add_optional_param("fee","Transaction fee set on the ledger.")
+                .add_optional_param("fees_inputs","The list of source inputs")
+                .add_optional_param("fees_outputs","The list of outputs in the following format: (recipient, amount)")
+                .add_optional_param("extra","Optional information for fees payment operation")
+                .add_optional_param("sign","Sign the request (True by default)")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_optional_param("endorser","DID of the Endorser that will submit the transaction to the ledger later. \
+                    Note that specifying of this parameter implies send=false so the transaction will be prepared to pass to the endorser instead of sending to the ledger.\
+                    The created request will be printed and stored into CLI context.")
+                .add_example("ledger revoc-reg-entry id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1 entry=/home/rev_reg_entry.json")
+                .add_example(r#"ledger revoc-reg-entry id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1 entry={"ver":"1.0","value":{"accum":"1 0000000000000000000000000000000000000000000000000000000000000000"}}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let id = get_str_param("id", params).map_err(error_err!())?;
+        let entry = get_json_param("entry", params)?;
+        let type_ = get_opt_str_param("type", params).map_err(error_err!())?.unwrap_or(REVOC_REG_TYPE);
+
+        let mut request = Ledger::build_revoc_reg_entry_request(&submitter_did, id, type_, &entry)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        set_author_agreement(ctx, &mut request)?;
+
+        let payment_method = set_request_fees(ctx, params, &mut request, wallet_handle, Some(&submitter_did))?;
+
+        let (response_json, response): (String, Response<serde_json::Value>) =
+            send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
+
+        handle_transaction_response(response)
+            .map(|result| print_transaction_response(result,
+                                                     "Revocation Registry Entry request has been sent to Ledger.",
+                                                     Some("value"),
+                                                     &[("accum", "Accumulator"),
+                                                         ("prevAccum", "Previous Accumulator"),
+                                                         ("issued", "Issued"),
+                                                         ("revoked", "Revoked")],
+                                                     true))?;
+
+        let receipts = parse_response_with_fees(&response_json, payment_method)?;
+
+        let res = print_response_receipts(receipts);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_revoc_reg_def_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-revoc-reg-def", "Get Revocation Registry Definition from Ledger.")
+                .add_required_param("id", "Identifier of Revocation Registry Definition")
+                .add_optional_param("file", "The path to file to store the received Revocation Registry Definition json")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger get-revoc-reg-def id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1")
+                .add_example("ledger get-revoc-reg-def id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1 file=/home/rev_reg_def.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let submitter_did = get_active_did(&ctx);
+
+        let id = get_str_param("id", params).map_err(error_err!())?;
+
+        let request = Ledger::build_get_revoc_reg_def_request(submitter_did.as_ref().map(String::as_str), id)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (response_json, response) = send_read_request!(&ctx, params, &request, submitter_did.as_ref().map(String::as_str));
+
+        let result = handle_transaction_response(response)?;
+        ensure_revoc_data_found(&result, "Revocation Registry Definition")?;
+
+        let (_, revoc_reg_def_json) = Ledger::parse_get_revoc_reg_def_response(&response_json)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Following Revocation Registry Definition has been received.");
+        let res = output_json(params, &revoc_reg_def_json);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_revoc_reg_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-revoc-reg", "Get the state of Revocation Registry accumulator from Ledger.")
+                .add_required_param("id", "Identifier of Revocation Registry Definition")
+                .add_optional_param("timestamp", "Moment of time the state is requested for, in seconds since Unix Epoch (current time by default)")
+                .add_optional_param("file", "The path to file to store the received Revocation Registry json")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger get-revoc-reg id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1")
+                .add_example("ledger get-revoc-reg id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1 timestamp=1570000000")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let submitter_did = get_active_did(&ctx);
+
+        let id = get_str_param("id", params).map_err(error_err!())?;
+        let timestamp = get_opt_number_param::<i64>("timestamp", params).map_err(error_err!())?
+            .unwrap_or_else(|| Utc::now().timestamp());
+
+        let request = Ledger::build_get_revoc_reg_request(submitter_did.as_ref().map(String::as_str), id, timestamp)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (response_json, response) = send_read_request!(&ctx, params, &request, submitter_did.as_ref().map(String::as_str));
+
+        let result = handle_transaction_response(response)?;
+        ensure_revoc_data_found(&result, "Revocation Registry")?;
+
+        let (_, revoc_reg_json, timestamp) = Ledger::parse_get_revoc_reg_response(&response_json)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Following Revocation Registry state has been received. It was actual at {}.", timestamp_to_datetime(timestamp as i64));
+        let res = output_json(params, &revoc_reg_json);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_revoc_reg_delta_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-revoc-reg-delta", "Get the changes of Revocation Registry accumulator made within the time interval from Ledger.")
+                .add_required_param("id", "Identifier of Revocation Registry Definition")
+                .add_optional_param("from", "Start of the time interval, in seconds since Unix Epoch (the registry creation by default)")
+                .add_optional_param("to", "End of the time interval, in seconds since Unix Epoch (current time by default)")
+                .add_optional_param("file", "The path to file to store the received Revocation Registry Delta json")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger get-revoc-reg-delta id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1")
+                .add_example("ledger get-revoc-reg-delta id=VsKV7grR1BUE29mG2Fm2kX:4:VsKV7grR1BUE29mG2Fm2kX:3:CL:1:1:CL_ACCUM:1 from=1570000000 to=1580000000 file=/home/rev_reg_delta.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let submitter_did = get_active_did(&ctx);
+
+        let id = get_str_param("id", params).map_err(error_err!())?;
+        let from = get_opt_number_param::<i64>("from", params).map_err(error_err!())?.unwrap_or(-1);
+        let to = get_opt_number_param::<i64>("to", params).map_err(error_err!())?
+            .unwrap_or_else(|| Utc::now().timestamp());
+
+        let request = Ledger::build_get_revoc_reg_delta_request(submitter_did.as_ref().map(String::as_str), id, from, to)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (response_json, response) = send_read_request!(&ctx, params, &request, submitter_did.as_ref().map(String::as_str));
+
+        let result = handle_transaction_response(response)?;
+        ensure_revoc_data_found(&result, "Revocation Registry")?;
+
+        let (_, revoc_reg_delta_json, timestamp) = Ledger::parse_get_revoc_reg_delta_response(&response_json)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Following Revocation Registry Delta has been received. It was actual at {}.", timestamp_to_datetime(timestamp as i64));
+        let res = output_json(params, &revoc_reg_delta_json);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod txns_command {
+    use super::*;
+
+    command!(CommandMetadata::build("txns", "Get the range of transactions from Ledger and print them in readable form.")
+                .add_optional_param("ledger", "Type of the ledger: DOMAIN, POOL or CONFIG (DOMAIN by default)")
+                .add_required_param("from", "Sequence number of the first transaction in the range")
+                .add_optional_param("to", "Sequence number of the last transaction in the range (equal to `from` by default)")
+                .add_optional_param("file", "The path to file to export the received transactions in JSON Lines format")
+                .add_example("ledger txns from=1 to=10")
+                .add_example("ledger txns ledger=pool from=1 to=4")
+                .add_example("ledger txns ledger=domain from=1 to=100 file=/home/domain.jsonl")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+        let submitter_did = get_active_did(&ctx);
+
+        let ledger_type = get_opt_str_param("ledger", params).map_err(error_err!())?.unwrap_or("DOMAIN").to_uppercase();
+        let from = _get_int_param::<i32>("from", params).map_err(error_err!())?;
+        let to = get_opt_number_param::<i32>("to", params).map_err(error_err!())?.unwrap_or(from);
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+
+        if !["DOMAIN", "POOL", "CONFIG"].contains(&ledger_type.as_str()) {
+            println_err!("Unsupported ledger type \"{}\". Use one of: DOMAIN, POOL, CONFIG", ledger_type);
+            return Err(());
+        }
+
+        if from < 1 || to < from {
+            println_err!("Invalid transactions range: {}..{}", from, to);
+            return Err(());
+        }
+
+        if to - from >= TXNS_MAX_COUNT {
+            println_err!("Too many transactions requested. Maximum range size is {}", TXNS_MAX_COUNT);
+            return Err(());
+        }
+
+        let mut txns: Vec<serde_json::Value> = Vec::new();
+
+        for seq_no in from..=to {
+            let request = Ledger::build_get_txn_request(submitter_did.as_ref().map(String::as_str), Some(&ledger_type), seq_no)
+                .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+            let response_json = Ledger::submit_request(pool_handle, &request)
+                .map_err(|err| handle_indy_error(err, submitter_did.as_ref().map(String::as_str), Some(&pool_name), None))?;
+
+            let response = serde_json::from_str::<Response<serde_json::Value>>(&response_json)
+                .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+            let mut result = handle_transaction_response(response)?;
+
+            // Ledger is contiguous so the first missed transaction means that the end is reached
+            if result["data"].is_null() {
+                break;
+            }
+
+            txns.push(result["data"].take());
+        }
+
+        if txns.len() < (to - from + 1) as usize {
+            println_warn!("{} ledger contains only {} transactions", ledger_type, from as usize + txns.len() - 1);
+        }
+
+        let rows = txns.iter().map(decode_txn).collect::<Vec<serde_json::Value>>();
+
+        print_list_table(&rows,
+                         &[("seqNo", "Sequence Number"),
+                             ("type", "Type"),
+                             ("txnTime", "Transaction time"),
+                             ("from", "From"),
+                             ("summary", "Summary")],
+                         "There are no transactions in the requested range");
+
+        if let Some(file) = file {
+            let content = txns.iter()
+                .map(|txn| txn.to_string())
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            write_file(file, &content)
+                .map_err(|err| println_err!("Cannot export transactions into the file: {}", err))?;
+
+            println_succ!("{} transactions have been exported into the file \"{}\"", txns.len(), file);
+        }
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 pub mod node_command {
     use super::*;
 
@@ -991,11 +1340,11 @@ pub mod pool_upgrade_command {
                                                      true));
         if let Some(h) = hash {
             println_succ!("Hash:");
-            println!("{}", h);
+            println_out!("{}", h);
         }
         if let Some(s) = schedule {
             println_succ!("Schedule:");
-            println!("{}", s);
+            println_out!("{}", s);
         }
         trace!("execute << {:?}", res);
         res
@@ -1029,13 +1378,13 @@ pub mod custom_command {
 
             match context_txn {
                 Some(txn_) => {
-                    println!("Transaction stored into context: {:?}.", txn_);
-                    println!("Would you like to send it? (y/n)");
+                    println_out!("Transaction stored into context: {:?}.", txn_);
+                    println_prompt!("Would you like to send it? (y/n)");
 
                     let use_transaction = crate::command_executor::wait_for_user_reply(ctx);
 
                     if !use_transaction {
-                        println!("No transaction has been send.");
+                        println_out!("No transaction has been send.");
                         return Ok(());
                     }
 
@@ -1043,7 +1392,7 @@ pub mod custom_command {
                 }
                 None => {
                     println_err!("There is not a transaction stored into CLI context.");
-                    println!("You either need to load transaction using `ledger load-transaction`, or \
+                    println_out!("You either need to load transaction using `ledger load-transaction`, or \
                         build a transaction (with passing a `send=false`) to store it into CLI context.");
                 }
             }
@@ -1072,7 +1421,7 @@ pub mod custom_command {
         let res = match response {
             Response { op: ResponseType::REPLY, result: Some(_), reason: None } =>
                 {
-                    println!("Response: \n{}", response_json);
+                    println_out!("Response: \n{}", response_json);
                     Ok(())
                 },
             Response { op: ResponseType::REQNACK, result: None, reason: Some(reason) } |
@@ -1304,7 +1653,7 @@ pub mod mint_prepare_command {
         set_author_agreement(ctx, &mut request)?;
 
         println_succ!("MINT transaction has been created:");
-        println!("     {}", request);
+        println_out!("     {}", request);
         set_transaction(&ctx, Some(request));
 
         let res = Ok(());
@@ -1338,7 +1687,7 @@ pub mod set_fees_prepare_command {
             .map_err(|err| handle_payment_error(err, None))?;
 
         println_succ!("SET_FEES transaction has been created:");
-        println!("     {}", request);
+        println_out!("     {}", request);
         set_transaction(&ctx, Some(request));
 
         let res = Ok(());
@@ -1373,7 +1722,7 @@ pub mod verify_payment_receipt_command {
         let res = match Payment::parse_verify_payment_response(&payment_method, &response) {
             Ok(info_json) => {
                 println_succ!("Following Payment Receipt Verification Info has been received.");
-                println!("{}", info_json);
+                println_out!("{}", info_json);
                 Ok(())
             }
             Err(err) => {
@@ -1414,6 +1763,7 @@ pub mod sign_multi_command {
                 Ok(())
             }
             Err(err) => {
+                output::set_error_code(err.error_code);
                 match err.error_code {
                     ErrorCode::WalletItemNotFound => {
                         println_err!("Signer DID: \"{}\" not found", submitter_did);
@@ -1532,7 +1882,7 @@ pub mod auth_rules_command {
             send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
 
         let result = handle_transaction_response(response)?;
-        println!("result {:?}", result);
+        println_out!("result {:?}", result);
 
         let rules: AuthRulesData = serde_json::from_value(result["txn"]["data"]["rules"].clone())
             .map_err(|_| println_err!("Wrong data has been received"))?;
@@ -1647,13 +1997,13 @@ pub mod save_transaction_command {
 
         let transaction = ensure_set_transaction(ctx)?;
 
-        println!("Transaction: {:?}.", transaction);
-        println!("Would you like to save it? (y/n)");
+        println_out!("Transaction: {:?}.", transaction);
+        println_prompt!("Would you like to save it? (y/n)");
 
         let save_transaction = crate::command_executor::wait_for_user_reply(ctx);
 
         if !save_transaction {
-            println!("The transaction has not been saved.");
+            println_out!("The transaction has not been saved.");
             return Ok(());
         }
 
@@ -1696,7 +2046,7 @@ pub mod load_transaction_command {
         serde_json::from_str::<Request>(&transaction)
             .map_err(|err| println_err!("File contains invalid transaction: {:?}", err))?;
 
-        println!("Transaction has been loaded: {}", transaction);
+        println_out!("Transaction has been loaded: {}", transaction);
 
         set_transaction(ctx, Some(transaction));
 
@@ -1707,6 +2057,158 @@ pub mod load_transaction_command {
     }
 }
 
+pub mod export_transaction_command {
+    use super::*;
+
+    command!(CommandMetadata::build("export-transaction", "Export signed transaction with its summary and digest into a file to pass it to the CLI connected to the pool. Doesn't require pool connection.")
+                .add_required_param("file", "The path to file to export the transaction.")
+                .add_optional_param("txn", "Transaction to export. Skip to use a transaction stored into CLI context.")
+                .add_example("ledger export-transaction file=/media/usb/nym.json")
+                .add_example(r#"ledger export-transaction file=/media/usb/nym.json txn={"reqId":123456789,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"protocolVersion":2}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let file = get_str_param("file", params).map_err(error_err!())?;
+        let param_txn = get_opt_str_param("txn", params).map_err(error_err!())?;
+
+        let txn = match param_txn {
+            Some(txn) => txn.to_string(),
+            None => ensure_set_transaction(ctx)?
+        };
+
+        let request = parse_request(&txn)?;
+        let exported = exported_transaction(request)?;
+
+        print_exported_transaction(&exported);
+
+        let exported = serde_json::to_string_pretty(&exported)
+            .map_err(|err| println_err!("Cannot serialize transaction: {:?}", err))?;
+
+        write_file(file, &exported)
+            .map_err(|err| println_err!("Cannot export transaction into the file: {}", err))?;
+
+        println_succ!("Transaction has been exported into the file \"{}\"", file);
+        println_succ!("Compare the digest with the one shown by `ledger import-transaction` on the connected side");
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod import_transaction_command {
+    use super::*;
+
+    command!(CommandMetadata::build("import-transaction", "Import transaction exported by `ledger export-transaction`, collect its signatures from several files and verify them.")
+                .add_required_param("files", "The list of files containing the same transaction signed by different DIDs.")
+                .add_optional_param("send", "Send the transaction to the Ledger if all signatures are valid (False by default). Otherwise it is only stored into CLI context.")
+                .add_example("ledger import-transaction files=/media/usb/nym.json")
+                .add_example("ledger import-transaction files=/media/usb/nym_trustee1.json,/media/usb/nym_trustee2.json send=true")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let files = get_str_array_param("files", params).map_err(error_err!())?;
+        let send = get_opt_bool_param("send", params).map_err(error_err!())?.unwrap_or(false);
+
+        let mut request: Option<serde_json::Value> = None;
+        let mut digest = String::new();
+        let mut signatures: BTreeMap<String, String> = BTreeMap::new();
+
+        for file in files {
+            let content = read_file(file)
+                .map_err(|err| println_err!("Can't read transaction from the file \"{}\": {}", file, err))?;
+
+            let exported = parse_request(&content)?;
+
+            // Plain requests stored by `ledger save-transaction` are accepted as well
+            let file_request = if exported["transaction"].is_object() { exported["transaction"].clone() } else { exported.clone() };
+            let file_digest = transaction_digest(&file_request);
+
+            if let Some(expected_digest) = exported["digest"].as_str() {
+                if expected_digest != file_digest {
+                    println_err!("Transaction in the file \"{}\" has been modified after export: digest \"{}\" is expected but \"{}\" is found", file, expected_digest, file_digest);
+                    return Err(());
+                }
+            }
+
+            if request.is_none() {
+                digest = file_digest;
+            } else if digest != file_digest {
+                println_err!("The file \"{}\" contains another transaction: digest \"{}\" is expected but \"{}\" is found", file, digest, file_digest);
+                return Err(());
+            }
+
+            for (did, signature) in request_signatures(&file_request) {
+                if signatures.get(&did).map_or(false, |existing| existing != &signature) {
+                    println_err!("The file \"{}\" contains another signature of \"{}\" DID", file, did);
+                    return Err(());
+                }
+                signatures.insert(did, signature);
+            }
+
+            request.get_or_insert(file_request);
+        }
+
+        let mut request = request.ok_or_else(|| println_err!("No files to import"))?;
+        set_request_signatures(&mut request, &signatures);
+
+        let signers = verify_request_signatures(pool_handle, &pool_name, &request, &signatures)?;
+
+        print_exported_transaction(&json!({
+            "transaction": request,
+            "summary": request_summary(&request),
+            "digest": digest
+        }));
+
+        println_succ!("Signatures:");
+        print_list_table(&signers,
+                         &[("did", "Signer DID"),
+                             ("verkey", "Verkey"),
+                             ("status", "Signature")],
+                         "Transaction isn't signed");
+
+        if signers.iter().any(|signer| signer["status"] != "valid") {
+            println_err!("Transaction contains invalid signatures");
+            return Err(());
+        }
+
+        let request = request.to_string();
+        set_transaction(ctx, Some(request.clone()));
+        println_succ!("Transaction has been stored into CLI context");
+
+        if !send {
+            println_succ!("Use `ledger custom context` to send it to the Ledger");
+            return Ok(());
+        }
+
+        let (_, response) = send_request!(&ctx, params, &request, None, None, true);
+
+        let res = handle_transaction_response(response)
+            .and_then(|result| parse_transaction_response(result))
+            .map(|(metadata_headers, metadata, data)| {
+                println_succ!("Transaction has been sent to Ledger.");
+
+                println_succ!("Metadata:");
+                print_table(&metadata, &metadata_headers);
+
+                println_succ!("Data:");
+                print_table(&json!({"data": data}), &[("data", "Data")]);
+            });
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 pub mod taa_command {
     use super::*;
 
@@ -1909,19 +2411,219 @@ pub mod endorse_transaction_command {
         trace!("execute <<");
         Ok(())
     }
-}
+}
+
+pub fn set_author_agreement(ctx: &CommandContext, request: &mut String) -> Result<(), ()> {
+    if let Some((text, version, acc_mech_type, time_of_acceptance)) = get_transaction_author_info(&ctx) {
+        if acc_mech_type.is_empty() {
+            println_err!("Transaction author agreement Acceptance Mechanism isn't set.");
+            return Err(());
+        }
+
+        *request = Ledger::append_txn_author_agreement_acceptance_to_request(&request, Some(&text), Some(&version), None, &acc_mech_type, time_of_acceptance)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+    };
+    Ok(())
+}
+
+fn parse_request(transaction: &str) -> Result<serde_json::Value, ()> {
+    let request: serde_json::Value = serde_json::from_str(transaction)
+        .map_err(|err| println_err!("Invalid transaction: {}", err))?;
+
+    if !request.is_object() {
+        println_err!("Invalid transaction: json object is expected");
+        return Err(());
+    }
+
+    Ok(request)
+}
+
+fn exported_transaction(request: serde_json::Value) -> Result<serde_json::Value, ()> {
+    if !request["operation"].is_object() || request["identifier"].as_str().is_none() {
+        println_err!("Invalid transaction: request operation and identifier are expected");
+        return Err(());
+    }
+
+    Ok(json!({
+        "summary": request_summary(&request),
+        "digest": transaction_digest(&request),
+        "transaction": request
+    }))
+}
+
+fn request_summary(request: &serde_json::Value) -> String {
+    format!("{}: {}", get_txn_title(&request["operation"]["type"]).as_str().unwrap_or("-"),
+            txn_data_summary(&request["operation"]["type"], &request["operation"]))
+}
+
+fn print_exported_transaction(exported: &serde_json::Value) {
+    let request = &exported["transaction"];
+
+    let signers = request_signatures(request).into_iter()
+        .map(|(did, _)| did)
+        .collect::<Vec<String>>();
+
+    print_table(&json!({
+                    "identifier": request["identifier"],
+                    "reqId": request["reqId"],
+                    "endorser": request["endorser"],
+                    "summary": exported["summary"],
+                    "signers": signers,
+                    "digest": exported["digest"]
+                }),
+                &[("identifier", "Identifier"),
+                    ("reqId", "Request ID"),
+                    ("endorser", "Endorser"),
+                    ("summary", "Summary"),
+                    ("signers", "Signed by"),
+                    ("digest", "Digest")]);
+}
+
+// Digest covers the same data as request signatures so it stays the same while signatures are collected
+fn transaction_digest(request: &serde_json::Value) -> String {
+    Sha256::digest(serialize_signature_input(request, true, request["operation"]["type"].as_str()).as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// Mirrors libindy request serialization which is signed by `ledger sign-multi` and send commands
+fn serialize_signature_input(value: &serde_json::Value, is_top_level: bool, txn_type: Option<&str>) -> String {
+    match value {
+        serde_json::Value::Bool(value) => if *value { "True".to_string() } else { "False".to_string() },
+        serde_json::Value::Number(value) => value.to_string(),
+        serde_json::Value::String(value) => value.clone(),
+        serde_json::Value::Array(array) => array.iter()
+            .map(|element| serialize_signature_input(element, false, txn_type))
+            .collect::<Vec<String>>()
+            .join(","),
+        serde_json::Value::Object(map) => map.iter()
+            .filter(|&(key, _)| !(is_top_level && (key == "signature" || key == "fees" || key == "signatures")))
+            .map(|(key, value)| {
+                let value = match (txn_type, key.as_str(), value.as_str()) {
+                    (Some("100"), "raw", Some(value)) |
+                    (Some("100"), "hash", Some(value)) |
+                    (Some("100"), "enc", Some(value)) |
+                    (Some("104"), "raw", Some(value)) |
+                    (Some("104"), "hash", Some(value)) |
+                    (Some("104"), "enc", Some(value)) =>
+                        Sha256::digest(value.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect(),
+                    _ => serialize_signature_input(value, false, txn_type)
+                };
+                format!("{}:{}", key, value)
+            })
+            .collect::<Vec<String>>()
+            .join("|"),
+        serde_json::Value::Null => String::new()
+    }
+}
+
+fn request_signatures(request: &serde_json::Value) -> Vec<(String, String)> {
+    let mut signatures = Vec::new();
+
+    if let (Some(identifier), Some(signature)) = (request["identifier"].as_str(), request["signature"].as_str()) {
+        signatures.push((identifier.to_string(), signature.to_string()));
+    }
+
+    if let Some(multi_signatures) = request["signatures"].as_object() {
+        for (did, signature) in multi_signatures {
+            if let Some(signature) = signature.as_str() {
+                signatures.push((did.to_string(), signature.to_string()));
+            }
+        }
+    }
+
+    signatures
+}
+
+// Single author signature is kept as is, otherwise all of them are moved to `signatures` as libindy multi sign does
+fn set_request_signatures(request: &mut serde_json::Value, signatures: &BTreeMap<String, String>) {
+    let request = match request.as_object_mut() {
+        Some(request) => request,
+        None => return
+    };
+
+    request.remove("signature");
+    request.remove("signatures");
+
+    let identifier = request.get("identifier").and_then(serde_json::Value::as_str).map(String::from);
+
+    match (signatures.len(), identifier) {
+        (0, _) => {}
+        (1, Some(ref identifier)) if signatures.contains_key(identifier) => {
+            request.insert("signature".to_string(), serde_json::Value::from(signatures[identifier].clone()));
+        }
+        _ => {
+            request.insert("signatures".to_string(), json!(signatures));
+        }
+    }
+}
+
+fn verify_request_signatures(pool_handle: i32, pool_name: &str, request: &serde_json::Value,
+                             signatures: &BTreeMap<String, String>) -> Result<Vec<serde_json::Value>, ()> {
+    let signature_input = serialize_signature_input(request, true, request["operation"]["type"].as_str());
+
+    let mut signers = Vec::new();
+
+    for (did, signature) in signatures {
+        let verkey = get_ledger_verkey(pool_handle, pool_name, did)?;
+
+        let status = match (verkey.as_ref(), signature.from_base58()) {
+            (None, _) => "unknown DID",
+            (Some(_), Err(_)) => "invalid",
+            (Some(verkey), Ok(signature)) => match Crypto::verify(verkey, signature_input.as_bytes(), &signature) {
+                Ok(true) => "valid",
+                _ => "invalid"
+            }
+        };
+
+        signers.push(json!({
+            "did": did,
+            "verkey": verkey,
+            "status": status
+        }));
+    }
+
+    Ok(signers)
+}
+
+fn get_ledger_verkey(pool_handle: i32, pool_name: &str, did: &str) -> Result<Option<String>, ()> {
+    let request = Ledger::build_get_nym_request(None, did)
+        .map_err(|err| handle_indy_error(err, None, Some(pool_name), None))?;
+
+    let response_json = Ledger::submit_request(pool_handle, &request)
+        .map_err(|err| handle_indy_error(err, None, Some(pool_name), None))?;
+
+    let response = serde_json::from_str::<Response<serde_json::Value>>(&response_json)
+        .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+    let result = handle_transaction_response(response)?;
+
+    let data = match result["data"].as_str().and_then(|data| serde_json::from_str::<serde_json::Value>(data).ok()) {
+        Some(data) => data,
+        None => return Ok(None)
+    };
+
+    let verkey = match data["verkey"].as_str() {
+        Some(verkey) => verkey,
+        None => return Ok(None)
+    };
+
+    full_verkey(did, verkey).map(Some)
+}
+
+// Abbreviated verkey contains only the part which isn't the DID itself
+fn full_verkey(did: &str, verkey: &str) -> Result<String, ()> {
+    if !verkey.starts_with('~') {
+        return Ok(verkey.to_string());
+    }
 
-pub fn set_author_agreement(ctx: &CommandContext, request: &mut String) -> Result<(), ()> {
-    if let Some((text, version, acc_mech_type, time_of_acceptance)) = get_transaction_author_info(&ctx) {
-        if acc_mech_type.is_empty() {
-            println_err!("Transaction author agreement Acceptance Mechanism isn't set.");
-            return Err(());
-        }
+    let mut full_verkey = did.from_base58()
+        .map_err(|_| println_err!("Invalid DID \"{}\"", did))?;
+    full_verkey.extend(verkey[1..].from_base58()
+        .map_err(|_| println_err!("Invalid verkey \"{}\" of \"{}\" DID", verkey, did))?);
 
-        *request = Ledger::append_txn_author_agreement_acceptance_to_request(&request, Some(&text), Some(&version), None, &acc_mech_type, time_of_acceptance)
-            .map_err(|err| handle_indy_error(err, None, None, None))?;
-    };
-    Ok(())
+    Ok(full_verkey.to_base58())
 }
 
 fn serialize<T>(obj: &T) -> Result<String, ()> where T: ::serde::Serialize {
@@ -2093,6 +2795,15 @@ pub fn handle_transaction_response(response: Response<serde_json::Value>) -> Res
     }
 }
 
+// GET_REVOC_REG_* replies contain null data instead of rejection if nothing is written for the id
+fn ensure_revoc_data_found(result: &serde_json::Value, entity: &str) -> Result<(), ()> {
+    if result["data"].is_null() {
+        println_err!("{} not found", entity);
+        return Err(());
+    }
+    Ok(())
+}
+
 fn extract_error_message(error: &str) -> String {
     let re = Regex::new(r#"\(["'](.*)["'],\)"#).unwrap();
     match re.captures(error) {
@@ -2101,6 +2812,71 @@ fn extract_error_message(error: &str) -> String {
     }
 }
 
+// Transaction row for `ledger txns` with the most meaningful data fields of the known types gathered into summary
+fn decode_txn(txn: &serde_json::Value) -> serde_json::Value {
+    let txn_type = &txn["txn"]["type"];
+
+    json!({
+        "seqNo": txn["txnMetadata"]["seqNo"],
+        "type": get_txn_title(txn_type),
+        "txnTime": txn["txnMetadata"]["txnTime"].as_i64().map(timestamp_to_datetime),
+        "from": txn["txn"]["metadata"]["from"],
+        "summary": txn_data_summary(txn_type, &txn["txn"]["data"])
+    })
+}
+
+// Ledger transaction data and request operation share the field names so both can be summarized the same way
+fn txn_data_summary(txn_type: &serde_json::Value, data: &serde_json::Value) -> String {
+    let fields: Vec<(&str, serde_json::Value)> = match txn_type.as_str() {
+        Some("0") => vec![("dest", data["dest"].clone()),
+                          ("alias", data["data"]["alias"].clone()),
+                          ("services", data["data"]["services"].clone())],
+        Some("1") => vec![("dest", data["dest"].clone()),
+                          ("verkey", data["verkey"].clone()),
+                          ("role", if data["role"].is_null() { serde_json::Value::Null } else { get_role_title(&data["role"]) })],
+        Some("4") => vec![("version", data["version"].clone())],
+        Some("5") => vec![("version", data["version"].clone())],
+        Some("100") => vec![("dest", data["dest"].clone()),
+                            ("raw", data["raw"].clone()),
+                            ("hash", data["hash"].clone()),
+                            ("enc", data["enc"].clone())],
+        Some("101") => vec![("name", data["data"]["name"].clone()),
+                            ("version", data["data"]["version"].clone()),
+                            ("attr_names", data["data"]["attr_names"].clone())],
+        Some("102") => vec![("ref", data["ref"].clone()),
+                            ("signature_type", data["signature_type"].clone()),
+                            ("tag", data["tag"].clone())],
+        Some("109") => vec![("name", data["name"].clone()),
+                            ("version", data["version"].clone()),
+                            ("action", data["action"].clone())],
+        Some("111") => vec![("writes", data["writes"].clone()),
+                            ("force", data["force"].clone())],
+        Some("113") => vec![("id", data["id"].clone()),
+                            ("credDefId", data["credDefId"].clone())],
+        Some("114") => vec![("revocRegDefId", data["revocRegDefId"].clone())],
+        Some("118") => vec![("action", data["action"].clone()),
+                            ("datetime", data["datetime"].clone())],
+        Some("120") => vec![("auth_action", data["auth_action"].clone()),
+                            ("auth_type", get_txn_title(&data["auth_type"])),
+                            ("field", data["field"].clone())],
+        Some("122") => vec![("rules", serde_json::Value::from(data["rules"].as_array().map(Vec::len).unwrap_or(0)))],
+        _ => vec![]
+    };
+
+    if fields.is_empty() {
+        return data.to_string();
+    }
+
+    fields.into_iter()
+        .filter(|&(_, ref value)| !value.is_null())
+        .map(|(name, value)| match value {
+            serde_json::Value::String(value) => format!("{}={}", name, value),
+            value => format!("{}={}", name, value)
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 fn get_role_title(role: &serde_json::Value) -> serde_json::Value {
     serde_json::Value::String(match role.as_str() {
         Some("0") => "TRUSTEE",
@@ -2393,6 +3169,7 @@ pub mod tests {
     use crate::commands::common::tests::{load_null_payment_plugin, NULL_PAYMENT_METHOD};
     #[cfg(feature = "nullpay_plugin")]
     use crate::commands::payment_address::tests::create_payment_address;
+    use crate::commands::tails::tests::generate_tails;
     use crate::libindy::ledger::Ledger;
     use crate::libindy::did::Did;
 
@@ -3558,6 +4335,207 @@ pub mod tests {
         }
     }
 
+    mod revoc_reg_def {
+        use super::*;
+
+        #[test]
+        pub fn revoc_reg_def_works_without_sending() {
+            let ctx = setup_with_wallet_and_pool();
+            let (def_file, _) = generate_tails(&ctx);
+            {
+                let cmd = revoc_reg_def_command::new();
+                let mut params = CommandParams::new();
+                params.insert("def", def_file);
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let transaction = get_transaction(&ctx).unwrap();
+            let transaction: serde_json::Value = serde_json::from_str(&transaction).unwrap();
+            assert_eq!("113", transaction["operation"]["type"].as_str().unwrap());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn revoc_reg_def_works_for_invalid_def() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = revoc_reg_def_command::new();
+                let mut params = CommandParams::new();
+                params.insert("def", r#"{"id":"1"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod revoc_reg_entry {
+        use super::*;
+
+        #[test]
+        pub fn revoc_reg_entry_works_without_sending() {
+            let ctx = setup_with_wallet_and_pool();
+            let (def_file, entry_file) = generate_tails(&ctx);
+            let revoc_reg_def: serde_json::Value = serde_json::from_str(&read_file(&def_file).unwrap()).unwrap();
+            {
+                let cmd = revoc_reg_entry_command::new();
+                let mut params = CommandParams::new();
+                params.insert("id", revoc_reg_def["id"].as_str().unwrap().to_string());
+                params.insert("entry", entry_file);
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let transaction = get_transaction(&ctx).unwrap();
+            let transaction: serde_json::Value = serde_json::from_str(&transaction).unwrap();
+            assert_eq!("114", transaction["operation"]["type"].as_str().unwrap());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod get_revoc_reg_def {
+        use super::*;
+
+        #[test]
+        pub fn get_revoc_reg_def_works_for_unknown_id() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = get_revoc_reg_def_command::new();
+                let mut params = CommandParams::new();
+                params.insert("id", format!("{}:4:{}:3:CL:1:TAG:CL_ACCUM:TAG", DID_TRUSTEE, DID_TRUSTEE));
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod get_revoc_reg {
+        use super::*;
+
+        #[test]
+        pub fn get_revoc_reg_works_for_unknown_id() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = get_revoc_reg_command::new();
+                let mut params = CommandParams::new();
+                params.insert("id", format!("{}:4:{}:3:CL:1:TAG:CL_ACCUM:TAG", DID_TRUSTEE, DID_TRUSTEE));
+                params.insert("timestamp", "100".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod get_revoc_reg_delta {
+        use super::*;
+
+        #[test]
+        pub fn get_revoc_reg_delta_works_for_unknown_id() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = get_revoc_reg_delta_command::new();
+                let mut params = CommandParams::new();
+                params.insert("id", format!("{}:4:{}:3:CL:1:TAG:CL_ACCUM:TAG", DID_TRUSTEE, DID_TRUSTEE));
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod txns {
+        use super::*;
+
+        #[test]
+        pub fn txns_works() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = txns_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "pool".to_string());
+                params.insert("from", "1".to_string());
+                params.insert("to", "4".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn txns_works_for_export() {
+            let ctx = setup_with_wallet_and_pool();
+            let file = crate::utils::environment::EnvironmentUtils::tmp_file_path("pool_txns.jsonl");
+            {
+                let cmd = txns_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "pool".to_string());
+                params.insert("from", "1".to_string());
+                params.insert("to", "4".to_string());
+                params.insert("file", file.to_str().unwrap().to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let content = read_file(&file).unwrap();
+            let txns = content.lines()
+                .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+                .collect::<Vec<serde_json::Value>>();
+            assert_eq!(4, txns.len());
+            assert_eq!("0", txns[0]["txn"]["type"].as_str().unwrap());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn txns_works_for_unknown_ledger() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = txns_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "audit".to_string());
+                params.insert("from", "1".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn txns_works_for_invalid_range() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = txns_command::new();
+                let mut params = CommandParams::new();
+                params.insert("from", "5".to_string());
+                params.insert("to", "1".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn decode_txn_works_for_nym() {
+            let txn = json!({
+                "txn": {
+                    "type": "1",
+                    "data": {"dest": DID_MY1, "verkey": VERKEY_MY1, "role": "101"},
+                    "metadata": {"from": DID_TRUSTEE}
+                },
+                "txnMetadata": {"seqNo": 10, "txnTime": 1570000000}
+            });
+            let row = decode_txn(&txn);
+            assert_eq!("NYM", row["type"].as_str().unwrap());
+            assert_eq!(10, row["seqNo"].as_i64().unwrap());
+            assert_eq!(DID_TRUSTEE, row["from"].as_str().unwrap());
+            assert_eq!(format!("dest={}, verkey={}, role=ENDORSER", DID_MY1, VERKEY_MY1), row["summary"].as_str().unwrap());
+        }
+
+        #[test]
+        pub fn decode_txn_works_for_unknown_type() {
+            let txn = json!({
+                "txn": {"type": "20000", "data": {"field": "value"}, "metadata": {}},
+                "txnMetadata": {"seqNo": 1}
+            });
+            let row = decode_txn(&txn);
+            assert_eq!("20000", row["type"].as_str().unwrap());
+            assert!(row["txnTime"].is_null());
+            assert_eq!(r#"{"field":"value"}"#, row["summary"].as_str().unwrap());
+        }
+    }
+
     mod node {
         use super::*;
 
@@ -4803,6 +5781,178 @@ pub mod tests {
         }
     }
 
+    mod export_transaction {
+        use super::*;
+
+        #[test]
+        pub fn export_transaction_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            build_nym(&ctx, DID_MY3, VERKEY_MY3, true);
+            let (_, path_str) = _export_path("nym.json");
+            {
+                let cmd = export_transaction_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path_str.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let exported: serde_json::Value = serde_json::from_str(&read_file(&path_str).unwrap()).unwrap();
+            assert_eq!(format!("NYM: dest={}, verkey={}", DID_MY3, VERKEY_MY3), exported["summary"].as_str().unwrap());
+            assert_eq!(transaction_digest(&exported["transaction"]), exported["digest"].as_str().unwrap());
+            assert_eq!(DID_MY1, exported["transaction"]["identifier"].as_str().unwrap());
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn export_transaction_works_for_no_txn_into_context() {
+            let ctx = setup();
+            let (_, path_str) = _export_path("nym.json");
+            {
+                let cmd = export_transaction_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path_str);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+
+        #[test]
+        pub fn transaction_digest_works_for_collected_signatures() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            new_did(&ctx, SEED_MY3);
+            use_did(&ctx, DID_MY1);
+            build_nym(&ctx, DID_MY3, VERKEY_MY3, true);
+            let signed: serde_json::Value = serde_json::from_str(&get_transaction(&ctx).unwrap()).unwrap();
+            use_did(&ctx, DID_MY3);
+            {
+                let cmd = sign_multi_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let multi_signed: serde_json::Value = serde_json::from_str(&get_transaction(&ctx).unwrap()).unwrap();
+            assert_eq!(transaction_digest(&signed), transaction_digest(&multi_signed));
+
+            let signature_input = serialize_signature_input(&multi_signed, true, Some("1"));
+            for (did, verkey) in &[(DID_MY1, VERKEY_MY1), (DID_MY3, VERKEY_MY3)] {
+                let signature = multi_signed["signatures"][did].as_str().unwrap().from_base58().unwrap();
+                assert!(Crypto::verify(verkey, signature_input.as_bytes(), &signature).unwrap());
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn full_verkey_works_for_abbreviated_verkey() {
+            let abbreviated_verkey = Did::abbreviate_verkey(DID_MY1, VERKEY_MY1).unwrap();
+            assert!(abbreviated_verkey.starts_with('~'));
+
+            assert_eq!(VERKEY_MY1, full_verkey(DID_MY1, &abbreviated_verkey).unwrap());
+            assert_eq!(VERKEY_MY1, full_verkey(DID_MY1, VERKEY_MY1).unwrap());
+        }
+
+        #[test]
+        pub fn serialize_signature_input_works_for_attrib() {
+            let request = json!({
+                "identifier": DID_MY1,
+                "operation": {"type": "100", "dest": DID_MY1, "raw": "1"},
+                "reqId": 1,
+                "signature": "signature"
+            });
+            assert_eq!(format!("identifier:{}|operation:dest:{}|raw:6b86b273ff34fce19d6b804eff5a3f5747ada4eaa22f1d49c01e52ddb7875b4b|type:100|reqId:1", DID_MY1, DID_MY1),
+                       serialize_signature_input(&request, true, Some("100")));
+        }
+    }
+
+    mod import_transaction {
+        use super::*;
+
+        #[test]
+        pub fn import_transaction_works() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            let (did, verkey) = create_new_did(&ctx);
+            build_nym(&ctx, &did, &verkey, true);
+            let path_str = export_transaction(&ctx, "nym.json");
+            {
+                let cmd = import_transaction_command::new();
+                let mut params = CommandParams::new();
+                params.insert("files", path_str);
+                params.insert("send", "true".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert!(_ensure_nym_added(&ctx, &did).is_ok());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn import_transaction_works_for_several_files() {
+            let ctx = setup_with_wallet_and_pool();
+            let (endorser_did, _) = use_new_identity(&ctx);
+            use_trustee(&ctx);
+            let (did, verkey) = create_new_did(&ctx);
+            build_nym(&ctx, &did, &verkey, false);
+            let unsigned = get_transaction(&ctx).unwrap();
+            {
+                let cmd = sign_multi_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let trustee_file = export_transaction(&ctx, "nym_trustee.json");
+            use_did(&ctx, &endorser_did);
+            {
+                let cmd = sign_multi_command::new();
+                let mut params = CommandParams::new();
+                params.insert("txn", unsigned);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let endorser_file = export_transaction(&ctx, "nym_endorser.json");
+            {
+                let cmd = import_transaction_command::new();
+                let mut params = CommandParams::new();
+                params.insert("files", format!("{},{}", trustee_file, endorser_file));
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let transaction: serde_json::Value = serde_json::from_str(&get_transaction(&ctx).unwrap()).unwrap();
+            assert!(transaction["signatures"][DID_TRUSTEE].is_string());
+            assert!(transaction["signatures"][&endorser_did].is_string());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn import_transaction_works_for_modified_transaction() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            build_nym(&ctx, DID_MY3, VERKEY_MY3, true);
+            let path_str = export_transaction(&ctx, "nym.json");
+            let exported = read_file(&path_str).unwrap().replace(DID_MY3, DID_MY1);
+            write_file(&path_str, &exported).unwrap();
+            {
+                let cmd = import_transaction_command::new();
+                let mut params = CommandParams::new();
+                params.insert("files", path_str);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn import_transaction_works_for_unknown_signer() {
+            let ctx = setup_with_wallet_and_pool();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            build_nym(&ctx, DID_MY3, VERKEY_MY3, true);
+            let path_str = export_transaction(&ctx, "nym.json");
+            {
+                let cmd = import_transaction_command::new();
+                let mut params = CommandParams::new();
+                params.insert("files", path_str);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
     mod aml {
         use super::*;
 
@@ -4834,6 +5984,30 @@ pub mod tests {
         (path.clone(), path.to_str().unwrap().to_string())
     }
 
+    fn _export_path(name: &str) -> (::std::path::PathBuf, String) {
+        let path = crate::utils::environment::EnvironmentUtils::tmp_file_path(name);
+        (path.clone(), path.to_str().unwrap().to_string())
+    }
+
+    fn build_nym(ctx: &CommandContext, did: &str, verkey: &str, sign: bool) {
+        let cmd = nym_command::new();
+        let mut params = CommandParams::new();
+        params.insert("did", did.to_string());
+        params.insert("verkey", verkey.to_string());
+        params.insert("sign", sign.to_string());
+        params.insert("send", "false".to_string());
+        cmd.execute(&ctx, &params).unwrap();
+    }
+
+    fn export_transaction(ctx: &CommandContext, name: &str) -> String {
+        let (_, path_str) = _export_path(name);
+        let cmd = export_transaction_command::new();
+        let mut params = CommandParams::new();
+        params.insert("file", path_str.clone());
+        cmd.execute(&ctx, &params).unwrap();
+        path_str
+    }
+
     fn create_new_did(ctx: &CommandContext) -> (String, String) {
         let (wallet_handle, _) = get_opened_wallet(ctx).unwrap();
         Did::new(wallet_handle, "{}").unwrap()
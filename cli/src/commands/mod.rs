@@ -7,10 +7,15 @@ pub mod pool;
 pub mod wallet;
 pub mod ledger;
 pub mod payment_address;
+pub mod anoncreds;
+pub mod crypto;
+pub mod pairwise;
+pub mod tails;
 
 use self::regex::Regex;
 
 use crate::command_executor::{CommandContext, CommandParams};
+use crate::utils::output;
 use indy::{ErrorCode, IndyError};
 
 use std;
@@ -308,6 +313,8 @@ pub fn get_transaction_author_info(ctx: &CommandContext) -> Option<(String, Stri
 }
 
 pub fn handle_indy_error(err: IndyError, submitter_did: Option<&str>, pool_name: Option<&str>, wallet_name: Option<&str>) {
+    output::set_error_code(err.error_code);
+
     match err.error_code {
         ErrorCode::WalletAlreadyExistsError => println_err!("Wallet \"{}\" already exists", wallet_name.unwrap_or("")),
         ErrorCode::WalletInvalidHandle => println_err!("Wallet: \"{}\" not found", wallet_name.unwrap_or("")),
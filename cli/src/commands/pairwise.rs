@@ -0,0 +1,397 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+
+use indy::{ErrorCode, IndyError};
+use crate::libindy::pairwise::Pairwise;
+
+use serde_json::Value as JSONValue;
+
+use crate::utils::table::{print_list_table, print_table};
+use crate::utils::output;
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("pairwise", "Pairwise relationship management commands"));
+}
+
+pub mod create_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create", "Create pairwise between their DID and my DID (active DID by default).")
+                .add_main_param("their_did", "Their DID stored in the wallet by \"did store-their\" command")
+                .add_optional_param("my_did", "My DID stored in the wallet (active DID is used by default)")
+                .add_optional_param("metadata", "Metadata of the pairwise")
+                .add_example("pairwise create VsKV7grR1BUE29mG2Fm2kX")
+                .add_example("pairwise create VsKV7grR1BUE29mG2Fm2kX my_did=5Uu7YveFSGcT3dSzjpvPab metadata=alice")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let their_did = get_str_param("their_did", params).map_err(error_err!())?;
+        let my_did = match get_opt_str_param("my_did", params).map_err(error_err!())? {
+            Some(my_did) => my_did.to_string(),
+            None => ensure_active_did(&ctx)?
+        };
+        let metadata = get_opt_str_param("metadata", params).map_err(error_err!())?;
+
+        let res = match Pairwise::create_pairwise(wallet_handle, their_did, &my_did, metadata) {
+            Ok(()) => {
+                println_succ!("Pairwise between their DID \"{}\" and my DID \"{}\" has been created", their_did, my_did);
+                Ok(())
+            }
+            Err(err) => Err(handle_pairwise_error(err, their_did, &wallet_name))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod list_command {
+    use super::*;
+
+    command!(CommandMetadata::build("list", "List pairwise stored in the opened wallet.")
+                .add_optional_param("query", "WQL query over tags of pairwise: ~label, ~state and ~created_at (all pairwise by default)")
+                .add_example("pairwise list")
+                .add_example(r#"pairwise list query={"~state":"active"}"#)
+                .add_example(r#"pairwise list query={"~created_at":{"$gt":"00000000001500000000"}}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let query = get_opt_object_param("query", params).map_err(error_err!())?;
+
+        let res = match query {
+            Some(query) => Pairwise::search_pairwise(wallet_handle, Some(&query.to_string())),
+            None => Pairwise::list_pairwise(wallet_handle)
+        };
+
+        let res = match res {
+            Ok(pairwise) => {
+                let pairwise = _parse_pairwise_list(&pairwise)?;
+
+                print_list_table(&pairwise, PAIRWISE_HEADERS, "There are no pairwise");
+                Ok(())
+            }
+            Err(err) => Err(handle_pairwise_error(err, "", &wallet_name))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get", "Get pairwise for their DID.")
+                .add_main_param("their_did", "Their DID")
+                .add_example("pairwise get VsKV7grR1BUE29mG2Fm2kX")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let their_did = get_str_param("their_did", params).map_err(error_err!())?;
+
+        let res = match Pairwise::get_pairwise(wallet_handle, their_did) {
+            Ok(pairwise) => {
+                let mut pairwise = serde_json::from_str::<JSONValue>(&pairwise)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+                pairwise["their_did"] = JSONValue::from(their_did);
+
+                print_table(&pairwise, PAIRWISE_HEADERS);
+                Ok(())
+            }
+            Err(err) => Err(handle_pairwise_error(err, their_did, &wallet_name))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod set_metadata_command {
+    use super::*;
+
+    command!(CommandMetadata::build("set-metadata", "Set metadata of pairwise for their DID.")
+                .add_main_param("their_did", "Their DID")
+                .add_optional_param("metadata", "New metadata of the pairwise (metadata is removed if omitted)")
+                .add_example("pairwise set-metadata VsKV7grR1BUE29mG2Fm2kX metadata=alice")
+                .add_example("pairwise set-metadata VsKV7grR1BUE29mG2Fm2kX")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let their_did = get_str_param("their_did", params).map_err(error_err!())?;
+        let metadata = get_opt_str_param("metadata", params).map_err(error_err!())?;
+
+        let res = match Pairwise::set_pairwise_metadata(wallet_handle, their_did, metadata) {
+            Ok(()) => {
+                match metadata {
+                    Some(_) => println_succ!("Metadata has been saved for pairwise with their DID \"{}\"", their_did),
+                    None => println_succ!("Metadata has been removed for pairwise with their DID \"{}\"", their_did)
+                }
+                Ok(())
+            }
+            Err(err) => Err(handle_pairwise_error(err, their_did, &wallet_name))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+const PAIRWISE_HEADERS: &[(&str, &str)] = &[("their_did", "Their DID"), ("my_did", "My DID"), ("metadata", "Metadata")];
+
+// Libindy returns pairwise list as json array of serialized pairwise jsons
+fn _parse_pairwise_list(pairwise: &str) -> Result<Vec<JSONValue>, ()> {
+    serde_json::from_str::<Vec<String>>(pairwise)
+        .map_err(|_| ())
+        .and_then(|pairwise| pairwise.iter()
+            .map(|pairwise| serde_json::from_str::<JSONValue>(pairwise).map_err(|_| ()))
+            .collect::<Result<Vec<JSONValue>, ()>>())
+        .map_err(|_| println_err!("Wrong data has been received"))
+}
+
+fn handle_pairwise_error(err: IndyError, their_did: &str, wallet_name: &str) {
+    output::set_error_code(err.error_code);
+
+    match err.error_code {
+        ErrorCode::WalletItemNotFound => println_err!("Pairwise or DID not found: {}", err.message),
+        ErrorCode::WalletItemAlreadyExists => println_err!("Pairwise for their DID \"{}\" already exists", their_did),
+        ErrorCode::WalletQueryError => println_err!("Invalid WQL query: {}", err.message),
+        _ => handle_indy_error(err, None, None, Some(wallet_name))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::{new_did, use_did, store_their_did, SEED_MY1, DID_MY1, DID_MY3, VERKEY_MY3};
+
+    const METADATA: &str = "alice";
+
+    fn get_pairwise(ctx: &CommandContext) -> JSONValue {
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        let pairwise = Pairwise::get_pairwise(wallet_handle, DID_MY3).unwrap();
+        serde_json::from_str(&pairwise).unwrap()
+    }
+
+    mod create {
+        use super::*;
+
+        #[test]
+        pub fn create_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+            store_their_did(&ctx, DID_MY3, VERKEY_MY3);
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                params.insert("metadata", METADATA.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            let pairwise = get_pairwise(&ctx);
+            assert_eq!(DID_MY1, pairwise["my_did"].as_str().unwrap());
+            assert_eq!(METADATA, pairwise["metadata"].as_str().unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_works_for_twice() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            store_their_did(&ctx, DID_MY3, VERKEY_MY3);
+            create_pairwise(&ctx);
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                params.insert("my_did", DID_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_works_for_not_stored_their_did() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                params.insert("my_did", DID_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_works_for_no_active_did() {
+            let ctx = setup_with_wallet();
+            store_their_did(&ctx, DID_MY3, VERKEY_MY3);
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod list {
+        use super::*;
+
+        #[test]
+        pub fn list_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            store_their_did(&ctx, DID_MY3, VERKEY_MY3);
+            create_pairwise(&ctx);
+            {
+                let cmd = list_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn list_works_for_query() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            store_their_did(&ctx, DID_MY3, VERKEY_MY3);
+            create_pairwise(&ctx);
+            {
+                let cmd = list_command::new();
+                let mut params = CommandParams::new();
+                params.insert("query", r#"{"~created_at":{"$gt":"00000000001500000000"}}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn list_works_for_empty() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = list_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn parse_pairwise_list_works() {
+            let pairwise = json!([json!({"my_did": DID_MY1, "their_did": DID_MY3}).to_string()]).to_string();
+
+            assert_eq!(vec![json!({"my_did": DID_MY1, "their_did": DID_MY3})], _parse_pairwise_list(&pairwise).unwrap());
+        }
+    }
+
+    mod get {
+        use super::*;
+
+        #[test]
+        pub fn get_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            store_their_did(&ctx, DID_MY3, VERKEY_MY3);
+            create_pairwise(&ctx);
+            {
+                let cmd = get_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn get_works_for_unknown_pairwise() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = get_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod set_metadata {
+        use super::*;
+
+        #[test]
+        pub fn set_metadata_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            store_their_did(&ctx, DID_MY3, VERKEY_MY3);
+            create_pairwise(&ctx);
+            {
+                let cmd = set_metadata_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                params.insert("metadata", METADATA.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert_eq!(METADATA, get_pairwise(&ctx)["metadata"].as_str().unwrap());
+
+            {
+                let cmd = set_metadata_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert!(get_pairwise(&ctx)["metadata"].is_null());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn set_metadata_works_for_unknown_pairwise() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = set_metadata_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                params.insert("metadata", METADATA.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    fn create_pairwise(ctx: &CommandContext) {
+        let cmd = create_command::new();
+        let mut params = CommandParams::new();
+        params.insert("their_did", DID_MY3.to_string());
+        params.insert("my_did", DID_MY1.to_string());
+        cmd.execute(&ctx, &params).unwrap();
+    }
+}
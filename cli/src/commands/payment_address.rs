@@ -11,6 +11,7 @@ use serde_json::Value as JSONValue;
 use serde_json::Map as JSONMap;
 
 use crate::utils::table::print_list_table;
+use crate::utils::output;
 
 
 pub mod group {
@@ -47,6 +48,7 @@ pub mod create_command {
         let res = match Payment::create_payment_address(wallet_handle, payment_method, &config) {
             Ok(payment_address) => {
                 println_succ!("Payment Address has been created \"{}\"", payment_address);
+                output::add_result(&payment_address);
                 Ok(())
             },
             Err(err) => {
@@ -204,6 +206,8 @@ pub mod verify_command {
 }
 
 pub fn handle_payment_error(err: IndyError, payment_method: Option<&str>) {
+    output::set_error_code(err.error_code);
+
     match err.error_code {
         ErrorCode::UnknownPaymentMethod => println_err!("Unknown payment method {}", payment_method.unwrap_or("")),
         ErrorCode::IncompatiblePaymentError => println_err!("No methods were scraped or more than one was scraped"),
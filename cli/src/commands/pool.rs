@@ -7,6 +7,7 @@ use crate::commands::*;
 use indy::{ErrorCode, IndyError};
 use crate::libindy::pool::Pool;
 use crate::utils::table::print_list_table;
+use crate::utils::output;
 
 use self::chrono::prelude::*;
 use serde_json::Value as JSONValue;
@@ -51,6 +52,7 @@ pub mod create_command {
                 Ok(())
             },
             Err(err) => {
+                output::set_error_code(err.error_code);
                 match err.error_code {
                     ErrorCode::CommonIOError => {
                         println_err!("Pool genesis file is invalid or does not exist.");
@@ -133,6 +135,7 @@ pub mod connect_command {
                     Ok(_) => Ok(()),
                     Err(IndyError { error_code: ErrorCode::PoolIncompatibleProtocolVersion, .. }) =>
                         {
+                            output::set_error_code(ErrorCode::PoolIncompatibleProtocolVersion);
                             println_err!("Unsupported Protocol Version has been specified \"{}\".", protocol_version);
                             Err(())
                         },
@@ -150,6 +153,7 @@ pub mod connect_command {
                         Ok(handle)
                     }
                     Err(err) => {
+                        output::set_error_code(err.error_code);
                         match err.error_code {
                             ErrorCode::PoolLedgerNotCreatedError => {
                                 println_err!("Pool \"{}\" does not exist.", name);
@@ -250,7 +254,7 @@ pub mod show_taa_command {
             Err(_) => Err(()),
             Ok(Some(_)) => Ok(()),
             Ok(None) => {
-                println!("There is no transaction agreement set on the Pool.");
+                println_out!("There is no transaction agreement set on the Pool.");
                 Ok(())
             }
         };
@@ -278,6 +282,7 @@ pub mod refresh_command {
                 Ok(())
             }
             Err(ref err) if err.error_code == ErrorCode::PoolLedgerTimeout => {
+                output::set_error_code(err.error_code);
                 println_err!("Cannot refresh pool. Transaction response has not been received");
                 close_pool(ctx, pool_handle, &pool_name)
                     .map(|_| println_err!("Pool \"{}\" has been disconnected", pool_name))
@@ -353,6 +358,7 @@ pub mod delete_command {
                 Ok(())
             },
             Err(err) => {
+                output::set_error_code(err.error_code);
                 match err.error_code {
                     ErrorCode::CommonIOError => {
                         println_err!("Pool \"{}\" does not exist.", name);
@@ -385,14 +391,14 @@ pub fn pool_list() -> Vec<String> {
 }
 
 pub fn accept_transaction_author_agreement(ctx: &CommandContext, text: &str, version: &str) {
-    println!("Would you like to accept it? (y/n)");
+    println_prompt!("Would you like to accept it? (y/n)");
 
     let accept_agreement = wait_for_user_reply(ctx);
 
     if !accept_agreement {
         println_warn!("The Transaction Author Agreement has NOT been Accepted.");
-        println!("Use `pool show-taa` command to accept the Agreement.");
-        println!();
+        println_out!("Use `pool show-taa` command to accept the Agreement.");
+        println_out!();
         return;
     }
 
@@ -406,25 +412,25 @@ pub fn accept_transaction_author_agreement(ctx: &CommandContext, text: &str, ver
 pub fn set_transaction_author_agreement(ctx: &CommandContext, pool_handle: i32, ask_for_showing: bool) -> Result<Option<()>, ()> {
     if let Some((text, version)) = ledger::get_active_transaction_author_agreement(pool_handle)? {
         if ask_for_showing {
-            println!();
-            println!("There is a Transaction Author Agreement set on the connected Pool.");
-            println!("You should read and accept it to be able to send transactions to the Pool.");
-            println!("You can postpone accepting the Agreement. Accept it later by calling `pool show-taa` command");
-            println!("Would you like to read it? (y/n)");
+            println_out!();
+            println_out!("There is a Transaction Author Agreement set on the connected Pool.");
+            println_out!("You should read and accept it to be able to send transactions to the Pool.");
+            println_out!("You can postpone accepting the Agreement. Accept it later by calling `pool show-taa` command");
+            println_prompt!("Would you like to read it? (y/n)");
 
             let read_agreement = wait_for_user_reply(ctx);
 
             if !read_agreement {
                 println_warn!("The Transaction Author Agreement has NOT been Accepted.");
-                println!("Use `pool show-taa` command to accept the Agreement.");
-                println!();
+                println_out!("Use `pool show-taa` command to accept the Agreement.");
+                println_out!();
                 return Ok(Some(()));
             }
         }
 
-        println!("Transaction Author Agreement");
-        println!("Version: {:?}", version);
-        println!("Content: \n {:?}", text);
+        println_out!("Transaction Author Agreement");
+        println_out!("Version: {:?}", version);
+        println_out!("Content: \n {:?}", text);
 
         accept_transaction_author_agreement(ctx, &text, &version);
 
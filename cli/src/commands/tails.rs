@@ -0,0 +1,231 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+use crate::commands::anoncreds::get_json_param;
+
+use serde_json::Value as JSONValue;
+
+use crate::utils::table::print_table;
+
+use rust_base58::ToBase58;
+use sha2::{Digest, Sha256};
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// Tails blob starts with the version tag written by libindy blob storage writer
+const TAILS_VERSION: [u8; 2] = [0, 2];
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("tails", "Revocation tails files verification commands"));
+}
+
+pub mod verify_command {
+    use super::*;
+
+    command!(CommandMetadata::build("verify", "Verify that the tails file matches to Revocation Registry Definition. Tails file is generated by `anoncreds create-revoc-reg`.")
+                .add_main_param("def", "Revocation Registry Definition json or the path to file containing it (can be received by `ledger get-revoc-reg-def`)")
+                .add_optional_param("tails_dir", "The path to directory containing the tails file (directory of the tails location from definition is used by default)")
+                .add_example("tails verify /home/rev_reg_def.json")
+                .add_example("tails verify /home/rev_reg_def.json tails_dir=/home/tails")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let rev_reg_def = get_json_param("def", params)?;
+        let tails_dir = get_opt_str_param("tails_dir", params).map_err(error_err!())?;
+
+        let tails = parse_tails_info(&rev_reg_def, tails_dir)?;
+        let content = read_tails_file(&tails.location)?;
+
+        print_tails_info(&tails, &content);
+
+        let actual_hash = tails_hash(&content);
+
+        let res = if !content.starts_with(&TAILS_VERSION) {
+            println_err!("Tails file \"{}\" has unsupported format", tails.location.to_string_lossy());
+            Err(())
+        } else if actual_hash != tails.hash {
+            println_err!("Tails file \"{}\" doesn't match to Revocation Registry Definition: hash \"{}\" is expected but \"{}\" is found",
+                         tails.location.to_string_lossy(), tails.hash, actual_hash);
+            Err(())
+        } else {
+            println_succ!("Tails file \"{}\" matches to Revocation Registry Definition", tails.location.to_string_lossy());
+            Ok(())
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+struct TailsInfo {
+    hash: String,
+    location: PathBuf,
+}
+
+fn parse_tails_info(rev_reg_def: &str, tails_dir: Option<&str>) -> Result<TailsInfo, ()> {
+    let rev_reg_def: JSONValue = serde_json::from_str(rev_reg_def)
+        .map_err(|_| println_err!("Invalid Revocation Registry Definition json"))?;
+
+    let value = &rev_reg_def["value"];
+
+    let hash = value["tailsHash"].as_str()
+        .ok_or_else(|| println_err!("Revocation Registry Definition doesn't contain tails hash"))?
+        .to_string();
+
+    // Blob storage names tails file by its hash so it can be found in any directory it was moved to
+    let location = match tails_dir {
+        Some(tails_dir) => Path::new(tails_dir).join(&hash),
+        None => value["tailsLocation"].as_str()
+            .map(PathBuf::from)
+            .ok_or_else(|| println_err!("Revocation Registry Definition doesn't contain tails location"))?
+    };
+
+    Ok(TailsInfo { hash, location })
+}
+
+fn read_tails_file(location: &Path) -> Result<Vec<u8>, ()> {
+    let mut content = Vec::new();
+
+    File::open(location)
+        .and_then(|mut file| file.read_to_end(&mut content))
+        .map_err(|err| println_err!("Can't read tails file \"{}\": {}", location.to_string_lossy(), err))?;
+
+    Ok(content)
+}
+
+fn tails_hash(content: &[u8]) -> String {
+    Sha256::digest(content).as_slice().to_base58()
+}
+
+fn print_tails_info(tails: &TailsInfo, content: &[u8]) {
+    print_table(&json!({
+                    "location": tails.location.to_string_lossy(),
+                    "hash": tails.hash,
+                    "size": content.len()
+                }),
+                &[("location", "Location"),
+                    ("hash", "Hash"),
+                    ("size", "Size (bytes)")]);
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::anoncreds::{create_schema_command, create_cred_def_command, create_revoc_reg_command};
+    use crate::commands::did::tests::{new_did, use_did, SEED_MY1, DID_MY1};
+    use crate::utils::environment::EnvironmentUtils;
+    use crate::utils::file::read_file;
+
+    fn tmp_file(name: &str) -> String {
+        EnvironmentUtils::tmp_file_path(name).to_str().unwrap().to_string()
+    }
+
+    mod verify {
+        use super::*;
+
+        #[test]
+        pub fn verify_works() {
+            let ctx = setup_with_wallet();
+            let (def_file, _) = generate_tails(&ctx);
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("def", def_file);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn verify_works_for_tails_dir() {
+            let ctx = setup_with_wallet();
+            let (def_file, _) = generate_tails(&ctx);
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("def", def_file);
+                params.insert("tails_dir", tmp_file("tails"));
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn verify_works_for_modified_tails_file() {
+            let ctx = setup_with_wallet();
+            let (def_file, _) = generate_tails(&ctx);
+            let rev_reg_def: JSONValue = serde_json::from_str(&read_file(&def_file).unwrap()).unwrap();
+            let tails_location = rev_reg_def["value"]["tailsLocation"].as_str().unwrap().to_string();
+            let mut content = read_tails_file(Path::new(&tails_location)).unwrap();
+            let last = content.len() - 1;
+            content[last] ^= 0xff;
+            ::std::fs::write(&tails_location, content).unwrap();
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("def", def_file);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn verify_works_for_missed_tails_file() {
+            let ctx = setup();
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("def", r#"{"value":{"tailsHash":"2KAhvRhXP3yf1WmfT8jXZDKZvPeUbzU5xkbHnY5wUWj","tailsLocation":"/unknown/2KAhvRhXP3yf1WmfT8jXZDKZvPeUbzU5xkbHnY5wUWj"}}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    fn create_revocable_cred_def(ctx: &CommandContext) -> String {
+        {
+            let cmd = create_schema_command::new();
+            let mut params = CommandParams::new();
+            params.insert("name", "gvt".to_string());
+            params.insert("version", "1.0".to_string());
+            params.insert("attr_names", "name,age".to_string());
+            params.insert("file", tmp_file("schema.json"));
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        {
+            let cmd = create_cred_def_command::new();
+            let mut params = CommandParams::new();
+            params.insert("schema", tmp_file("schema.json"));
+            params.insert("tag", "1".to_string());
+            params.insert("support_revocation", "true".to_string());
+            params.insert("file", tmp_file("cred_def.json"));
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        let cred_def: JSONValue = serde_json::from_str(&read_file(tmp_file("cred_def.json")).unwrap()).unwrap();
+        cred_def["id"].as_str().unwrap().to_string()
+    }
+
+    pub fn generate_tails(ctx: &CommandContext) -> (String, String) {
+        new_did(&ctx, SEED_MY1);
+        use_did(&ctx, DID_MY1);
+        let cred_def_id = create_revocable_cred_def(ctx);
+        {
+            let cmd = create_revoc_reg_command::new();
+            let mut params = CommandParams::new();
+            params.insert("cred_def_id", cred_def_id);
+            params.insert("tag", "1".to_string());
+            params.insert("tails_dir", tmp_file("tails"));
+            params.insert("max_cred_num", "5".to_string());
+            params.insert("def_file", tmp_file("rev_reg_def.json"));
+            params.insert("entry_file", tmp_file("rev_reg_entry.json"));
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        (tmp_file("rev_reg_def.json"), tmp_file("rev_reg_entry.json"))
+    }
+}
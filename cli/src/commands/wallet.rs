@@ -1,7 +1,8 @@
 use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata, DynamicCompletionType};
 use crate::commands::*;
-use crate::utils::table::print_list_table;
-use indy::ErrorCode;
+use crate::utils::table::{print_list_table, print_table};
+use crate::utils::output;
+use indy::{ErrorCode, IndyError};
 use crate::libindy::wallet::Wallet;
 
 use serde_json;
@@ -89,6 +90,7 @@ pub mod create_command {
                 Ok(())
             }
             Err(err) => {
+                output::set_error_code(err.error_code);
                 match err.error_code {
                     ErrorCode::WalletAlreadyExistsError => {
                         println_err!("Wallet \"{}\" already exists", id);
@@ -221,6 +223,7 @@ pub mod open_command {
                     }
                     Err(err) => {
                         set_opened_wallet(ctx, None);
+                        output::set_error_code(err.error_code);
                         match err.error_code {
                             ErrorCode::WalletAlreadyOpenedError => {
                                 println_err!("Wallet \"{}\" already opened", id);
@@ -372,6 +375,7 @@ pub mod delete_command {
                 Ok(())
             }
             Err(err) => {
+                output::set_error_code(err.error_code);
                 match err.error_code {
                     ErrorCode::WalletNotFoundError => {
                         println_err!("Wallet \"{}\" not found or unavailable", id);
@@ -550,6 +554,286 @@ pub mod import_command {
     }
 }
 
+pub mod record_add_command {
+    use super::*;
+
+    command!(CommandMetadata::build("record-add", "Add non-secret record to the opened wallet.")
+                .add_required_param("type", "Type of the record")
+                .add_required_param("id", "Identifier of the record")
+                .add_required_param("value", "Value of the record")
+                .add_optional_param("tags", "Tags of the record as json object. Tags with names starting with ~ are stored unencrypted")
+                .add_example(r#"wallet record-add type=connection id=conn1 value=active"#)
+                .add_example(r#"wallet record-add type=connection id=conn1 value=active tags={"~label":"Faber"}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+        let value = get_str_param("value", params).map_err(error_err!())?;
+        let tags = get_opt_object_param("tags", params).map_err(error_err!())?
+            .map(|tags| tags.to_string());
+
+        let res = match Wallet::add_wallet_record(wallet_handle, type_, id, value, tags.as_ref().map(String::as_str)) {
+            Ok(()) => {
+                println_succ!("Record \"{}\" of type \"{}\" has been added", id, type_);
+                Ok(())
+            }
+            Err(err) => handle_record_error(err, type_, id, &wallet_name)
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod record_get_command {
+    use super::*;
+
+    command!(CommandMetadata::build("record-get", "Get non-secret record with value and tags from the opened wallet.")
+                .add_required_param("type", "Type of the record")
+                .add_required_param("id", "Identifier of the record")
+                .add_example("wallet record-get type=connection id=conn1")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+
+        let res = match Wallet::get_wallet_record(wallet_handle, type_, id, &_record_options()) {
+            Ok(record) => {
+                let record = serde_json::from_str::<JSONValue>(&record)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                print_table(&record, RECORD_HEADERS);
+                Ok(())
+            }
+            Err(err) => handle_record_error(err, type_, id, &wallet_name)
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod record_update_command {
+    use super::*;
+
+    command!(CommandMetadata::build("record-update", "Update value and/or replace tags of non-secret record in the opened wallet.")
+                .add_required_param("type", "Type of the record")
+                .add_required_param("id", "Identifier of the record")
+                .add_optional_param("value", "New value of the record")
+                .add_optional_param("tags", "New tags of the record as json object. All current tags are replaced")
+                .add_example("wallet record-update type=connection id=conn1 value=inactive")
+                .add_example(r#"wallet record-update type=connection id=conn1 tags={"~label":"Faber College"}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+        let value = get_opt_str_param("value", params).map_err(error_err!())?;
+        let tags = get_opt_object_param("tags", params).map_err(error_err!())?;
+
+        if value.is_none() && tags.is_none() {
+            println_err!("Nothing to update. Specify \"value\" or \"tags\" parameter");
+            return Err(());
+        }
+
+        if let Some(value) = value {
+            Wallet::update_wallet_record_value(wallet_handle, type_, id, value)
+                .or_else(|err| handle_record_error(err, type_, id, &wallet_name))?;
+        }
+
+        if let Some(tags) = tags {
+            Wallet::update_wallet_record_tags(wallet_handle, type_, id, &tags.to_string())
+                .or_else(|err| handle_record_error(err, type_, id, &wallet_name))?;
+        }
+
+        println_succ!("Record \"{}\" of type \"{}\" has been updated", id, type_);
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod record_delete_command {
+    use super::*;
+
+    command!(CommandMetadata::build("record-delete", "Delete non-secret record from the opened wallet.")
+                .add_required_param("type", "Type of the record")
+                .add_required_param("id", "Identifier of the record")
+                .add_example("wallet record-delete type=connection id=conn1")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+
+        let res = match Wallet::delete_wallet_record(wallet_handle, type_, id) {
+            Ok(()) => {
+                println_succ!("Record \"{}\" of type \"{}\" has been deleted", id, type_);
+                Ok(())
+            }
+            Err(err) => handle_record_error(err, type_, id, &wallet_name)
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod record_tag_command {
+    use super::*;
+
+    command!(CommandMetadata::build("record-tag", "Add or remove tags of non-secret record in the opened wallet.")
+                .add_required_param("type", "Type of the record")
+                .add_required_param("id", "Identifier of the record")
+                .add_optional_param("add", "Tags to add as json object. Values of existing tags are overwritten")
+                .add_optional_param("remove", "Names of tags to remove separated by comma")
+                .add_example(r#"wallet record-tag type=connection id=conn1 add={"state":"complete"}"#)
+                .add_example("wallet record-tag type=connection id=conn1 remove=state,~label")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+        let add = get_opt_object_param("add", params).map_err(error_err!())?;
+        let remove = get_opt_str_array_param("remove", params).map_err(error_err!())?;
+
+        if add.is_none() && remove.is_none() {
+            println_err!("Nothing to change. Specify \"add\" or \"remove\" parameter");
+            return Err(());
+        }
+
+        if let Some(add) = add {
+            Wallet::add_wallet_record_tags(wallet_handle, type_, id, &add.to_string())
+                .or_else(|err| handle_record_error(err, type_, id, &wallet_name))?;
+        }
+
+        if let Some(remove) = remove {
+            Wallet::delete_wallet_record_tags(wallet_handle, type_, id, &json!(remove).to_string())
+                .or_else(|err| handle_record_error(err, type_, id, &wallet_name))?;
+        }
+
+        println_succ!("Tags of record \"{}\" of type \"{}\" have been updated", id, type_);
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod record_search_command {
+    use super::*;
+
+    command!(CommandMetadata::build("record-search", "Search non-secret records of the type in the opened wallet.")
+                .add_required_param("type", "Type of the records")
+                .add_optional_param("query", "WQL query over tags of the records (all records of the type by default)")
+                .add_optional_param("count", "Maximum number of records to show (100 by default)")
+                .add_example("wallet record-search type=connection")
+                .add_example(r#"wallet record-search type=connection query={"~label":{"$like":"Fab%"}} count=10"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let query = get_opt_object_param("query", params).map_err(error_err!())?
+            .unwrap_or_else(|| json!({}));
+        let count = get_opt_number_param::<usize>("count", params).map_err(error_err!())?
+            .unwrap_or(RECORDS_SEARCH_COUNT);
+
+        let options = json!({
+            "retrieveRecords": true,
+            "retrieveTotalCount": true,
+            "retrieveType": true,
+            "retrieveValue": true,
+            "retrieveTags": true
+        });
+
+        let search_handle = Wallet::open_wallet_search(wallet_handle, type_, &query.to_string(), &options.to_string())
+            .or_else(|err| handle_record_error(err, type_, "", &wallet_name).map(|_| -1))?;
+
+        let res = Wallet::fetch_wallet_search_next_records(wallet_handle, search_handle, count);
+
+        Wallet::close_wallet_search(search_handle)
+            .unwrap_or_else(|err| warn!("Wallet::close_wallet_search failed: {:?}", err));
+
+        let res = match res {
+            Ok(records) => {
+                let records = serde_json::from_str::<JSONValue>(&records)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                let found = records["records"].as_array().cloned().unwrap_or_default();
+
+                print_list_table(&found, RECORD_HEADERS, &format!("There are no records of type \"{}\"", type_));
+
+                for id in found.iter().filter_map(|record| record["id"].as_str()) {
+                    output::add_result(id);
+                }
+
+                if let Some(total_count) = records["totalCount"].as_u64() {
+                    if total_count as usize > found.len() {
+                        println_warn!("Shown {} of {} found records. Use \"count\" parameter to show more", found.len(), total_count);
+                    }
+                }
+                Ok(())
+            }
+            Err(err) => handle_record_error(err, type_, "", &wallet_name)
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+const RECORDS_SEARCH_COUNT: usize = 100;
+
+const RECORD_HEADERS: &[(&str, &str)] = &[("id", "Id"), ("type", "Type"), ("value", "Value"), ("tags", "Tags")];
+
+fn _record_options() -> String {
+    json!({ "retrieveType": true, "retrieveValue": true, "retrieveTags": true }).to_string()
+}
+
+fn handle_record_error(err: IndyError, type_: &str, id: &str, wallet_name: &str) -> Result<(), ()> {
+    output::set_error_code(err.error_code);
+    match err.error_code {
+        ErrorCode::WalletItemNotFound => println_err!("Record \"{}\" of type \"{}\" not found", id, type_),
+        ErrorCode::WalletItemAlreadyExists => println_err!("Record \"{}\" of type \"{}\" already exists", id, type_),
+        ErrorCode::WalletQueryError => println_err!("Invalid WQL query: {}", err.message),
+        _ => handle_indy_error(err, None, None, Some(wallet_name))
+    }
+    Err(())
+}
+
 fn _wallets_path() -> PathBuf {
     let mut path = EnvironmentUtils::indy_home_path();
     path.push("wallets");
@@ -1412,6 +1696,236 @@ pub mod tests {
         }
     }
 
+    mod record {
+        use super::*;
+
+        const TYPE: &str = "connection";
+        const ID: &str = "conn1";
+
+        fn get_record(wallet_handle: i32, id: &str) -> JSONValue {
+            let record = Wallet::get_wallet_record(wallet_handle, TYPE, id, &_record_options()).unwrap();
+            serde_json::from_str(&record).unwrap()
+        }
+
+        #[test]
+        pub fn record_add_works() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = record_add_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", "active".to_string());
+                params.insert("tags", r#"{"~label":"Faber"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            let record = get_record(ensure_opened_wallet_handle(&ctx).unwrap(), ID);
+            assert_eq!("active", record["value"].as_str().unwrap());
+            assert_eq!(json!({"~label": "Faber"}), record["tags"]);
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_add_works_for_twice() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx, ID);
+            {
+                let cmd = record_add_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", "active".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_add_works_for_no_opened_wallet() {
+            let ctx = setup();
+            {
+                let cmd = record_add_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", "active".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+
+        #[test]
+        pub fn record_get_works() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx, ID);
+            {
+                let cmd = record_get_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_get_works_for_unknown_record() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = record_get_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_update_works() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx, ID);
+            {
+                let cmd = record_update_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", "inactive".to_string());
+                params.insert("tags", r#"{"state":"closed"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            let record = get_record(ensure_opened_wallet_handle(&ctx).unwrap(), ID);
+            assert_eq!("inactive", record["value"].as_str().unwrap());
+            assert_eq!(json!({"state": "closed"}), record["tags"]);
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_update_works_for_nothing_to_update() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx, ID);
+            {
+                let cmd = record_update_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_tag_works() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx, ID);
+            {
+                let cmd = record_tag_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("add", r#"{"state":"complete"}"#.to_string());
+                params.insert("remove", "~label".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            let record = get_record(ensure_opened_wallet_handle(&ctx).unwrap(), ID);
+            assert_eq!(json!({"state": "complete"}), record["tags"]);
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_delete_works() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx, ID);
+            {
+                let cmd = record_delete_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            Wallet::get_wallet_record(wallet_handle, TYPE, ID, &_record_options()).unwrap_err();
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_delete_works_for_unknown_record() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = record_delete_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_search_works() {
+            let ctx = setup_with_wallet();
+            add_record_with_tags(&ctx, ID, r#"{"~label":"Faber","state":"active"}"#);
+            add_record_with_tags(&ctx, "conn2", r#"{"~label":"Acme","state":"active"}"#);
+
+            assert_eq!(vec![ID.to_string()], search_records(&ctx, r#"{"~label":"Faber"}"#, None));
+            assert_eq!(vec![ID.to_string(), "conn2".to_string()], search_records(&ctx, r#"{"state":"active"}"#, None));
+            assert_eq!(1, search_records(&ctx, r#"{"state":"active"}"#, Some(1)).len());
+            assert!(search_records(&ctx, r#"{"~label":"Alice"}"#, None).is_empty());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn record_search_works_for_invalid_query() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = record_search_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("query", r#"{"$unknown":"Faber"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        fn add_record(ctx: &CommandContext, id: &str) {
+            add_record_with_tags(ctx, id, r#"{"~label":"Faber"}"#);
+        }
+
+        fn add_record_with_tags(ctx: &CommandContext, id: &str, tags: &str) {
+            let cmd = record_add_command::new();
+            let mut params = CommandParams::new();
+            params.insert("type", TYPE.to_string());
+            params.insert("id", id.to_string());
+            params.insert("value", "active".to_string());
+            params.insert("tags", tags.to_string());
+            cmd.execute(&ctx, &params).unwrap();
+        }
+
+        // Returns sorted ids of records found by the command
+        fn search_records(ctx: &CommandContext, query: &str, count: Option<usize>) -> Vec<String> {
+            let cmd = record_search_command::new();
+            let mut params = CommandParams::new();
+            params.insert("type", TYPE.to_string());
+            params.insert("query", query.to_string());
+            if let Some(count) = count {
+                params.insert("count", count.to_string());
+            }
+            let (res, mut ids) = output::capture(|| cmd.execute(&ctx, &params));
+            res.unwrap();
+            ids.sort();
+            ids
+        }
+    }
+
     pub fn create_wallet(ctx: &CommandContext) {
         let create_cmd = create_command::new();
         let mut params = CommandParams::new();
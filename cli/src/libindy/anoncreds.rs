@@ -0,0 +1,88 @@
+use indy::IndyError;
+use indy::anoncreds;
+use indy::blob_storage;
+use indy::future::Future;
+
+pub struct Anoncreds {}
+
+impl Anoncreds {
+    pub fn issuer_create_schema(issuer_did: &str, name: &str, version: &str, attrs: &str) -> Result<(String, String), IndyError> {
+        anoncreds::issuer_create_schema(issuer_did, name, version, attrs).wait()
+    }
+
+    pub fn issuer_create_and_store_credential_def(wallet_handle: i32, issuer_did: &str, schema_json: &str, tag: &str,
+                                                  signature_type: Option<&str>, config_json: &str) -> Result<(String, String), IndyError> {
+        anoncreds::issuer_create_and_store_credential_def(wallet_handle, issuer_did, schema_json, tag, signature_type, config_json).wait()
+    }
+
+    pub fn issuer_create_and_store_revoc_reg(wallet_handle: i32, issuer_did: &str, revoc_def_type: Option<&str>, tag: &str,
+                                             cred_def_id: &str, config_json: &str, tails_writer_handle: i32) -> Result<(String, String, String), IndyError> {
+        anoncreds::issuer_create_and_store_revoc_reg(wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle).wait()
+    }
+
+    pub fn issuer_create_credential_offer(wallet_handle: i32, cred_def_id: &str) -> Result<String, IndyError> {
+        anoncreds::issuer_create_credential_offer(wallet_handle, cred_def_id).wait()
+    }
+
+    pub fn issuer_create_credential(wallet_handle: i32, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str,
+                                    rev_reg_id: Option<&str>, blob_storage_reader_handle: i32) -> Result<(String, Option<String>, Option<String>), IndyError> {
+        anoncreds::issuer_create_credential(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle).wait()
+    }
+
+    pub fn encode_credential_values(cred_values_json: &str) -> Result<String, IndyError> {
+        anoncreds::encode_credential_values(cred_values_json).wait()
+    }
+
+    pub fn prover_create_master_secret(wallet_handle: i32, master_secret_id: Option<&str>) -> Result<String, IndyError> {
+        anoncreds::prover_create_master_secret(wallet_handle, master_secret_id).wait()
+    }
+
+    pub fn prover_create_credential_req(wallet_handle: i32, prover_did: &str, cred_offer_json: &str,
+                                        cred_def_json: &str, master_secret_id: &str) -> Result<(String, String), IndyError> {
+        anoncreds::prover_create_credential_req(wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id).wait()
+    }
+
+    pub fn prover_store_credential(wallet_handle: i32, cred_id: Option<&str>, cred_req_metadata_json: &str, cred_json: &str,
+                                   cred_def_json: &str, rev_reg_def_json: Option<&str>) -> Result<String, IndyError> {
+        anoncreds::prover_store_credential(wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json).wait()
+    }
+
+    pub fn prover_get_credentials(wallet_handle: i32, filter_json: Option<&str>) -> Result<String, IndyError> {
+        anoncreds::prover_get_credentials(wallet_handle, filter_json).wait()
+    }
+
+    pub fn prover_search_credentials(wallet_handle: i32, query_json: Option<&str>) -> Result<(i32, usize), IndyError> {
+        anoncreds::prover_search_credentials(wallet_handle, query_json).wait()
+    }
+
+    pub fn prover_fetch_credentials(search_handle: i32, count: usize) -> Result<String, IndyError> {
+        anoncreds::prover_fetch_credentials(search_handle, count).wait()
+    }
+
+    pub fn prover_close_credentials_search(search_handle: i32) -> Result<(), IndyError> {
+        anoncreds::prover_close_credentials_search(search_handle).wait()
+    }
+
+    pub fn prover_create_proof(wallet_handle: i32, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str,
+                               schemas_json: &str, credential_defs_json: &str, rev_states_json: &str) -> Result<String, IndyError> {
+        anoncreds::prover_create_proof(wallet_handle, proof_req_json, requested_credentials_json, master_secret_id,
+                                       schemas_json, credential_defs_json, rev_states_json).wait()
+    }
+
+    pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str,
+                                 rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<bool, IndyError> {
+        anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json).wait()
+    }
+
+    pub fn open_tails_writer(tails_dir: &str) -> Result<i32, IndyError> {
+        blob_storage::open_writer("default", &Anoncreds::_tails_config(tails_dir)).wait()
+    }
+
+    pub fn open_tails_reader(tails_dir: &str) -> Result<i32, IndyError> {
+        blob_storage::open_reader("default", &Anoncreds::_tails_config(tails_dir)).wait()
+    }
+
+    fn _tails_config(tails_dir: &str) -> String {
+        json!({ "base_dir": tails_dir, "uri_pattern": "" }).to_string()
+    }
+}
@@ -0,0 +1,31 @@
+use indy::IndyError;
+use indy::crypto;
+use indy::future::Future;
+
+pub struct Crypto {}
+
+impl Crypto {
+    pub fn sign(wallet_handle: i32, signer_vk: &str, message: &[u8]) -> Result<Vec<u8>, IndyError> {
+        crypto::sign(wallet_handle, signer_vk, message).wait()
+    }
+
+    pub fn verify(signer_vk: &str, message: &[u8], signature: &[u8]) -> Result<bool, IndyError> {
+        crypto::verify(signer_vk, message, signature).wait()
+    }
+
+    pub fn anon_crypt(recipient_vk: &str, message: &[u8]) -> Result<Vec<u8>, IndyError> {
+        crypto::anon_crypt(recipient_vk, message).wait()
+    }
+
+    pub fn anon_decrypt(wallet_handle: i32, recipient_vk: &str, encrypted_message: &[u8]) -> Result<Vec<u8>, IndyError> {
+        crypto::anon_decrypt(wallet_handle, recipient_vk, encrypted_message).wait()
+    }
+
+    pub fn pack_message(wallet_handle: i32, message: &[u8], receiver_keys: &str, sender: Option<&str>) -> Result<Vec<u8>, IndyError> {
+        crypto::pack_message(wallet_handle, message, receiver_keys, sender).wait()
+    }
+
+    pub fn unpack_message(wallet_handle: i32, jwe: &[u8]) -> Result<Vec<u8>, IndyError> {
+        crypto::unpack_message(wallet_handle, jwe).wait()
+    }
+}
@@ -30,6 +30,14 @@ impl Did {
         did::list_my_dids_with_metadata(wallet_handle).wait()
     }
 
+    pub fn store_their_did(wallet_handle: WalletHandle, identity_json: &str) -> Result<(), IndyError> {
+        did::store_their_did(wallet_handle, identity_json).wait()
+    }
+
+    pub fn key_for_local_did(wallet_handle: WalletHandle, did: &str) -> Result<String, IndyError> {
+        did::key_for_local_did(wallet_handle, did).wait()
+    }
+
     pub fn abbreviate_verkey(did: &str, verkey: &str) -> Result<String, IndyError> {
         did::abbreviate_verkey(did, verkey).wait()
     }
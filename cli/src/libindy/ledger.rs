@@ -62,6 +62,42 @@ impl Ledger {
         ledger::build_get_cred_def_request(submitter_did, id).wait()
     }
 
+    pub fn build_revoc_reg_def_request(submitter_did: &str, data: &str) -> Result<String, IndyError> {
+        ledger::build_revoc_reg_def_request(submitter_did, data).wait()
+    }
+
+    pub fn build_get_revoc_reg_def_request(submitter_did: Option<&str>, id: &str) -> Result<String, IndyError> {
+        ledger::build_get_revoc_reg_def_request(submitter_did, id).wait()
+    }
+
+    pub fn parse_get_revoc_reg_def_response(get_revoc_reg_def_response: &str) -> Result<(String, String), IndyError> {
+        ledger::parse_get_revoc_reg_def_response(get_revoc_reg_def_response).wait()
+    }
+
+    pub fn build_revoc_reg_entry_request(submitter_did: &str, revoc_reg_def_id: &str, rev_def_type: &str, value: &str) -> Result<String, IndyError> {
+        ledger::build_revoc_reg_entry_request(submitter_did, revoc_reg_def_id, rev_def_type, value).wait()
+    }
+
+    pub fn build_get_revoc_reg_request(submitter_did: Option<&str>, revoc_reg_def_id: &str, timestamp: i64) -> Result<String, IndyError> {
+        ledger::build_get_revoc_reg_request(submitter_did, revoc_reg_def_id, timestamp).wait()
+    }
+
+    pub fn parse_get_revoc_reg_response(get_revoc_reg_response: &str) -> Result<(String, String, u64), IndyError> {
+        ledger::parse_get_revoc_reg_response(get_revoc_reg_response).wait()
+    }
+
+    pub fn build_get_revoc_reg_delta_request(submitter_did: Option<&str>, revoc_reg_def_id: &str, from: i64, to: i64) -> Result<String, IndyError> {
+        ledger::build_get_revoc_reg_delta_request(submitter_did, revoc_reg_def_id, from, to).wait()
+    }
+
+    pub fn parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response: &str) -> Result<(String, String, u64), IndyError> {
+        ledger::parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response).wait()
+    }
+
+    pub fn build_get_txn_request(submitter_did: Option<&str>, ledger_type: Option<&str>, seq_no: i32) -> Result<String, IndyError> {
+        ledger::build_get_txn_request(submitter_did, ledger_type, seq_no).wait()
+    }
+
     pub fn build_node_request(submitter_did: &str, target_did: &str, data: &str) -> Result<String, IndyError> {
         ledger::build_node_request(submitter_did, target_did, data).wait()
     }
@@ -0,0 +1,27 @@
+use indy::IndyError;
+use indy::pairwise;
+use indy::future::Future;
+
+pub struct Pairwise {}
+
+impl Pairwise {
+    pub fn create_pairwise(wallet_handle: i32, their_did: &str, my_did: &str, metadata: Option<&str>) -> Result<(), IndyError> {
+        pairwise::create_pairwise(wallet_handle, their_did, my_did, metadata).wait()
+    }
+
+    pub fn list_pairwise(wallet_handle: i32) -> Result<String, IndyError> {
+        pairwise::list_pairwise(wallet_handle).wait()
+    }
+
+    pub fn search_pairwise(wallet_handle: i32, query_json: Option<&str>) -> Result<String, IndyError> {
+        pairwise::search_pairwise(wallet_handle, query_json).wait()
+    }
+
+    pub fn get_pairwise(wallet_handle: i32, their_did: &str) -> Result<String, IndyError> {
+        pairwise::get_pairwise(wallet_handle, their_did).wait()
+    }
+
+    pub fn set_pairwise_metadata(wallet_handle: i32, their_did: &str, metadata: Option<&str>) -> Result<(), IndyError> {
+        pairwise::set_pairwise_metadata(wallet_handle, their_did, metadata).wait()
+    }
+}
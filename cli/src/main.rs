@@ -3,7 +3,7 @@
 extern crate atty;
 extern crate ansi_term;
 extern crate unescape;
-#[cfg(test)]
+#[macro_use]
 extern crate lazy_static;
 extern crate libc;
 extern crate linefeed;
@@ -21,21 +21,23 @@ extern crate indyrs as indy;
 #[macro_use]
 mod utils;
 mod command_executor;
+mod batch;
 #[macro_use]
 mod commands;
 mod libindy;
 
+use crate::batch::BatchExecutor;
 use crate::command_executor::CommandExecutor;
 
-use crate::commands::{common, did, ledger, pool, wallet, payment_address};
+use crate::commands::{common, did, ledger, pool, wallet, payment_address, anoncreds, crypto, pairwise, tails};
 use crate::utils::history;
+use crate::utils::output::{self, OutputFormat};
 
 use linefeed::{Reader, ReadResult, Terminal, Signal};
 use linefeed::complete::{Completer, Completion};
 
 use std::env;
-use std::fs::File;
-use std::io::BufReader;
+use std::path::Path;
 use std::rc::Rc;
 
 fn main() {
@@ -66,10 +68,29 @@ fn main() {
                     Err(err) => return println_err!("{}", err)
                 }
             }
+            "--output" => {
+                let format = unwrap_or_return!(args.next(), println_err!("Output format is not specified"));
+                let format = unwrap_or_return!(OutputFormat::from_str(&format), println_err!("Unsupported output format \"{}\"", format));
+                output::set_format(format);
+            }
             "--plugins" => {
                 let plugins = unwrap_or_return!(args.next(), println_err!("Plugins are not specified"));
                 _load_plugins(&command_executor, &plugins)
             }
+            "--exec" => {
+                let commands = unwrap_or_return!(args.next(), println_err!("Commands to execute are not specified"));
+                let res = execute_commands(&command_executor, &commands);
+                return _exit(command_executor, res);
+            }
+            _ if command_executor.has_command(&arg) => {
+                let commands = Some(arg.clone()).into_iter()
+                    .chain(args.by_ref())
+                    .map(|arg| if arg == ";" { arg } else { CommandExecutor::quote_param(&arg) })
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                let res = execute_commands(&command_executor, &commands);
+                return _exit(command_executor, res);
+            }
             _ if args.len() == 0 => {
                 execute_batch(&command_executor, Some(&arg));
 
@@ -134,6 +155,7 @@ fn build_executor() -> CommandExecutor {
         .add_command(did::rotate_key_command::new())
         .add_command(did::list_command::new())
         .add_command(did::qualify_command::new())
+        .add_command(did::store_their_command::new())
         .finalize_group()
         .add_group(pool::group::new())
         .add_command(pool::create_command::new())
@@ -154,6 +176,12 @@ fn build_executor() -> CommandExecutor {
         .add_command(wallet::detach_command::new())
         .add_command(wallet::export_command::new())
         .add_command(wallet::import_command::new())
+        .add_command(wallet::record_add_command::new())
+        .add_command(wallet::record_get_command::new())
+        .add_command(wallet::record_update_command::new())
+        .add_command(wallet::record_delete_command::new())
+        .add_command(wallet::record_tag_command::new())
+        .add_command(wallet::record_search_command::new())
         .finalize_group()
         .add_group(ledger::group::new())
         .add_command(ledger::nym_command::new())
@@ -165,6 +193,12 @@ fn build_executor() -> CommandExecutor {
         .add_command(ledger::get_validator_info_command::new())
         .add_command(ledger::cred_def_command::new())
         .add_command(ledger::get_cred_def_command::new())
+        .add_command(ledger::revoc_reg_def_command::new())
+        .add_command(ledger::revoc_reg_entry_command::new())
+        .add_command(ledger::get_revoc_reg_def_command::new())
+        .add_command(ledger::get_revoc_reg_command::new())
+        .add_command(ledger::get_revoc_reg_delta_command::new())
+        .add_command(ledger::txns_command::new())
         .add_command(ledger::node_command::new())
         .add_command(ledger::pool_config_command::new())
         .add_command(ledger::pool_restart_command::new())
@@ -182,6 +216,8 @@ fn build_executor() -> CommandExecutor {
         .add_command(ledger::get_auth_rule_command::new())
         .add_command(ledger::save_transaction_command::new())
         .add_command(ledger::load_transaction_command::new())
+        .add_command(ledger::export_transaction_command::new())
+        .add_command(ledger::import_transaction_command::new())
         .add_command(ledger::taa_command::new())
         .add_command(ledger::aml_command::new())
         .add_command(ledger::endorse_transaction_command::new())
@@ -192,6 +228,37 @@ fn build_executor() -> CommandExecutor {
         .add_command(payment_address::sign_command::new())
         .add_command(payment_address::verify_command::new())
         .finalize_group()
+        .add_group(anoncreds::group::new())
+        .add_command(anoncreds::create_schema_command::new())
+        .add_command(anoncreds::create_cred_def_command::new())
+        .add_command(anoncreds::create_revoc_reg_command::new())
+        .add_command(anoncreds::create_master_secret_command::new())
+        .add_command(anoncreds::create_cred_offer_command::new())
+        .add_command(anoncreds::create_cred_req_command::new())
+        .add_command(anoncreds::create_cred_command::new())
+        .add_command(anoncreds::store_cred_command::new())
+        .add_command(anoncreds::list_creds_command::new())
+        .add_command(anoncreds::search_creds_command::new())
+        .add_command(anoncreds::create_proof_command::new())
+        .add_command(anoncreds::verify_proof_command::new())
+        .finalize_group()
+        .add_group(crypto::group::new())
+        .add_command(crypto::sign_command::new())
+        .add_command(crypto::verify_command::new())
+        .add_command(crypto::pack_command::new())
+        .add_command(crypto::unpack_command::new())
+        .add_command(crypto::anoncrypt_command::new())
+        .add_command(crypto::anondecrypt_command::new())
+        .finalize_group()
+        .add_group(pairwise::group::new())
+        .add_command(pairwise::create_command::new())
+        .add_command(pairwise::list_command::new())
+        .add_command(pairwise::get_command::new())
+        .add_command(pairwise::set_metadata_command::new())
+        .finalize_group()
+        .add_group(tails::group::new())
+        .add_command(tails::verify_command::new())
+        .finalize_group()
         .finalize()
 }
 
@@ -237,19 +304,46 @@ fn execute_interactive<T>(command_executor: CommandExecutor, mut reader: Reader<
 
 fn execute_batch(command_executor: &CommandExecutor, script_path: Option<&str>) {
     command_executor.ctx().set_batch_mode();
+    let mut batch_executor = BatchExecutor::new(command_executor);
     if let Some(script_path) = script_path {
-        let file = match File::open(script_path) {
-            Ok(file) => file,
-            Err(err) => return println_err!("Can't open script file {}\nError: {}", script_path, err),
-        };
-        _iter_batch(command_executor, BufReader::new(file));
+        batch_executor.execute_file(Path::new(script_path)).ok();
     } else {
         let stdin = std::io::stdin();
-        _iter_batch(command_executor, stdin.lock());
+        batch_executor.execute(stdin.lock()).ok();
     };
     command_executor.ctx().set_not_batch_mode();
 }
 
+// Commands are executed as lines of a batch script, so the number of the failed line is the number of the failed command
+fn execute_commands(command_executor: &CommandExecutor, commands: &str) -> Result<(), i32> {
+    command_executor.ctx().set_batch_mode();
+    let commands = CommandExecutor::split_commands(commands).join("\n");
+    let mut batch_executor = BatchExecutor::new(command_executor);
+    let res = batch_executor.execute(commands.as_bytes())
+        .map_err(|()| _exit_code(batch_executor.failed_line()));
+    command_executor.ctx().set_not_batch_mode();
+    res
+}
+
+// Exit code is the number of the failed command, failures which don't belong to a command (like script syntax errors) exit with 255
+const FAILURE_EXIT_CODE: i32 = 255;
+
+fn _exit_code(failed_command: Option<usize>) -> i32 {
+    match failed_command {
+        Some(num) if num < FAILURE_EXIT_CODE as usize => num as i32,
+        _ => FAILURE_EXIT_CODE
+    }
+}
+
+// Opened wallets and pools are closed on executor drop, so it must happen before the process exit
+fn _exit(command_executor: CommandExecutor, res: Result<(), i32>) {
+    drop(command_executor);
+
+    if let Err(code) = res {
+        std::process::exit(code);
+    }
+}
+
 fn _load_plugins(command_executor: &CommandExecutor, plugins_str: &str) {
     for plugin in plugins_str.split(',') {
         let parts: Vec<&str> = plugin.split(':').collect::<Vec<&str>>();
@@ -264,13 +358,17 @@ fn _load_plugins(command_executor: &CommandExecutor, plugins_str: &str) {
 fn _print_help() {
     println_acc!("Hyperledger Indy CLI");
     println!();
-    println_acc!("CLI supports 2 execution modes:");
+    println_acc!("CLI supports 3 execution modes:");
     println_acc!("\tInteractive - reads commands from terminal. To start just run indy-cli without params.");
     println_acc!("\tUsage: indy-cli");
     println!();
     println_acc!("\tBatch - all commands will be read from text file or pipe and executed in series.");
     println_acc!("\tUsage: indy-cli <path-to-text-file>");
     println!();
+    println_acc!("\tCommands - commands separated by `;` will be executed in series, CLI exits with non-zero code if any of them fails.");
+    println_acc!("\tUsage: indy-cli <command> [<params>] [\\; <command> [<params>]]...");
+    println_acc!("\tUsage: indy-cli --exec \"<command> [<params>]; <command> [<params>]\"");
+    println!();
     println_acc!("Options:");
     println_acc!("\tLoad plugins in Libindy.");
     println_acc!("\tUsage: indy-cli --plugins <lib-1-name>:<init-func-1-name>,...,<lib-n-name>:<init-func-n-name>");
@@ -284,36 +382,9 @@ fn _print_help() {
     println_acc!("\t\ttaaAcceptanceMechanism - transaction author agreement acceptance mechanism to use for sending write transactions to the Ledger.");
     println_acc!("\tUsage: indy-cli --config <path-to-config-json-file>");
     println!();
-}
-
-fn _iter_batch<T>(command_executor: &CommandExecutor, reader: T) where T: std::io::BufRead {
-    let mut line_num = 1;
-    for line in reader.lines() {
-        let line = if let Ok(line) = line { line } else {
-            return println_err!("Can't parse line #{}", line_num);
-        };
-
-        if line.starts_with('#') || line.is_empty() {
-            // Skip blank lines and lines starting with #
-            continue;
-        }
-
-        println!("{}", line);
-        let (line, force) = if line.starts_with('-') {
-            (line[1..].as_ref(), true)
-        } else {
-            (line[0..].as_ref(), false)
-        };
-        if command_executor.execute(line).is_err() && !force {
-            return println_err!("Batch execution failed at line #{}", line_num);
-        }
-        println!();
-        line_num += 1;
-
-        if command_executor.ctx().is_exit() {
-            break;
-        }
-    }
+    println_acc!("\tPrint the result of each command as a single JSON object with status, data and error code.");
+    println_acc!("\tUsage: indy-cli --output json");
+    println!();
 }
 
 impl<Term: Terminal> Completer<Term> for CommandExecutor {
@@ -7,6 +7,7 @@ pub mod term;
 #[cfg(test)]
 pub mod test;
 pub mod table;
+pub mod output;
 pub mod file;
 pub mod history;
 
@@ -0,0 +1,301 @@
+use std::cell::{Cell, RefCell};
+
+use ansi_term::Color;
+use indy::ErrorCode;
+
+use crate::utils::term;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn from_str(format: &str) -> Option<OutputFormat> {
+        match format {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageKind {
+    Success,
+    Warning,
+    Error,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Success,
+    Error,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    pub command: Option<String>,
+    pub status: Status,
+    pub messages: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_name: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub data: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub output: Vec<String>,
+    #[serde(skip)]
+    pending_output: String,
+}
+
+impl Report {
+    fn new(command: Option<&str>) -> Report {
+        Report {
+            command: command.map(String::from),
+            status: Status::Success,
+            messages: Vec::new(),
+            warnings: Vec::new(),
+            errors: Vec::new(),
+            error_code: None,
+            error_name: None,
+            data: Vec::new(),
+            output: Vec::new(),
+            pending_output: String::new(),
+        }
+    }
+}
+
+// CLI executes commands in a single thread, so output of json mode is collected per thread
+thread_local! {
+    static FORMAT: Cell<OutputFormat> = Cell::new(OutputFormat::Text);
+    static REPORT: RefCell<Option<Report>> = RefCell::new(None);
+    static CAPTURED: RefCell<Option<Vec<String>>> = RefCell::new(None);
+}
+
+pub fn set_format(format: OutputFormat) {
+    FORMAT.with(|f| f.set(format));
+}
+
+pub fn get_format() -> OutputFormat {
+    FORMAT.with(|f| f.get())
+}
+
+pub fn is_json() -> bool {
+    get_format() == OutputFormat::Json
+}
+
+pub fn begin(command: &str) {
+    if !is_json() {
+        return;
+    }
+
+    REPORT.with(|report| *report.borrow_mut() = Some(Report::new(Some(command))));
+}
+
+pub fn finish(res: &Result<(), ()>) {
+    let report = REPORT.with(|report| report.borrow_mut().take());
+
+    if let Some(mut report) = report {
+        report.status = if res.is_ok() { Status::Success } else { Status::Error };
+        _print_report(report);
+    }
+}
+
+pub fn print_message(kind: MessageKind, message: String) {
+    if is_json() {
+        return add_message(kind, message);
+    }
+
+    if term::is_term() {
+        let color = match kind {
+            MessageKind::Success => Color::Green,
+            MessageKind::Warning => Color::Blue,
+            MessageKind::Error => Color::Red,
+        };
+        println!("{}", color.bold().paint(message))
+    } else {
+        println!("{}", message)
+    }
+}
+
+// Calls the function and returns values reported by it with `add_result` in addition to its result.
+// Output is printed as usual, so capturing doesn't change what user sees.
+pub fn capture<F, R>(f: F) -> (R, Vec<String>) where F: FnOnce() -> R {
+    let prev = CAPTURED.with(|captured| captured.replace(Some(Vec::new())));
+    let res = f();
+    let captured = CAPTURED.with(|captured| captured.replace(prev)).unwrap_or_default();
+    (res, captured)
+}
+
+pub fn add_message(kind: MessageKind, message: String) {
+    let standalone = REPORT.with(|report| {
+        match report.borrow_mut().as_mut() {
+            Some(report) => {
+                _add_message(report, kind, message);
+                None
+            }
+            None => Some(message)
+        }
+    });
+
+    // Messages printed outside of a command (batch processing, startup options) form a report of their own
+    if let Some(message) = standalone {
+        let mut report = Report::new(None);
+        if kind == MessageKind::Error {
+            report.status = Status::Error;
+        }
+        _add_message(&mut report, kind, message);
+        _print_report(report);
+    }
+}
+
+pub fn add_output(text: &str, new_line: bool) {
+    let collected = REPORT.with(|report| {
+        match report.borrow_mut().as_mut() {
+            Some(report) => {
+                report.pending_output.push_str(text);
+                if new_line {
+                    let line = ::std::mem::replace(&mut report.pending_output, String::new());
+                    report.output.push(line);
+                }
+                true
+            }
+            None => false
+        }
+    });
+
+    // Text printed outside of a command (help of CLI options) isn't a command result
+    if !collected {
+        if new_line { println!("{}", text) } else { print!("{}", text) }
+    }
+}
+
+pub fn add_data(data: serde_json::Value) {
+    REPORT.with(|report| {
+        if let Some(report) = report.borrow_mut().as_mut() {
+            report.data.push(data);
+        }
+    })
+}
+
+// Reports value produced by the command (like created DID) that batch script can assign to variable
+pub fn add_result(value: &str) {
+    CAPTURED.with(|captured| {
+        if let Some(captured) = captured.borrow_mut().as_mut() {
+            captured.push(value.to_string());
+        }
+    })
+}
+
+pub fn set_error_code(error_code: ErrorCode) {
+    REPORT.with(|report| {
+        if let Some(report) = report.borrow_mut().as_mut() {
+            report.error_code = Some(error_code as i32);
+            report.error_name = Some(format!("{:?}", error_code));
+        }
+    })
+}
+
+// Prints json received from Libindy as is in text mode and puts it into report data in json mode
+pub fn print_json(json: &str) {
+    if !is_json() {
+        return println!("{}", json);
+    }
+
+    match serde_json::from_str::<serde_json::Value>(json) {
+        Ok(value) => add_data(value),
+        Err(_) => add_output(json, true)
+    }
+}
+
+fn _add_message(report: &mut Report, kind: MessageKind, message: String) {
+    match kind {
+        MessageKind::Success => report.messages.push(message),
+        MessageKind::Warning => report.warnings.push(message),
+        MessageKind::Error => report.errors.push(message),
+    }
+}
+
+fn _print_report(mut report: Report) {
+    if !report.pending_output.is_empty() {
+        let line = ::std::mem::replace(&mut report.pending_output, String::new());
+        report.output.push(line);
+    }
+
+    match serde_json::to_string(&report) {
+        Ok(report) => println!("{}", report),
+        Err(err) => println!("{{\"status\":\"error\",\"errors\":[\"Can't serialize output: {}\"]}}", err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn take_report() -> Report {
+        REPORT.with(|report| report.borrow_mut().take()).unwrap()
+    }
+
+    #[test]
+    fn report_collects_messages_data_and_error_code() {
+        set_format(OutputFormat::Json);
+        begin("did list");
+
+        add_message(MessageKind::Success, "success".to_string());
+        add_message(MessageKind::Error, "error".to_string());
+        add_output("out", false);
+        add_output("put", true);
+        add_data(json!({"did": "VsKV7grR1BUE29mG2Fm2kX"}));
+        set_error_code(ErrorCode::WalletItemNotFound);
+
+        let report = take_report();
+        set_format(OutputFormat::Text);
+
+        assert_eq!(Some("did list".to_string()), report.command);
+        assert_eq!(vec!["success".to_string()], report.messages);
+        assert_eq!(vec!["error".to_string()], report.errors);
+        assert_eq!(vec!["output".to_string()], report.output);
+        assert_eq!(vec![json!({"did": "VsKV7grR1BUE29mG2Fm2kX"})], report.data);
+        assert_eq!(Some(ErrorCode::WalletItemNotFound as i32), report.error_code);
+        assert_eq!(Some("WalletItemNotFound".to_string()), report.error_name);
+    }
+
+    #[test]
+    fn begin_does_nothing_for_text_format() {
+        set_format(OutputFormat::Text);
+        begin("did list");
+
+        assert!(REPORT.with(|report| report.borrow().is_none()));
+    }
+
+    #[test]
+    fn capture_collects_results() {
+        let (res, captured) = capture(|| {
+            print_message(MessageKind::Success, "success \"message\"".to_string());
+            add_result("VsKV7grR1BUE29mG2Fm2kX");
+            42
+        });
+
+        assert_eq!(42, res);
+        assert_eq!(vec!["VsKV7grR1BUE29mG2Fm2kX".to_string()], captured);
+        assert!(CAPTURED.with(|captured| captured.borrow().is_none()));
+    }
+
+    #[test]
+    fn report_serializes_status_in_lowercase() {
+        let mut report = Report::new(Some("did list"));
+        report.status = Status::Error;
+
+        let report = serde_json::to_value(&report).unwrap();
+        assert_eq!(json!({"command": "did list", "status": "error", "messages": []}), report);
+    }
+}
@@ -6,11 +6,20 @@ use prettytable::Table;
 use prettytable::row::Row;
 use prettytable::cell::Cell;
 
+use crate::utils::output;
+
 pub fn print_list_table(rows: &[serde_json::Value], headers: &[(&str, &str)], empty_msg: &str) {
     if rows.is_empty() {
         return println_succ!("{}", empty_msg);
     }
 
+    if output::is_json() {
+        for row in rows {
+            output::add_data(json_row(row, headers));
+        }
+        return;
+    }
+
     let mut table = Table::new();
 
     print_header(&mut table, headers);
@@ -23,6 +32,10 @@ pub fn print_list_table(rows: &[serde_json::Value], headers: &[(&str, &str)], em
 }
 
 pub fn print_table(row: &serde_json::Value, headers: &[(&str, &str)]) {
+    if output::is_json() {
+        return output::add_data(json_row(row, headers));
+    }
+
     let mut table = Table::new();
 
     print_header(&mut table, headers);
@@ -32,6 +45,15 @@ pub fn print_table(row: &serde_json::Value, headers: &[(&str, &str)]) {
     table.printstd();
 }
 
+// Keeps only the columns which would be shown in the table
+fn json_row(row: &serde_json::Value, headers: &[(&str, &str)]) -> serde_json::Value {
+    let columns = headers.iter()
+        .map(|&(key, _)| (key.to_string(), row[key].clone()))
+        .collect::<serde_json::Map<String, serde_json::Value>>();
+
+    serde_json::Value::Object(columns)
+}
+
 pub fn print_header(table: &mut Table, headers: &[(&str, &str)]) {
     let tittles = headers.iter().clone()
         .map(|&(_, ref header)| Cell::new(header)
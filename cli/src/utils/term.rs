@@ -3,39 +3,30 @@ use atty;
 #[macro_export]
 macro_rules! println_err {
     ($($arg:tt)*) => (
-        if $crate::utils::term::is_term() {
-            println!("{}", $crate::ansi_term::Color::Red.bold().paint(format!($($arg)*)))
-        } else {
-            println!($($arg)*)
-        }
+        $crate::utils::output::print_message($crate::utils::output::MessageKind::Error, format!($($arg)*))
     )
 }
 
 #[macro_export]
 macro_rules! println_succ {
     ($($arg:tt)*) => (
-        if $crate::utils::term::is_term() {
-            println!("{}", $crate::ansi_term::Color::Green.bold().paint(format!($($arg)*)))
-        } else {
-            println!($($arg)*)
-        }
+        $crate::utils::output::print_message($crate::utils::output::MessageKind::Success, format!($($arg)*))
     )
 }
+
 #[macro_export]
 macro_rules! println_warn {
     ($($arg:tt)*) => (
-        if $crate::utils::term::is_term() {
-            println!("{}", $crate::ansi_term::Color::Blue.bold().paint(format!($($arg)*)))
-        } else {
-            println!($($arg)*)
-        }
+        $crate::utils::output::print_message($crate::utils::output::MessageKind::Warning, format!($($arg)*))
     )
 }
 
 #[macro_export]
 macro_rules! println_acc {
     ($($arg:tt)*) => (
-       if $crate::utils::term::is_term() {
+       if $crate::utils::output::is_json() {
+           $crate::utils::output::add_output(&format!($($arg)*), true)
+       } else if $crate::utils::term::is_term() {
            println!("{}", $crate::ansi_term::Style::new().bold().paint(format!($($arg)*)))
        } else {
            println!($($arg)*)
@@ -43,6 +34,39 @@ macro_rules! println_acc {
     )
 }
 
+#[macro_export]
+macro_rules! println_out {
+    () => (
+        println_out!("")
+    );
+    ($($arg:tt)*) => (
+        if $crate::utils::output::is_json() {
+            $crate::utils::output::add_output(&format!($($arg)*), true)
+        } else {
+            println!($($arg)*)
+        }
+    )
+}
+
+#[macro_export]
+macro_rules! print_out {
+    ($($arg:tt)*) => (
+        if $crate::utils::output::is_json() {
+            $crate::utils::output::add_output(&format!($($arg)*), false)
+        } else {
+            print!($($arg)*)
+        }
+    )
+}
+
+// Prompts of interactive input go to the terminal in any output mode, so they aren't mixed with command output
+#[macro_export]
+macro_rules! println_prompt {
+    ($($arg:tt)*) => (
+        eprintln!($($arg)*)
+    )
+}
+
 // TODO: move to more relevant place
 #[macro_export]
 macro_rules! map_println_err {
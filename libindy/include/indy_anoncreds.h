@@ -121,6 +121,14 @@ extern "C" {
                                                                                           const char*   merged_rev_reg_delta)
                                                                      );
 
+    extern indy_error_t indy_encode_credential_values(indy_handle_t command_handle,
+                                                      const char *  cred_values_json,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   cred_values_json)
+                                                      );
+
     extern indy_error_t indy_prover_create_master_secret(indy_handle_t command_handle,
                                                          indy_handle_t wallet_handle,
                                                          const char *  master_secret_id,
@@ -274,6 +282,33 @@ extern "C" {
                                                                         indy_bool_t   valid )
                                                    );
 
+    extern indy_error_t indy_verifier_verify_proof_with_config(indy_handle_t command_handle,
+                                                               const char *  proof_request_json,
+                                                               const char *  proof_json,
+                                                               const char *  schemas_json,
+                                                               const char *  credential_defs_jsons,
+                                                               const char *  rev_reg_defs_json,
+                                                               const char *  rev_regs_json,
+                                                               const char *  config_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    indy_bool_t   valid )
+                                                               );
+
+
+    extern indy_error_t indy_verifier_verify_proof_detailed(indy_handle_t command_handle,
+                                                            const char *  proof_request_json,
+                                                            const char *  proof_json,
+                                                            const char *  schemas_json,
+                                                            const char *  credential_defs_json,
+                                                            const char *  rev_reg_defs_json,
+                                                            const char *  rev_regs_json,
+
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   report_json)
+                                                            );
 
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
@@ -301,6 +336,41 @@ extern "C" {
                                                                           const char*   updated_rev_state_json)
                                                      );
 
+    extern indy_error_t indy_prover_update_cached_revocation_state(indy_handle_t command_handle,
+                                                                   indy_handle_t wallet_handle,
+                                                                   indy_handle_t blob_storage_reader_handle,
+                                                                   const char *  rev_reg_def_json,
+                                                                   const char *  rev_reg_delta_json,
+                                                                   indy_u64_t    timestamp,
+                                                                   const char *  cred_rev_id,
+
+                                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   rev_state_json)
+                                                                   );
+
+    extern indy_error_t indy_prover_get_cached_revocation_state(indy_handle_t command_handle,
+                                                                indy_handle_t wallet_handle,
+                                                                const char *  rev_reg_id,
+                                                                const char *  cred_rev_id,
+
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   rev_state_json)
+                                                                );
+
+    extern indy_error_t indy_prover_check_revocation_status(indy_handle_t command_handle,
+                                                            indy_handle_t wallet_handle,
+                                                            const char *  cred_id,
+                                                            const char *  rev_reg_def_json,
+                                                            const char *  rev_reg_delta_json,
+                                                            indy_u64_t    timestamp,
+
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   revocation_info_json)
+                                                            );
+
 
     extern indy_error_t indy_generate_nonce(indy_handle_t command_handle,
                                             void           (*cb)(indy_handle_t command_handle_,
@@ -308,6 +378,14 @@ extern "C" {
                                                                  const char*   nonce)
                                             );
 
+    extern indy_error_t indy_verifier_validate_proof_request(indy_handle_t command_handle,
+                                                             const char *  proof_request_json,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   report_json)
+                                                             );
+
     extern indy_error_t indy_to_unqualified(indy_handle_t command_handle,
                                             const char *  entity,
                                             void           (*cb)(indy_handle_t command_handle_,
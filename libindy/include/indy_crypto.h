@@ -7,6 +7,50 @@ extern "C" {
 
 
 
+    /// Registers external signer implementation (For example, HSM or KMS backed one).
+    ///
+    /// Keys created with "signer" field of key info set to the registered name keep
+    /// private part in the external signer and libindy delegates private key operations
+    /// (indy_crypto_sign, indy_sign_request, indy_crypto_auth_crypt, indy_pack_message) to it.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// signer_name: Signer name.
+    /// create_key: Signer create key operation handler
+    /// sign: Signer sign operation handler
+    /// crypto_box: Signer crypto box operation handler
+    /// free: Handler that allows to de-allocate data returned by signer handlers
+    ///
+    /// #Returns
+    /// Error code
+
+    extern indy_error_t indy_register_external_signer(indy_handle_t  command_handle,
+                                                      const char*    signer_name,
+                                                      indy_error_t (*createKeyFn)(const char* crypto_type,
+                                                                                  const char* config,
+                                                                                  const char** key_ref_p,
+                                                                                  const char** verkey_p),
+
+                                                      indy_error_t (*signFn)(const char*        key_ref,
+                                                                             const indy_u8_t*   message_raw,
+                                                                             indy_u32_t         message_len,
+                                                                             const indy_u8_t**  signature_raw_p,
+                                                                             indy_u32_t*        signature_len_p),
+
+                                                      indy_error_t (*cryptoBoxFn)(const char*        key_ref,
+                                                                                  const char*        their_vk,
+                                                                                  const indy_u8_t*   message_raw,
+                                                                                  indy_u32_t         message_len,
+                                                                                  const indy_u8_t*   nonce_raw,
+                                                                                  indy_u32_t         nonce_len,
+                                                                                  const indy_u8_t**  encrypted_raw_p,
+                                                                                  indy_u32_t*        encrypted_len_p),
+
+                                                      indy_error_t (*freeFn)(const void* ptr),
+
+                                                      void         (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                      );
+
     /// Creates keys pair and stores in the wallet.
     ///
     /// #Params
@@ -16,7 +60,10 @@ extern "C" {
     /// {
     ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
     ///                                Can be UTF-8, base64 or hex string.
-    ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+    ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Supported values: 'ed25519', 'secp256k1', 'p256'.
+    ///     "signer": string, // Optional name of external signer (see indy_register_external_signer) that will hold the private key.
+    ///                       // Seed can't be used together with signer.
+    ///     "signer_config": object, // Optional signer specific key config passed to the signer as is.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -97,6 +144,80 @@ extern "C" {
                                              );
 
 
+    /// Lists keys stored in the wallet.
+    ///
+    /// The following unencrypted tags are maintained for keys and can be used in the query
+    /// (timestamps are zero-padded to 20 digits, e.g. {"~created_at": {"$lt": "00000000001600000000"}}):
+    ///     {
+    ///         "~created_at": <time of key creation as unix timestamp>,
+    ///         "~crypto_type": <crypto type of the key, e.g. "ed25519">,
+    ///     }
+    /// Note that keys created before tags introduction have no tags.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// query_json: (optional, default all keys) Wallet query (WQL) over key tags.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - keys_json - list of keys:
+    ///     [{
+    ///         "verkey": string, - key id (verkey)
+    ///         "crypto_type": string, - crypto type of the key
+    ///         "metadata": Optional<string>, - the meta information stored with the key by indy_set_key_metadata
+    ///         "created_at": Optional<int>, - time of key creation as unix timestamp
+    ///     }]
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_list_keys(indy_handle_t     command_handle,
+                                       indy_handle_t     wallet_handle,
+                                       const char *const query_json,
+
+                                       void              (*cb)(indy_handle_t     command_handle,
+                                                               indy_error_t      err,
+                                                               const char *const keys_json)
+                                      );
+
+    /// Deletes the key and its meta information from the wallet.
+    ///
+    /// Key can't be deleted while it's used by my DID, pending key replacement of my DID
+    /// (see indy_replace_keys_start) or pairwise.
+    /// Note for keys of external signer only wallet reference is deleted.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// verkey - The key (verkey, key id) to delete.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_delete_key(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const verkey,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                indy_error_t      err)
+                                       );
+
+
 
 
     /// Signs a message with a key.
@@ -165,6 +286,128 @@ extern "C" {
                                                                 indy_bool_t   valid )
                                           );
 
+    /// Signs a payload as JWS (RFC 7515) with the key of DID stored in the wallet.
+    ///
+    /// Protected header contains "alg" (EdDSA for ed25519 keys, ES256K for secp256k1, ES256 for p256)
+    /// and "kid" set to DID key reference ("<fully qualified did>#key-1").
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// signer_did: DID of the signer (must be created with indy_create_and_store_my_did).
+    /// payload_raw: a pointer to first byte of payload to be signed
+    /// payload_len: a payload length
+    /// options_json: (optional) signing options as json:
+    /// {
+    ///     "detached": bool, (optional, false by default) omit payload from the result (RFC 7515 Appendix F)
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// jws: JWS in compact serialization
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_sign_jws(indy_handle_t      command_handle,
+                                             indy_handle_t      wallet_handle,
+                                             const char *       signer_did,
+                                             const indy_u8_t *  payload_raw,
+                                             indy_u32_t         payload_len,
+                                             const char *       options_json,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char *  jws)
+                                            );
+
+    /// Verifies JWS in compact serialization created by indy_crypto_sign_jws (or any other EdDSA/ES256K/ES256 JWS).
+    ///
+    /// Signer key is found by "kid" header: DID of the key reference must be stored in the wallet
+    /// as my DID or their DID (see indy_store_their_did).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// jws: JWS in compact serialization
+    /// detached_payload_raw: (optional) a pointer to first byte of payload for JWS with detached payload
+    /// detached_payload_len: detached payload length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// valid: true - if signature is valid, false - otherwise
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_verify_jws(indy_handle_t      command_handle,
+                                               indy_handle_t      wallet_handle,
+                                               const char *       jws,
+                                               const indy_u8_t *  detached_payload_raw,
+                                               indy_u32_t         detached_payload_len,
+
+                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err,
+                                                                    indy_bool_t   valid)
+                                              );
+
+    /// Builds and signs JWT (RFC 7519) with the key of DID stored in the wallet.
+    ///
+    /// "iss" claim is set to signer DID if not provided, "iat" claim is set to current time
+    /// and "exp" claim is set to current time + expires_in if expires_in is greater than 0.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// signer_did: DID of the signer (must be created with indy_create_and_store_my_did).
+    /// claims_json: JWT claims as json object
+    /// expires_in: token lifetime in seconds (0 - token without expiry)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// jwt: signed JWT
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_sign_jwt(indy_handle_t      command_handle,
+                                             indy_handle_t      wallet_handle,
+                                             const char *       signer_did,
+                                             const char *       claims_json,
+                                             indy_u64_t         expires_in,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char *  jwt)
+                                            );
+
+    /// Verifies JWT signature (see indy_crypto_verify_jws) and validates "exp" and "nbf" claims against current time.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// jwt: signed JWT
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// claims_json: JWT claims as json object
+    ///
+    /// #Errors
+    /// Common* (CommonInvalidStructure if signature is invalid or token is expired)
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_verify_jwt(indy_handle_t      command_handle,
+                                               indy_handle_t      wallet_handle,
+                                               const char *       jwt,
+
+                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err,
+                                                                    const char *  claims_json)
+                                              );
+
     /// **** THIS FUNCTION WILL BE DEPRECATED USE indy_pack_message() INSTEAD ****
     /// Encrypt a message by authenticated-encryption scheme.
     ///
@@ -404,7 +647,155 @@ extern "C" {
                                           );
 
 
+    /// Packs a file for the receivers by encrypting its content in chunks (Experimental)
+    ///
+    /// Unlike indy_pack_message the payload is never loaded in memory completely,
+    /// so it fits for large attachments (documents, images).
+    /// Content key is wrapped for each receiver the same way as indy_pack_message does (authcrypt or anoncrypt).
+    ///
+    /// Output file format:
+    /// <header length: u32 LE><header json>
+    /// <chunks encrypted by chacha20poly1305_ietf with incremented nonce: sha256(header json), payload length: u64 LE, payload>
+    ///
+    /// where header json is:
+    /// {
+    ///     "protected": <the same as "protected" of indy_pack_message result>,
+    ///     "nonce": b64URLencode(start nonce),
+    ///     "chunk_size": <size of plain chunk>,
+    ///     "version": 0
+    /// }
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// input_path: path of the file to be packed
+    /// output_path: path of the packed file to be created (must not exist)
+    /// receiver_keys: a string in the format of a json list which will contain the list of receiver's keys
+    ///                the file is being encrypted for.
+    ///                Example:
+    ///                "[<receiver edge_agent_1 verkey>, <receiver edge_agent_2 verkey>]"
+    /// sender: the sender's verkey as a string When null pointer is used in this parameter, anoncrypt is used
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_pack_file(indy_handle_t      command_handle,
+                                       indy_handle_t      wallet_handle,
+                                       const char *       input_path,
+                                       const char *       output_path,
+                                       const char *       receiver_keys,
+                                       const char *       sender,
+
+                                       void           (*cb)(indy_handle_t     command_handle_,
+                                                            indy_error_t      err)
+                                       );
+
+    /// Unpacks a file packed by indy_pack_file (Experimental)
+    ///
+    /// The content is decrypted in chunks and written to the output file.
+    /// Truncated or modified packed files are rejected, output file isn't left in this case.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// input_path: path of the packed file
+    /// output_path: path of the unpacked file to be created (must not exist)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// res_json: if authcrypt was used to pack the file
+    /// {
+    ///     sender_verkey: <sender_verkey>,
+    ///     recipient_verkey: <recipient_verkey>
+    /// }
+    /// or, if anoncrypt was used
+    /// {
+    ///     recipient_verkey: <recipient_verkey>
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_unpack_file(indy_handle_t      command_handle,
+                                         indy_handle_t      wallet_handle,
+                                         const char *       input_path,
+                                         const char *       output_path,
+
+                                         void           (*cb)(indy_handle_t     command_handle_,
+                                                              indy_error_t      err,
+                                                              const char *      res_json)
+                                         );
+
+    /// Packs a message as DIDComm v2 encrypted message (JWE in General JSON Serialization) (Experimental)
+    ///
+    /// Content encryption key is wrapped for each receiver with A256KW using the key derived by
+    /// ECDH-1PU (if sender is set) or ECDH-ES (otherwise) over X25519 forms of the receiver and sender ed25519 keys.
+    /// Receivers and sender are identified by DID URLs of their keys ("<fully qualified did>#key-1").
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// message: a pointer to the first byte of the message to be packed
+    /// message_len: the length of the message
+    /// receivers: a string in the format of a json list which will contain the list of receiver's DIDs or key ids (DID URLs).
+    ///     DIDs must be stored in the wallet as my DIDs or their DIDs (see indy_store_their_did).
+    /// sender: (optional) DID or key id (DID URL) of the sender (must be created with indy_create_and_store_my_did).
+    ///     If set, the message is authenticated with ECDH-1PU; otherwise it is anonymous (ECDH-ES).
+    /// options_json: (optional) packing options as json:
+    /// {
+    ///     "enc": string, (optional) content encryption: "A256CBC-HS512", "A256GCM" or "XC20P".
+    ///         Authcrypt (ECDH-1PU) supports "A256CBC-HS512" only and uses it by default, anoncrypt uses "A256GCM" by default.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// a DIDComm v2 encrypted message:
+    /// {
+    ///     "protected": b64URLencode({
+    ///         "typ": "application/didcomm-encrypted+json",
+    ///         "alg": "ECDH-1PU+A256KW" | "ECDH-ES+A256KW",
+    ///         "enc": "A256CBC-HS512" | "A256GCM" | "XC20P",
+    ///         "skid": <sender key id>, (ECDH-1PU only)
+    ///         "apu": b64URLencode(<sender key id>), (ECDH-1PU only)
+    ///         "apv": b64URLencode(sha256(<sorted receiver key ids joined with ".">)),
+    ///         "epk": {"kty": "OKP", "crv": "X25519", "x": b64URLencode(<ephemeral public key>)}
+    ///     }),
+    ///     "recipients": [
+    ///         {
+    ///             "header": {"kid": <receiver key id>},
+    ///             "encrypted_key": b64URLencode(A256KW(kek, cek))
+    ///         },
+    ///     ],
+    ///     "iv": b64URLencode(iv),
+    ///     "ciphertext": b64URLencode(encrypt_detached(message, protected, iv, cek)),
+    ///     "tag": b64URLencode(tag)
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_pack_didcomm_message(indy_handle_t      command_handle,
+                                                  indy_handle_t      wallet_handle,
+                                                  const indy_u8_t*   message,
+                                                  indy_u32_t         message_len,
+                                                  const char *       receivers,
+                                                  const char *       sender,
+                                                  const char *       options_json,
+
+                                                  void           (*cb)(indy_handle_t     command_handle_,
+                                                                       indy_error_t      err,
+                                                                       const indy_u8_t*  jwe_msg_raw,
+                                                                       indy_u32_t        jwe_msg_len)
+                                                  );
+
+
     /// Unpacks a JWE-like formatted message outputted by indy_pack_message (Experimental)
+    /// or DIDComm v2 encrypted message outputted by indy_pack_didcomm_message.
+    /// The format is detected automatically.
     ///
     /// #Params
     /// command_handle: command handle to map callback to user context.
@@ -429,6 +820,15 @@ extern "C" {
     ///     recipient_verkey: <recipient_verkey>
     /// }
     ///
+    /// for DIDComm v2 message key ids are returned as well:
+    /// {
+    ///     message: <decrypted message>,
+    ///     recipient_verkey: <recipient_verkey>,
+    ///     recipient_kid: <recipient key id>,
+    ///     sender_verkey: <sender_verkey>, (ECDH-1PU only)
+    ///     sender_kid: <sender key id> (ECDH-1PU only)
+    /// }
+    ///
     ///
     /// #Errors
     /// Common*
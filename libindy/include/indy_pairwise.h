@@ -84,6 +84,42 @@ extern "C" {
                                           );
 
 
+    /// Search for saved pairwise matching the given query.
+    ///
+    /// Besides, the following unencrypted tags are maintained for pairwise records
+    /// (timestamps are zero-padded to 20 digits, so they can be used in range queries,
+    /// e.g. {"~created_at": {"$gt": "00000000001600000000"}}):
+    ///     {
+    ///         "~created_at": <time of pairwise creation as unix timestamp>,
+    ///         "~label": <pairwise label>, // only if set by indy_update_pairwise
+    ///         "~state": <pairwise state>, // only if set by indy_update_pairwise
+    ///     }
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// query_json: (optional, default all pairwise) Wallet query (WQL) over pairwise tags, e.g.
+    ///     {"$and": [{"~state": "active"}, {"~label": {"$like": "alice%"}}]}
+    ///     Note that pairwise created before tags introduction has no "~created_at" tag.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// list_pairwise: list of found pairwise in the same format as indy_list_pairwise returns
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_search_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  query_json,
+
+                                             void          (*cb)(indy_handle_t  command_handle_,
+                                                                 indy_error_t   err,
+                                                                 const char*    list_pairwise)
+                                            );
+
+
     /// Gets pairwise information for specific their_did.
     ///
     /// #Params
@@ -135,6 +171,64 @@ extern "C" {
                                                   );
 
 
+    /// Updates pairwise associated with their_did.
+    /// Allows to switch pairwise to another my DID (e.g. after rotation) and set label and state tags
+    /// used by indy_search_pairwise.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// their_did: encoded Did
+    /// pairwise_update_json: fields to update, at least one must be present
+    ///     {
+    ///         "my_did": Optional<string>, - my DID to use for pairwise, must be stored in the wallet
+    ///         "label": Optional<string>, - pairwise label
+    ///         "state": Optional<string>, - pairwise state (e.g. "invited", "active", "completed")
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_update_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  their_did,
+                                             const char *  pairwise_update_json,
+
+                                             void          (*cb)(indy_handle_t  command_handle_,
+                                                                 indy_error_t   err)
+                                            );
+
+
+    /// Deletes pairwise associated with their_did.
+    /// Their DID and my DID records are kept in the wallet.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// their_did: encoded Did
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_delete_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  their_did,
+
+                                             void          (*cb)(indy_handle_t  command_handle_,
+                                                                 indy_error_t   err)
+                                            );
+
+
 #ifdef __cplusplus
 }
 #endif
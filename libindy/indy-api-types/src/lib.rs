@@ -576,4 +576,63 @@ pub mod wallet {
     pub type WalletFreeSearch = extern fn(storage_handle: StorageHandle,
                                           search_handle: SearchHandle) -> ErrorCode;
 
-}
\ No newline at end of file
+}
+
+pub mod signer {
+    use super::*;
+    use libc::{c_char, c_void};
+
+    /// Create a new key inside of external key storage (For example, HSM or KMS)
+    ///
+    /// #Params
+    /// crypto_type: crypto type of the key ("ed25519", "secp256k1", "p256")
+    /// config: signer specific key config as json (the "signer_config" value of key info)
+    ///
+    /// returns: key_ref - signer specific reference to the created key
+    ///          verkey - base58 encoded public key
+    ///          Note that pointers must stay valid until free handler called for each of them
+    pub type SignerCreateKey = extern fn(crypto_type: *const c_char,
+                                         config: *const c_char,
+                                         key_ref_p: *mut *const c_char,
+                                         verkey_p: *mut *const c_char) -> ErrorCode;
+
+    /// Sign a message with the key held by external key storage
+    ///
+    /// #Params
+    /// key_ref: reference to the key (See create_key handler)
+    /// message_raw, message_len: a pointer to first byte of message to be signed and message length
+    ///
+    /// returns: signature
+    ///          Note that pointer must stay valid until free handler called
+    pub type SignerSign = extern fn(key_ref: *const c_char,
+                                    message_raw: *const u8,
+                                    message_len: usize,
+                                    signature_raw_p: *mut *const u8,
+                                    signature_len_p: *mut usize) -> ErrorCode;
+
+    /// Encrypt a message with the key held by external key storage and recipient public key
+    /// (the same box construction libindy uses for the key crypto type)
+    ///
+    /// #Params
+    /// key_ref: reference to the key (See create_key handler)
+    /// their_vk: base58 encoded recipient public key
+    /// message_raw, message_len: a pointer to first byte of message to be encrypted and message length
+    /// nonce_raw, nonce_len: a pointer to first byte of nonce generated by libindy and nonce length
+    ///
+    /// returns: encrypted message
+    ///          Note that pointer must stay valid until free handler called
+    pub type SignerCryptoBox = extern fn(key_ref: *const c_char,
+                                         their_vk: *const c_char,
+                                         message_raw: *const u8,
+                                         message_len: usize,
+                                         nonce_raw: *const u8,
+                                         nonce_len: usize,
+                                         encrypted_raw_p: *mut *const u8,
+                                         encrypted_len_p: *mut usize) -> ErrorCode;
+
+    /// Free data returned by one of signer handlers
+    ///
+    /// #Params
+    /// ptr: pointer returned by create_key, sign or crypto_box handler
+    pub type SignerFree = extern fn(ptr: *const c_void) -> ErrorCode;
+}
@@ -23,6 +23,17 @@ pub fn decode_urlsafe(doc: &str) -> Result<Vec<u8>, IndyError> {
         .map_err(|err| err.into())
 }
 
+pub fn encode_urlsafe_nopad(doc: &[u8]) -> String {
+    base64::encode_config(doc, base64::URL_SAFE_NO_PAD)
+}
+
+pub fn decode_urlsafe_nopad(doc: &str) -> Result<Vec<u8>, IndyError> {
+    base64::decode_config(doc, base64::URL_SAFE_NO_PAD)
+        .context("Invalid base64URL_SAFE_NO_PAD sequence")
+        .context(IndyErrorKind::InvalidStructure)
+        .map_err(|err| err.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,4 +65,18 @@ mod tests {
         assert!(result.is_ok(), "Got error");
         assert_eq!(&[1, 2, 3], &result.unwrap()[..]);
     }
+
+    #[test]
+    fn encode_urlsafe_nopad_works() {
+        let result = encode_urlsafe_nopad(&[1, 2, 3, 4]);
+        assert_eq!("AQIDBA", &result);
+    }
+
+    #[test]
+    fn decode_urlsafe_nopad_works() {
+        let result = decode_urlsafe_nopad("AQIDBA");
+
+        assert!(result.is_ok(), "Got error");
+        assert_eq!(&[1, 2, 3, 4], &result.unwrap()[..]);
+    }
 }
\ No newline at end of file
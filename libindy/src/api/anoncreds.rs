@@ -11,10 +11,10 @@ use crate::domain::anoncreds::credential_definition::{CredentialDefinition, Cred
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
-use crate::domain::anoncreds::credential::{Credential, CredentialValues};
+use crate::domain::anoncreds::credential::{Credential, RawCredentialValues};
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition, RevocationRegistryId, RevocationRegistryDefinitions};
 use crate::domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
-use crate::domain::anoncreds::proof::Proof;
+use crate::domain::anoncreds::proof::{Proof, ProofVerificationConfig};
 use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
 use crate::domain::anoncreds::revocation_registry::RevocationRegistries;
@@ -555,6 +555,8 @@ pub extern fn indy_issuer_create_credential_offer(command_handle: CommandHandle,
 ///      "attr1" : {"raw": "value1", "encoded": "value1_as_int" },
 ///      "attr2" : {"raw": "value1", "encoded": "value1_as_int" }
 ///     }
+///     `encoded` is optional. If omitted it is filled in with the canonical encoding of `raw`
+///     (see indy_encode_credential_values).
 /// rev_reg_id: id of revocation registry stored in the wallet
 /// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
 /// cb: Callback that takes command result as parameter.
@@ -604,7 +606,7 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
 
     check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
     check_useful_validatable_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
-    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, CredentialValues);
+    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, RawCredentialValues);
     check_useful_validatable_opt_string!(rev_reg_id, ErrorCode::CommonInvalidParam6, RevocationRegistryId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
 
@@ -839,6 +841,61 @@ pub extern fn indy_issuer_merge_revocation_registry_deltas(command_handle: Comma
     res
 }
 
+/// Fills in `encoded` values of credential attributes using the canonical encoding.
+///
+/// The canonical encoding of a raw value is:
+///  - the value itself if it is a 32-bit signed integer (-2147483648..2147483647) in canonical decimal form
+///    (without sign plus, leading zeros or negative zero),
+///  - otherwise SHA-256 hash of the UTF-8 bytes of the value interpreted as a big-endian unsigned integer
+///    and represented as a decimal string.
+///
+/// Attributes that already have `encoded` value are left unchanged.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// cred_values_json: credential attribute values:
+///     {
+///      "attr1" : {"raw": "value1"},
+///      "attr2" : {"raw": "value2", "encoded": "value2_as_int" }
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_values_json: credential attribute values with `encoded` set for each attribute
+///     {
+///      "attr1" : {"raw": "value1", "encoded": "value1_as_int" },
+///      "attr2" : {"raw": "value2", "encoded": "value2_as_int" }
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_encode_credential_values(command_handle: CommandHandle,
+                                            cred_values_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                 cred_values_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_encode_credential_values: >>> cred_values_json: {:?}", cred_values_json);
+
+    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam2, RawCredentialValues);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_encode_credential_values: entities >>> cred_values_json: {:?}", secret!(&cred_values_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::EncodeCredentialValues(
+                    cred_values_json,
+                    boxed_callback_string!("indy_encode_credential_values", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_encode_credential_values: <<< res: {:?}", res);
+
+    res
+}
+
 /// Creates a master secret with a given id and stores it in the wallet.
 /// The id must be unique.
 ///
@@ -1113,6 +1170,16 @@ pub extern fn indy_prover_get_credential_attr_tag_policy(command_handle: Command
 ///         "attr::<attribute name>::value": <attribute raw value>,
 ///     }
 ///
+/// Besides, the following unencrypted lifecycle tags will be maintained for stored credential
+/// (timestamps are zero-padded to 20 digits, so they can be used in range queries,
+/// e.g. {"~expires_at": {"$lt": "00000000001600000000"}}):
+///     {
+///         "~received_at": <time of storing the credential as unix timestamp>,
+///         "~expires_at": <value of credential `expiry` attribute>, // only if the issuer set `expiry` attribute to unix timestamp
+///         "~revocation_status": "unknown" | "active" | "revoked", // only for revocable credentials, see indy_prover_check_revocation_status
+///         "~revocation_checked_at": <timestamp of the last revocation status check>, // see indy_prover_check_revocation_status
+///     }
+///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by open_wallet).
@@ -1239,6 +1306,7 @@ pub extern fn indy_prover_get_credential(command_handle: CommandHandle,
 }
 
 /// Deletes credential by given id.
+/// Revocation state cached for the credential (see indy_prover_update_cached_revocation_state) is deleted as well.
 ///
 /// #Params
 /// wallet_handle: wallet handle (created by open_wallet).
@@ -1901,7 +1969,7 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 ///         "cred_def2_id": <credential_def2>,
 ///         "cred_def3_id": <credential_def3>,
 ///     }
-/// rev_states_json: all revocation states participating in the proof request
+/// rev_states_json: (Optional) all revocation states participating in the proof request
 ///     {
 ///         "rev_reg_def1_id": {
 ///             "timestamp1": <rev_state1>,
@@ -1914,6 +1982,9 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 ///             "timestamp4": <rev_state4>
 ///         },
 ///     }
+///     If omitted, revocation states are taken from the wallet cache maintained by
+///     indy_prover_update_cached_revocation_state. The cached state of each revocable credential
+///     must correspond to the timestamp specified for it in `requested_credentials_json`.
 /// cb: Callback that takes command result as parameter.
 ///
 /// where
@@ -2009,7 +2080,7 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
     check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam5);
     check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam6, Schemas);
     check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam7, CredentialDefinitions);
-    check_useful_json!(rev_states_json, ErrorCode::CommonInvalidParam8, RevocationStates);
+    check_useful_opt_json!(rev_states_json, ErrorCode::CommonInvalidParam8, RevocationStates);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
 
     trace!("indy_prover_create_proof: entities >>> wallet_handle: {:?}, proof_req_json: {:?}, requested_credentials_json: {:?}, master_secret_id: {:?}, \
@@ -2199,6 +2270,7 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
             credential_defs_json,
             rev_reg_defs_json,
             rev_regs_json,
+            ProofVerificationConfig::default(),
             Box::new(move |result| {
                 let (err, valid) = prepare_result_1!(result, false);
                 trace!("indy_verifier_verify_proof: valid: {:?}", valid);
@@ -2214,6 +2286,162 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     res
 }
 
+/// Verifies a proof (of multiple credential) like `indy_verifier_verify_proof` applying additional checks.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see indy_verifier_verify_proof)
+/// proof_json: created for request proof json (see indy_verifier_verify_proof)
+/// schemas_json: all schemas participating in the proof (see indy_verifier_verify_proof)
+/// credential_defs_json: all credential definitions participating in the proof (see indy_verifier_verify_proof)
+/// rev_reg_defs_json: all revocation registry definitions participating in the proof (see indy_verifier_verify_proof)
+/// rev_regs_json: all revocation registries participating in the proof (see indy_verifier_verify_proof)
+/// config_json: verification configuration json:
+///     {
+///         "check_raw_encoding": Optional<bool> - whether `encoded` value of every revealed attribute must be
+///                 the canonical encoding of its `raw` value (see indy_encode_credential_values). false by default.
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_with_config(command_handle: CommandHandle,
+                                                     proof_request_json: *const c_char,
+                                                     proof_json: *const c_char,
+                                                     schemas_json: *const c_char,
+                                                     credential_defs_json: *const c_char,
+                                                     rev_reg_defs_json: *const c_char,
+                                                     rev_regs_json: *const c_char,
+                                                     config_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                          valid: bool)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_with_config: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}, config_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, config_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDefinitions);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, RevocationRegistries);
+    check_useful_validatable_json!(config_json, ErrorCode::CommonInvalidParam8, ProofVerificationConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_verifier_verify_proof_with_config: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}, config_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, config_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            config_json,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verifier_verify_proof_with_config: valid: {:?}", valid);
+
+                cb(command_handle, err, valid)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_with_config: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies a proof (of multiple credential) like `indy_verifier_verify_proof`,
+/// but instead of failing on the first problem returns a report explaining which checks failed for every referent.
+///
+/// Checks are grouped per referent of the proof request (and unexpected referents of the proof):
+///     "structure" - referent is missing in the proof or not requested, schema/credential definition is not provided
+///     "restrictions" - credential doesn't satisfy restrictions of the proof request
+///     "encoding" - revealed value in `requested_proof` differs from one in CL proof
+///     "timestamp" - `non_revoked` interval is requested but the proof has no timestamp
+///     "revocation" - revocation registry definition or state for the proven timestamp is not provided
+///     "non_revocation" - revocation registry definition or credential definition of the credential can't match its non-revocation proof
+/// CL proof is aggregated over all credentials and checked only if all referent checks passed. If it fails "signature" failure
+/// is reported on the proof level. Non-revocation proofs are bound into CL proof, so in this case "non_revocation" failure
+/// is reported only for the referents which revocation data is checked apart from the proof to be inconsistent.
+/// A revoked credential is reported by "signature" failure only.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json:
+///     the same as for `indy_verifier_verify_proof`
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// report_json: verification report json
+///     {
+///         "valid": bool, - the same as result of `indy_verifier_verify_proof`
+///         "referents": {
+///             "<referent>": {
+///                 "kind": "revealed_attribute" | "unrevealed_attribute" | "self_attested_attribute" | "predicate" | "missing",
+///                 "sub_proof_index": Optional<int>,
+///                 "valid": bool,
+///                 "failures": [{"check": string, "reason": string}]
+///             }
+///         },
+///         "failures": [{"check": string, "reason": string}] - proof level failures
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_detailed(command_handle: CommandHandle,
+                                                  proof_request_json: *const c_char,
+                                                  proof_json: *const c_char,
+                                                  schemas_json: *const c_char,
+                                                  credential_defs_json: *const c_char,
+                                                  rev_reg_defs_json: *const c_char,
+                                                  rev_regs_json: *const c_char,
+                                                  cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                       report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_detailed: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDefinitions);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, RevocationRegistries);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_verifier_verify_proof_detailed: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofDetailed(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            boxed_callback_string!("indy_verifier_verify_proof_detailed", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_detailed: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create revocation state for a credential in the particular time moment.
 ///
 /// #Params
@@ -2343,6 +2571,198 @@ pub extern fn indy_update_revocation_state(command_handle: CommandHandle,
     res
 }
 
+/// Creates or incrementally updates revocation state of a credential cached in the wallet.
+///
+/// The state is stored in the wallet under (`rev_reg_id`, `cred_rev_id`) key.
+/// If there is no cached state yet it is created from the delta (as indy_create_revocation_state does),
+/// otherwise the witness of the cached state is updated with the delta (as indy_update_revocation_state does).
+/// Cached states are used by indy_prover_create_proof when `rev_states_json` is omitted.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// rev_reg_def_json: revocation registry definition json related to `rev_reg_id` in a credential
+/// rev_reg_delta_json: revocation registry delta json:
+///     accumulated from the registry creation if there is no cached state yet,
+///     or starting from the timestamp of the cached state otherwise
+/// timestamp: time represented as a total number of seconds from Unix Epoch the delta corresponds to
+/// cred_rev_id: user credential revocation id in revocation registry (match to `cred_rev_id` in a credential)
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// revocation state json:
+///     {
+///         "rev_reg": <revocation registry>,
+///         "witness": <witness>,  (opaque type that contains data structures internal to Ursa.
+///                                 It should not be parsed and are likely to change in future versions).
+///         "timestamp" : integer
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_prover_update_cached_revocation_state(command_handle: CommandHandle,
+                                                         wallet_handle: WalletHandle,
+                                                         blob_storage_reader_handle: IndyHandle,
+                                                         rev_reg_def_json: *const c_char,
+                                                         rev_reg_delta_json: *const c_char,
+                                                         timestamp: u64,
+                                                         cred_rev_id: *const c_char,
+                                                         cb: Option<extern fn(
+                                                             command_handle_: CommandHandle, err: ErrorCode,
+                                                             rev_state_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_update_cached_revocation_state: >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, \
+    timestamp: {:?}, cred_rev_id: {:?}", wallet_handle, blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
+
+    check_useful_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam4, RevocationRegistryDefinition);
+    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam5, RevocationRegistryDelta);
+    check_useful_c_str!(cred_rev_id, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_prover_update_cached_revocation_state: entities >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, \
+    timestamp: {:?}, cred_rev_id: {:?}", wallet_handle, blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateCachedRevocationState(
+            wallet_handle,
+            blob_storage_reader_handle,
+            rev_reg_def_json,
+            rev_reg_delta_json,
+            timestamp,
+            cred_rev_id,
+            boxed_callback_string!("indy_prover_update_cached_revocation_state", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_update_cached_revocation_state: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets revocation state of a credential cached in the wallet (see indy_prover_update_cached_revocation_state).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handle (created by open_wallet).
+/// rev_reg_id: identifier of revocation registry definition
+/// cred_rev_id: user credential revocation id in revocation registry (match to `cred_rev_id` in a credential)
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// revocation state json:
+///     {
+///         "rev_reg": <revocation registry>,
+///         "witness": <witness>,
+///         "timestamp" : integer
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_prover_get_cached_revocation_state(command_handle: CommandHandle,
+                                                      wallet_handle: WalletHandle,
+                                                      rev_reg_id: *const c_char,
+                                                      cred_rev_id: *const c_char,
+                                                      cb: Option<extern fn(
+                                                          command_handle_: CommandHandle, err: ErrorCode,
+                                                          rev_state_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_get_cached_revocation_state: >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}", wallet_handle, rev_reg_id, cred_rev_id);
+
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam3, RevocationRegistryId);
+    check_useful_c_str!(cred_rev_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prover_get_cached_revocation_state: entities >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}", wallet_handle, rev_reg_id, cred_rev_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetCachedRevocationState(
+            wallet_handle,
+            rev_reg_id,
+            cred_rev_id,
+            boxed_callback_string!("indy_prover_get_cached_revocation_state", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_get_cached_revocation_state: <<< res: {:?}", res);
+
+    res
+}
+
+/// Checks revocation status of the credential stored in the wallet using revocation registry delta
+/// and updates `~revocation_status` and `~revocation_checked_at` tags of the credential
+/// (see indy_prover_store_credential).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_id: identifier by which requested credential is stored in the wallet
+/// rev_reg_def_json: revocation registry definition json related to `rev_reg_id` of the credential
+/// rev_reg_delta_json: revocation registry delta json related to `rev_reg_def_json`
+///     accumulated from the registry creation (e.g. received by indy_build_get_revoc_reg_delta_request with `from` omitted)
+/// timestamp: time represented as a total number of seconds from Unix Epoch the delta corresponds to
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// revocation_info_json:
+///     {
+///         "referent": string, - id of credential in the wallet
+///         "rev_reg_id": string, - identifier of revocation registry definition
+///         "cred_rev_id": string, - identifier of credential in the revocation registry definition
+///         "status": "active" | "revoked", - credential of ISSUANCE_ON_DEMAND registry which issuance isn't in the delta is reported as revoked
+///         "checked_at": int - timestamp the status corresponds to
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+/// CommonInvalidStructure if revocation registry definition or delta doesn't belong to the credential revocation registry
+///                        or delta isn't accumulated from the registry creation
+#[no_mangle]
+pub extern fn indy_prover_check_revocation_status(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  cred_id: *const c_char,
+                                                  rev_reg_def_json: *const c_char,
+                                                  rev_reg_delta_json: *const c_char,
+                                                  timestamp: u64,
+                                                  cb: Option<extern fn(
+                                                      command_handle_: CommandHandle, err: ErrorCode,
+                                                      revocation_info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_check_revocation_status: >>> wallet_handle: {:?}, cred_id: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}",
+           wallet_handle, cred_id, rev_reg_def_json, rev_reg_delta_json, timestamp);
+
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam4, RevocationRegistryDefinition);
+    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam5, RevocationRegistryDelta);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_prover_check_revocation_status: entities >>> wallet_handle: {:?}, cred_id: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}",
+           wallet_handle, cred_id, rev_reg_def_json, rev_reg_delta_json, timestamp);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CheckRevocationStatus(
+            wallet_handle,
+            cred_id,
+            rev_reg_def_json,
+            rev_reg_delta_json,
+            timestamp,
+            boxed_callback_string!("indy_prover_check_revocation_status", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_check_revocation_status: <<< res: {:?}", res);
+
+    res
+}
+
 
 ///  Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
@@ -2375,6 +2795,56 @@ pub extern fn indy_generate_nonce(command_handle: CommandHandle,
     res
 }
 
+/// Validates proof request json prepared by a verifier before sending it to a prover.
+///
+/// Performs all checks done by `indy_verifier_verify_proof` and prover functions for the proof request
+/// (including restrictions WQL, versioning of identifiers, duplicate referents, `non_revoked` intervals, predicate types)
+/// and reports all found problems at once instead of failing on the first one.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// proof_request_json: proof request json to validate (see `indy_prover_get_credentials_for_proof_req` for format)
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// report_json: validation report json
+///     {
+///         "valid": bool, - true if proof request can be used as is
+///         "errors": [ // list of found problems
+///             {
+///                 "referent": Optional<string>, - attribute or predicate referent the problem relates to (null for proof request level problems)
+///                 "message": string - problem description
+///             }
+///         ]
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_verifier_validate_proof_request(command_handle: CommandHandle,
+                                                   proof_request_json: *const c_char,
+                                                   cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                        report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_validate_proof_request: >>> proof_request_json: {:?}", proof_request_json);
+
+    check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_verifier_validate_proof_request: entities >>> proof_request_json: {:?}", proof_request_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::ValidateProofRequest(
+            proof_request_json,
+            boxed_callback_string!("indy_verifier_validate_proof_request", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_validate_proof_request: <<< res: {:?}", res);
+
+    res
+}
+
 /// Get unqualified form (short form without method) of a fully qualified entity like DID.
 ///
 /// This function should be used to the proper casting of fully qualified entity to unqualified form in the following cases:
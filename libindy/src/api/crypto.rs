@@ -2,15 +2,77 @@
 use indy_api_types::{ErrorCode, CommandHandle, WalletHandle};
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::crypto::CryptoCommand;
-use crate::domain::crypto::pack::JWE;
+use crate::domain::crypto::pack::{DidCommPackOptions, PackedMessage};
 use crate::domain::crypto::key::KeyInfo;
+use crate::domain::crypto::did::DidValue;
+use crate::domain::crypto::jws::JwsOptions;
 use indy_api_types::errors::prelude::*;
+use indy_api_types::signer::*;
 use indy_utils::ctypes;
+use indy_api_types::validation::Validatable;
 
 use serde_json;
 use libc::c_char;
 
 
+/// Register external signer implementation (For example, HSM or KMS backed one).
+///
+/// Keys created with "signer" field of key info set to the registered name keep
+/// private part in the external signer and libindy delegates private key operations
+/// (indy_crypto_sign, indy_sign_request, indy_crypto_auth_crypt, indy_pack_message) to it.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// signer_name: Signer name.
+/// create_key: Signer create key operation handler
+/// sign: Signer sign operation handler
+/// crypto_box: Signer crypto box operation handler
+/// free: Handler that allows to de-allocate data returned by signer handlers
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern fn indy_register_external_signer(command_handle: CommandHandle,
+                                            signer_name: *const c_char,
+                                            create_key: Option<SignerCreateKey>,
+                                            sign: Option<SignerSign>,
+                                            crypto_box: Option<SignerCryptoBox>,
+                                            free: Option<SignerFree>,
+                                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_external_signer: >>> signer_name: {:?}", signer_name);
+
+    check_useful_c_str!(signer_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(create_key, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(sign, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(crypto_box, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(free, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_register_external_signer: entities >>> signer_name: {:?}", signer_name);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::RegisterExternalSigner(
+            signer_name,
+            create_key,
+            sign,
+            crypto_box,
+            free,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_external_signer: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_external_signer: <<< res: {:?}", res);
+
+    res
+}
+
 /// Creates keys pair and stores in the wallet.
 ///
 /// #Params
@@ -20,7 +82,10 @@ use libc::c_char;
 /// {
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
-///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Supported values: 'ed25519', 'secp256k1', 'p256'.
+///     "signer": string, // Optional name of external signer (see indy_register_external_signer) that will hold the private key.
+///                       // Seed can't be used together with signer.
+///     "signer_config": object, // Optional signer specific key config passed to the signer as is.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -163,6 +228,119 @@ pub  extern fn indy_get_key_metadata(command_handle: CommandHandle,
     res
 }
 
+/// Lists keys stored in the wallet.
+///
+/// The following unencrypted tags are maintained for keys and can be used in the query
+/// (timestamps are zero-padded to 20 digits, e.g. {"~created_at": {"$lt": "00000000001600000000"}}):
+///     {
+///         "~created_at": <time of key creation as unix timestamp>,
+///         "~crypto_type": <crypto type of the key, e.g. "ed25519">,
+///     }
+/// Note that keys created before tags introduction have no tags.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// query_json: (optional, default all keys) Wallet query (WQL) over key tags.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - keys_json - list of keys:
+///     [{
+///         "verkey": string, - key id (verkey)
+///         "crypto_type": string, - crypto type of the key
+///         "metadata": Optional<string>, - the meta information stored with the key by indy_set_key_metadata
+///         "created_at": Optional<int>, - time of key creation as unix timestamp
+///     }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_list_keys(command_handle: CommandHandle,
+                              wallet_handle: WalletHandle,
+                              query_json: *const c_char,
+                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                   err: ErrorCode,
+                                                   keys_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_list_keys: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_list_keys: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ListKeys(
+            wallet_handle,
+            query_json,
+            boxed_callback_string!("indy_list_keys", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_list_keys: <<< res: {:?}", res);
+
+    res
+}
+
+/// Deletes the key and its meta information from the wallet.
+///
+/// Key can't be deleted while it's used by my DID, pending key replacement of my DID
+/// (see indy_replace_keys_start) or pairwise.
+/// Note for keys of external signer only wallet reference is deleted.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// verkey - The key (verkey, key id) to delete.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_delete_key(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               verkey: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_delete_key: >>> wallet_handle: {:?}, verkey: {:?}", wallet_handle, verkey);
+
+    check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_delete_key: entities >>> wallet_handle: {:?}, verkey: {:?}", wallet_handle, verkey);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::DeleteKey(
+            wallet_handle,
+            verkey,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_delete_key:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_delete_key: <<< res: {:?}", res);
+
+    res
+}
+
 /// Signs a message with a key.
 ///
 /// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
@@ -285,6 +463,228 @@ pub  extern fn indy_crypto_verify(command_handle: CommandHandle,
     res
 }
 
+/// Signs a payload as JWS (RFC 7515) with the key of DID stored in the wallet.
+///
+/// Protected header contains "alg" (EdDSA for ed25519 keys, ES256K for secp256k1, ES256 for p256)
+/// and "kid" set to DID key reference ("<fully qualified did>#key-1").
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// signer_did: DID of the signer (must be created with indy_create_and_store_my_did).
+/// payload_raw: a pointer to first byte of payload to be signed
+/// payload_len: a payload length
+/// options_json: (optional) signing options as json:
+/// {
+///     "detached": bool, (optional, false by default) omit payload from the result (RFC 7515 Appendix F)
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// jws: JWS in compact serialization
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_sign_jws(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   signer_did: *const c_char,
+                                   payload_raw: *const u8,
+                                   payload_len: u32,
+                                   options_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        jws: *const c_char)>) -> ErrorCode {
+    trace!("indy_crypto_sign_jws: >>> wallet_handle: {:?}, signer_did: {:?}, payload_raw: {:?}, payload_len: {:?}, options_json: {:?}",
+           wallet_handle, signer_did, payload_raw, payload_len, options_json);
+
+    check_useful_validatable_string!(signer_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_byte_array!(payload_raw, payload_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam6, JwsOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_crypto_sign_jws: entities >>> wallet_handle: {:?}, signer_did: {:?}, payload_raw: {:?}, options_json: {:?}",
+           wallet_handle, signer_did, payload_raw, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignJws(
+            wallet_handle,
+            signer_did,
+            payload_raw,
+            options_json.unwrap_or_default(),
+            boxed_callback_string!("indy_crypto_sign_jws", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_sign_jws: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies JWS in compact serialization created by indy_crypto_sign_jws (or any other EdDSA/ES256K/ES256 JWS).
+///
+/// Signer key is found by "kid" header: DID of the key reference must be stored in the wallet
+/// as my DID or their DID (see indy_store_their_did).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// jws: JWS in compact serialization
+/// detached_payload_raw: (optional) a pointer to first byte of payload for JWS with detached payload
+/// detached_payload_len: detached payload length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_verify_jws(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     jws: *const c_char,
+                                     detached_payload_raw: *const u8,
+                                     detached_payload_len: u32,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          valid: bool)>) -> ErrorCode {
+    trace!("indy_crypto_verify_jws: >>> wallet_handle: {:?}, jws: {:?}, detached_payload_raw: {:?}, detached_payload_len: {:?}",
+           wallet_handle, jws, detached_payload_raw, detached_payload_len);
+
+    check_useful_c_str!(jws, ErrorCode::CommonInvalidParam3);
+    let detached_payload_raw = if detached_payload_raw.is_null() {
+        None
+    } else {
+        check_useful_c_byte_array!(detached_payload_raw, detached_payload_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+        Some(detached_payload_raw)
+    };
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_verify_jws: entities >>> wallet_handle: {:?}, jws: {:?}, detached_payload_raw: {:?}",
+           wallet_handle, jws, detached_payload_raw);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyJws(
+            wallet_handle,
+            jws,
+            detached_payload_raw,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_crypto_verify_jws: valid: {:?}", valid);
+                cb(command_handle, err, valid)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_verify_jws: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds and signs JWT (RFC 7519) with the key of DID stored in the wallet.
+///
+/// "iss" claim is set to signer DID if not provided, "iat" claim is set to current time
+/// and "exp" claim is set to current time + expires_in if expires_in is greater than 0.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// signer_did: DID of the signer (must be created with indy_create_and_store_my_did).
+/// claims_json: JWT claims as json object
+/// expires_in: token lifetime in seconds (0 - token without expiry)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// jwt: signed JWT
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_sign_jwt(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   signer_did: *const c_char,
+                                   claims_json: *const c_char,
+                                   expires_in: u64,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        jwt: *const c_char)>) -> ErrorCode {
+    trace!("indy_crypto_sign_jwt: >>> wallet_handle: {:?}, signer_did: {:?}, claims_json: {:?}, expires_in: {:?}",
+           wallet_handle, signer_did, claims_json, expires_in);
+
+    check_useful_validatable_string!(signer_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_json!(claims_json, ErrorCode::CommonInvalidParam4, serde_json::Map<String, serde_json::Value>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_sign_jwt: entities >>> wallet_handle: {:?}, signer_did: {:?}, claims_json: {:?}, expires_in: {:?}",
+           wallet_handle, signer_did, claims_json, expires_in);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignJwt(
+            wallet_handle,
+            signer_did,
+            claims_json,
+            expires_in,
+            boxed_callback_string!("indy_crypto_sign_jwt", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_sign_jwt: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies JWT signature (see indy_crypto_verify_jws) and validates "exp" and "nbf" claims against current time.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// jwt: signed JWT
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// claims_json: JWT claims as json object
+///
+/// #Errors
+/// Common* (CommonInvalidStructure if signature is invalid or token is expired)
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_crypto_verify_jwt(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     jwt: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          claims_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_crypto_verify_jwt: >>> wallet_handle: {:?}, jwt: {:?}", wallet_handle, jwt);
+
+    check_useful_c_str!(jwt, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_crypto_verify_jwt: entities >>> wallet_handle: {:?}, jwt: {:?}", wallet_handle, jwt);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyJwt(
+            wallet_handle,
+            jwt,
+            boxed_callback_string!("indy_crypto_verify_jwt", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_crypto_verify_jwt: <<< res: {:?}", res);
+
+    res
+}
+
 /// **** THIS FUNCTION WILL BE DEPRECATED USE indy_pack_message() INSTEAD ****
 /// Encrypt a message by authenticated-encryption scheme.
 ///
@@ -675,8 +1075,246 @@ pub extern fn indy_pack_message(
     res
 }
 
+/// Packs a file for the receivers by encrypting its content in chunks (Experimental)
+///
+/// Unlike indy_pack_message the payload is never loaded in memory completely,
+/// so it fits for large attachments (documents, images).
+/// Content key is wrapped for each receiver the same way as indy_pack_message does (authcrypt or anoncrypt).
+///
+/// Output file format:
+/// <header length: u32 LE><header json>
+/// <chunks encrypted by chacha20poly1305_ietf with incremented nonce: sha256(header json), payload length: u64 LE, payload>
+///
+/// where header json is:
+/// {
+///     "protected": <the same as "protected" of indy_pack_message result>,
+///     "nonce": b64URLencode(start nonce),
+///     "chunk_size": <size of plain chunk>,
+///     "version": 0
+/// }
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// input_path: path of the file to be packed
+/// output_path: path of the packed file to be created (must not exist)
+/// receiver_keys: a string in the format of a json list which will contain the list of receiver's keys
+///                the file is being encrypted for.
+///                Example:
+///                "[<receiver edge_agent_1 verkey>, <receiver edge_agent_2 verkey>]"
+/// sender: the sender's verkey as a string When null pointer is used in this parameter, anoncrypt is used
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_pack_file(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    input_path: *const c_char,
+    output_path: *const c_char,
+    receiver_keys: *const c_char,
+    sender: *const c_char,
+    cb: Option<extern fn(xcommand_handle: CommandHandle, err: ErrorCode)>,
+) -> ErrorCode {
+    trace!("indy_pack_file: >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}, receiver_keys: {:?}, sender: {:?}",
+           wallet_handle, input_path, output_path, receiver_keys, sender);
+
+    check_useful_c_str!(input_path, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(output_path, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(receiver_keys, ErrorCode::CommonInvalidParam5, Vec<String>);
+    check_useful_opt_c_str!(sender, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_pack_file: entities >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}, receiver_keys: {:?}, sender: {:?}",
+           wallet_handle, input_path, output_path, receiver_keys, sender);
+
+    //break early and error out if no receivers keys are provided
+    if receiver_keys.is_empty() {
+        return ErrorCode::CommonInvalidParam5;
+    }
+
+    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::PackFile(
+        wallet_handle,
+        input_path,
+        output_path,
+        receiver_keys,
+        sender,
+        Box::new(move |result| {
+            let err = prepare_result!(result);
+            trace!("indy_pack_file: err: {:?}", err);
+            cb(command_handle, err)
+        }),
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_file: <<< res: {:?}", res);
+
+    res
+}
+
+/// Unpacks a file packed by indy_pack_file (Experimental)
+///
+/// The content is decrypted in chunks and written to the output file.
+/// Truncated or modified packed files are rejected, output file isn't left in this case.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// input_path: path of the packed file
+/// output_path: path of the unpacked file to be created (must not exist)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// res_json: if authcrypt was used to pack the file
+/// {
+///     sender_verkey: <sender_verkey>,
+///     recipient_verkey: <recipient_verkey>
+/// }
+/// or, if anoncrypt was used
+/// {
+///     recipient_verkey: <recipient_verkey>
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_unpack_file(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    input_path: *const c_char,
+    output_path: *const c_char,
+    cb: Option<extern fn(xcommand_handle: CommandHandle, err: ErrorCode, res_json: *const c_char)>,
+) -> ErrorCode {
+    trace!("indy_unpack_file: >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}", wallet_handle, input_path, output_path);
+
+    check_useful_c_str!(input_path, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(output_path, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_unpack_file: entities >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}", wallet_handle, input_path, output_path);
+
+    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::UnpackFile(
+        wallet_handle,
+        input_path,
+        output_path,
+        boxed_callback_string!("indy_unpack_file", cb, command_handle)
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unpack_file: <<< res: {:?}", res);
+
+    res
+}
+
+/// Packs a message as DIDComm v2 encrypted message (JWE in General JSON Serialization) (Experimental)
+///
+/// Content encryption key is wrapped for each receiver with A256KW using the key derived by
+/// ECDH-1PU (if sender is set) or ECDH-ES (otherwise) over X25519 forms of the receiver and sender ed25519 keys.
+/// Receivers and sender are identified by DID URLs of their keys ("<fully qualified did>#key-1").
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// message: a pointer to the first byte of the message to be packed
+/// message_len: the length of the message
+/// receivers: a string in the format of a json list which will contain the list of receiver's DIDs or key ids (DID URLs).
+///     DIDs must be stored in the wallet as my DIDs or their DIDs (see indy_store_their_did).
+/// sender: (optional) DID or key id (DID URL) of the sender (must be created with indy_create_and_store_my_did).
+///     If set, the message is authenticated with ECDH-1PU; otherwise it is anonymous (ECDH-ES).
+/// options_json: (optional) packing options as json:
+/// {
+///     "enc": string, (optional) content encryption: "A256CBC-HS512", "A256GCM" or "XC20P".
+///         Authcrypt (ECDH-1PU) supports "A256CBC-HS512" only and uses it by default, anoncrypt uses "A256GCM" by default.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// a DIDComm v2 encrypted message:
+/// {
+///     "protected": b64URLencode({
+///         "typ": "application/didcomm-encrypted+json",
+///         "alg": "ECDH-1PU+A256KW" | "ECDH-ES+A256KW",
+///         "enc": "A256CBC-HS512" | "A256GCM" | "XC20P",
+///         "skid": <sender key id>, (ECDH-1PU only)
+///         "apu": b64URLencode(<sender key id>), (ECDH-1PU only)
+///         "apv": b64URLencode(sha256(<sorted receiver key ids joined with ".">)),
+///         "epk": {"kty": "OKP", "crv": "X25519", "x": b64URLencode(<ephemeral public key>)}
+///     }),
+///     "recipients": [
+///         {
+///             "header": {"kid": <receiver key id>},
+///             "encrypted_key": b64URLencode(A256KW(kek, cek))
+///         },
+///     ],
+///     "iv": b64URLencode(iv),
+///     "ciphertext": b64URLencode(encrypt_detached(message, protected, iv, cek)),
+///     "tag": b64URLencode(tag)
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_pack_didcomm_message(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    message: *const u8,
+    message_len: u32,
+    receivers: *const c_char,
+    sender: *const c_char,
+    options_json: *const c_char,
+    cb: Option<extern fn(xcommand_handle: CommandHandle, err: ErrorCode, jwe_data: *const u8, jwe_len: u32)>,
+) -> ErrorCode {
+    trace!("indy_pack_didcomm_message: >>> wallet_handle: {:?}, message: {:?}, message_len {:?}, \
+            receivers: {:?}, sender: {:?}, options_json: {:?}", wallet_handle, message, message_len, receivers, sender, options_json);
+
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(receivers, ErrorCode::CommonInvalidParam4, Vec<String>);
+    check_useful_opt_c_str!(sender, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam6, DidCommPackOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_pack_didcomm_message: entities >>> wallet_handle: {:?}, message: {:?}, message_len {:?}, \
+            receivers: {:?}, sender: {:?}, options_json: {:?}", wallet_handle, message, message_len, receivers, sender, options_json);
+
+    //break early and error out if no receivers are provided
+    if receivers.is_empty() {
+        return ErrorCode::CommonInvalidParam4;
+    }
+
+    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::PackDidCommMessage(
+        message,
+        receivers,
+        sender,
+        options_json.unwrap_or_default(),
+        wallet_handle,
+        Box::new(move |result| {
+            let (err, jwe) = prepare_result_1!(result, Vec::new());
+            trace!("indy_pack_didcomm_message: jwe: {:?}", jwe);
+            let (jwe_data, jwe_len) = ctypes::vec_to_pointer(&jwe);
+            cb(command_handle, err, jwe_data, jwe_len)
+        }),
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_didcomm_message: <<< res: {:?}", res);
+
+    res
+}
+
 
 /// Unpacks a JWE-like formatted message outputted by indy_pack_message (Experimental)
+/// or DIDComm v2 encrypted message outputted by indy_pack_didcomm_message.
+/// The format is detected automatically.
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
@@ -701,6 +1339,15 @@ pub extern fn indy_pack_message(
 ///     recipient_verkey: <recipient_verkey>
 /// }
 ///
+/// for DIDComm v2 message key ids are returned as well:
+/// {
+///     message: <decrypted message>,
+///     recipient_verkey: <recipient_verkey>,
+///     recipient_kid: <recipient key id>,
+///     sender_verkey: <sender_verkey>, (ECDH-1PU only)
+///     sender_kid: <sender key id> (ECDH-1PU only)
+/// }
+///
 ///
 /// #Errors
 /// Common*
@@ -739,8 +1386,8 @@ pub extern fn indy_unpack_message(
         jwe_len
     );
 
-    //serialize JWE to struct
-    let jwe_struct: JWE = match serde_json::from_slice(jwe_data.as_slice()) {
+    //serialize JWE to struct of detected format
+    let jwe_struct: PackedMessage = match serde_json::from_slice(jwe_data.as_slice()) {
         Ok(x) => x,
         Err(_) => return ErrorCode::CommonInvalidParam3
     };
@@ -1,6 +1,6 @@
 use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, PoolHandle};
 use crate::commands::{Command, CommandExecutor};
-use crate::commands::did::DidCommand;
+use crate::commands::did::{DidCommand, ResolveDidStage};
 use crate::domain::crypto::did::{TheirDidInfo, DidValue, MyDidInfo, DidMethod};
 use crate::domain::crypto::key::KeyInfo;
 use indy_api_types::errors::prelude::*;
@@ -33,9 +33,13 @@ use crate::domain::ledger::attrib::Endpoint;
 ///     "seed": string, (optional) Seed that allows deterministic did creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               supported values: 'ed25519', 'secp256k1', 'p256')
 ///     "cid": bool, (optional; if not set then false is used;)
 ///     "method_name": string, (optional) method name to create fully qualified did.
+///                   `key` and `peer` methods build self-certifying did:key and did:peer identifiers
+///                   from the ed25519 verkey, so the key can be resolved from the DID without a ledger.
+///     "peer_numalgo": int, (optional; if not set then 0 is used) did:peer generation algorithm:
+///                   0 - inception key, 2 - multiple keys (X25519 key agreement and ed25519 verification keys).
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -98,7 +102,10 @@ pub  extern fn indy_create_and_store_my_did(command_handle: CommandHandle,
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               supported values: 'ed25519', 'secp256k1', 'p256')
+///     "signer": string, (optional) Name of external signer that will hold the private key
+///               (see indy_register_external_signer)
+///     "signer_config": object, (optional) Signer specific key config
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -194,6 +201,82 @@ pub  extern fn indy_replace_keys_apply(command_handle: CommandHandle,
     res
 }
 
+/// Rotates keys for an existing DID (owned by the caller of the library) on the ledger and in the wallet.
+///
+/// Performs the whole key rotation sequence:
+/// 1. generates temporary keys as "indy_replace_keys_start" does,
+/// 2. sends NYM transaction with the new verkey signed with the current DID key,
+/// 3. checks with GET_NYM that the ledger shows the new verkey,
+/// 4. applies the new keys as "indy_replace_keys_apply" does.
+///
+/// If the ledger doesn't apply NYM transaction temporary keys are removed from the wallet,
+/// so the DID keeps using the current keys. If NYM transaction is written but the ledger state
+/// can't be checked, temporary keys are kept and can be applied later by "indy_replace_keys_apply".
+/// Temporary keys kept in the wallet are checked against the ledger by the next rotation:
+/// they are applied if the ledger shows them and discarded if the ledger shows the current keys.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// did: target did to rotate keys.
+/// key_info: key information as json. Example:
+/// {
+///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
+///                                Can be UTF-8, base64 or hex string.
+///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
+///               supported values: 'ed25519', 'secp256k1', 'p256')
+///     "signer": string, (optional) Name of external signer that will hold the private key
+///               (see indy_register_external_signer)
+///     "signer_config": object, (optional) Signer specific key config
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///   verkey: The DIDs new verification key
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_rotate_did_key(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  pool_handle: PoolHandle,
+                                  did: *const c_char,
+                                  key_info: *const c_char,
+                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                       err: ErrorCode,
+                                                       verkey: *const c_char)>) -> ErrorCode {
+    trace!("indy_rotate_did_key: >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}, key_info: {:?}", wallet_handle, pool_handle, did, key_info);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_json!(key_info, ErrorCode::CommonInvalidParam5, KeyInfo);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_rotate_did_key: entities >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}, key_info: {:?}", wallet_handle, pool_handle, did, secret!(&key_info));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::RotateDidKey(
+            wallet_handle,
+            pool_handle,
+            did,
+            key_info,
+            boxed_callback_string!("indy_rotate_did_key", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_rotate_did_key: <<< res: {:?}", res);
+
+    res
+}
+
 /// Saves their DID for a pairwise connection in a secured Wallet,
 /// so that it can be used to verify transaction.
 ///
@@ -258,6 +341,9 @@ pub  extern fn indy_store_their_did(command_handle: CommandHandle,
 /// "indy_key_for_local_did" call instead that will look only to the local wallet and skip
 /// freshness checking.
 ///
+/// did:key and did:peer DIDs are resolved from the identifier itself without wallet
+/// or ledger lookup.
+///
 /// Note that "indy_create_and_store_my_did" makes similar wallet record as "indy_create_key".
 /// As result we can use returned ver key in all generic crypto and messaging functions.
 ///
@@ -317,6 +403,9 @@ pub extern fn indy_key_for_did(command_handle: CommandHandle,
 /// Note if you want to get fresh data from the ledger you can use "indy_key_for_did" call
 /// instead.
 ///
+/// did:key and did:peer DIDs are resolved from the identifier itself without wallet
+/// or ledger lookup.
+///
 /// Note that "indy_create_and_store_my_did" makes similar wallet record as "indy_create_key".
 /// As result we can use returned ver key in all generic crypto and messaging functions.
 ///
@@ -365,6 +454,89 @@ pub extern fn indy_key_for_local_did(command_handle: CommandHandle,
     res
 }
 
+/// Resolves the given DID into W3C DID Document.
+///
+/// DID Document is built from DID verkey and endpoint attribute: it contains verification method
+/// for the DID key (with `#key-1` fragment) referenced as authentication and assertion method,
+/// and `did-communication` service with routing keys if endpoint is known.
+///
+/// DIDs stored in the wallet (my DIDs and their DIDs) are resolved locally. Other DIDs are resolved
+/// from the ledger with GET_NYM and `endpoint` GET_ATTRIB requests and cached in the wallet
+/// as "indy_key_for_did" and "indy_get_endpoint_for_did" do.
+/// did:key and did:peer DIDs are resolved from the identifier itself without wallet or ledger lookup.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// pool_handle:   Pool handle (created by open_pool).
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID to resolve.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - resolution_result_json - DID resolution result:
+///   {
+///     "didDocument": {
+///         "@context": "https://www.w3.org/ns/did/v1",
+///         "id": string, (unqualified DIDs are represented as `did:sov` ones)
+///         "verificationMethod": [{"id": string, "type": string, "controller": string, "publicKeyBase58": string}],
+///         "authentication": [string],
+///         "assertionMethod": [string],
+///         "keyAgreement": [string], (optional; X25519 key of did:peer:2 DIDs)
+///         "service": [{ (optional; if endpoint is known)
+///             "id": string,
+///             "type": "did-communication",
+///             "serviceEndpoint": string,
+///             "recipientKeys": [string],
+///             "routingKeys": [string], (endpoint routing keys and transport key if it differs from DID key)
+///             "priority": int
+///         }]
+///     },
+///     "didResolutionMetadata": {
+///         "contentType": "application/did+ld+json",
+///         "source": string, (one of "identifier", "wallet", "ledger")
+///     }
+///   }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_resolve_did(command_handle: CommandHandle,
+                               pool_handle: PoolHandle,
+                               wallet_handle: WalletHandle,
+                               did: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    resolution_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_resolve_did: >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}", pool_handle, wallet_handle, did);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_resolve_did: entities >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}", pool_handle, wallet_handle, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::ResolveDid(
+            pool_handle,
+            wallet_handle,
+            did,
+            ResolveDidStage::Local,
+            boxed_callback_string!("indy_resolve_did", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_resolve_did: <<< res: {:?}", res);
+
+    res
+}
+
 /// Set/replaces endpoint information for the given DID.
 ///
 /// #Params
@@ -5,6 +5,7 @@ use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 use indy_api_types::validation::Validatable;
 use crate::domain::crypto::did::DidValue;
+use crate::domain::pairwise::PairwiseUpdate;
 
 use libc::c_char;
 
@@ -146,6 +147,59 @@ pub  extern fn indy_list_pairwise(command_handle: CommandHandle,
     res
 }
 
+/// Search for saved pairwise matching the given query.
+///
+/// Besides, the following unencrypted tags are maintained for pairwise records
+/// (timestamps are zero-padded to 20 digits, so they can be used in range queries,
+/// e.g. {"~created_at": {"$gt": "00000000001600000000"}}):
+///     {
+///         "~created_at": <time of pairwise creation as unix timestamp>,
+///         "~label": <pairwise label>, // only if set by indy_update_pairwise
+///         "~state": <pairwise state>, // only if set by indy_update_pairwise
+///     }
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// query_json: (optional, default all pairwise) Wallet query (WQL) over pairwise tags, e.g.
+///     {"$and": [{"~state": "active"}, {"~label": {"$like": "alice%"}}]}
+///     Note that pairwise created before tags introduction has no "~created_at" tag.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// list_pairwise: list of found pairwise in the same format as indy_list_pairwise returns
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_search_pairwise(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    query_json: *const c_char,
+                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode,
+                                                         list_pairwise: *const c_char)>) -> ErrorCode {
+    trace!("indy_search_pairwise: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_search_pairwise: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::SearchPairwise(
+            wallet_handle,
+            query_json,
+            boxed_callback_string!("indy_search_pairwise", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_search_pairwise: <<< res: {:?}", res);
+
+    res
+}
+
 /// Gets pairwise information for specific their_did.
 ///
 /// #Params
@@ -236,3 +290,105 @@ pub  extern fn indy_set_pairwise_metadata(command_handle: CommandHandle,
 
     res
 }
+
+/// Updates pairwise associated with their_did.
+/// Allows to switch pairwise to another my DID (e.g. after rotation) and set label and state tags
+/// used by indy_search_pairwise.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// their_did: encoded Did
+/// pairwise_update_json: fields to update, at least one must be present
+///     {
+///         "my_did": Optional<string>, - my DID to use for pairwise, must be stored in the wallet
+///         "label": Optional<string>, - pairwise label
+///         "state": Optional<string>, - pairwise state (e.g. "invited", "active", "completed")
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_update_pairwise(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    their_did: *const c_char,
+                                    pairwise_update_json: *const c_char,
+                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_update_pairwise: >>> wallet_handle: {:?}, their_did: {:?}, pairwise_update_json: {:?}", wallet_handle, their_did, pairwise_update_json);
+
+    check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_json!(pairwise_update_json, ErrorCode::CommonInvalidParam4, PairwiseUpdate);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_update_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}, pairwise_update_json: {:?}", wallet_handle, their_did, pairwise_update_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::UpdatePairwise(
+            wallet_handle,
+            their_did,
+            pairwise_update_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_update_pairwise:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_update_pairwise: <<< res: {:?}", res);
+
+    res
+}
+
+/// Deletes pairwise associated with their_did.
+/// Their DID and my DID records are kept in the wallet.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// their_did: encoded Did
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_delete_pairwise(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    their_did: *const c_char,
+                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_delete_pairwise: >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+    check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_delete_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::DeletePairwise(
+            wallet_handle,
+            their_did,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_delete_pairwise:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_delete_pairwise: <<< res: {:?}", res);
+
+    res
+}
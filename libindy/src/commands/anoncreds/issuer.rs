@@ -11,7 +11,7 @@ use ursa::cl::{CredentialKeyCorrectnessProof, CredentialPrivateKey};
 
 use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend};
 use crate::commands::anoncreds::AnoncredsCommand;
-use crate::domain::anoncreds::credential::{CredentialValues, Credential};
+use crate::domain::anoncreds::credential::{CredentialValues, Credential, RawCredentialValues};
 use crate::domain::anoncreds::credential_definition::{
     CredentialDefinition,
     CredentialDefinitionConfig,
@@ -49,7 +49,7 @@ use crate::domain::crypto::did::DidValue;
 use indy_api_types::domain::wallet::Tags;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
-use crate::services::anoncreds::helpers::parse_cred_rev_id;
+use crate::services::anoncreds::helpers::{parse_cred_rev_id, encode_credential_values};
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
 use crate::services::pool::PoolService;
@@ -121,7 +121,7 @@ pub enum IssuerCommand {
         WalletHandle,
         CredentialOffer, // credential offer
         CredentialRequest, // credential request
-        CredentialValues, // credential values
+        RawCredentialValues, // credential values
         Option<RevocationRegistryId>, // revocation registry id
         Option<i32>, // blob storage reader config handle
         Box<dyn Fn(IndyResult<(String, Option<String>, Option<String>)>) + Send>),
@@ -141,6 +141,9 @@ pub enum IssuerCommand {
         RevocationRegistryDelta, //revocation registry delta
         RevocationRegistryDelta, //other revocation registry delta
         Box<dyn Fn(IndyResult<String>) + Send>),
+    EncodeCredentialValues(
+        RawCredentialValues, // credential values
+        Box<dyn Fn(IndyResult<String>) + Send>),
 }
 
 pub struct IssuerCommandExecutor {
@@ -229,6 +232,10 @@ impl IssuerCommandExecutor {
                 cb(self.merge_revocation_registry_deltas(&mut RevocationRegistryDeltaV1::from(rev_reg_delta),
                                                          &RevocationRegistryDeltaV1::from(other_rev_reg_delta)));
             }
+            IssuerCommand::EncodeCredentialValues(cred_values, cb) => {
+                debug!(target: "issuer_command_executor", "EncodeCredentialValues command received");
+                cb(self.encode_credential_values(&cred_values));
+            }
         };
     }
 
@@ -671,12 +678,14 @@ impl IssuerCommandExecutor {
                       wallet_handle: WalletHandle,
                       cred_offer: &CredentialOffer,
                       cred_request: &CredentialRequest,
-                      cred_values: &CredentialValues,
+                      cred_values: &RawCredentialValues,
                       rev_reg_id: Option<&RevocationRegistryId>,
                       blob_storage_reader_handle: Option<i32>) -> IndyResult<(String, Option<String>, Option<String>)> {
         debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
                wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle);
 
+        let cred_values: CredentialValues = encode_credential_values(cred_values)?;
+
         let cred_def_id = match cred_offer.method_name {
             Some(ref method_name) => cred_offer.cred_def_id.qualify(method_name),
             None => cred_offer.cred_def_id.clone()
@@ -931,6 +940,19 @@ impl IssuerCommandExecutor {
         Ok(merged_rev_reg_delta_json)
     }
 
+    fn encode_credential_values(&self, cred_values: &RawCredentialValues) -> IndyResult<String> {
+        debug!("encode_credential_values >>> cred_values: {:?}", secret!(cred_values));
+
+        let cred_values = encode_credential_values(cred_values)?;
+
+        let cred_values_json = serde_json::to_string(&cred_values)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialValues")?;
+
+        debug!("encode_credential_values <<< cred_values_json: {:?}", secret!(&cred_values_json));
+
+        Ok(cred_values_json)
+    }
+
     // TODO: DELETE IT
     fn _wallet_set_schema_id(&self, wallet_handle: WalletHandle, id: &str, schema_id: &SchemaId) -> IndyResult<()> {
         self.wallet_service.add_record(wallet_handle, &self.wallet_service.add_prefix("SchemaId"), id, &schema_id.0, &Tags::new())
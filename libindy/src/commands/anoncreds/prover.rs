@@ -7,7 +7,7 @@ use ursa::cl::{new_nonce, RevocationRegistry, Witness};
 use serde_json::Value;
 
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
-use crate::domain::anoncreds::credential::{Credential, CredentialInfo};
+use crate::domain::anoncreds::credential::{Credential, CredentialInfo, CredentialRevocationInfo};
 use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinition, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::credential_for_proof_request::{CredentialsForProofRequest, RequestedCredential};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
@@ -15,11 +15,12 @@ use crate::domain::anoncreds::credential_request::{CredentialRequest, Credential
 use crate::domain::anoncreds::master_secret::MasterSecret;
 use crate::domain::anoncreds::proof_request::{NonRevocedInterval, PredicateInfo, ProofRequest, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
-use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
+use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
 use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
 use crate::domain::crypto::did::DidValue;
+use indy_api_types::domain::wallet::Tags;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
 use crate::services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_interval};
@@ -27,6 +28,7 @@ use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
 use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
 use indy_utils::sequence;
+use crate::utils::timestamp::{current_timestamp, timestamp_tag_value};
 use crate::utils::wql::Query;
 
 use super::tails::SDKTailsAccessor;
@@ -110,7 +112,7 @@ pub enum ProverCommand {
         String, // master secret name
         Schemas, // schemas
         CredentialDefinitions, // credential defs
-        RevocationStates, // revocation states
+        Option<RevocationStates>, // revocation states
         Box<dyn Fn(IndyResult<String>) + Send>),
     CreateRevocationState(
         i32, // blob storage reader handle
@@ -126,6 +128,26 @@ pub enum ProverCommand {
         RevocationRegistryDelta, // revocation registry delta
         u64, //timestamp
         String, //credential revocation id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    UpdateCachedRevocationState(
+        WalletHandle,
+        i32, // blob storage reader handle
+        RevocationRegistryDefinition, // revocation registry definition
+        RevocationRegistryDelta, // revocation registry delta
+        u64, //timestamp
+        String, //credential revocation id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetCachedRevocationState(
+        WalletHandle,
+        RevocationRegistryId, // revocation registry id
+        String, //credential revocation id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CheckRevocationStatus(
+        WalletHandle,
+        String, // credential id
+        RevocationRegistryDefinition, // revocation registry definition
+        RevocationRegistryDelta, // revocation registry delta
+        u64, //timestamp
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
 
@@ -244,7 +266,7 @@ impl ProverCommandExecutor {
                 cb(self.create_proof(wallet_handle, &proof_req, &requested_credentials, &master_secret_name,
                                      &schemas_map_to_schemas_v1_map(schemas),
                                      &cred_defs_map_to_cred_defs_v1_map(cred_defs),
-                                     &rev_states));
+                                     rev_states.as_ref()));
             }
             ProverCommand::CreateRevocationState(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id, cb) => {
                 debug!(target: "prover_command_executor", "CreateRevocationState command received");
@@ -254,6 +276,18 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "UpdateRevocationState command received");
                 cb(self.update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
             }
+            ProverCommand::UpdateCachedRevocationState(wallet_handle, blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id, cb) => {
+                debug!(target: "prover_command_executor", "UpdateCachedRevocationState command received");
+                cb(self.update_cached_revocation_state(wallet_handle, blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
+            }
+            ProverCommand::GetCachedRevocationState(wallet_handle, rev_reg_id, cred_rev_id, cb) => {
+                debug!(target: "prover_command_executor", "GetCachedRevocationState command received");
+                cb(self.get_cached_revocation_state(wallet_handle, &rev_reg_id, &cred_rev_id));
+            }
+            ProverCommand::CheckRevocationStatus(wallet_handle, cred_id, rev_reg_def, rev_reg_delta, timestamp, cb) => {
+                debug!(target: "prover_command_executor", "CheckRevocationStatus command received");
+                cb(self.check_revocation_status(wallet_handle, &cred_id, &RevocationRegistryDefinitionV1::from(rev_reg_def), &RevocationRegistryDeltaV1::from(rev_reg_delta), timestamp));
+            }
         };
     }
 
@@ -347,11 +381,26 @@ impl ProverCommandExecutor {
         // Cascade whether we updated policy or not: could be a retroactive cred attr tags reset to existing policy
         if retroactive {
             let query_json = format!(r#"{{"cred_def_id": "{}"}}"#, cred_def_id.0);
-            let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.as_str(), &SearchOptions::id_value())?;
+            let options_json = json!({
+                "retrieveRecords": true,
+                "retrieveTotalCount": false,
+                "retrieveType": false,
+                "retrieveValue": true,
+                "retrieveTags": true,
+            }).to_string();
+            let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.as_str(), &options_json)?;
 
             while let Some(credential_record) = credentials_search.fetch_next_record()? {
                 let (_, credential) = self._get_credential(&credential_record)?;
-                let cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol)?;
+                let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol)?;
+
+                // lifecycle tags are not affected by the policy
+                if let Some(tags) = credential_record.get_tags() {
+                    cred_tags.extend(tags.iter()
+                        .filter(|(name, _)| Credential::LIFECYCLE_TAGS.contains(&name.as_str()))
+                        .map(|(name, value)| (name.clone(), value.clone())));
+                }
+
                 self.wallet_service.update_record_tags(wallet_handle, self.wallet_service.add_prefix("Credential").as_str(), credential_record.get_id(), &cred_tags)?;
             }
         }
@@ -409,7 +458,8 @@ impl ProverCommandExecutor {
             None
         };
 
-        let cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
+        let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
+        cred_tags.extend(self.anoncreds_service.prover.build_credential_lifecycle_tags(&credential, current_timestamp()));
         self.wallet_service.add_indy_object(wallet_handle, &out_cred_id, credential, &cred_tags)?;
 
         debug!("store_credential <<< out_cred_id: {:?}", out_cred_id);
@@ -662,6 +712,16 @@ impl ProverCommandExecutor {
             return Err(err_msg(IndyErrorKind::WalletItemNotFound, format!("Credential {} not found", cred_id)));
         }
 
+        let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, cred_id, &RecordOptions::id_value())?;
+
+        if let (Some(rev_reg_id), Some(cred_rev_id)) = (credential.rev_reg_id.as_ref(), credential.signature.extract_index()) {
+            let rev_state_id = _cached_revocation_state_id(rev_reg_id, &cred_rev_id.to_string());
+
+            if self.wallet_service.record_exists::<RevocationState>(wallet_handle, &rev_state_id)? {
+                self.wallet_service.delete_indy_record::<RevocationState>(wallet_handle, &rev_state_id)?;
+            }
+        }
+
         self.wallet_service.delete_indy_record::<Credential>(wallet_handle, cred_id)
     }
 
@@ -672,7 +732,7 @@ impl ProverCommandExecutor {
                     master_secret_id: &str,
                     schemas: &HashMap<SchemaId, SchemaV1>,
                     cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
-                    rev_states: &RevocationStates) -> IndyResult<String> {
+                    rev_states: Option<&RevocationStates>) -> IndyResult<String> {
         debug!("create_proof >>> wallet_handle: {:?}, proof_req: {:?}, requested_credentials: {:?}, master_secret_id: {:?}, schemas: {:?}, \
         cred_defs: {:?}, rev_states: {:?}",
                wallet_handle, proof_req, requested_credentials, master_secret_id, schemas, cred_defs, rev_states);
@@ -700,6 +760,15 @@ impl ProverCommandExecutor {
             credentials.insert(cred_referent, credential);
         }
 
+        let cached_rev_states;
+        let rev_states = match rev_states {
+            Some(rev_states) => rev_states,
+            None => {
+                cached_rev_states = self._get_cached_revocation_states(wallet_handle, requested_credentials, &credentials)?;
+                &cached_rev_states
+            }
+        };
+
         let proof = self.anoncreds_service.prover.create_proof(&credentials,
                                                                &proof_req,
                                                                &requested_credentials,
@@ -725,23 +794,11 @@ impl ProverCommandExecutor {
         debug!("create_revocation_state >>> , blob_storage_reader_handle: {:?}, revoc_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
                blob_storage_reader_handle, revoc_reg_def, rev_reg_delta, timestamp, cred_rev_id);
 
-        let revoc_reg_def = RevocationRegistryDefinitionV1::from(revoc_reg_def);
-
-        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
-
-        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
-                                                       blob_storage_reader_handle,
-                                                       &revoc_reg_def)?;
-
-        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
-
-        let witness = Witness::new(rev_idx, revoc_reg_def.value.max_cred_num, revoc_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
-
-        let revocation_state = RevocationState {
-            witness,
-            rev_reg: RevocationRegistry::from(rev_reg_delta.value),
-            timestamp,
-        };
+        let revocation_state = self._create_revocation_state(blob_storage_reader_handle,
+                                                             &RevocationRegistryDefinitionV1::from(revoc_reg_def),
+                                                             RevocationRegistryDeltaV1::from(rev_reg_delta),
+                                                             timestamp,
+                                                             cred_rev_id)?;
 
         let revocation_state_json = serde_json::to_string(&revocation_state)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
@@ -753,7 +810,7 @@ impl ProverCommandExecutor {
 
     fn update_revocation_state(&self,
                                blob_storage_reader_handle: i32,
-                               mut rev_state: RevocationState,
+                               rev_state: RevocationState,
                                rev_reg_def: RevocationRegistryDefinition,
                                rev_reg_delta: RevocationRegistryDelta,
                                timestamp: u64,
@@ -761,27 +818,208 @@ impl ProverCommandExecutor {
         debug!("update_revocation_state >>> blob_storage_reader_handle: {:?}, rev_state: {:?}, rev_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
                blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id);
 
-        let revocation_registry_definition = RevocationRegistryDefinitionV1::from(rev_reg_def);
+        let rev_state = self._update_revocation_state(blob_storage_reader_handle,
+                                                      rev_state,
+                                                      &RevocationRegistryDefinitionV1::from(rev_reg_def),
+                                                      RevocationRegistryDeltaV1::from(rev_reg_delta),
+                                                      timestamp,
+                                                      cred_rev_id)?;
+
+        let rev_state_json = serde_json::to_string(&rev_state)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+
+        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+
+        Ok(rev_state_json)
+    }
 
+    fn update_cached_revocation_state(&self,
+                                      wallet_handle: WalletHandle,
+                                      blob_storage_reader_handle: i32,
+                                      rev_reg_def: RevocationRegistryDefinition,
+                                      rev_reg_delta: RevocationRegistryDelta,
+                                      timestamp: u64,
+                                      cred_rev_id: &str) -> IndyResult<String> {
+        debug!("update_cached_revocation_state >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, rev_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
+               wallet_handle, blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id);
+
+        let rev_reg_def = RevocationRegistryDefinitionV1::from(rev_reg_def);
         let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
 
+        let rev_state_id = _cached_revocation_state_id(&rev_reg_def.id, cred_rev_id);
+
+        let cached_rev_state = self.wallet_service.get_indy_opt_object::<RevocationState>(wallet_handle, &rev_state_id, &RecordOptions::id_value())?;
+
+        let rev_state = match cached_rev_state {
+            Some(rev_state) => {
+                if timestamp < rev_state.timestamp {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                       format!("Cached RevocationState for {:?} is newer than timestamp {:?}", rev_state_id, timestamp)));
+                }
+
+                let rev_state = self._update_revocation_state(blob_storage_reader_handle, rev_state, &rev_reg_def, rev_reg_delta, timestamp, cred_rev_id)?;
+                self.wallet_service.update_indy_object(wallet_handle, &rev_state_id, &rev_state)?;
+                rev_state
+            }
+            None => {
+                let rev_state = self._create_revocation_state(blob_storage_reader_handle, &rev_reg_def, rev_reg_delta, timestamp, cred_rev_id)?;
+
+                let mut tags = Tags::new();
+                tags.insert("rev_reg_id".to_string(), rev_reg_def.id.0.clone());
+                tags.insert("cred_rev_id".to_string(), cred_rev_id.to_string());
+
+                self.wallet_service.add_indy_object(wallet_handle, &rev_state_id, &rev_state, &tags)?;
+                rev_state
+            }
+        };
+
+        let rev_state_json = serde_json::to_string(&rev_state)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+
+        debug!("update_cached_revocation_state <<< rev_state_json: {:?}", rev_state_json);
+
+        Ok(rev_state_json)
+    }
+
+    fn get_cached_revocation_state(&self,
+                                   wallet_handle: WalletHandle,
+                                   rev_reg_id: &RevocationRegistryId,
+                                   cred_rev_id: &str) -> IndyResult<String> {
+        debug!("get_cached_revocation_state >>> wallet_handle: {:?}, rev_reg_id: {:?}, cred_rev_id: {:?}", wallet_handle, rev_reg_id, cred_rev_id);
+
+        let rev_state_id = _cached_revocation_state_id(rev_reg_id, cred_rev_id);
+
+        let rev_state: RevocationState = self.wallet_service.get_indy_object(wallet_handle, &rev_state_id, &RecordOptions::id_value())?;
+
+        let rev_state_json = serde_json::to_string(&rev_state)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+
+        debug!("get_cached_revocation_state <<< rev_state_json: {:?}", rev_state_json);
+
+        Ok(rev_state_json)
+    }
+
+    fn _create_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                rev_reg_def: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                cred_rev_id: &str) -> IndyResult<RevocationState> {
+        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       rev_reg_def)?;
+
+        let witness = Witness::new(rev_idx, rev_reg_def.value.max_cred_num, rev_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
+
+        Ok(RevocationState {
+            witness,
+            rev_reg: RevocationRegistry::from(rev_reg_delta.value),
+            timestamp,
+        })
+    }
+
+    fn _update_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                mut rev_state: RevocationState,
+                                rev_reg_def: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                cred_rev_id: &str) -> IndyResult<RevocationState> {
         let rev_idx = parse_cred_rev_id(cred_rev_id)?;
 
         let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
                                                        blob_storage_reader_handle,
-                                                       &revocation_registry_definition)?;
+                                                       rev_reg_def)?;
 
-        rev_state.witness.update(rev_idx, revocation_registry_definition.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
+        rev_state.witness.update(rev_idx, rev_reg_def.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
 
         rev_state.rev_reg = RevocationRegistry::from(rev_reg_delta.value);
         rev_state.timestamp = timestamp;
 
-        let rev_state_json = serde_json::to_string(&rev_state)
-            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+        Ok(rev_state)
+    }
 
-        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+    fn _get_cached_revocation_states(&self,
+                                     wallet_handle: WalletHandle,
+                                     requested_credentials: &RequestedCredentials,
+                                     credentials: &HashMap<String, Credential>) -> IndyResult<RevocationStates> {
+        let requested_timestamps = requested_credentials.requested_attributes
+            .values()
+            .map(|requested_attr| (&requested_attr.cred_id, requested_attr.timestamp))
+            .chain(requested_credentials.requested_predicates
+                .values()
+                .map(|requested_predicate| (&requested_predicate.cred_id, requested_predicate.timestamp)));
 
-        Ok(rev_state_json)
+        let mut rev_states: RevocationStates = HashMap::new();
+
+        for (cred_id, timestamp) in requested_timestamps {
+            let timestamp = match timestamp {
+                Some(timestamp) => timestamp,
+                None => continue
+            };
+
+            let credential = credentials.get(cred_id)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Credential not found by id: {:?}", cred_id)))?;
+
+            let (rev_reg_id, cred_rev_id) = match (credential.rev_reg_id.as_ref(), credential.signature.extract_index()) {
+                (Some(rev_reg_id), Some(cred_rev_id)) => (rev_reg_id, cred_rev_id.to_string()),
+                _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Credential {:?} is not revocable", cred_id)))
+            };
+
+            let rev_state_id = _cached_revocation_state_id(rev_reg_id, &cred_rev_id);
+
+            let rev_state: RevocationState = self.wallet_service.get_indy_opt_object(wallet_handle, &rev_state_id, &RecordOptions::id_value())?
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Cached RevocationState not found for credential: {:?}", cred_id)))?;
+
+            if rev_state.timestamp != timestamp {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Cached RevocationState for credential {:?} has timestamp {:?} but {:?} is requested",
+                                           cred_id, rev_state.timestamp, timestamp)));
+            }
+
+            rev_states.entry(rev_reg_id.clone())
+                .or_insert_with(HashMap::new)
+                .insert(timestamp, rev_state);
+        }
+
+        Ok(rev_states)
+    }
+
+    fn check_revocation_status(&self,
+                               wallet_handle: WalletHandle,
+                               cred_id: &str,
+                               rev_reg_def: &RevocationRegistryDefinitionV1,
+                               rev_reg_delta: &RevocationRegistryDeltaV1,
+                               timestamp: u64) -> IndyResult<String> {
+        debug!("check_revocation_status >>> wallet_handle: {:?}, cred_id: {:?}, rev_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}",
+               wallet_handle, cred_id, rev_reg_def, rev_reg_delta, timestamp);
+
+        let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, &cred_id, &RecordOptions::id_value())?;
+
+        let (cred_rev_id, status) = self.anoncreds_service.prover.get_credential_revocation_status(&credential, rev_reg_def, rev_reg_delta)?;
+
+        let mut tags = Tags::new();
+        tags.insert(Credential::REVOCATION_STATUS_TAG.to_string(), status.to_tag_value().to_string());
+        tags.insert(Credential::REVOCATION_CHECKED_AT_TAG.to_string(), timestamp_tag_value(timestamp));
+
+        self.wallet_service.add_record_tags(wallet_handle, &self.wallet_service.add_prefix("Credential").as_str(), cred_id, &tags)?;
+
+        let revocation_info = CredentialRevocationInfo {
+            referent: cred_id.to_string(),
+            rev_reg_id: credential.rev_reg_id.ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Revocation Registry Id not found"))?,
+            cred_rev_id: cred_rev_id.to_string(),
+            status,
+            checked_at: timestamp,
+        };
+
+        let revocation_info_json = serde_json::to_string(&revocation_info)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialRevocationInfo")?;
+
+        debug!("check_revocation_status <<< revocation_info_json: {:?}", revocation_info_json);
+
+        Ok(revocation_info_json)
     }
 
     fn _get_credential_info(&self,
@@ -879,3 +1117,6 @@ impl ProverCommandExecutor {
     }
 }
 
+fn _cached_revocation_state_id(rev_reg_id: &RevocationRegistryId, cred_rev_id: &str) -> String {
+    format!("{}::{}", rev_reg_id.0, cred_rev_id)
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
-use crate::domain::anoncreds::proof::Proof;
+use crate::domain::anoncreds::proof::{Proof, ProofVerificationConfig};
 use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestPayload};
 use crate::domain::anoncreds::revocation_registry::{rev_regs_map_to_rev_regs_local_map, RevocationRegistryV1, RevocationRegistries};
 use crate::domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinitionV1, RevocationRegistryId, RevocationRegistryDefinitions};
@@ -18,8 +18,20 @@ pub enum VerifierCommand {
         CredentialDefinitions, // credential defs
         RevocationRegistryDefinitions, // rev reg defs
         RevocationRegistries, // rev reg entries
+        ProofVerificationConfig, // verification config
         Box<dyn Fn(IndyResult<bool>) + Send>),
+    VerifyProofDetailed(
+        ProofRequest, // proof request
+        Proof, // proof
+        Schemas, // credential schemas
+        CredentialDefinitions, // credential defs
+        RevocationRegistryDefinitions, // rev reg defs
+        RevocationRegistries, // rev reg entries
+        Box<dyn Fn(IndyResult<String>) + Send>),
     GenerateNonce(
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ValidateProofRequest(
+        String, // proof request json
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
 
@@ -36,18 +48,31 @@ impl VerifierCommandExecutor {
 
     pub fn execute(&self, command: VerifierCommand) {
         match command {
-            VerifierCommand::VerifyProof(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+            VerifierCommand::VerifyProof(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, config, cb) => {
                 debug!(target: "verifier_command_executor", "VerifyProof command received");
                 cb(self.verify_proof(&proof_request.value(), proof,
                                      &schemas_map_to_schemas_v1_map(schemas),
                                      &cred_defs_map_to_cred_defs_v1_map(credential_defs),
                                      &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
-                                     &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+                                     &rev_regs_map_to_rev_regs_local_map(rev_regs),
+                                     &config));
+            }
+            VerifierCommand::VerifyProofDetailed(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+                debug!(target: "verifier_command_executor", "VerifyProofDetailed command received");
+                cb(self.verify_proof_detailed(&proof_request.value(), proof,
+                                              &schemas_map_to_schemas_v1_map(schemas),
+                                              &cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                              &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                              &rev_regs_map_to_rev_regs_local_map(rev_regs)));
             }
             VerifierCommand::GenerateNonce(cb) => {
                 debug!(target: "verifier_command_executor", "GenerateNonce command received");
                 cb(self.generate_nonce());
             }
+            VerifierCommand::ValidateProofRequest(proof_request_json, cb) => {
+                debug!(target: "verifier_command_executor", "ValidateProofRequest command received");
+                cb(self.validate_proof_request(&proof_request_json));
+            }
         };
     }
 
@@ -57,10 +82,15 @@ impl VerifierCommandExecutor {
                     schemas: &HashMap<SchemaId, SchemaV1>,
                     cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
                     rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
-                    rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
+                    rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+                    config: &ProofVerificationConfig) -> IndyResult<bool> {
         debug!("verify_proof >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
-               rev_reg_defs: {:?}, rev_regs: {:?}",
-               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+               rev_reg_defs: {:?}, rev_regs: {:?}, config: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs, config);
+
+        if config.check_raw_encoding {
+            self.anoncreds_service.verifier.verify_revealed_attrs_encoding(&proof)?;
+        }
 
         let result = self.anoncreds_service.verifier.verify(&proof,
                                                             &proof_req,
@@ -74,6 +104,32 @@ impl VerifierCommandExecutor {
         Ok(result)
     }
 
+    fn verify_proof_detailed(&self,
+                             proof_req: &ProofRequestPayload,
+                             proof: Proof,
+                             schemas: &HashMap<SchemaId, SchemaV1>,
+                             cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                             rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                             rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<String> {
+        debug!("verify_proof_detailed >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
+               rev_reg_defs: {:?}, rev_regs: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let report = self.anoncreds_service.verifier.verify_detailed(&proof,
+                                                                     &proof_req,
+                                                                     schemas,
+                                                                     cred_defs,
+                                                                     rev_reg_defs,
+                                                                     rev_regs)?;
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ProofVerificationReport")?;
+
+        debug!("verify_proof_detailed <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn generate_nonce(&self) -> IndyResult<String> {
         debug!("generate_nonce >>> ");
 
@@ -86,4 +142,17 @@ impl VerifierCommandExecutor {
 
         Ok(result)
     }
+
+    fn validate_proof_request(&self, proof_request_json: &str) -> IndyResult<String> {
+        debug!("validate_proof_request >>> proof_request_json: {:?}", proof_request_json);
+
+        let report = self.anoncreds_service.verifier.validate_proof_request(proof_request_json);
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ProofRequestValidationReport")?;
+
+        debug!("validate_proof_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
 }
@@ -1,17 +1,29 @@
 use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
-use crate::domain::crypto::key::{Key, KeyInfo, KeyMetadata};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::domain::crypto::key::{Key, KeyDetails, KeyInfo, KeyMetadata};
 use crate::domain::crypto::pack::*;
+use crate::domain::crypto::did::{Did, DidValue, TemporaryDid, TheirDid};
+use crate::domain::crypto::jws::{self, Jws, JwsHeader, JwsOptions};
 use indy_api_types::errors::prelude::*;
-use crate::services::crypto::CryptoService;
-use indy_wallet::{RecordOptions, WalletService};
+use crate::services::crypto::{CryptoService, ExternalSigner};
+use indy_wallet::{RecordOptions, WalletRecord, WalletService};
 
+use serde_json;
 use std::rc::Rc;
 use std::str;
 use crate::utils::crypto::base64;
 use crate::utils::crypto::chacha20poly1305_ietf;
+use indy_utils::crypto::hash::{hash, HASHBYTES};
+use crate::utils::crypto::verkey_builder::verkey_get_cryptoname;
+use crate::utils::timestamp::current_timestamp;
 use crate::domain::crypto::combo_box::ComboBox;
 use indy_api_types::WalletHandle;
+use indy_api_types::signer::*;
 
 pub const PROTECTED_HEADER_ENC: &str = "xchacha20poly1305_ietf";
 pub const PROTECTED_HEADER_TYP: &str = "JWM/1.0";
@@ -19,6 +31,14 @@ pub const PROTECTED_HEADER_ALG_AUTH: &str = "Authcrypt";
 pub const PROTECTED_HEADER_ALG_ANON: &str = "Anoncrypt";
 
 pub enum CryptoCommand {
+    RegisterExternalSigner(
+        String, // signer name
+        SignerCreateKey, // create key
+        SignerSign, // sign
+        SignerCryptoBox, // crypto box
+        SignerFree, // free
+        Box<dyn Fn(IndyResult<()>) + Send>,
+    ),
     CreateKey(
         WalletHandle,
         KeyInfo, // key info
@@ -35,6 +55,16 @@ pub enum CryptoCommand {
         String, // verkey
         Box<dyn Fn(IndyResult<String>) + Send>,
     ),
+    ListKeys(
+        WalletHandle,
+        Option<String>, // query
+        Box<dyn Fn(IndyResult<String>) + Send>,
+    ),
+    DeleteKey(
+        WalletHandle,
+        String, // verkey
+        Box<dyn Fn(IndyResult<()>) + Send>,
+    ),
     CryptoSign(
         WalletHandle,
         String,  // my vk
@@ -71,6 +101,31 @@ pub enum CryptoCommand {
         Vec<u8>, // msg
         Box<dyn Fn(IndyResult<Vec<u8>>) + Send>,
     ),
+    SignJws(
+        WalletHandle,
+        DidValue, // signer did
+        Vec<u8>, // payload
+        JwsOptions, // options
+        Box<dyn Fn(IndyResult<String>) + Send>,
+    ),
+    VerifyJws(
+        WalletHandle,
+        String, // jws
+        Option<Vec<u8>>, // detached payload
+        Box<dyn Fn(IndyResult<bool>) + Send>,
+    ),
+    SignJwt(
+        WalletHandle,
+        DidValue, // signer did
+        serde_json::Map<String, serde_json::Value>, // claims
+        u64, // expires in (seconds)
+        Box<dyn Fn(IndyResult<String>) + Send>,
+    ),
+    VerifyJwt(
+        WalletHandle,
+        String, // jwt
+        Box<dyn Fn(IndyResult<String>) + Send>,
+    ),
     PackMessage(
         Vec<u8>, // plaintext message
         Vec<String>,  // list of receiver's keys
@@ -78,8 +133,30 @@ pub enum CryptoCommand {
         WalletHandle,
         Box<dyn Fn(IndyResult<Vec<u8>>) + Send>,
     ),
+    PackFile(
+        WalletHandle,
+        String, // input path
+        String, // output path
+        Vec<String>, // list of receiver's keys
+        Option<String>, // senders verkey
+        Box<dyn Fn(IndyResult<()>) + Send>,
+    ),
+    UnpackFile(
+        WalletHandle,
+        String, // input path
+        String, // output path
+        Box<dyn Fn(IndyResult<String>) + Send>,
+    ),
+    PackDidCommMessage(
+        Vec<u8>, // plaintext message
+        Vec<String>, // list of receiver's DIDs or key ids
+        Option<String>, // sender's DID or key id
+        DidCommPackOptions,
+        WalletHandle,
+        Box<dyn Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
     UnpackMessage(
-        JWE,
+        PackedMessage,
         WalletHandle,
         Box<dyn Fn(IndyResult<Vec<u8>>) + Send>,
     ),
@@ -103,6 +180,10 @@ impl CryptoCommandExecutor {
 
     pub fn execute(&self, command: CryptoCommand) {
         match command {
+            CryptoCommand::RegisterExternalSigner(signer_name, create_key, sign, crypto_box, free, cb) => {
+                debug!("RegisterExternalSigner command received");
+                cb(self.register_external_signer(&signer_name, create_key, sign, crypto_box, free));
+            }
             CryptoCommand::CreateKey(wallet_handle, key_info, cb) => {
                 debug!("CreateKey command received");
                 cb(self.create_key(wallet_handle, &key_info));
@@ -115,6 +196,14 @@ impl CryptoCommandExecutor {
                 debug!("GetKeyMetadata command received");
                 cb(self.get_key_metadata(wallet_handle, &verkey));
             }
+            CryptoCommand::ListKeys(wallet_handle, query, cb) => {
+                debug!("ListKeys command received");
+                cb(self.list_keys(wallet_handle, query.as_ref().map(String::as_str)));
+            }
+            CryptoCommand::DeleteKey(wallet_handle, verkey, cb) => {
+                debug!("DeleteKey command received");
+                cb(self.delete_key(wallet_handle, &verkey));
+            }
             CryptoCommand::CryptoSign(wallet_handle, my_vk, msg, cb) => {
                 debug!("CryptoSign command received");
                 cb(self.crypto_sign(wallet_handle, &my_vk, &msg));
@@ -139,10 +228,38 @@ impl CryptoCommandExecutor {
                 debug!("AnonymousDecrypt command received");
                 cb(self.anonymous_decrypt(wallet_handle, &my_vk, &encrypted_msg));
             }
+            CryptoCommand::SignJws(wallet_handle, did, payload, options, cb) => {
+                debug!("SignJws command received");
+                cb(self.sign_jws(wallet_handle, &did, &payload, &options));
+            }
+            CryptoCommand::VerifyJws(wallet_handle, jws, detached_payload, cb) => {
+                debug!("VerifyJws command received");
+                cb(self.verify_jws(wallet_handle, &jws, detached_payload.as_ref().map(Vec::as_slice)));
+            }
+            CryptoCommand::SignJwt(wallet_handle, did, claims, expires_in, cb) => {
+                debug!("SignJwt command received");
+                cb(self.sign_jwt(wallet_handle, &did, claims, expires_in));
+            }
+            CryptoCommand::VerifyJwt(wallet_handle, jwt, cb) => {
+                debug!("VerifyJwt command received");
+                cb(self.verify_jwt(wallet_handle, &jwt));
+            }
             CryptoCommand::PackMessage(message, receivers, sender_vk, wallet_handle, cb) => {
                 debug!("PackMessage command received");
                 cb(self.pack_msg(message, receivers, sender_vk, wallet_handle));
             }
+            CryptoCommand::PackFile(wallet_handle, input_path, output_path, receivers, sender_vk, cb) => {
+                debug!("PackFile command received");
+                cb(self.pack_file(wallet_handle, &input_path, &output_path, receivers, sender_vk));
+            }
+            CryptoCommand::UnpackFile(wallet_handle, input_path, output_path, cb) => {
+                debug!("UnpackFile command received");
+                cb(self.unpack_file(wallet_handle, &input_path, &output_path));
+            }
+            CryptoCommand::PackDidCommMessage(message, receivers, sender, options, wallet_handle, cb) => {
+                debug!("PackDidCommMessage command received");
+                cb(self.pack_didcomm_msg(message, receivers, sender, &options, wallet_handle));
+            }
             CryptoCommand::UnpackMessage(jwe_json, wallet_handle, cb) => {
                 debug!("UnpackMessage command received");
                 cb(self.unpack_msg(jwe_json, wallet_handle));
@@ -150,6 +267,21 @@ impl CryptoCommandExecutor {
         };
     }
 
+    fn register_external_signer(&self,
+                                signer_name: &str,
+                                create_key: SignerCreateKey,
+                                sign: SignerSign,
+                                crypto_box: SignerCryptoBox,
+                                free: SignerFree) -> IndyResult<()> {
+        debug!("register_external_signer >>> signer_name: {:?}", signer_name);
+
+        let signer = ExternalSigner::new(create_key, sign, crypto_box, free);
+        self.crypto_service.register_external_signer(signer_name, signer)?;
+
+        debug!("register_external_signer <<<");
+        Ok(())
+    }
+
     fn create_key(&self, wallet_handle: WalletHandle, key_info: &KeyInfo) -> IndyResult<String> {
         debug!(
             "create_key >>> wallet_handle: {:?}, key_info: {:?}",
@@ -158,8 +290,9 @@ impl CryptoCommandExecutor {
         );
 
         let key = self.crypto_service.create_key(key_info)?;
+        let key_tags = self.crypto_service.build_key_tags(&key, current_timestamp());
         self.wallet_service
-            .add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
+            .add_indy_object(wallet_handle, &key.verkey, &key, &key_tags)?;
 
         let res = key.verkey.to_string();
         debug!("create_key <<< res: {:?}", res);
@@ -205,6 +338,210 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn sign_jws(&self,
+                wallet_handle: WalletHandle,
+                did: &DidValue,
+                payload: &[u8],
+                options: &JwsOptions) -> IndyResult<String> {
+        debug!("sign_jws >>> wallet_handle: {:?}, did: {:?}, payload: {:?}, options: {:?}",
+               wallet_handle, did, payload, options);
+
+        let mut jws = self._sign_jws(wallet_handle, did, None, payload)?;
+
+        if options.detached {
+            jws.payload = None;
+        }
+
+        let res = jws.to_compact();
+
+        debug!("sign_jws <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_jws(&self,
+                  wallet_handle: WalletHandle,
+                  jws: &str,
+                  detached_payload: Option<&[u8]>) -> IndyResult<bool> {
+        debug!("verify_jws >>> wallet_handle: {:?}, jws: {:?}, detached_payload: {:?}",
+               wallet_handle, jws, detached_payload);
+
+        let jws = Jws::from_compact(jws)?;
+
+        let payload = match (jws.payload.as_ref(), detached_payload) {
+            (Some(payload), None) => payload.to_string(),
+            (None, Some(detached_payload)) => Jws::encode_payload(detached_payload),
+            (Some(_), Some(_)) =>
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "JWS contains payload, detached payload must not be provided")),
+            (None, None) =>
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "JWS payload is detached, but detached payload isn't provided"))
+        };
+
+        let res = self._verify_jws(wallet_handle, &jws, &payload)?;
+
+        debug!("verify_jws <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn sign_jwt(&self,
+                wallet_handle: WalletHandle,
+                did: &DidValue,
+                mut claims: serde_json::Map<String, serde_json::Value>,
+                expires_in: u64) -> IndyResult<String> {
+        debug!("sign_jwt >>> wallet_handle: {:?}, did: {:?}, claims: {:?}, expires_in: {:?}",
+               wallet_handle, did, claims, expires_in);
+
+        let now = current_timestamp();
+
+        if !claims.contains_key("iss") {
+            claims.insert("iss".to_string(), json!(did.0));
+        }
+
+        claims.insert("iat".to_string(), json!(now));
+
+        if expires_in > 0 {
+            claims.insert("exp".to_string(), json!(now + expires_in));
+        }
+
+        let claims = serde_json::to_vec(&claims)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize JWT claims")?;
+
+        let res = self._sign_jws(wallet_handle, did, Some(jws::JWT_TYP.to_string()), &claims)?.to_compact();
+
+        debug!("sign_jwt <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_jwt(&self,
+                  wallet_handle: WalletHandle,
+                  jwt: &str) -> IndyResult<String> {
+        debug!("verify_jwt >>> wallet_handle: {:?}, jwt: {:?}", wallet_handle, jwt);
+
+        let jws = Jws::from_compact(jwt)?;
+
+        let payload = jws.payload.clone()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "JWT payload can't be detached"))?;
+
+        if !self._verify_jws(wallet_handle, &jws, &payload)? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "JWT signature is invalid"));
+        }
+
+        let claims = base64::decode_urlsafe_nopad(&payload)?;
+        let claims: serde_json::Map<String, serde_json::Value> = serde_json::from_slice(&claims)
+            .to_indy(IndyErrorKind::InvalidStructure, "JWT claims must be json object")?;
+
+        let now = current_timestamp();
+
+        if let Some(exp) = claims.get("exp") {
+            let exp = exp.as_u64()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "JWT exp claim must be NumericDate"))?;
+
+            if exp <= now {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "JWT has expired"));
+            }
+        }
+
+        if let Some(nbf) = claims.get("nbf") {
+            let nbf = nbf.as_u64()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "JWT nbf claim must be NumericDate"))?;
+
+            if nbf > now {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "JWT isn't valid yet"));
+            }
+        }
+
+        let res = serde_json::to_string(&claims)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize JWT claims")?;
+
+        debug!("verify_jwt <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _sign_jws(&self,
+                 wallet_handle: WalletHandle,
+                 did: &DidValue,
+                 typ: Option<String>,
+                 payload: &[u8]) -> IndyResult<Jws> {
+        let my_did: Did = self.wallet_service.get_indy_object(wallet_handle, &did.0, &RecordOptions::id_value())?;
+        let my_key: Key = self.wallet_service.get_indy_object(wallet_handle, &my_did.verkey, &RecordOptions::id_value())?;
+
+        let header = JwsHeader {
+            alg: jws::alg_for_crypto_type(verkey_get_cryptoname(&my_key.verkey))?.to_string(),
+            kid: jws::kid_for_did(&my_did.did),
+            typ,
+        };
+
+        let protected = Jws::encode_header(&header)?;
+        let payload = Jws::encode_payload(payload);
+
+        let signature = self.crypto_service.sign(&my_key, &Jws::signing_input(&protected, &payload))?;
+
+        Ok(Jws {
+            header,
+            protected,
+            payload: Some(payload),
+            signature,
+        })
+    }
+
+    fn _verify_jws(&self, wallet_handle: WalletHandle, jws: &Jws, payload: &str) -> IndyResult<bool> {
+        let verkey = self._resolve_kid(wallet_handle, &jws.header.kid)?;
+
+        if jws.header.alg != jws::alg_for_crypto_type(verkey_get_cryptoname(&verkey))? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("JWS alg {} doesn't match key of {}", jws.header.alg, jws.header.kid)));
+        }
+
+        self.crypto_service.verify(&verkey, &Jws::signing_input(&jws.protected, payload), &jws.signature)
+    }
+
+    // Looks for the key of kid DID in the wallet (my DIDs first, then their DIDs)
+    fn _resolve_kid(&self, wallet_handle: WalletHandle, kid: &str) -> IndyResult<String> {
+        for did in self._kid_did_candidates(kid)?.iter() {
+            match self.wallet_service.get_indy_object::<Did>(wallet_handle, &did.0, &RecordOptions::id_value()) {
+                Ok(my_did) => return Ok(my_did.verkey),
+                Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {}
+                Err(err) => return Err(err)
+            }
+
+            match self.wallet_service.get_indy_object::<TheirDid>(wallet_handle, &did.0, &RecordOptions::id_value()) {
+                Ok(their_did) => return Ok(their_did.verkey),
+                Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {}
+                Err(err) => return Err(err)
+            }
+        }
+
+        Err(err_msg(IndyErrorKind::WalletItemNotFound, format!("DID of kid not found in the wallet: {}", kid)))
+    }
+
+    // Resolves kid to key of my DID only as secret key is required
+    fn _resolve_my_kid(&self, wallet_handle: WalletHandle, kid: &str) -> IndyResult<Key> {
+        for did in self._kid_did_candidates(kid)?.iter() {
+            match self.wallet_service.get_indy_object::<Did>(wallet_handle, &did.0, &RecordOptions::id_value()) {
+                Ok(my_did) => return self.wallet_service.get_indy_object(wallet_handle, &my_did.verkey, &RecordOptions::id_value()),
+                Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {}
+                Err(err) => return Err(err)
+            }
+        }
+
+        Err(err_msg(IndyErrorKind::WalletItemNotFound, format!("My DID of kid not found in the wallet: {}", kid)))
+    }
+
+    // Wallet DIDs can be stored unqualified
+    fn _kid_did_candidates(&self, kid: &str) -> IndyResult<Vec<DidValue>> {
+        let did = jws::did_from_kid(kid)?;
+
+        let mut candidates = vec![did.clone()];
+        if did.is_abbreviatable() {
+            candidates.push(did.to_unqualified());
+        }
+
+        Ok(candidates)
+    }
+
     //TODO begin deprecation process this function. It will be replaced by pack
     fn authenticated_encrypt(
         &self,
@@ -357,6 +694,111 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn list_keys(&self, wallet_handle: WalletHandle, query_json: Option<&str>) -> IndyResult<String> {
+        debug!(
+            "list_keys >>> wallet_handle: {:?}, query_json: {:?}",
+            wallet_handle, query_json
+        );
+
+        // Key record id is its verkey, so signkeys aren't fetched
+        let options_json = json!({
+            "retrieveRecords": true,
+            "retrieveTotalCount": false,
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": true,
+        }).to_string();
+
+        let mut keys_search = self.wallet_service.search_indy_records::<Key>(
+            wallet_handle,
+            query_json.unwrap_or("{}"),
+            &options_json,
+        )?;
+
+        let mut keys: Vec<KeyDetails> = Vec::new();
+
+        while let Some(key_record) = keys_search.fetch_next_record()? {
+            let verkey = key_record.get_id().to_string();
+
+            let created_at = key_record.get_tags()
+                .and_then(|tags| tags.get(Key::CREATED_AT_TAG))
+                .and_then(|created_at| created_at.parse::<u64>().ok());
+
+            let metadata = self.wallet_service.get_indy_opt_object::<KeyMetadata>(
+                wallet_handle,
+                &verkey,
+                &RecordOptions::id_value(),
+            )?.map(|metadata| metadata.value);
+
+            keys.push(KeyDetails {
+                crypto_type: verkey_get_cryptoname(&verkey).to_string(),
+                verkey,
+                metadata,
+                created_at,
+            });
+        }
+
+        let res = serde_json::to_string(&keys)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize list of keys")?;
+
+        debug!("list_keys <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn delete_key(&self, wallet_handle: WalletHandle, verkey: &str) -> IndyResult<()> {
+        debug!(
+            "delete_key >>> wallet_handle: {:?}, verkey: {:?}",
+            wallet_handle, verkey
+        );
+
+        self.crypto_service.validate_key(verkey)?;
+
+        self.wallet_service.get_indy_record::<Key>(wallet_handle, verkey, &RecordOptions::id())?;
+
+        self._check_key_not_referenced(wallet_handle, verkey)?;
+
+        self.wallet_service.delete_indy_record::<Key>(wallet_handle, verkey)?;
+
+        if self.wallet_service.record_exists::<KeyMetadata>(wallet_handle, verkey)? {
+            self.wallet_service.delete_indy_record::<KeyMetadata>(wallet_handle, verkey)?;
+        }
+
+        debug!("delete_key <<<");
+
+        Ok(())
+    }
+
+    // DID or pending key replacement that uses the key would lose ability to sign and decrypt.
+    // Pairwise uses key of its my DID, so it is covered by DID check
+    fn _check_key_not_referenced(&self, wallet_handle: WalletHandle, verkey: &str) -> IndyResult<()> {
+        let mut did_search =
+            self.wallet_service.search_indy_records::<Did>(wallet_handle, "{}", &RecordOptions::id_value())?;
+
+        while let Some(did_record) = did_search.fetch_next_record()? {
+            let did: Did = _record_object(&did_record)?;
+
+            if did.verkey == verkey {
+                return Err(err_msg(IndyErrorKind::InvalidState,
+                                   format!("Key is used by DID: {}", did.did.0)));
+            }
+        }
+
+        let mut temp_did_search =
+            self.wallet_service.search_indy_records::<TemporaryDid>(wallet_handle, "{}", &RecordOptions::id_value())?;
+
+        while let Some(temp_did_record) = temp_did_search.fetch_next_record()? {
+            let temp_did: TemporaryDid = _record_object(&temp_did_record)?;
+
+            if temp_did.verkey == verkey {
+                return Err(err_msg(IndyErrorKind::InvalidState,
+                                   format!("Key is used by pending key replacement of DID: {}", temp_did.did.0)));
+            }
+        }
+
+        Ok(())
+    }
+
     //TODO: Refactor pack to be more modular to version changes or crypto_scheme changes
     //this match statement is super messy, but the easiest way to comply with current architecture
     pub fn pack_msg(
@@ -375,15 +817,7 @@ impl CryptoCommandExecutor {
         //generate content encryption key that will encrypt `message`
         let cek = chacha20poly1305_ietf::gen_key();
 
-        let base64_protected = if let Some(sender_vk) = sender_vk {
-            self.crypto_service.validate_key(&sender_vk)?;
-
-            //returns authcrypted pack_message format. See Wire message format HIPE for details
-            self._prepare_protected_authcrypt(&cek, receiver_list, &sender_vk, wallet_handle)?
-        } else {
-            //returns anoncrypted pack_message format. See Wire message format HIPE for details
-            self._prepare_protected_anoncrypt(&cek, receiver_list)?
-        };
+        let base64_protected = self._prepare_protected(&cek, receiver_list, sender_vk, wallet_handle)?;
 
         // Use AEAD to encrypt `message` with "protected" data as "associated data"
         let (ciphertext, iv, tag) =
@@ -393,6 +827,23 @@ impl CryptoCommandExecutor {
         self._format_pack_message(&base64_protected, &ciphertext, &iv, &tag)
     }
 
+    fn _prepare_protected(&self,
+                          cek: &chacha20poly1305_ietf::Key,
+                          receiver_list: Vec<String>,
+                          sender_vk: Option<String>,
+                          wallet_handle: WalletHandle,
+    ) -> IndyResult<String> {
+        if let Some(sender_vk) = sender_vk {
+            self.crypto_service.validate_key(&sender_vk)?;
+
+            //returns authcrypted pack_message format. See Wire message format HIPE for details
+            self._prepare_protected_authcrypt(cek, receiver_list, &sender_vk, wallet_handle)
+        } else {
+            //returns anoncrypted pack_message format. See Wire message format HIPE for details
+            self._prepare_protected_anoncrypt(cek, receiver_list)
+        }
+    }
+
     fn _prepare_protected_anoncrypt(&self,
                                     cek: &chacha20poly1305_ietf::Key,
                                     receiver_list: Vec<String>,
@@ -494,32 +945,354 @@ impl CryptoCommandExecutor {
         })
     }
 
-    pub fn unpack_msg(&self, jwe_struct: JWE, wallet_handle: WalletHandle) -> IndyResult<Vec<u8>> {
-        //decode protected data
-        let protected_decoded_vec = base64::decode_urlsafe(&jwe_struct.protected)?;
-        let protected_decoded_str = String::from_utf8(protected_decoded_vec).map_err(|err| {
-            err_msg(IndyErrorKind::InvalidStructure, format!(
-                "Failed to utf8 encode data {}",
-                err
-            ))
-        })?;
-        //convert protected_data_str to struct
-        let protected_struct: Protected = serde_json::from_str(&protected_decoded_str).map_err(|err| {
-            err_msg(IndyErrorKind::InvalidStructure, format!(
-                "Failed to deserialize protected data {}",
-                err
-            ))
-        })?;
+    // Packs file in chunks, so the payload is never loaded in memory completely.
+    // Content key is wrapped the same way as for pack_msg.
+    pub fn pack_file(
+        &self,
+        wallet_handle: WalletHandle,
+        input_path: &str,
+        output_path: &str,
+        receiver_list: Vec<String>,
+        sender_vk: Option<String>,
+    ) -> IndyResult<()> {
+        trace!("pack_file >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}, receiver_list: {:?}, sender_vk: {:?}",
+               wallet_handle, input_path, output_path, receiver_list, sender_vk);
 
-        //extract recipient that matches a key in the wallet
-        let (recipient, is_auth_recipient) = self._find_correct_recipient(protected_struct, wallet_handle)?;
+        if receiver_list.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "No receiver keys found"));
+        }
 
-        //get cek and sender data
-        let (sender_verkey_option, cek) = if is_auth_recipient {
-            self._unpack_cek_authcrypt(recipient.clone(), wallet_handle)
+        let input = fs::File::open(input_path)?;
+        let payload_len = input.metadata()?.len();
+
+        let cek = chacha20poly1305_ietf::gen_key();
+        let nonce = chacha20poly1305_ietf::gen_nonce();
+
+        let header = StreamHeader {
+            protected: self._prepare_protected(&cek, receiver_list, sender_vk, wallet_handle)?,
+            nonce: base64::encode_urlsafe(&nonce[..]),
+            chunk_size: STREAM_CHUNK_SIZE,
+            version: STREAM_VERSION,
+        };
+
+        let header = serde_json::to_vec(&header)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize packed file header")?;
+
+        let output = self._create_output_file(output_path)?;
+
+        let res = self._write_packed_file(input, payload_len, output, &header, cek, nonce);
+
+        if res.is_err() {
+            // Don't leave partially written file
+            fs::remove_file(output_path).ok();
+        }
+
+        trace!("pack_file <<< res: {:?}", res);
+
+        res
+    }
+
+    fn _write_packed_file(&self,
+                          input: fs::File,
+                          payload_len: u64,
+                          output: fs::File,
+                          header: &[u8],
+                          cek: chacha20poly1305_ietf::Key,
+                          nonce: chacha20poly1305_ietf::Nonce) -> IndyResult<()> {
+        // Write plain
+        let mut writer = BufWriter::new(output);
+        writer.write_u32::<LittleEndian>(header.len() as u32)?;
+        writer.write_all(header)?;
+
+        // Write encrypted. Header hash binds header to the content,
+        // payload length allows to detect truncated files.
+        let mut writer = chacha20poly1305_ietf::Writer::new(writer, cek, nonce, STREAM_CHUNK_SIZE);
+
+        writer.write_all(&hash(header)?)?;
+        writer.write_u64::<LittleEndian>(payload_len)?;
+
+        let copied = io::copy(&mut BufReader::new(input).take(payload_len), &mut writer)?;
+
+        if copied != payload_len {
+            return Err(err_msg(IndyErrorKind::IOError, "Input file was changed while packing"));
+        }
+
+        writer.flush()?;
+        writer.into_inner().flush()?;
+
+        Ok(())
+    }
+
+    pub fn unpack_file(&self, wallet_handle: WalletHandle, input_path: &str, output_path: &str) -> IndyResult<String> {
+        trace!("unpack_file >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}", wallet_handle, input_path, output_path);
+
+        let mut reader = BufReader::new(fs::File::open(input_path)?);
+
+        // Read plain
+        let header_len = reader.read_u32::<LittleEndian>().map_err(_map_stream_io_err)? as usize;
+
+        if header_len == 0 || header_len > STREAM_MAX_HEADER_SIZE {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid packed file header length"));
+        }
+
+        let mut header_bytes = vec![0u8; header_len];
+        reader.read_exact(&mut header_bytes).map_err(_map_stream_io_err)?;
+
+        let header: StreamHeader = serde_json::from_slice(&header_bytes)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid packed file header")?;
+
+        if header.version != STREAM_VERSION {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported packed file version: {}", header.version)));
+        }
+
+        if header.chunk_size == 0 || header.chunk_size > STREAM_MAX_CHUNK_SIZE {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid packed file chunk size: {}", header.chunk_size)));
+        }
+
+        let (recipient, sender_verkey, cek) = self._unpack_protected(&header.protected, wallet_handle)?;
+
+        let nonce = chacha20poly1305_ietf::Nonce::from_slice(&base64::decode_urlsafe(&header.nonce)?)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid packed file nonce")?;
+
+        // Read encrypted
+        let mut reader = chacha20poly1305_ietf::Reader::new(reader, cek, nonce, header.chunk_size);
+
+        let mut header_hash = vec![0u8; HASHBYTES];
+        reader.read_exact(&mut header_hash).map_err(_map_stream_io_err)?;
+
+        if header_hash != hash(&header_bytes)? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Packed file header doesn't match content"));
+        }
+
+        let payload_len = reader.read_u64::<LittleEndian>().map_err(_map_stream_io_err)?;
+
+        let output = self._create_output_file(output_path)?;
+
+        let res = self._write_unpacked_file(&mut reader, payload_len, output);
+
+        if res.is_err() {
+            // Don't leave partially decrypted file
+            fs::remove_file(output_path).ok();
+        }
+
+        res?;
+
+        let res = UnpackFile {
+            recipient_verkey: recipient.header.kid,
+            sender_verkey,
+        };
+
+        let res = serde_json::to_string(&res)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize unpack file result")?;
+
+        trace!("unpack_file <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _write_unpacked_file<R: Read>(&self, reader: &mut R, payload_len: u64, output: fs::File) -> IndyResult<()> {
+        let mut writer = BufWriter::new(output);
+
+        let copied = io::copy(&mut reader.take(payload_len), &mut writer).map_err(_map_stream_io_err)?;
+
+        if copied != payload_len {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Packed file is truncated"));
+        }
+
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    fn _create_output_file(&self, output_path: &str) -> IndyResult<fs::File> {
+        if let Some(parent_path) = Path::new(output_path).parent() {
+            fs::DirBuilder::new()
+                .recursive(true)
+                .create(parent_path)?;
+        }
+
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(output_path)?;
+
+        Ok(file)
+    }
+
+    pub fn pack_didcomm_msg(
+        &self,
+        message: Vec<u8>,
+        receivers: Vec<String>,
+        sender: Option<String>,
+        options: &DidCommPackOptions,
+        wallet_handle: WalletHandle,
+    ) -> IndyResult<Vec<u8>> {
+        trace!("pack_didcomm_msg >>> receivers: {:?}, sender: {:?}, options: {:?}, wallet_handle: {:?}", receivers, sender, options, wallet_handle);
+
+        if receivers.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "No receivers found"));
+        }
+
+        let kids = receivers
+            .iter()
+            .map(|receiver| self._didcomm_kid(receiver))
+            .collect::<IndyResult<Vec<String>>>()?;
+
+        let their_vks = kids
+            .iter()
+            .map(|kid| self._resolve_kid(wallet_handle, kid))
+            .collect::<IndyResult<Vec<String>>>()?;
+
+        // ECDH-1PU authenticates sender, ECDH-ES is anonymous
+        let (alg, skid, my_key) = match sender {
+            Some(sender) => {
+                let skid = self._didcomm_kid(&sender)?;
+                let my_key = self._resolve_my_kid(wallet_handle, &skid)?;
+                (DIDCOMM_V2_ALG_AUTH, Some(skid), Some(my_key))
+            }
+            None => (DIDCOMM_V2_ALG_ANON, None, None)
+        };
+
+        let enc = options.enc(alg);
+
+        if alg == DIDCOMM_V2_ALG_AUTH && enc != DIDCOMM_V2_ENC_A256CBC_HS512 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("DIDComm authcrypt requires {} content encryption, provided: {}", DIDCOMM_V2_ENC_A256CBC_HS512, enc)));
+        }
+
+        let (epk, epk_sk) = self.crypto_service.didcomm_gen_ephemeral_key();
+
+        let protected = DidCommProtected {
+            typ: DIDCOMM_V2_TYP.to_string(),
+            alg: alg.to_string(),
+            enc: enc.to_string(),
+            apu: skid.as_ref().map(|skid| base64::encode_urlsafe_nopad(skid.as_bytes())),
+            skid,
+            apv: self.crypto_service.didcomm_apv(&kids),
+            epk,
+        };
+
+        let protected_encoded = serde_json::to_vec(&protected)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize DIDComm protected header")?;
+        let protected_encoded = base64::encode_urlsafe_nopad(&protected_encoded);
+
+        // Protected header is used as associated data
+        let cek = self.crypto_service.didcomm_gen_cek(&protected.enc)?;
+        let (ciphertext, iv, tag) = self.crypto_service.didcomm_encrypt_content(&protected.enc, &message, &protected_encoded, &cek)?;
+
+        let mut recipients: Vec<DidCommRecipient> = Vec::with_capacity(kids.len());
+
+        for (kid, their_vk) in kids.into_iter().zip(their_vks) {
+            let encrypted_key = self.crypto_service.didcomm_wrap_cek(&protected, &epk_sk, my_key.as_ref(), &their_vk, &tag, &cek)?;
+
+            recipients.push(DidCommRecipient {
+                header: DidCommHeader { kid },
+                encrypted_key: base64::encode_urlsafe_nopad(&encrypted_key),
+            });
+        }
+
+        let jwe = DidCommJWE {
+            protected: protected_encoded,
+            recipients,
+            iv: base64::encode_urlsafe_nopad(&iv),
+            ciphertext: base64::encode_urlsafe_nopad(&ciphertext),
+            tag: base64::encode_urlsafe_nopad(&tag),
+        };
+
+        let res = serde_json::to_vec(&jwe)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize DIDComm message")?;
+
+        trace!("pack_didcomm_msg <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    // Receiver and sender can be passed as DID or as DID URL of its key
+    fn _didcomm_kid(&self, value: &str) -> IndyResult<String> {
+        if value.contains('#') {
+            jws::did_from_kid(value)?;
+            Ok(value.to_string())
         } else {
-            self._unpack_cek_anoncrypt(recipient.clone(), wallet_handle)
-        }?; //close cek and sender_data match statement
+            Ok(jws::kid_for_did(&DidValue(value.to_string())))
+        }
+    }
+
+    pub fn unpack_msg(&self, message: PackedMessage, wallet_handle: WalletHandle) -> IndyResult<Vec<u8>> {
+        match message {
+            PackedMessage::JWM(jwe_struct) => self._unpack_jwm_msg(jwe_struct, wallet_handle),
+            PackedMessage::DidCommV2(jwe) => self._unpack_didcomm_msg(jwe, wallet_handle),
+        }
+    }
+
+    fn _unpack_didcomm_msg(&self, jwe: DidCommJWE, wallet_handle: WalletHandle) -> IndyResult<Vec<u8>> {
+        trace!("_unpack_didcomm_msg >>> jwe: {:?}, wallet_handle: {:?}", jwe, wallet_handle);
+
+        let protected = base64::decode_urlsafe_nopad(&jwe.protected)?;
+        let protected: DidCommProtected = serde_json::from_slice(&protected)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid DIDComm protected header")?;
+
+        let (recipient, my_key) = self._find_didcomm_recipient(&jwe.recipients, wallet_handle)?;
+
+        let (sender_verkey, sender_kid) = if protected.alg == DIDCOMM_V2_ALG_AUTH {
+            let skid = protected.skid.clone()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "DIDComm authcrypted message doesn't contain skid"))?;
+
+            (Some(self._resolve_kid(wallet_handle, &skid)?), Some(skid))
+        } else {
+            (None, None)
+        };
+
+        let tag = base64::decode_urlsafe_nopad(&jwe.tag)?;
+        let encrypted_key = base64::decode_urlsafe_nopad(&recipient.encrypted_key)?;
+
+        let cek = self.crypto_service.didcomm_unwrap_cek(&protected,
+                                                         &my_key,
+                                                         sender_verkey.as_ref().map(String::as_str),
+                                                         &tag,
+                                                         &encrypted_key)?;
+
+        let message = self.crypto_service.didcomm_decrypt_content(&protected.enc,
+                                                                  &base64::decode_urlsafe_nopad(&jwe.ciphertext)?,
+                                                                  &jwe.protected,
+                                                                  &base64::decode_urlsafe_nopad(&jwe.iv)?,
+                                                                  &tag,
+                                                                  &cek)?;
+
+        let message = String::from_utf8(message)
+            .to_indy(IndyErrorKind::InvalidStructure, "Failed to convert message to UTF-8")?;
+
+        let res = UnpackMessage {
+            message,
+            recipient_verkey: my_key.verkey,
+            sender_verkey,
+            recipient_kid: Some(recipient.header.kid.clone()),
+            sender_kid,
+        };
+
+        let res = serde_json::to_vec(&res)
+            .to_indy(IndyErrorKind::InvalidState, "Failed to serialize message")?;
+
+        trace!("_unpack_didcomm_msg <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _find_didcomm_recipient<'a>(&self, recipients: &'a [DidCommRecipient], wallet_handle: WalletHandle) -> IndyResult<(&'a DidCommRecipient, Key)> {
+        for recipient in recipients {
+            match self._resolve_my_kid(wallet_handle, &recipient.header.kid) {
+                Ok(my_key) => return Ok((recipient, my_key)),
+                Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound
+                    || err.kind() == IndyErrorKind::InvalidStructure => {}
+                Err(err) => return Err(err)
+            }
+        }
+
+        Err(err_msg(IndyErrorKind::WalletItemNotFound, "Key of any DIDComm message recipient not found in the wallet"))
+    }
+
+    fn _unpack_jwm_msg(&self, jwe_struct: JWE, wallet_handle: WalletHandle) -> IndyResult<Vec<u8>> {
+        //extract recipient that matches a key in the wallet and get cek and sender data
+        let (recipient, sender_verkey_option, cek) = self._unpack_protected(&jwe_struct.protected, wallet_handle)?;
 
         //decrypt message
         let message = self.crypto_service.decrypt_ciphertext(
@@ -534,7 +1307,9 @@ impl CryptoCommandExecutor {
         let res = UnpackMessage {
             message,
             sender_verkey: sender_verkey_option,
-            recipient_verkey: recipient.header.kid
+            recipient_verkey: recipient.header.kid,
+            recipient_kid: None,
+            sender_kid: None,
         };
 
         serde_json::to_vec(&res).map_err(|err| {
@@ -545,6 +1320,36 @@ impl CryptoCommandExecutor {
         })
     }
 
+    fn _unpack_protected(&self, protected: &str, wallet_handle: WalletHandle) -> IndyResult<(Recipient, Option<String>, chacha20poly1305_ietf::Key)> {
+        //decode protected data
+        let protected_decoded_vec = base64::decode_urlsafe(protected)?;
+        let protected_decoded_str = String::from_utf8(protected_decoded_vec).map_err(|err| {
+            err_msg(IndyErrorKind::InvalidStructure, format!(
+                "Failed to utf8 encode data {}",
+                err
+            ))
+        })?;
+        //convert protected_data_str to struct
+        let protected_struct: Protected = serde_json::from_str(&protected_decoded_str).map_err(|err| {
+            err_msg(IndyErrorKind::InvalidStructure, format!(
+                "Failed to deserialize protected data {}",
+                err
+            ))
+        })?;
+
+        //extract recipient that matches a key in the wallet
+        let (recipient, is_auth_recipient) = self._find_correct_recipient(protected_struct, wallet_handle)?;
+
+        //get cek and sender data
+        let (sender_verkey_option, cek) = if is_auth_recipient {
+            self._unpack_cek_authcrypt(recipient.clone(), wallet_handle)
+        } else {
+            self._unpack_cek_anoncrypt(recipient.clone(), wallet_handle)
+        }?; //close cek and sender_data match statement
+
+        Ok((recipient, sender_verkey_option, cek))
+    }
+
     fn _find_correct_recipient(&self, protected_struct: Protected, wallet_handle: WalletHandle) -> IndyResult<(Recipient, bool)>{
         for recipient in protected_struct.recipients {
             let my_key_res = self.wallet_service.get_indy_object::<Key>(
@@ -622,3 +1427,19 @@ impl CryptoCommandExecutor {
     }
 
 }
+
+fn _map_stream_io_err(e: io::Error) -> IndyError {
+    match e {
+        ref e if e.kind() == io::ErrorKind::UnexpectedEof
+            || e.kind() == io::ErrorKind::InvalidData => err_msg(IndyErrorKind::InvalidStructure, "Invalid packed file format"),
+        e => e.to_indy(IndyErrorKind::IOError, "Can't read packed file"),
+    }
+}
+
+fn _record_object<T>(record: &WalletRecord) -> IndyResult<T> where T: ::serde::de::DeserializeOwned {
+    let value = record.get_value()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("No value for wallet record: {}", record.get_id())))?;
+
+    serde_json::from_str(value)
+        .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize wallet record: {}", record.get_id()))
+}
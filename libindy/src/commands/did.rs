@@ -7,7 +7,9 @@ use serde_json;
 use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend};
 use crate::commands::ledger::LedgerCommand;
 use crate::domain::crypto::did::{Did, DidValue, DidMetadata, DidWithMeta, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo, DidMethod};
-use crate::domain::crypto::key::KeyInfo;
+use crate::domain::crypto::did_doc::{DidDocument, DidResolutionResult, DidResolutionSource};
+use crate::domain::crypto::did_key;
+use crate::domain::crypto::key::{Key, KeyInfo};
 use crate::domain::ledger::attrib::{AttribData, Endpoint, GetAttrReplyResult};
 use crate::domain::ledger::nym::{GetNymReplyResult, GetNymResultDataV0};
 use crate::domain::ledger::response::Reply;
@@ -18,6 +20,7 @@ use crate::services::ledger::LedgerService;
 use indy_wallet::{RecordOptions, SearchOptions, WalletService};
 use indy_api_types::{WalletHandle, PoolHandle, CommandHandle};
 use indy_utils::next_command_handle;
+use crate::utils::timestamp::current_timestamp;
 use rust_base58::{FromBase58, ToBase58};
 use named_type::NamedType;
 
@@ -35,6 +38,12 @@ pub enum DidCommand {
         WalletHandle,
         DidValue, // my did
         Box<dyn Fn(IndyResult<()>) + Send>),
+    RotateDidKey(
+        WalletHandle,
+        PoolHandle, // pool handle
+        DidValue, // my did
+        KeyInfo, // new key info
+        Box<dyn Fn(IndyResult<String>) + Send>),
     StoreTheirDid(
         WalletHandle,
         TheirDidInfo, // their did info json
@@ -55,6 +64,12 @@ pub enum DidCommand {
         WalletHandle,
         DidValue, // did (my or their)
         Box<dyn Fn(IndyResult<String/*key*/>) + Send>),
+    ResolveDid(
+        PoolHandle, // pool handle
+        WalletHandle,
+        DidValue, // did (my or their)
+        ResolveDidStage, // ledger data already fetched
+        Box<dyn Fn(IndyResult<String/*resolution result*/>) + Send>),
     SetEndpointForDid(
         WalletHandle,
         DidValue, // did
@@ -97,6 +112,36 @@ pub enum DidCommand {
         DidMethod, // method
         Box<dyn Fn(IndyResult<String /*full qualified did*/>) + Send>,
     ),
+    // Internal commands
+    RotateDidKeyTemporaryDidAck(
+        WalletHandle,
+        DidValue, // my did
+        IndyResult<String>, // GetNym Result
+        CommandHandle, // deferred cmd id
+    ),
+    RotateDidKeySubmitAck(
+        WalletHandle,
+        PoolHandle, // pool handle
+        DidValue, // my did
+        String, // new verkey
+        IndyResult<String>, // Nym submit result
+        CommandHandle, // callback id
+    ),
+    RotateDidKeyGetNymAck(
+        WalletHandle,
+        DidValue, // my did
+        String, // new verkey
+        Option<IndyError>, // Nym submit error
+        IndyResult<String>, // GetNym Result
+        CommandHandle, // callback id
+    ),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResolveDidStage {
+    Local,
+    NymFetched,
+    AttribFetched,
 }
 
 macro_rules! ensure_their_did {
@@ -115,6 +160,7 @@ pub struct DidCommandExecutor {
     crypto_service: Rc<CryptoService>,
     ledger_service: Rc<LedgerService>,
     deferred_commands: RefCell<HashMap<CommandHandle, DidCommand>>,
+    pending_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>) + Send>>>,
 }
 
 impl DidCommandExecutor {
@@ -126,6 +172,7 @@ impl DidCommandExecutor {
             crypto_service,
             ledger_service,
             deferred_commands: RefCell::new(HashMap::new()),
+            pending_callbacks: RefCell::new(HashMap::new()),
         }
     }
 
@@ -143,6 +190,10 @@ impl DidCommandExecutor {
                 debug!("ReplaceKeysApply command received");
                 cb(self.replace_keys_apply(wallet_handle, &did));
             }
+            DidCommand::RotateDidKey(wallet_handle, pool_handle, did, key_info, cb) => {
+                debug!("RotateDidKey command received");
+                self.rotate_did_key(wallet_handle, pool_handle, did, key_info, cb);
+            }
             DidCommand::StoreTheirDid(wallet_handle, their_did_info, cb) => {
                 debug!("StoreTheirDid command received");
                 cb(self.store_their_did(wallet_handle, &their_did_info));
@@ -163,6 +214,10 @@ impl DidCommandExecutor {
                 debug!("KeyForLocalDid command received");
                 cb(self.key_for_local_did(wallet_handle, &did));
             }
+            DidCommand::ResolveDid(pool_handle, wallet_handle, did, stage, cb) => {
+                debug!("ResolveDid command received");
+                self.resolve_did(pool_handle, wallet_handle, did, stage, cb);
+            }
             DidCommand::SetEndpointForDid(wallet_handle, did, endpoint, cb) => {
                 debug!("SetEndpointForDid command received");
                 cb(self.set_endpoint_for_did(wallet_handle, &did, &endpoint));
@@ -195,6 +250,18 @@ impl DidCommandExecutor {
                 info!("QualifyDid command received");
                 cb(self.qualify_did(wallet_handle, &did, &method));
             }
+            DidCommand::RotateDidKeyTemporaryDidAck(wallet_handle, did, result, deferred_cmd_id) => {
+                debug!("RotateDidKeyTemporaryDidAck command received");
+                self.rotate_did_key_temporary_did_ack(wallet_handle, did, result, deferred_cmd_id);
+            }
+            DidCommand::RotateDidKeySubmitAck(wallet_handle, pool_handle, did, new_verkey, result, cb_id) => {
+                debug!("RotateDidKeySubmitAck command received");
+                self.rotate_did_key_submit_ack(wallet_handle, pool_handle, did, new_verkey, result, cb_id);
+            }
+            DidCommand::RotateDidKeyGetNymAck(wallet_handle, did, new_verkey, submit_err, result, cb_id) => {
+                debug!("RotateDidKeyGetNymAck command received");
+                self.rotate_did_key_get_nym_ack(wallet_handle, did, new_verkey, submit_err, result, cb_id);
+            }
         };
     }
 
@@ -215,7 +282,8 @@ impl DidCommandExecutor {
         }
 
         self.wallet_service.add_indy_object(wallet_handle, &did.did.0, &did, &HashMap::new())?;
-        let _ = self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new()).ok();
+        let key_tags = self.crypto_service.build_key_tags(&key, current_timestamp());
+        let _ = self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &key_tags).ok();
 
         let res = (did.did.0, did.verkey);
 
@@ -237,8 +305,15 @@ impl DidCommandExecutor {
         let temporary_key = self.crypto_service.create_key(&key_info)?;
         let my_temporary_did = TemporaryDid { did: my_did.did, verkey: temporary_key.verkey.clone() };
 
-        self.wallet_service.add_indy_object(wallet_handle, &temporary_key.verkey, &temporary_key, &HashMap::new())?;
-        self.wallet_service.add_indy_object(wallet_handle, &my_temporary_did.did.0, &my_temporary_did, &HashMap::new())?;
+        let key_tags = self.crypto_service.build_key_tags(&temporary_key, current_timestamp());
+        self.wallet_service.add_indy_object(wallet_handle, &temporary_key.verkey, &temporary_key, &key_tags)?;
+
+        if let Err(err) = self.wallet_service.add_indy_object(wallet_handle, &my_temporary_did.did.0, &my_temporary_did, &HashMap::new()) {
+            if let Err(rollback_err) = self.wallet_service.delete_indy_record::<Key>(wallet_handle, &temporary_key.verkey) {
+                warn!("Can't delete temporary key of {:?}: {:?}", my_temporary_did.did, rollback_err);
+            }
+            return Err(err);
+        }
 
         let res = my_temporary_did.verkey;
 
@@ -268,6 +343,201 @@ impl DidCommandExecutor {
         Ok(())
     }
 
+    fn rotate_did_key(&self,
+                      wallet_handle: WalletHandle,
+                      pool_handle: PoolHandle,
+                      my_did: DidValue,
+                      key_info: KeyInfo,
+                      cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("rotate_did_key >>> wallet_handle: {:?}, pool_handle: {:?}, my_did: {:?}, key_info: {:?}", wallet_handle, pool_handle, my_did, secret!(&key_info));
+
+        try_cb!(self.crypto_service.validate_did(&my_did), cb);
+
+        let temporary_did = try_cb!(self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, &my_did.0, &RecordOptions::id_value()), cb);
+
+        // Temporary DID left by the rotation with unknown ledger state is resolved against the ledger first
+        if temporary_did.is_some() {
+            return self._fetch_temporary_did_state_from_ledger(wallet_handle,
+                                                               pool_handle,
+                                                               &my_did,
+                                                               DidCommand::RotateDidKey(
+                                                                   wallet_handle,
+                                                                   pool_handle,
+                                                                   my_did.clone(),
+                                                                   key_info,
+                                                                   cb));
+        }
+
+        let new_verkey = try_cb!(self.replace_keys_start(wallet_handle, &key_info, &my_did), cb);
+
+        let nym_request = match self.ledger_service.build_nym_request(&my_did, &my_did, Some(&new_verkey), None, None) {
+            Ok(nym_request) => nym_request,
+            Err(err) => return cb(Err(self._rollback_rotate_did_key(wallet_handle, &my_did, &new_verkey, err)))
+        };
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        // NYM is signed with current DID key as temporary key isn't applied yet
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SignAndSubmitRequest(
+                pool_handle,
+                wallet_handle,
+                my_did.clone(),
+                nym_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::RotateDidKeySubmitAck(
+                            wallet_handle,
+                            pool_handle,
+                            my_did.clone(),
+                            new_verkey.clone(),
+                            result,
+                            cb_id,
+                        ))).unwrap();
+                }),
+            ))).unwrap();
+    }
+
+    fn rotate_did_key_temporary_did_ack(&self,
+                                        wallet_handle: WalletHandle,
+                                        my_did: DidValue,
+                                        get_nym_result: IndyResult<String>,
+                                        deferred_cmd_id: CommandHandle) {
+        let res = self._rotate_did_key_temporary_did_ack(wallet_handle, &my_did, get_nym_result);
+        self._execute_deferred_command(deferred_cmd_id, res.err());
+    }
+
+    fn _rotate_did_key_temporary_did_ack(&self,
+                                         wallet_handle: WalletHandle,
+                                         my_did: &DidValue,
+                                         get_nym_result: IndyResult<String>) -> IndyResult<()> {
+        trace!("_rotate_did_key_temporary_did_ack >>> wallet_handle: {:?}, my_did: {:?}, get_nym_result: {:?}", wallet_handle, my_did, get_nym_result);
+
+        let ledger_did_info = self._parse_get_nym_reply(my_did, &get_nym_result?)?;
+        let ledger_verkey = self.crypto_service.create_their_did(&ledger_did_info)?.verkey;
+
+        let current_did = self._wallet_get_my_did(wallet_handle, my_did)?;
+        let temporary_did: TemporaryDid =
+            self.wallet_service.get_indy_object(wallet_handle, &my_did.0, &RecordOptions::id_value())?;
+
+        if ledger_verkey == temporary_did.verkey {
+            // NYM of the previous rotation is written, so its key is applied
+            self.replace_keys_apply(wallet_handle, my_did)?;
+        } else if ledger_verkey == current_did.verkey {
+            // NYM of the previous rotation isn't written, so its temporary DID is discarded
+            self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.0)?;
+        } else {
+            return Err(err_msg(IndyErrorKind::InvalidState,
+                               "DID verkey on the ledger matches neither current nor temporary DID verkey"));
+        }
+
+        trace!("_rotate_did_key_temporary_did_ack <<<");
+
+        Ok(())
+    }
+
+    fn rotate_did_key_submit_ack(&self,
+                                 wallet_handle: WalletHandle,
+                                 pool_handle: PoolHandle,
+                                 my_did: DidValue,
+                                 new_verkey: String,
+                                 submit_result: IndyResult<String>,
+                                 cb_id: CommandHandle) {
+        debug!("rotate_did_key_submit_ack >>> wallet_handle: {:?}, pool_handle: {:?}, my_did: {:?}, new_verkey: {:?}, submit_result: {:?}",
+               wallet_handle, pool_handle, my_did, new_verkey, submit_result);
+
+        let submit_err = match submit_result.and_then(|reply| _check_write_reply(&reply)) {
+            Ok(()) => None,
+            // Pool could apply NYM without sending reply, so ledger state is checked before rollback
+            Err(ref err) if err.kind() == IndyErrorKind::PoolTimeout => Some(err.clone()),
+            Err(err) => {
+                let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+                return cb(Err(self._rollback_rotate_did_key(wallet_handle, &my_did, &new_verkey, err)));
+            }
+        };
+
+        let get_nym_request = match self.ledger_service.build_get_nym_request(None, &my_did) {
+            Ok(get_nym_request) => get_nym_request,
+            Err(err) => return self.rotate_did_key_get_nym_ack(wallet_handle, my_did, new_verkey, submit_err, Err(err), cb_id)
+        };
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                get_nym_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::RotateDidKeyGetNymAck(
+                            wallet_handle,
+                            my_did.clone(),
+                            new_verkey.clone(),
+                            submit_err.clone(),
+                            result,
+                            cb_id,
+                        ))).unwrap();
+                }),
+            ))).unwrap();
+    }
+
+    fn rotate_did_key_get_nym_ack(&self,
+                                  wallet_handle: WalletHandle,
+                                  my_did: DidValue,
+                                  new_verkey: String,
+                                  submit_err: Option<IndyError>,
+                                  get_nym_result: IndyResult<String>,
+                                  cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        cb(self._rotate_did_key_get_nym_ack(wallet_handle, &my_did, new_verkey, submit_err, get_nym_result));
+    }
+
+    fn _rotate_did_key_get_nym_ack(&self,
+                                   wallet_handle: WalletHandle,
+                                   my_did: &DidValue,
+                                   new_verkey: String,
+                                   submit_err: Option<IndyError>,
+                                   get_nym_result: IndyResult<String>) -> IndyResult<String> {
+        trace!("_rotate_did_key_get_nym_ack >>> wallet_handle: {:?}, my_did: {:?}, new_verkey: {:?}, submit_err: {:?}, get_nym_result: {:?}",
+               wallet_handle, my_did, new_verkey, submit_err, get_nym_result);
+
+        // If ledger state is unknown temporary key is kept, so it can be applied by indy_replace_keys_apply
+        let ledger_did_info = self._parse_get_nym_reply(my_did, &get_nym_result?)?;
+        let ledger_verkey = self.crypto_service.create_their_did(&ledger_did_info)?.verkey;
+
+        if ledger_verkey == new_verkey {
+            self.replace_keys_apply(wallet_handle, my_did)?;
+
+            trace!("_rotate_did_key_get_nym_ack <<< res: {:?}", new_verkey);
+
+            return Ok(new_verkey);
+        }
+
+        match submit_err {
+            // NYM isn't applied, so the new key isn't known to anybody
+            Some(err) => Err(self._rollback_rotate_did_key(wallet_handle, my_did, &new_verkey, err)),
+            // NYM is written, but the node replied to GET_NYM doesn't show it yet
+            None => Err(err_msg(IndyErrorKind::InvalidState,
+                                "NYM transaction is written, but ledger doesn't show new DID verkey yet. Call indy_replace_keys_apply after it does"))
+        }
+    }
+
+    fn _rollback_rotate_did_key(&self,
+                                wallet_handle: WalletHandle,
+                                my_did: &DidValue,
+                                new_verkey: &str,
+                                err: IndyError) -> IndyError {
+        debug!("_rollback_rotate_did_key >>> wallet_handle: {:?}, my_did: {:?}, new_verkey: {:?}, err: {:?}", wallet_handle, my_did, new_verkey, err);
+
+        let res = self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.0)
+            .and_then(|_| self.wallet_service.delete_indy_record::<Key>(wallet_handle, new_verkey));
+
+        if let Err(rollback_err) = res {
+            warn!("Can't rollback DID key rotation for {:?}: {:?}", my_did, rollback_err);
+        }
+
+        err
+    }
+
     fn store_their_did(&self,
                        wallet_handle: WalletHandle,
                        their_did_info: &TheirDidInfo) -> IndyResult<()> {
@@ -350,6 +620,12 @@ impl DidCommandExecutor {
 
         try_cb!(self.crypto_service.validate_did(&did), cb);
 
+        // did:key and did:peer contain the key itself
+        if let Some(verkey) = try_cb!(did_key::resolve_verkey(&did), cb) {
+            debug!("key_for_did <<< res: {:?}", verkey);
+            return cb(Ok(verkey));
+        }
+
         // Look to my did
         match self._wallet_get_my_did(wallet_handle, &did) {
             Ok(my_did) => return cb(Ok(my_did.verkey)),
@@ -383,6 +659,12 @@ impl DidCommandExecutor {
 
         self.crypto_service.validate_did(&did)?;
 
+        // did:key and did:peer contain the key itself
+        if let Some(verkey) = did_key::resolve_verkey(did)? {
+            info!("key_for_local_did <<< res: {:?}", verkey);
+            return Ok(verkey);
+        }
+
         // Look to my did
         match self._wallet_get_my_did(wallet_handle, did) {
             Ok(my_did) => return Ok(my_did.verkey),
@@ -402,6 +684,90 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
+    fn resolve_did(&self,
+                   pool_handle: PoolHandle,
+                   wallet_handle: WalletHandle,
+                   did: DidValue,
+                   stage: ResolveDidStage,
+                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("resolve_did >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, stage: {:?}", pool_handle, wallet_handle, did, stage);
+
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        // did:key and did:peer contain the key itself
+        if let Some(verkey) = try_cb!(did_key::resolve_verkey(&did), cb) {
+            let mut did_doc = try_cb!(self._build_did_doc(&did, &verkey), cb);
+
+            if let Some(agreement_key) = try_cb!(did_key::resolve_agreement_key(&did), cb) {
+                did_doc.add_key_agreement(&agreement_key);
+            }
+
+            return cb(self._did_resolution_result(did_doc, DidResolutionSource::Identifier));
+        }
+
+        // Look to my did
+        let verkey = match self._wallet_get_my_did(wallet_handle, &did) {
+            Ok(my_did) => Some(my_did.verkey),
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => None,
+            Err(err) => return cb(Err(err))
+        };
+
+        // look to their did
+        let verkey = match verkey {
+            Some(verkey) => verkey,
+            None => ensure_their_did!(self,
+                                      wallet_handle,
+                                      pool_handle,
+                                      did,
+                                      DidCommand::ResolveDid(
+                                          pool_handle,
+                                          wallet_handle,
+                                          did.clone(),
+                                          ResolveDidStage::NymFetched,
+                                          cb),
+                                      cb).verkey
+        };
+
+        let endpoint = try_cb!(self.wallet_service.get_indy_opt_object::<Endpoint>(wallet_handle, &did.0, &RecordOptions::id_value()), cb);
+
+        // Wallet-held DIDs are resolved locally, ledger DIDs also need endpoint ATTRIB
+        if endpoint.is_none() && stage == ResolveDidStage::NymFetched {
+            return self._fetch_attrib_from_ledger(wallet_handle,
+                                                  pool_handle,
+                                                  &did,
+                                                  DidCommand::ResolveDid(
+                                                      pool_handle,
+                                                      wallet_handle,
+                                                      did.clone(),
+                                                      ResolveDidStage::AttribFetched,
+                                                      cb));
+        }
+
+        let mut did_doc = try_cb!(self._build_did_doc(&did, &verkey), cb);
+
+        if let Some(endpoint) = endpoint {
+            did_doc.add_endpoint(&endpoint);
+        }
+
+        let source = if stage == ResolveDidStage::Local { DidResolutionSource::Wallet } else { DidResolutionSource::Ledger };
+
+        let res = self._did_resolution_result(did_doc, source);
+
+        debug!("resolve_did <<< res: {:?}", res);
+
+        cb(res)
+    }
+
+    fn _build_did_doc(&self, did: &DidValue, verkey: &str) -> IndyResult<DidDocument> {
+        let key_type = self.crypto_service.verification_method_type(verkey)?;
+        Ok(DidDocument::new(did, key_type, verkey))
+    }
+
+    fn _did_resolution_result(&self, did_doc: DidDocument, source: DidResolutionSource) -> IndyResult<String> {
+        serde_json::to_string(&DidResolutionResult::new(did_doc, source))
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID resolution result")
+    }
+
     fn set_endpoint_for_did(&self,
                             wallet_handle: WalletHandle,
                             did: &DidValue,
@@ -580,7 +946,19 @@ impl DidCommandExecutor {
 
         let get_nym_reply = get_nym_reply_result?;
 
-        let get_nym_response: Reply<GetNymReplyResult> = serde_json::from_str(&get_nym_reply)
+        let their_did_info = self._parse_get_nym_reply(&did, &get_nym_reply)?;
+
+        let their_did = self.crypto_service.create_their_did(&their_did_info)?;
+
+        self.wallet_service.add_indy_object(wallet_handle, &their_did.did.0, &their_did, &HashMap::new())?;
+
+        trace!("_get_nym_ack <<<");
+
+        Ok(())
+    }
+
+    fn _parse_get_nym_reply(&self, did: &DidValue, get_nym_reply: &str) -> IndyResult<TheirDidInfo> {
+        let get_nym_response: Reply<GetNymReplyResult> = serde_json::from_str(get_nym_reply)
             .to_indy(IndyErrorKind::InvalidState, "Invalid GetNymReplyResult json")?;
 
         let their_did_info = match get_nym_response.result() {
@@ -597,13 +975,7 @@ impl DidCommandExecutor {
             GetNymReplyResult::GetNymReplyResultV1(res) => TheirDidInfo::new(res.txn.data.did.qualify(did.get_method()), res.txn.data.verkey)
         };
 
-        let their_did = self.crypto_service.create_their_did(&their_did_info)?;
-
-        self.wallet_service.add_indy_object(wallet_handle, &their_did.did.0, &their_did, &HashMap::new())?;
-
-        trace!("_get_nym_ack <<<");
-
-        Ok(())
+        Ok(their_did_info)
     }
 
     fn get_attrib_ack(&self,
@@ -623,16 +995,18 @@ impl DidCommandExecutor {
             .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttrReplyResult json")?;
 
         let (raw, did) = match get_attrib_reply.result() {
-            GetAttrReplyResult::GetAttrReplyResultV0(res) => (res.data, res.dest),
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => {
+                let data = res.data
+                    .ok_or_else(|| err_msg(IndyErrorKind::WalletItemNotFound, "Endpoint ATTRIB isn't found on the ledger"))?; //TODO FIXME use separate error
+                (data, res.dest)
+            }
             GetAttrReplyResult::GetAttrReplyResultV1(res) => (res.txn.data.raw, res.txn.data.did)
         };
 
         let attrib_data: AttribData = serde_json::from_str(&raw)
             .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttReply json")?;
 
-        let endpoint = Endpoint::new(attrib_data.endpoint.ha, attrib_data.endpoint.verkey);
-
-        self.wallet_service.add_indy_object(wallet_handle, &did.0, &endpoint, &HashMap::new())?;
+        self.wallet_service.add_indy_object(wallet_handle, &did.0, &attrib_data.endpoint, &HashMap::new())?;
 
         trace!("_get_attrib_ack <<<");
 
@@ -646,7 +1020,10 @@ impl DidCommandExecutor {
     }
 
     fn _execute_deferred_command(&self, deferred_cmd_id: CommandHandle, err: Option<IndyError>) {
-        if let Some(cmd) = self.deferred_commands.borrow_mut().remove(&deferred_cmd_id) {
+        // Deferred command can defer itself again, so release deferred commands before execution
+        let cmd = self.deferred_commands.borrow_mut().remove(&deferred_cmd_id);
+
+        if let Some(cmd) = cmd {
             if let Some(err) = err {
                 self._call_error_cb(cmd, err);
             } else {
@@ -668,14 +1045,31 @@ impl DidCommandExecutor {
             DidCommand::ReplaceKeysApply(_, _, cb) => {
                 cb(Err(err));
             }
+            DidCommand::RotateDidKey(_, _, _, _, cb) => {
+                cb(Err(err));
+            }
             DidCommand::StoreTheirDid(_, _, cb) => {
                 cb(Err(err));
             }
             DidCommand::KeyForDid(_, _, _, cb) => {
                 cb(Err(err));
             }
+            // Missing endpoint ATTRIB is reported as invalid state by get_endpoint_for_did
             DidCommand::GetEndpointForDid(_, _, _, cb) => {
-                cb(Err(err));
+                if err.kind() == IndyErrorKind::WalletItemNotFound {
+                    cb(Err(err.map(IndyErrorKind::InvalidState, "Endpoint isn't found on the ledger")));
+                } else {
+                    cb(Err(err));
+                }
+            }
+            // DID Document is resolved without service only if ledger doesn't contain endpoint ATTRIB,
+            // any other failure of the ATTRIB fetch (e.g. malformed reply) is returned to the caller
+            DidCommand::ResolveDid(pool_handle, wallet_handle, did, stage, cb) => {
+                if stage == ResolveDidStage::AttribFetched && err.kind() == IndyErrorKind::WalletItemNotFound {
+                    self.resolve_did(pool_handle, wallet_handle, did, stage, cb);
+                } else {
+                    cb(Err(err));
+                }
             }
             _ => {}
         }
@@ -708,6 +1102,32 @@ impl DidCommandExecutor {
             ))).unwrap();
     }
 
+    fn _fetch_temporary_did_state_from_ledger(&self,
+                                              wallet_handle: WalletHandle, pool_handle: PoolHandle,
+                                              did: &DidValue, deferred_cmd: DidCommand) {
+        // Defer this command until DID state is fetched from ledger.
+        let deferred_cmd_id = self._defer_command(deferred_cmd);
+
+        // TODO: FIXME: Remove this unwrap by sending RotateDidKeyTemporaryDidAck with the error.
+        let get_nym_request = self.ledger_service.build_get_nym_request(None, did).unwrap();
+        let did = did.clone();
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                get_nym_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::RotateDidKeyTemporaryDidAck(
+                            wallet_handle,
+                            did.clone(),
+                            result,
+                            deferred_cmd_id,
+                        ))).unwrap();
+                }),
+            ))).unwrap();
+    }
+
     fn _fetch_attrib_from_ledger(&self,
                                  wallet_handle: WalletHandle, pool_handle: PoolHandle,
                                  did: &DidValue, deferred_cmd: DidCommand) {
@@ -741,3 +1161,13 @@ impl DidCommandExecutor {
         self.wallet_service.get_indy_object(wallet_handle, &their_did.0, &RecordOptions::id_value())
     }
 }
+
+fn _check_write_reply(reply: &str) -> IndyResult<()> {
+    let reply: serde_json::Value = serde_json::from_str(reply)
+        .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+    match reply["op"].as_str() {
+        Some("REPLY") => Ok(()),
+        _ => Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction has been failed: {:?}", reply["reason"])))
+    }
+}
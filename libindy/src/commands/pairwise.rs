@@ -1,5 +1,5 @@
 use crate::domain::crypto::did::{Did, TheirDid};
-use crate::domain::pairwise::{Pairwise, PairwiseInfo};
+use crate::domain::pairwise::{Pairwise, PairwiseInfo, PairwiseUpdate};
 use indy_api_types::errors::prelude::*;
 use indy_wallet::{RecordOptions, WalletService};
 use std::collections::HashMap;
@@ -7,6 +7,7 @@ use std::rc::Rc;
 use std::str;
 use indy_api_types::WalletHandle;
 use crate::domain::crypto::did::DidValue;
+use crate::utils::timestamp::{current_timestamp, timestamp_tag_value};
 
 
 pub enum PairwiseCommand {
@@ -23,6 +24,10 @@ pub enum PairwiseCommand {
     ListPairwise(
         WalletHandle,
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SearchPairwise(
+        WalletHandle,
+        Option<String>, // query
+        Box<dyn Fn(IndyResult<String>) + Send>),
     GetPairwise(
         WalletHandle,
         DidValue, // their_did
@@ -31,6 +36,15 @@ pub enum PairwiseCommand {
         WalletHandle,
         DidValue, // their_did
         Option<String>, // metadata
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    UpdatePairwise(
+        WalletHandle,
+        DidValue, // their_did
+        PairwiseUpdate, // pairwise update
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    DeletePairwise(
+        WalletHandle,
+        DidValue, // their_did
         Box<dyn Fn(IndyResult<()>) + Send>)
 }
 
@@ -59,6 +73,10 @@ impl PairwiseCommandExecutor {
                 debug!(target: "pairwise_command_executor", "ListPairwise command received");
                 cb(self.list_pairwise(wallet_handle));
             }
+            PairwiseCommand::SearchPairwise(wallet_handle, query, cb) => {
+                debug!(target: "pairwise_command_executor", "SearchPairwise command received");
+                cb(self.search_pairwise(wallet_handle, query.as_ref().map(String::as_str)));
+            }
             PairwiseCommand::GetPairwise(wallet_handle, their_did, cb) => {
                 debug!(target: "pairwise_command_executor", "GetPairwise command received");
                 cb(self.get_pairwise(wallet_handle, &their_did));
@@ -67,6 +85,14 @@ impl PairwiseCommandExecutor {
                 debug!(target: "pairwise_command_executor", "SetPairwiseMetadata command received");
                 cb(self.set_pairwise_metadata(wallet_handle, &their_did, metadata.as_ref().map(String::as_str)));
             }
+            PairwiseCommand::UpdatePairwise(wallet_handle, their_did, pairwise_update, cb) => {
+                debug!(target: "pairwise_command_executor", "UpdatePairwise command received");
+                cb(self.update_pairwise(wallet_handle, &their_did, &pairwise_update));
+            }
+            PairwiseCommand::DeletePairwise(wallet_handle, their_did, cb) => {
+                debug!(target: "pairwise_command_executor", "DeletePairwise command received");
+                cb(self.delete_pairwise(wallet_handle, &their_did));
+            }
         };
     }
 
@@ -98,7 +124,10 @@ impl PairwiseCommandExecutor {
             metadata: metadata.map(str::to_string)
         };
 
-        self.wallet_service.add_indy_object(wallet_handle, &their_did.0, &pairwise, &HashMap::new())?;
+        let mut tags = HashMap::new();
+        tags.insert(Pairwise::CREATED_AT_TAG.to_string(), timestamp_tag_value(current_timestamp()));
+
+        self.wallet_service.add_indy_object(wallet_handle, &their_did.0, &pairwise, &tags)?;
 
         debug!("create_pairwise <<<");
 
@@ -109,8 +138,30 @@ impl PairwiseCommandExecutor {
                      wallet_handle: WalletHandle) -> IndyResult<String> {
         debug!("list_pairwise >>> wallet_handle: {:?}", wallet_handle);
 
+        let res = self._search_pairwise(wallet_handle, "{}")?;
+
+        debug!("list_pairwise <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn search_pairwise(&self,
+                       wallet_handle: WalletHandle,
+                       query_json: Option<&str>) -> IndyResult<String> {
+        debug!("search_pairwise >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        let res = self._search_pairwise(wallet_handle, query_json.unwrap_or("{}"))?;
+
+        debug!("search_pairwise <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _search_pairwise(&self,
+                        wallet_handle: WalletHandle,
+                        query_json: &str) -> IndyResult<String> {
         let mut pairwise_search =
-            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, "{}", &RecordOptions::id_value())?;
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, query_json, &RecordOptions::id_value())?;
 
         let mut list_pairwise: Vec<String> = Vec::new();
 
@@ -123,12 +174,8 @@ impl PairwiseCommandExecutor {
             list_pairwise.push(pairwise_value);
         }
 
-        let res = serde_json::to_string(&list_pairwise)
-            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pairwise list")?;
-
-        debug!("list_pairwise <<< res: {:?}", res);
-
-        Ok(res)
+        serde_json::to_string(&list_pairwise)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pairwise list")
     }
 
     fn get_pairwise(&self,
@@ -166,4 +213,66 @@ impl PairwiseCommandExecutor {
 
         Ok(())
     }
+
+    fn update_pairwise(&self,
+                       wallet_handle: WalletHandle,
+                       their_did: &DidValue,
+                       pairwise_update: &PairwiseUpdate) -> IndyResult<()> {
+        debug!("update_pairwise >>> wallet_handle: {:?}, their_did: {:?}, pairwise_update: {:?}", wallet_handle, their_did, pairwise_update);
+
+        let mut pairwise: Pairwise =
+            self.wallet_service.get_indy_object(wallet_handle, &their_did.0, &RecordOptions::id_value())?;
+
+        if let Some(ref my_did) = pairwise_update.my_did {
+            self.wallet_service.get_indy_record::<Did>(wallet_handle, &my_did.0, &RecordOptions::id())?;
+        }
+
+        let mut tags = HashMap::new();
+
+        if let Some(ref label) = pairwise_update.label {
+            tags.insert(Pairwise::LABEL_TAG.to_string(), label.to_string());
+        }
+
+        if let Some(ref state) = pairwise_update.state {
+            tags.insert(Pairwise::STATE_TAG.to_string(), state.to_string());
+        }
+
+        // Wallet can't update value and tags of the record at once,
+        // so previous value is restored if tags update fails
+        let prev_my_did = match pairwise_update.my_did {
+            Some(ref my_did) => {
+                let prev_my_did = ::std::mem::replace(&mut pairwise.my_did, my_did.clone());
+                self.wallet_service.update_indy_object(wallet_handle, &their_did.0, &pairwise)?;
+                Some(prev_my_did)
+            }
+            None => None
+        };
+
+        if !tags.is_empty() {
+            if let Err(err) = self.wallet_service.add_record_tags(wallet_handle, &self.wallet_service.add_prefix("Pairwise"), &their_did.0, &tags) {
+                if let Some(prev_my_did) = prev_my_did {
+                    pairwise.my_did = prev_my_did;
+                    self.wallet_service.update_indy_object(wallet_handle, &their_did.0, &pairwise)?;
+                }
+
+                return Err(err);
+            }
+        }
+
+        debug!("update_pairwise <<<");
+
+        Ok(())
+    }
+
+    fn delete_pairwise(&self,
+                       wallet_handle: WalletHandle,
+                       their_did: &DidValue) -> IndyResult<()> {
+        debug!("delete_pairwise >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+        self.wallet_service.delete_indy_record::<Pairwise>(wallet_handle, &their_did.0)?;
+
+        debug!("delete_pairwise <<<");
+
+        Ok(())
+    }
 }
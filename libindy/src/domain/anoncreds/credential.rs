@@ -33,6 +33,43 @@ impl Credential {
     pub fn add_extra_tag_suffix(tag: &str) -> String {
         format!("{}{}", tag, Self::EXTRA_TAG_SUFFIX)
     }
+
+    // Holder side lifecycle tags. They are stored unencrypted to allow range queries over timestamps.
+    pub const RECEIVED_AT_TAG: &'static str = "~received_at";
+    pub const EXPIRES_AT_TAG: &'static str = "~expires_at";
+    pub const REVOCATION_CHECKED_AT_TAG: &'static str = "~revocation_checked_at";
+    pub const REVOCATION_STATUS_TAG: &'static str = "~revocation_status";
+    pub const LIFECYCLE_TAGS: [&'static str; 4] = [Self::RECEIVED_AT_TAG, Self::EXPIRES_AT_TAG, Self::REVOCATION_CHECKED_AT_TAG, Self::REVOCATION_STATUS_TAG];
+
+    // Attribute the issuer can use to declare credential expiration time (as unix timestamp).
+    pub const EXPIRY_ATTRIBUTE: &'static str = "expiry";
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialRevocationStatus {
+    Unknown,
+    Active,
+    Revoked,
+}
+
+impl CredentialRevocationStatus {
+    pub fn to_tag_value(&self) -> &'static str {
+        match self {
+            CredentialRevocationStatus::Unknown => "unknown",
+            CredentialRevocationStatus::Active => "active",
+            CredentialRevocationStatus::Revoked => "revoked",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct CredentialRevocationInfo {
+    pub referent: String,
+    pub rev_reg_id: RevocationRegistryId,
+    pub cred_rev_id: String,
+    pub status: CredentialRevocationStatus,
+    pub checked_at: u64
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -56,6 +93,28 @@ pub struct AttributeValues {
     pub encoded: String
 }
 
+/// Credential values as passed by the issuer: `encoded` can be omitted and will be
+/// filled in with the canonical encoding of `raw`.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct RawCredentialValues(pub HashMap<String, RawAttributeValues>);
+
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct RawAttributeValues {
+    pub raw: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoded: Option<String>
+}
+
+impl Validatable for RawCredentialValues {
+    fn validate(&self) -> Result<(), String> {
+        if self.0.is_empty() {
+            return Err(String::from("CredentialValues validation failed: empty list has been passed"));
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable for CredentialValues {
     fn validate(&self) -> Result<(), String> {
         if self.0.is_empty() {
@@ -33,6 +33,14 @@ impl Default for RequestedProof {
     }
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct ProofVerificationConfig {
+    #[serde(default)]
+    pub check_raw_encoding: bool
+}
+
+impl Validatable for ProofVerificationConfig {}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SubProofReferent {
     pub sub_proof_index: u32,
@@ -54,4 +62,71 @@ pub struct Identifier {
     pub timestamp: Option<u64>
 }
 
-impl Validatable for Proof {}
\ No newline at end of file
+impl Validatable for Proof {}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ProofVerificationReport {
+    pub valid: bool,
+    pub referents: HashMap<String, ReferentVerificationReport>,
+    pub failures: Vec<VerificationFailure>
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ReferentVerificationReport {
+    pub kind: ReferentKind,
+    pub sub_proof_index: Option<u32>,
+    pub valid: bool,
+    pub failures: Vec<VerificationFailure>
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReferentKind {
+    RevealedAttribute,
+    UnrevealedAttribute,
+    SelfAttestedAttribute,
+    Predicate,
+    Missing
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct VerificationFailure {
+    pub check: VerificationCheck,
+    pub reason: String
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationCheck {
+    /// Referent, identifier or public entity required for verification is absent
+    Structure,
+    /// Credential doesn't satisfy restrictions of the proof request
+    Restrictions,
+    /// Revealed value in `requested_proof` differs from one in CL proof
+    Encoding,
+    /// `non_revoked` interval is requested but proof has no timestamp
+    Timestamp,
+    /// Revocation registry state needed for non-revocation proof is absent
+    Revocation,
+    /// Revocation data of the credential can't match its non-revocation proof
+    NonRevocation,
+    /// CL proof of credentials is invalid
+    Signature
+}
+
+impl VerificationFailure {
+    pub fn new(check: VerificationCheck, reason: String) -> VerificationFailure {
+        VerificationFailure { check, reason }
+    }
+}
+
+impl ReferentVerificationReport {
+    pub fn new(kind: ReferentKind, sub_proof_index: Option<u32>, failures: Vec<VerificationFailure>) -> ReferentVerificationReport {
+        ReferentVerificationReport {
+            kind,
+            sub_proof_index,
+            valid: failures.is_empty(),
+            failures
+        }
+    }
+}
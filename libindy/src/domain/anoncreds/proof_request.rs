@@ -168,6 +168,66 @@ pub struct RequestedPredicateInfo {
     pub predicate_info: PredicateInfo
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ProofRequestValidationReport {
+    pub valid: bool,
+    pub errors: Vec<ProofRequestIssue>
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ProofRequestIssue {
+    pub referent: Option<String>,
+    pub message: String
+}
+
+impl ProofRequestIssue {
+    pub fn new(referent: Option<&str>, message: String) -> ProofRequestIssue {
+        ProofRequestIssue {
+            referent: referent.map(String::from),
+            message
+        }
+    }
+}
+
+/// Requested attributes or predicates in the order they appear in JSON.
+/// Unlike a map it keeps repeated referents, so they can be reported instead of silently overridden.
+#[derive(Debug)]
+pub struct RequestedItems(pub Vec<(String, Value)>);
+
+impl<'de> Deserialize<'de> for RequestedItems {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        struct RequestedItemsVisitor;
+
+        impl<'de> de::Visitor<'de> for RequestedItemsVisitor {
+            type Value = RequestedItems;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("map of referents")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<RequestedItems, A::Error>
+                where A: de::MapAccess<'de>
+            {
+                let mut items = Vec::new();
+                while let Some((referent, value)) = map.next_entry::<String, Value>()? {
+                    items.push((referent, value));
+                }
+                Ok(RequestedItems(items))
+            }
+        }
+
+        deserializer.deserialize_map(RequestedItemsVisitor)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProofRequestReferents {
+    pub requested_attributes: Option<RequestedItems>,
+    pub requested_predicates: Option<RequestedItems>
+}
+
 impl Validatable for ProofRequest {
     fn validate(&self) -> Result<(), String> {
         let value = self.value();
@@ -266,6 +326,10 @@ fn _convert_value_to_unqualified(tag_name: &str, tag_value: &str) -> String {
     }
 }
 
+pub fn validate_restrictions(restrictions: &Query, version: &ProofRequestsVersion) -> Result<(), String> {
+    _process_operator(restrictions, version)
+}
+
 fn _process_operator(restriction_op: &Query, version: &ProofRequestsVersion) -> Result<(), String> {
     match restriction_op {
         Query::Eq(ref tag_name, ref tag_value) |
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use ursa::cl::RevocationRegistryDelta as RegistryDelta;
 
 use indy_api_types::validation::Validatable;
@@ -23,4 +25,16 @@ impl From<RevocationRegistryDelta> for RevocationRegistryDeltaV1 {
     }
 }
 
-impl Validatable for RevocationRegistryDelta {}
\ No newline at end of file
+impl Validatable for RevocationRegistryDelta {}
+
+/// Indices of credentials issued and revoked within revocation registry delta.
+#[derive(Debug, Default, Deserialize)]
+pub struct RevocationRegistryDeltaIndices {
+    /// Delta accumulated from the registry creation doesn't contain previous accumulator
+    #[serde(rename = "prevAccum")]
+    pub prev_accum: Option<serde_json::Value>,
+    #[serde(default)]
+    pub issued: HashSet<u32>,
+    #[serde(default)]
+    pub revoked: HashSet<u32>
+}
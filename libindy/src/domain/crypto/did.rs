@@ -5,6 +5,7 @@ use rust_base58::FromBase58;
 
 use indy_api_types::validation::Validatable;
 use crate::utils::qualifier;
+use super::did_key;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DidMethod(pub String);
@@ -28,6 +29,7 @@ pub struct MyDidInfo {
     pub crypto_type: Option<String>,
     pub cid: Option<bool>,
     pub method_name: Option<DidMethod>,
+    pub peer_numalgo: Option<u8>,
 }
 
 impl Validatable for MyDidInfo {
@@ -38,6 +40,15 @@ impl Validatable for MyDidInfo {
         if let Some(ref name) = self.method_name {
             name.validate()?
         }
+        if let Some(numalgo) = self.peer_numalgo {
+            match self.method_name {
+                Some(ref name) if name.0 == did_key::DID_PEER_METHOD => {}
+                _ => return Err(String::from("Peer numalgo can be specified only for `peer` DID method"))
+            }
+            if numalgo != did_key::PEER_NUMALGO_INCEPTION_KEY && numalgo != did_key::PEER_NUMALGO_MULTIPLE_KEYS {
+                return Err(format!("Unsupported did:peer numalgo: {}", numalgo));
+            }
+        }
         Ok(())
     }
 }
@@ -112,7 +123,10 @@ impl DidValue {
 
 impl Validatable for DidValue {
     fn validate(&self) -> Result<(), String> {
-        if self.is_fully_qualified() {
+        if did_key::is_self_certifying(self) {
+            did_key::resolve_verkey(self)
+                .map_err(|err| err.to_string())?;
+        } else if self.is_fully_qualified() {
             // pass
         } else {
             let did = self.0.from_base58()
@@ -0,0 +1,224 @@
+use super::did::DidValue;
+use super::jws::kid_for_did;
+use super::super::ledger::attrib::Endpoint;
+
+pub const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+pub const DID_DOC_CONTENT_TYPE: &str = "application/did+ld+json";
+
+pub const ED25519_VERIFICATION_KEY_2018: &str = "Ed25519VerificationKey2018";
+pub const SECP256K1_VERIFICATION_KEY_2019: &str = "EcdsaSecp256k1VerificationKey2019";
+pub const SECP256R1_VERIFICATION_KEY_2019: &str = "EcdsaSecp256r1VerificationKey2019";
+pub const X25519_KEY_AGREEMENT_KEY_2019: &str = "X25519KeyAgreementKey2019";
+
+pub const DIDCOMM_SERVICE_TYPE: &str = "did-communication";
+
+const KEY_AGREEMENT_FRAGMENT: &str = "key-agreement-1";
+const DIDCOMM_SERVICE_FRAGMENT: &str = "did-communication";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: String,
+    pub id: DidValue,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    pub assertion_method: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_agreement: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub service: Vec<DidService>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub controller: DidValue,
+    pub public_key_base58: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+    pub recipient_keys: Vec<String>,
+    pub routing_keys: Vec<String>,
+    pub priority: u32,
+}
+
+impl DidDocument {
+    // Unqualified DIDs are represented as did:sov ones, so key ids match JWS kid
+    pub fn new(did: &DidValue, key_type: &str, verkey: &str) -> DidDocument {
+        let id = if did.is_fully_qualified() { did.clone() } else { did.qualify("sov") };
+        let key_id = kid_for_did(&id);
+
+        DidDocument {
+            context: DID_CONTEXT.to_string(),
+            verification_method: vec![VerificationMethod {
+                id: key_id.clone(),
+                type_: key_type.to_string(),
+                controller: id.clone(),
+                public_key_base58: _key_base58(verkey).to_string(),
+            }],
+            authentication: vec![key_id.clone()],
+            assertion_method: vec![key_id],
+            key_agreement: Vec::new(),
+            service: Vec::new(),
+            id,
+        }
+    }
+
+    pub fn add_key_agreement(&mut self, key: &str) {
+        let key_id = format!("{}#{}", self.id.0, KEY_AGREEMENT_FRAGMENT);
+
+        self.verification_method.push(VerificationMethod {
+            id: key_id.clone(),
+            type_: X25519_KEY_AGREEMENT_KEY_2019.to_string(),
+            controller: self.id.clone(),
+            public_key_base58: key.to_string(),
+        });
+        self.key_agreement.push(key_id);
+    }
+
+    // Legacy endpoint transport key is treated as routing key if it differs from DID key
+    pub fn add_endpoint(&mut self, endpoint: &Endpoint) {
+        let verkey = self.verification_method[0].public_key_base58.clone();
+
+        let mut routing_keys = endpoint.routing_keys.clone().unwrap_or_default();
+
+        if let Some(ref transport_key) = endpoint.verkey {
+            let transport_key = _key_base58(transport_key);
+            if transport_key != verkey && !routing_keys.iter().any(|key| key == transport_key) {
+                routing_keys.push(transport_key.to_string());
+            }
+        }
+
+        self.service.push(DidService {
+            id: format!("{}#{}", self.id.0, DIDCOMM_SERVICE_FRAGMENT),
+            type_: DIDCOMM_SERVICE_TYPE.to_string(),
+            service_endpoint: endpoint.ha.clone(),
+            recipient_keys: vec![self.verification_method[0].id.clone()],
+            routing_keys,
+            priority: 0,
+        });
+    }
+}
+
+// Verkey can be followed by crypto type suffix: "<base58 key>:<crypto type>"
+fn _key_base58(verkey: &str) -> &str {
+    verkey.split(':').next().unwrap_or_default()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DidResolutionSource {
+    // did:key and did:peer DIDs
+    Identifier,
+    Wallet,
+    Ledger,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+    pub content_type: String,
+    pub source: DidResolutionSource,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionResult {
+    pub did_document: DidDocument,
+    pub did_resolution_metadata: DidResolutionMetadata,
+}
+
+impl DidResolutionResult {
+    pub fn new(did_document: DidDocument, source: DidResolutionSource) -> DidResolutionResult {
+        DidResolutionResult {
+            did_document,
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: DID_DOC_CONTENT_TYPE.to_string(),
+                source,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+    const VERKEY: &str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+
+    #[test]
+    fn did_document_new_works_for_unqualified_did() {
+        let did_doc = DidDocument::new(&DidValue(DID.to_string()), ED25519_VERIFICATION_KEY_2018, VERKEY);
+
+        let expected = json!({
+            "@context": DID_CONTEXT,
+            "id": "did:sov:NcYxiDXkpYi6ov5FcYDi1e",
+            "verificationMethod": [{
+                "id": "did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1",
+                "type": ED25519_VERIFICATION_KEY_2018,
+                "controller": "did:sov:NcYxiDXkpYi6ov5FcYDi1e",
+                "publicKeyBase58": VERKEY
+            }],
+            "authentication": ["did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1"],
+            "assertionMethod": ["did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1"]
+        });
+
+        assert_eq!(expected, serde_json::to_value(&did_doc).unwrap());
+    }
+
+    #[test]
+    fn did_document_new_works_for_verkey_with_crypto_type() {
+        let did_doc = DidDocument::new(&DidValue(DID.to_string()), SECP256K1_VERIFICATION_KEY_2019, &format!("{}:secp256k1", VERKEY));
+
+        assert_eq!(VERKEY, did_doc.verification_method[0].public_key_base58);
+    }
+
+    #[test]
+    fn did_document_add_endpoint_works() {
+        let mut did_doc = DidDocument::new(&DidValue(DID.to_string()), ED25519_VERIFICATION_KEY_2018, VERKEY);
+
+        did_doc.add_endpoint(&Endpoint {
+            ha: "https://agent.example.com".to_string(),
+            verkey: Some("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp".to_string()),
+            routing_keys: Some(vec!["did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH".to_string()]),
+        });
+
+        let service = &did_doc.service[0];
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e#did-communication", service.id);
+        assert_eq!("https://agent.example.com", service.service_endpoint);
+        assert_eq!(vec!["did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1".to_string()], service.recipient_keys);
+        assert_eq!(vec!["did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH".to_string(),
+                        "5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp".to_string()], service.routing_keys);
+    }
+
+    #[test]
+    fn did_document_add_endpoint_skips_transport_key_equal_to_did_key() {
+        let mut did_doc = DidDocument::new(&DidValue(DID.to_string()), ED25519_VERIFICATION_KEY_2018, VERKEY);
+
+        did_doc.add_endpoint(&Endpoint::new("127.0.0.1:9700".to_string(), Some(VERKEY.to_string())));
+
+        assert!(did_doc.service[0].routing_keys.is_empty());
+    }
+
+    #[test]
+    fn did_document_add_key_agreement_works() {
+        let mut did_doc = DidDocument::new(&DidValue(DID.to_string()), ED25519_VERIFICATION_KEY_2018, VERKEY);
+
+        did_doc.add_key_agreement("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp");
+
+        assert_eq!(2, did_doc.verification_method.len());
+        assert_eq!(X25519_KEY_AGREEMENT_KEY_2019, did_doc.verification_method[1].type_);
+        assert_eq!(vec!["did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-agreement-1".to_string()], did_doc.key_agreement);
+    }
+}
@@ -0,0 +1,204 @@
+use indy_api_types::errors::prelude::*;
+use rust_base58::{FromBase58, ToBase58};
+
+use super::did::DidValue;
+
+pub const DID_KEY_METHOD: &str = "key";
+pub const DID_PEER_METHOD: &str = "peer";
+
+pub const PEER_NUMALGO_INCEPTION_KEY: u8 = 0;
+pub const PEER_NUMALGO_MULTIPLE_KEYS: u8 = 2;
+
+// Multibase prefix of base58btc encoding
+const MULTIBASE_BASE58BTC: char = 'z';
+
+// Multicodec varint prefixes of public keys
+pub const MULTICODEC_ED25519_PUB: [u8; 2] = [0xed, 0x01];
+pub const MULTICODEC_X25519_PUB: [u8; 2] = [0xec, 0x01];
+
+// did:peer:2 key purpose codes
+const PEER_PURPOSE_KEY_AGREEMENT: char = 'E';
+const PEER_PURPOSE_VERIFICATION: char = 'V';
+
+const ED25519_PUB_BYTES: usize = 32;
+
+pub fn is_self_certifying(did: &DidValue) -> bool {
+    match did.get_method() {
+        Some(ref method) => method == DID_KEY_METHOD || method == DID_PEER_METHOD,
+        None => false
+    }
+}
+
+pub fn encode_multikey(codec: &[u8], key: &[u8]) -> String {
+    let mut value = codec.to_vec();
+    value.extend_from_slice(key);
+    format!("{}{}", MULTIBASE_BASE58BTC, value.to_base58())
+}
+
+pub fn decode_multikey(codec: &[u8], value: &str) -> IndyResult<Vec<u8>> {
+    if !value.starts_with(MULTIBASE_BASE58BTC) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Unsupported multibase encoding of key: {}", value)));
+    }
+
+    let value = value[1..].from_base58()
+        .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid base58 key: {:?}", err)))?;
+
+    if !value.starts_with(codec) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Unsupported multicodec type of key"));
+    }
+
+    let key = value[codec.len()..].to_vec();
+
+    if key.len() != ED25519_PUB_BYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Invalid key length, expected: {}, provided: {}", ED25519_PUB_BYTES, key.len())));
+    }
+
+    Ok(key)
+}
+
+pub fn build_did_key(verkey: &[u8]) -> DidValue {
+    DidValue::new(&encode_multikey(&MULTICODEC_ED25519_PUB, verkey), Some(DID_KEY_METHOD))
+}
+
+pub fn build_did_peer_0(verkey: &[u8]) -> DidValue {
+    let id = format!("{}{}", PEER_NUMALGO_INCEPTION_KEY, encode_multikey(&MULTICODEC_ED25519_PUB, verkey));
+    DidValue::new(&id, Some(DID_PEER_METHOD))
+}
+
+pub fn build_did_peer_2(verkey: &[u8], agreement_key: &[u8]) -> DidValue {
+    let id = format!("{}.{}{}.{}{}",
+                     PEER_NUMALGO_MULTIPLE_KEYS,
+                     PEER_PURPOSE_KEY_AGREEMENT, encode_multikey(&MULTICODEC_X25519_PUB, agreement_key),
+                     PEER_PURPOSE_VERIFICATION, encode_multikey(&MULTICODEC_ED25519_PUB, verkey));
+    DidValue::new(&id, Some(DID_PEER_METHOD))
+}
+
+// Returns base58 ed25519 verkey encoded into did:key or did:peer identifier
+// and None for DIDs of other methods
+pub fn resolve_verkey(did: &DidValue) -> IndyResult<Option<String>> {
+    if !is_self_certifying(did) {
+        return Ok(None);
+    }
+
+    let id = did.to_unqualified().0;
+
+    let verkey = match did.get_method() {
+        Some(ref method) if method == DID_KEY_METHOD =>
+            decode_multikey(&MULTICODEC_ED25519_PUB, &id)?,
+        _ => _resolve_peer_verkey(&id)?
+    };
+
+    Ok(Some(verkey.to_base58()))
+}
+
+// Returns base58 X25519 key agreement key of did:peer:2 identifier if it's present
+pub fn resolve_agreement_key(did: &DidValue) -> IndyResult<Option<String>> {
+    match did.get_method() {
+        Some(ref method) if method == DID_PEER_METHOD => {}
+        _ => return Ok(None)
+    }
+
+    let id = did.to_unqualified().0;
+
+    if !id.starts_with(&format!("{}.", PEER_NUMALGO_MULTIPLE_KEYS)) {
+        return Ok(None);
+    }
+
+    id.split('.')
+        .skip(1)
+        .find(|element| element.starts_with(PEER_PURPOSE_KEY_AGREEMENT))
+        .map(|element| decode_multikey(&MULTICODEC_X25519_PUB, &element[1..]).map(|key| key.to_base58()))
+        .map_or(Ok(None), |key| key.map(Some))
+}
+
+fn _resolve_peer_verkey(id: &str) -> IndyResult<Vec<u8>> {
+    let numalgo = id.chars().next()
+        .and_then(|c| c.to_digit(10))
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid did:peer identifier: {}", id)))?;
+
+    match numalgo as u8 {
+        PEER_NUMALGO_INCEPTION_KEY => decode_multikey(&MULTICODEC_ED25519_PUB, &id[1..]),
+        PEER_NUMALGO_MULTIPLE_KEYS => {
+            let mut elements = id.split('.');
+
+            if elements.next() != Some("2") {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid did:peer identifier: {}", id)));
+            }
+
+            elements
+                .find(|element| element.starts_with(PEER_PURPOSE_VERIFICATION))
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                                       format!("did:peer identifier doesn't contain verification key: {}", id)))
+                .and_then(|element| decode_multikey(&MULTICODEC_ED25519_PUB, &element[1..]))
+        }
+        numalgo => Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported did:peer numalgo: {}", numalgo)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERKEY: &str = "B12NYF8RrR3h41TDCTJojY59usg3mbtbjnFs7Eud1Y6u";
+    const DID_KEY: &str = "did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH";
+
+    fn _verkey() -> Vec<u8> {
+        VERKEY.from_base58().unwrap()
+    }
+
+    #[test]
+    fn build_did_key_works() {
+        assert_eq!(DID_KEY, build_did_key(&_verkey()).0);
+    }
+
+    #[test]
+    fn resolve_verkey_works_for_did_key() {
+        assert_eq!(Some(VERKEY.to_string()), resolve_verkey(&DidValue(DID_KEY.to_string())).unwrap());
+    }
+
+    #[test]
+    fn resolve_verkey_works_for_did_peer_0() {
+        let did = build_did_peer_0(&_verkey());
+
+        assert_eq!("did:peer:0z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH", did.0);
+        assert_eq!(Some(VERKEY.to_string()), resolve_verkey(&did).unwrap());
+    }
+
+    #[test]
+    fn resolve_verkey_works_for_did_peer_2() {
+        let did = build_did_peer_2(&_verkey(), &[1u8; 32]);
+
+        assert!(did.0.starts_with("did:peer:2.Ez6LS"));
+        assert!(did.0.ends_with(".Vz6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH"));
+        assert_eq!(Some(VERKEY.to_string()), resolve_verkey(&did).unwrap());
+    }
+
+    #[test]
+    fn resolve_agreement_key_works() {
+        let agreement_key = [1u8; 32];
+
+        let did = build_did_peer_2(&_verkey(), &agreement_key);
+        assert_eq!(Some(agreement_key.to_base58()), resolve_agreement_key(&did).unwrap());
+
+        let did = build_did_peer_0(&_verkey());
+        assert_eq!(None, resolve_agreement_key(&did).unwrap());
+
+        assert_eq!(None, resolve_agreement_key(&DidValue(DID_KEY.to_string())).unwrap());
+    }
+
+    #[test]
+    fn resolve_verkey_works_for_other_methods() {
+        assert_eq!(None, resolve_verkey(&DidValue("did:sov:NcYxiDXkpYi6ov5FcYDi1e".to_string())).unwrap());
+        assert_eq!(None, resolve_verkey(&DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string())).unwrap());
+    }
+
+    #[test]
+    fn resolve_verkey_fails_for_invalid_dids() {
+        assert!(resolve_verkey(&DidValue("did:key:6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH".to_string())).is_err());
+        assert!(resolve_verkey(&DidValue("did:key:z6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc".to_string())).is_err());
+        assert!(resolve_verkey(&DidValue("did:peer:1zQmZMygzYqNwU6Uhmewx5Xepf2VLp5S4HLSwwgf2aiKZuwa".to_string())).is_err());
+        assert!(resolve_verkey(&DidValue("did:peer:2.Ez6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc".to_string())).is_err());
+    }
+}
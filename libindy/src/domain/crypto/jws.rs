@@ -0,0 +1,160 @@
+use indy_api_types::errors::prelude::*;
+use serde_json;
+
+use indy_utils::crypto::base64;
+use super::did::DidValue;
+
+pub const JWS_ALG_EDDSA: &str = "EdDSA";
+pub const JWS_ALG_ES256K: &str = "ES256K";
+pub const JWS_ALG_ES256: &str = "ES256";
+pub const JWT_TYP: &str = "JWT";
+
+// Wallet DIDs have single key, so key reference is fixed
+pub const DID_KEY_FRAGMENT: &str = "key-1";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct JwsHeader {
+    pub alg: String,
+    pub kid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typ: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct JwsOptions {
+    #[serde(default)]
+    pub detached: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Jws {
+    pub header: JwsHeader,
+    pub protected: String,
+    // None for detached payload
+    pub payload: Option<String>,
+    pub signature: Vec<u8>,
+}
+
+impl Jws {
+    pub fn signing_input(protected: &str, payload: &str) -> Vec<u8> {
+        format!("{}.{}", protected, payload).into_bytes()
+    }
+
+    pub fn encode_header(header: &JwsHeader) -> IndyResult<String> {
+        let header = serde_json::to_vec(header)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize JWS header")?;
+
+        Ok(base64::encode_urlsafe_nopad(&header))
+    }
+
+    pub fn encode_payload(payload: &[u8]) -> String {
+        base64::encode_urlsafe_nopad(payload)
+    }
+
+    pub fn from_compact(jws: &str) -> IndyResult<Jws> {
+        let parts: Vec<&str> = jws.split('.').collect();
+
+        if parts.len() != 3 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid JWS: compact serialization must contain 3 parts"));
+        }
+
+        let header = base64::decode_urlsafe_nopad(parts[0])?;
+        let header: JwsHeader = serde_json::from_slice(&header)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid JWS header")?;
+
+        let payload = if parts[1].is_empty() { None } else { Some(parts[1].to_string()) };
+
+        Ok(Jws {
+            header,
+            protected: parts[0].to_string(),
+            payload,
+            signature: base64::decode_urlsafe_nopad(parts[2])?,
+        })
+    }
+
+    pub fn to_compact(&self) -> String {
+        format!("{}.{}.{}",
+                self.protected,
+                self.payload.as_ref().map(String::as_str).unwrap_or(""),
+                base64::encode_urlsafe_nopad(&self.signature))
+    }
+}
+
+pub fn alg_for_crypto_type(crypto_type: &str) -> IndyResult<&'static str> {
+    match crypto_type {
+        "ed25519" => Ok(JWS_ALG_EDDSA),
+        "secp256k1" => Ok(JWS_ALG_ES256K),
+        "p256" => Ok(JWS_ALG_ES256),
+        _ => Err(err_msg(IndyErrorKind::UnknownCrypto, format!("JWS alg isn't defined for crypto type: {}", crypto_type)))
+    }
+}
+
+pub fn kid_for_did(did: &DidValue) -> String {
+    let did = if did.is_fully_qualified() { did.clone() } else { did.qualify("sov") };
+    format!("{}#{}", did.0, DID_KEY_FRAGMENT)
+}
+
+pub fn did_from_kid(kid: &str) -> IndyResult<DidValue> {
+    match kid.find('#') {
+        Some(pos) if &kid[pos + 1..] == DID_KEY_FRAGMENT => Ok(DidValue(kid[..pos].to_string())),
+        _ => Err(err_msg(IndyErrorKind::InvalidStructure, format!("JWS kid isn't DID key reference: {}", kid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _header() -> JwsHeader {
+        JwsHeader {
+            alg: JWS_ALG_EDDSA.to_string(),
+            kid: "did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1".to_string(),
+            typ: None,
+        }
+    }
+
+    #[test]
+    fn jws_compact_roundtrip_works() {
+        let jws = Jws {
+            header: _header(),
+            protected: Jws::encode_header(&_header()).unwrap(),
+            payload: Some(Jws::encode_payload(b"payload")),
+            signature: vec![1, 2, 3],
+        };
+
+        let parsed = Jws::from_compact(&jws.to_compact()).unwrap();
+
+        assert_eq!(jws.header, parsed.header);
+        assert_eq!(jws.payload, parsed.payload);
+        assert_eq!(jws.signature, parsed.signature);
+    }
+
+    #[test]
+    fn jws_from_compact_works_for_detached_payload() {
+        let compact = format!("{}..AQID", Jws::encode_header(&_header()).unwrap());
+
+        let parsed = Jws::from_compact(&compact).unwrap();
+
+        assert_eq!(None, parsed.payload);
+        assert_eq!(vec![1, 2, 3], parsed.signature);
+    }
+
+    #[test]
+    fn jws_from_compact_fails_for_invalid_parts() {
+        assert!(Jws::from_compact("a.b").is_err());
+        assert!(Jws::from_compact("a.b.c.d").is_err());
+    }
+
+    #[test]
+    fn kid_for_did_works() {
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1", kid_for_did(&DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string())));
+        assert_eq!("did:peer:NcYxiDXkpYi6ov5FcYDi1e#key-1", kid_for_did(&DidValue("did:peer:NcYxiDXkpYi6ov5FcYDi1e".to_string())));
+    }
+
+    #[test]
+    fn did_from_kid_works() {
+        assert_eq!(DidValue("did:sov:NcYxiDXkpYi6ov5FcYDi1e".to_string()), did_from_kid("did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1").unwrap());
+        assert!(did_from_kid("did:sov:NcYxiDXkpYi6ov5FcYDi1e").is_err());
+        assert!(did_from_kid("did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-2").is_err());
+    }
+}
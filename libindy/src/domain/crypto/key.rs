@@ -15,13 +15,29 @@ pub struct Key {
     pub signkey: String,
     #[cfg(test)]
     pub signkey: String,
+    // Name of registered external signer holding the private key.
+    // For such keys signkey contains signer specific key reference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
 }
 
 impl Key {
+    pub const CREATED_AT_TAG: &'static str = "~created_at";
+    pub const CRYPTO_TYPE_TAG: &'static str = "~crypto_type";
+
     pub fn new(verkey: String, signkey: String) -> Key {
         Key {
             verkey,
             signkey,
+            signer: None,
+        }
+    }
+
+    pub fn new_external(verkey: String, key_ref: String, signer: String) -> Key {
+        Key {
+            verkey,
+            signkey: key_ref,
+            signer: Some(signer),
         }
     }
 }
@@ -42,9 +58,22 @@ impl Drop for Key {
 pub struct KeyInfo {
     pub seed: Option<String>,
     pub crypto_type: Option<String>,
+    pub signer: Option<String>,
+    pub signer_config: Option<serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug, NamedType)]
 pub struct KeyMetadata {
     pub value: String
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KeyDetails {
+    pub verkey: String,
+    pub crypto_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    // Keys created before creation time tagging have no such tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+}
@@ -1,4 +1,7 @@
 pub mod key;
 pub mod did;
+pub mod did_key;
+pub mod did_doc;
 pub mod combo_box;
 pub mod pack;
+pub mod jws;
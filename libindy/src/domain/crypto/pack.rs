@@ -37,5 +37,163 @@ pub struct UnpackMessage {
     pub message: String,
     pub recipient_verkey: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sender_verkey: Option<String>
-}
\ No newline at end of file
+    pub sender_verkey: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_kid: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_kid: Option<String>,
+}
+
+pub const STREAM_VERSION: u32 = 0;
+pub const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+pub const STREAM_MAX_CHUNK_SIZE: usize = 1024 * 1024;
+pub const STREAM_MAX_HEADER_SIZE: usize = 1024 * 1024;
+
+// Plain header of packed file. Encrypted chunks of the payload follow it.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct StreamHeader {
+    // Same as protected of packed message: content key wrapped for each receiver
+    pub protected: String,
+    // Start nonce. Nonce is incremented for each chunk
+    pub nonce: String,
+    // Size of plain chunk
+    pub chunk_size: usize,
+    pub version: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct UnpackFile {
+    pub recipient_verkey: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_verkey: Option<String>,
+}
+
+pub const DIDCOMM_V2_TYP: &str = "application/didcomm-encrypted+json";
+pub const DIDCOMM_V2_ALG_ANON: &str = "ECDH-ES+A256KW";
+pub const DIDCOMM_V2_ALG_AUTH: &str = "ECDH-1PU+A256KW";
+pub const DIDCOMM_V2_ENC_A256CBC_HS512: &str = "A256CBC-HS512";
+pub const DIDCOMM_V2_ENC_A256GCM: &str = "A256GCM";
+pub const DIDCOMM_V2_ENC_XC20P: &str = "XC20P";
+
+// DIDComm v2 encrypted message in General JWE JSON Serialization
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct DidCommJWE {
+    pub protected: String,
+    pub recipients: Vec<DidCommRecipient>,
+    pub iv: String,
+    pub ciphertext: String,
+    pub tag: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct DidCommRecipient {
+    pub header: DidCommHeader,
+    pub encrypted_key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct DidCommHeader {
+    // DID URL of recipient key
+    pub kid: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct DidCommProtected {
+    pub typ: String,
+    pub alg: String,
+    pub enc: String,
+    // DID URL of sender key, set for ECDH-1PU only
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skid: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apu: Option<String>,
+    pub apv: String,
+    pub epk: EphemeralKey,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct EphemeralKey {
+    pub kty: String,
+    pub crv: String,
+    pub x: String,
+}
+
+impl EphemeralKey {
+    pub const KTY_OKP: &'static str = "OKP";
+    pub const CRV_X25519: &'static str = "X25519";
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct DidCommPackOptions {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enc: Option<String>,
+}
+
+impl DidCommPackOptions {
+    // DIDComm v2 requires A256CBC-HS512 for authcrypt, anoncrypt uses A256GCM by default
+    pub fn enc(&self, alg: &str) -> &str {
+        match self.enc {
+            Some(ref enc) => enc,
+            None if alg == DIDCOMM_V2_ALG_AUTH => DIDCOMM_V2_ENC_A256CBC_HS512,
+            None => DIDCOMM_V2_ENC_A256GCM
+        }
+    }
+}
+
+// Format of message passed to unpack is detected by its structure:
+// only DIDComm v2 message has top-level recipients.
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PackedMessage {
+    DidCommV2(DidCommJWE),
+    JWM(JWE),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn packed_message_detects_jwm_format() {
+        let message = r#"{"protected":"e30","iv":"aXY","ciphertext":"Y3Q","tag":"dGFn"}"#;
+
+        match serde_json::from_str::<PackedMessage>(message).unwrap() {
+            PackedMessage::JWM(_) => {}
+            other => panic!("Unexpected format: {:?}", other)
+        }
+    }
+
+    #[test]
+    fn packed_message_detects_didcomm_v2_format() {
+        let message = r#"{"protected":"e30","recipients":[{"header":{"kid":"did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1"},"encrypted_key":"a2V5"}],"iv":"aXY","ciphertext":"Y3Q","tag":"dGFn"}"#;
+
+        match serde_json::from_str::<PackedMessage>(message).unwrap() {
+            PackedMessage::DidCommV2(jwe) => assert_eq!(1, jwe.recipients.len()),
+            other => panic!("Unexpected format: {:?}", other)
+        }
+    }
+
+    #[test]
+    fn didcomm_pack_options_uses_a256gcm_for_anoncrypt_by_default() {
+        let options: DidCommPackOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(DIDCOMM_V2_ENC_A256GCM, options.enc(DIDCOMM_V2_ALG_ANON));
+    }
+
+    #[test]
+    fn didcomm_pack_options_uses_a256cbc_hs512_for_authcrypt_by_default() {
+        let options: DidCommPackOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(DIDCOMM_V2_ENC_A256CBC_HS512, options.enc(DIDCOMM_V2_ALG_AUTH));
+    }
+
+    #[test]
+    fn didcomm_pack_options_keeps_passed_enc() {
+        let options: DidCommPackOptions = serde_json::from_str(r#"{"enc":"XC20P"}"#).unwrap();
+        assert_eq!(DIDCOMM_V2_ENC_XC20P, options.enc(DIDCOMM_V2_ALG_ANON));
+    }
+}
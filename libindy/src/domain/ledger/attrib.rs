@@ -66,7 +66,7 @@ pub enum GetAttrReplyResult {
 #[serde(rename_all = "camelCase")]
 pub struct GetAttResultV0 {
     pub  identifier: ShortDidValue,
-    pub  data: String,
+    pub  data: Option<String>,
     pub  dest: ShortDidValue,
     pub  raw: String
 }
@@ -87,14 +87,17 @@ pub struct AttribData {
 #[derive(Serialize, Deserialize, Clone, Debug, NamedType)]
 pub struct Endpoint {
     pub ha: String, // indy-node and indy-plenum restrict this to ip-address:port
-    pub verkey: Option<String>
+    pub verkey: Option<String>,
+    #[serde(rename = "routingKeys", default, skip_serializing_if = "Option::is_none")]
+    pub routing_keys: Option<Vec<String>>
 }
 
 impl Endpoint {
     pub fn new(ha: String, verkey: Option<String>) -> Endpoint {
         Endpoint {
             ha,
-            verkey
+            verkey,
+            routing_keys: None
         }
     }
 }
@@ -1,4 +1,5 @@
 use named_type::NamedType;
+use indy_api_types::validation::Validatable;
 use super::crypto::did::DidValue;
 
 #[derive(Serialize, Deserialize, NamedType)]
@@ -9,6 +10,12 @@ pub struct Pairwise {
     pub metadata: Option<String>,
 }
 
+impl Pairwise {
+    pub const LABEL_TAG: &'static str = "~label";
+    pub const STATE_TAG: &'static str = "~state";
+    pub const CREATED_AT_TAG: &'static str = "~created_at";
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct PairwiseInfo {
     pub my_did: DidValue,
@@ -23,4 +30,23 @@ impl From<Pairwise> for PairwiseInfo {
             metadata: pairwise.metadata
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PairwiseUpdate {
+    pub my_did: Option<DidValue>,
+    pub label: Option<String>,
+    pub state: Option<String>,
+}
+
+impl Validatable for PairwiseUpdate {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(ref my_did) = self.my_did {
+            my_did.validate()?;
+        }
+        if self.my_did.is_none() && self.label.is_none() && self.state.is_none() {
+            return Err(String::from("Nothing to update in pairwise"));
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use indy_api_types::errors::prelude::*;
 
-use crate::domain::anoncreds::credential::AttributeValues;
+use crate::domain::anoncreds::credential::{AttributeValues, CredentialValues as CredentialValuesDomain, RawCredentialValues};
 use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, NonRevocedInterval};
 use ursa::cl::{issuer, verifier, CredentialSchema, NonCredentialSchema, MasterSecret, CredentialValues, SubProofRequest};
+use ursa::bn::BigNumber;
 
 use crate::domain::crypto::did::DidValue;
 use crate::domain::anoncreds::schema::SchemaId;
@@ -17,10 +18,46 @@ use crate::domain::anoncreds::proof_request::ProofRequest;
 
 use std::collections::{HashSet, HashMap};
 
+use sha2::Sha256;
+use sha2::digest::{FixedOutput, Input};
+
 pub fn attr_common_view(attr: &str) -> String {
     attr.replace(" ", "").to_lowercase()
 }
 
+/// Canonical encoding of a raw credential attribute value:
+///  - a value that is a 32-bit signed integer in its canonical decimal form is kept as is
+///    (values like "+5", "028" or "-0" are not canonical and are hashed),
+///  - any other value is hashed with SHA-256 and the digest is taken as a big-endian
+///    unsigned integer represented in decimal.
+pub fn encode_attribute_value(raw: &str) -> IndyResult<String> {
+    if let Ok(value) = raw.parse::<i32>() {
+        if value.to_string() == raw {
+            return Ok(raw.to_string());
+        }
+    }
+
+    let mut hasher = Sha256::default();
+    hasher.input(raw.as_bytes());
+
+    let res = BigNumber::from_bytes(hasher.fixed_result().as_slice())?.to_dec()?;
+    Ok(res)
+}
+
+pub fn encode_credential_values(values: &RawCredentialValues) -> IndyResult<CredentialValuesDomain> {
+    let values = values.0.iter()
+        .map(|(attr, values)| {
+            let encoded = match values.encoded {
+                Some(ref encoded) => encoded.clone(),
+                None => encode_attribute_value(&values.raw)?
+            };
+            Ok((attr.clone(), AttributeValues { raw: values.raw.clone(), encoded }))
+        })
+        .collect::<IndyResult<HashMap<String, AttributeValues>>>()?;
+
+    Ok(CredentialValuesDomain(values))
+}
+
 pub fn build_credential_schema(attrs: &HashSet<String>) -> IndyResult<CredentialSchema> {
     trace!("build_credential_schema >>> attrs: {:?}", attrs);
 
@@ -167,6 +204,43 @@ mod tests {
         assert_eq!(None, res);
     }
 
+    mod encode_attribute_value {
+        use super::*;
+
+        #[test]
+        fn encode_attribute_value_works_for_int32() {
+            assert_eq!("28", encode_attribute_value("28").unwrap());
+            assert_eq!("-2147483648", encode_attribute_value("-2147483648").unwrap());
+            assert_eq!("2147483647", encode_attribute_value("2147483647").unwrap());
+        }
+
+        #[test]
+        fn encode_attribute_value_works_for_string() {
+            assert_eq!("99262857098057710338306967609588410025648622308394250666849665532448612202874", encode_attribute_value("Alex").unwrap());
+            assert_eq!("102987336249554097029535212322581322789799900648198034993379397001115665086549", encode_attribute_value("").unwrap());
+        }
+
+        #[test]
+        fn encode_attribute_value_works_for_int_out_of_int32_range() {
+            assert_eq!("26221484005389514539852548961319751347124425277437769688639924217837557266135", encode_attribute_value("2147483648").unwrap());
+        }
+
+        #[test]
+        fn encode_attribute_value_works_for_not_canonical_int32() {
+            assert_eq!("1750515543741438901228798751119195788978562198301161085510979368940106517001", encode_attribute_value("+5").unwrap());
+            assert_eq!("53655714090685082388080833664978425519947536648610133261399763545371122787897", encode_attribute_value("028").unwrap());
+            assert_eq!("107413606770738771354060003529704906623296854012143859948039936364918895758090", encode_attribute_value("-0").unwrap());
+        }
+
+        #[test]
+        fn encode_credential_values_keeps_passed_encoded() {
+            let values: RawCredentialValues = serde_json::from_str(r#"{"name":{"raw":"Alex","encoded":"123"},"age":{"raw":"28"}}"#).unwrap();
+            let values = encode_credential_values(&values).unwrap();
+            assert_eq!("123", values.0["name"].encoded);
+            assert_eq!("28", values.0["age"].encoded);
+        }
+    }
+
     mod to_unqualified {
         use super::*;
 
@@ -13,7 +13,7 @@ use ursa::cl::issuer::Issuer as CryptoIssuer;
 use ursa::cl::prover::Prover as CryptoProver;
 use ursa::cl::verifier::Verifier as CryptoVerifier;
 
-use crate::domain::anoncreds::credential::{AttributeValues, Credential};
+use crate::domain::anoncreds::credential::{AttributeValues, Credential, CredentialRevocationStatus};
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1 as CredentialDefinition, CredentialDefinitionId};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
@@ -22,11 +22,13 @@ use crate::domain::anoncreds::proof::{Identifier, Proof, RequestedProof, Reveale
 use crate::domain::anoncreds::proof_request::{PredicateInfo, PredicateTypes, ProofRequest, ProofRequestPayload, ProofRequestsVersion, RequestedAttributeInfo, RequestedPredicateInfo, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::ProvingCredentialKey;
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
-use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryId};
+use crate::domain::anoncreds::revocation_registry_definition::{IssuanceType, RevocationRegistryDefinitionV1, RevocationRegistryId};
+use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDeltaV1, RevocationRegistryDeltaIndices};
 use crate::domain::anoncreds::revocation_state::RevocationState;
 use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::helpers::*;
+use crate::utils::timestamp::timestamp_tag_value;
 use crate::utils::wql::Query;
 
 const ATTRIBUTE_EXISTENCE_MARKER: &str = "1";
@@ -315,6 +317,77 @@ impl Prover {
         Ok(res)
     }
 
+    pub fn build_credential_lifecycle_tags(&self, credential: &Credential, received_at: u64) -> HashMap<String, String> {
+        trace!("build_credential_lifecycle_tags >>> credential: {:?}, received_at: {:?}", credential, received_at);
+
+        let mut res: HashMap<String, String> = HashMap::new();
+
+        res.insert(Credential::RECEIVED_AT_TAG.to_string(), timestamp_tag_value(received_at));
+
+        let expires_at = credential.values.0
+            .iter()
+            .find(|(attr, _)| attr_common_view(attr) == Credential::EXPIRY_ATTRIBUTE)
+            .and_then(|(_, values)| values.raw.parse::<u64>().ok());
+
+        if let Some(expires_at) = expires_at {
+            res.insert(Credential::EXPIRES_AT_TAG.to_string(), timestamp_tag_value(expires_at));
+        }
+
+        if credential.rev_reg_id.is_some() {
+            res.insert(Credential::REVOCATION_STATUS_TAG.to_string(), CredentialRevocationStatus::Unknown.to_tag_value().to_string());
+        }
+
+        trace!("build_credential_lifecycle_tags <<< res: {:?}", res);
+
+        res
+    }
+
+    pub fn get_credential_revocation_status(&self,
+                                            credential: &Credential,
+                                            rev_reg_def: &RevocationRegistryDefinitionV1,
+                                            rev_reg_delta: &RevocationRegistryDeltaV1) -> IndyResult<(u32, CredentialRevocationStatus)> {
+        trace!("get_credential_revocation_status >>> credential: {:?}, rev_reg_def: {:?}, rev_reg_delta: {:?}", credential, rev_reg_def, rev_reg_delta);
+
+        let (rev_reg_id, cred_rev_id) = match (credential.rev_reg_id.as_ref(), credential.signature.extract_index()) {
+            (Some(rev_reg_id), Some(cred_rev_id)) => (rev_reg_id, cred_rev_id),
+            _ => return Err(err_msg(IndyErrorKind::InvalidStructure, "Credential doesn't support revocation"))
+        };
+
+        if rev_reg_id.to_unqualified() != rev_reg_def.id.to_unqualified() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Revocation Registry Definition {:?} doesn't match Revocation Registry {:?} of the credential", rev_reg_def.id.0, rev_reg_id.0)));
+        }
+
+        let indices: RevocationRegistryDeltaIndices = serde_json::to_value(&rev_reg_delta.value)
+            .and_then(serde_json::from_value)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot get credential indices from RevocationRegistryDelta")?;
+
+        // Partial delta doesn't list credentials revoked before its beginning
+        if indices.prev_accum.is_some() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               "Revocation Registry Delta must be accumulated from the registry creation"));
+        }
+
+        let max_cred_num = rev_reg_def.value.max_cred_num;
+
+        if cred_rev_id > max_cred_num || indices.issued.iter().chain(indices.revoked.iter()).any(|index| *index > max_cred_num) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Revocation Registry Delta doesn't belong to Revocation Registry {:?}", rev_reg_def.id.0)));
+        }
+
+        let active = match rev_reg_def.value.issuance_type {
+            IssuanceType::ISSUANCE_BY_DEFAULT => !indices.revoked.contains(&cred_rev_id),
+            // Credential of ISSUANCE_ON_DEMAND registry is valid only after its issuance is written
+            IssuanceType::ISSUANCE_ON_DEMAND => indices.issued.contains(&cred_rev_id) && !indices.revoked.contains(&cred_rev_id),
+        };
+
+        let status = if active { CredentialRevocationStatus::Active } else { CredentialRevocationStatus::Revoked };
+
+        trace!("get_credential_revocation_status <<< cred_rev_id: {:?}, status: {:?}", cred_rev_id, status);
+
+        Ok((cred_rev_id, status))
+    }
+
     pub fn attribute_satisfy_predicate(&self,
                                        predicate: &PredicateInfo,
                                        attribute_value: &str) -> IndyResult<bool> {
@@ -643,6 +716,66 @@ mod tests {
         }
     }
 
+    mod build_credential_lifecycle_tags {
+        use super::*;
+
+        fn _credential(values: serde_json::Value, rev_reg_id: Option<&str>) -> Credential {
+            serde_json::from_str::<Credential>(
+                &json!({
+                    "schema_id": SCHEMA_ID,
+                    "cred_def_id": CRED_DEF_ID,
+                    "rev_reg_id": rev_reg_id,
+                    "values": values,
+                    "signature": json!({
+                        "p_credential": json!({"m_2": "0","a": "0","e": "0","v": "0"})
+                    }),
+                    "signature_correctness_proof": json!({"se":"0", "c":"0"})
+                }).to_string()
+            ).unwrap()
+        }
+
+        #[test]
+        fn build_credential_lifecycle_tags_works() {
+            let ps = Prover::new();
+            let credential = _credential(json!({"name": {"raw": "Alex", "encoded": "12345"}}), None);
+
+            let tags = ps.build_credential_lifecycle_tags(&credential, 1500000000);
+
+            let expected_tags: HashMap<String, String> = hashmap!(
+                    "~received_at".to_string() => "00000000001500000000".to_string()
+                 );
+
+            assert_eq!(expected_tags, tags)
+        }
+
+        #[test]
+        fn build_credential_lifecycle_tags_works_for_expiry_and_rev_reg_id() {
+            let ps = Prover::new();
+            let credential = _credential(json!({"name": {"raw": "Alex", "encoded": "12345"}, "Expiry": {"raw": "1600000000", "encoded": "1600000000"}}),
+                                         Some(REV_REG_ID));
+
+            let tags = ps.build_credential_lifecycle_tags(&credential, 1500000000);
+
+            let expected_tags: HashMap<String, String> = hashmap!(
+                    "~received_at".to_string() => "00000000001500000000".to_string(),
+                    "~expires_at".to_string() => "00000000001600000000".to_string(),
+                    "~revocation_status".to_string() => "unknown".to_string()
+                 );
+
+            assert_eq!(expected_tags, tags)
+        }
+
+        #[test]
+        fn build_credential_lifecycle_tags_works_for_not_timestamp_expiry() {
+            let ps = Prover::new();
+            let credential = _credential(json!({"expiry": {"raw": "2030-01-01", "encoded": "12345"}}), None);
+
+            let tags = ps.build_credential_lifecycle_tags(&credential, 1500000000);
+
+            assert!(!tags.contains_key("~expires_at"));
+        }
+    }
+
     mod attribute_satisfy_predicate {
         use super::*;
 
@@ -1,12 +1,15 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1, CredentialDefinitionId};
-use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier};
-use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequestPayload, NonRevocedInterval};
+use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeInfo, ProofVerificationReport, ReferentVerificationReport,
+                                      ReferentKind, VerificationFailure, VerificationCheck};
+use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, PredicateTypes, ProofRequest, ProofRequestPayload, ProofRequestsVersion,
+                                              ProofRequestReferents, ProofRequestValidationReport, ProofRequestIssue, NonRevocedInterval, validate_restrictions};
 use crate::domain::anoncreds::revocation_registry::RevocationRegistryV1;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
 use indy_api_types::errors::prelude::*;
+use indy_api_types::validation::Validatable;
 use crate::services::anoncreds::helpers::*;
 
 
@@ -14,6 +17,8 @@ use ursa::cl::{CredentialPublicKey, new_nonce, Nonce};
 use ursa::cl::verifier::Verifier as CryptoVerifier;
 use crate::utils::wql::Query;
 
+use serde_json::Value;
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Filter {
     schema_id: String,
@@ -67,6 +72,288 @@ impl Verifier {
                                                              &received_self_attested_attrs,
                                                              &received_predicates)?;
 
+        let valid = Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        trace!("verify <<< valid: {:?}", valid);
+
+        Ok(valid)
+    }
+
+    pub fn verify_revealed_attrs_encoding(&self, full_proof: &Proof) -> IndyResult<()> {
+        trace!("verify_revealed_attrs_encoding >>> full_proof: {:?}", full_proof);
+
+        for (attr_referent, attr_info) in full_proof.requested_proof.revealed_attrs.iter() {
+            Verifier::_verify_revealed_attribute_encoding(attr_referent, attr_info)?;
+        }
+
+        trace!("verify_revealed_attrs_encoding <<<");
+
+        Ok(())
+    }
+
+    pub fn verify_detailed(&self,
+                           full_proof: &Proof,
+                           proof_req: &ProofRequestPayload,
+                           schemas: &HashMap<SchemaId, SchemaV1>,
+                           cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                           rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                           rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<ProofVerificationReport> {
+        trace!("verify_detailed >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
+               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let requested_proof = &full_proof.requested_proof;
+        let self_attested_attrs: HashSet<String> = Verifier::_received_self_attested_attrs(&full_proof);
+
+        let mut referents: HashMap<String, ReferentVerificationReport> = HashMap::new();
+
+        for (referent, info) in proof_req.requested_attributes.iter() {
+            let (kind, sub_proof_index) =
+                if let Some(attr) = requested_proof.revealed_attrs.get(referent) {
+                    (ReferentKind::RevealedAttribute, Some(attr.sub_proof_index))
+                } else if let Some(attr) = requested_proof.unrevealed_attrs.get(referent) {
+                    (ReferentKind::UnrevealedAttribute, Some(attr.sub_proof_index))
+                } else if self_attested_attrs.contains(referent) {
+                    (ReferentKind::SelfAttestedAttribute, None)
+                } else {
+                    (ReferentKind::Missing, None)
+                };
+
+            let mut failures: Vec<VerificationFailure> = Vec::new();
+
+            match kind {
+                ReferentKind::Missing => {
+                    failures.push(VerificationFailure::new(VerificationCheck::Structure,
+                                                           format!("Requested attribute \"{}\" is not present in the proof", info.name)));
+                }
+                ReferentKind::SelfAttestedAttribute => {
+                    if !Verifier::_is_self_attested(referent, info, &self_attested_attrs) {
+                        failures.push(VerificationFailure::new(VerificationCheck::Restrictions,
+                                                               format!("Self-attested value is provided for attribute \"{}\" with restrictions", info.name)));
+                    }
+                }
+                _ => {}
+            }
+
+            if let Some(sub_proof_index) = sub_proof_index {
+                let revealed_attr = requested_proof.revealed_attrs.get(referent);
+
+                Verifier::_check_referent_credential(full_proof, proof_req, referent, &info.name, &info.restrictions, &info.non_revoked,
+                                                     sub_proof_index, revealed_attr.map(|attr| attr.raw.as_str()),
+                                                     schemas, cred_defs, rev_reg_defs, rev_regs, &mut failures);
+
+                if let Some(attr) = revealed_attr {
+                    if let Err(err) = Verifier::_verify_revealed_attribute_value(proof_req, full_proof, referent, attr) {
+                        failures.push(VerificationFailure::new(VerificationCheck::Encoding, Verifier::_failure_reason(&err)));
+                    }
+                }
+            }
+
+            Verifier::_add_referent_report(&mut referents, referent, ReferentVerificationReport::new(kind, sub_proof_index, failures));
+        }
+
+        for (referent, info) in proof_req.requested_predicates.iter() {
+            let mut failures: Vec<VerificationFailure> = Vec::new();
+
+            let (kind, sub_proof_index) = match requested_proof.predicates.get(referent) {
+                Some(predicate) => {
+                    Verifier::_check_referent_credential(full_proof, proof_req, referent, &info.name, &info.restrictions, &info.non_revoked,
+                                                         predicate.sub_proof_index, None,
+                                                         schemas, cred_defs, rev_reg_defs, rev_regs, &mut failures);
+                    (ReferentKind::Predicate, Some(predicate.sub_proof_index))
+                }
+                None => {
+                    failures.push(VerificationFailure::new(VerificationCheck::Structure,
+                                                           format!("Requested predicate \"{} {} {}\" is not present in the proof", info.name, info.p_type, info.p_value)));
+                    (ReferentKind::Missing, None)
+                }
+            };
+
+            Verifier::_add_referent_report(&mut referents, referent, ReferentVerificationReport::new(kind, sub_proof_index, failures));
+        }
+
+        let received_attrs = requested_proof.revealed_attrs.iter()
+            .map(|(referent, attr)| (referent, ReferentKind::RevealedAttribute, Some(attr.sub_proof_index)))
+            .chain(requested_proof.unrevealed_attrs.iter()
+                .map(|(referent, attr)| (referent, ReferentKind::UnrevealedAttribute, Some(attr.sub_proof_index))))
+            .chain(requested_proof.self_attested_attrs.keys()
+                .map(|referent| (referent, ReferentKind::SelfAttestedAttribute, None)))
+            .filter(|&(referent, _, _)| !proof_req.requested_attributes.contains_key(referent));
+
+        let received_predicates = requested_proof.predicates.iter()
+            .map(|(referent, predicate)| (referent, ReferentKind::Predicate, Some(predicate.sub_proof_index)))
+            .filter(|&(referent, _, _)| !proof_req.requested_predicates.contains_key(referent));
+
+        for (referent, kind, sub_proof_index) in received_attrs.chain(received_predicates) {
+            let failure = VerificationFailure::new(VerificationCheck::Structure, "Referent is not requested in the proof request".to_string());
+            Verifier::_add_referent_report(&mut referents, referent, ReferentVerificationReport::new(kind, sub_proof_index, vec![failure]));
+        }
+
+        let mut failures: Vec<VerificationFailure> = Vec::new();
+
+        // CL proof is aggregated over all credentials, so it can't be attributed to a particular referent
+        // and it is only checked when all referent level checks passed.
+        let referents_valid = referents.values().all(|referent| referent.valid);
+
+        if referents_valid {
+            match Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs) {
+                Ok(true) => {}
+                Ok(false) => {
+                    failures.push(VerificationFailure::new(VerificationCheck::Signature, "CL proof verification failed".to_string()));
+                    Verifier::_add_non_revocation_failures(full_proof, cred_defs, rev_reg_defs, &mut referents);
+                }
+                Err(err) => failures.push(VerificationFailure::new(VerificationCheck::Signature, Verifier::_failure_reason(&err)))
+            }
+        }
+
+        let res = ProofVerificationReport {
+            valid: referents.values().all(|referent| referent.valid) && failures.is_empty(),
+            referents,
+            failures
+        };
+
+        trace!("verify_detailed <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _check_referent_credential(full_proof: &Proof,
+                                  proof_req: &ProofRequestPayload,
+                                  referent: &str,
+                                  name: &str,
+                                  restrictions: &Option<Query>,
+                                  non_revoked: &Option<NonRevocedInterval>,
+                                  sub_proof_index: u32,
+                                  revealed_value: Option<&str>,
+                                  schemas: &HashMap<SchemaId, SchemaV1>,
+                                  cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                                  rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                                  rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+                                  failures: &mut Vec<VerificationFailure>) {
+        let identifier = match Verifier::_get_proof_identifier(full_proof, sub_proof_index) {
+            Ok(identifier) => identifier,
+            Err(err) => return failures.push(VerificationFailure::new(VerificationCheck::Structure, Verifier::_failure_reason(&err)))
+        };
+
+        if !schemas.contains_key(&identifier.schema_id) {
+            failures.push(VerificationFailure::new(VerificationCheck::Structure,
+                                                   format!("Schema not found for id: {:?}", identifier.schema_id.0)));
+        }
+
+        if !cred_defs.contains_key(&identifier.cred_def_id) {
+            failures.push(VerificationFailure::new(VerificationCheck::Structure,
+                                                   format!("CredentialDefinition not found for id: {:?}", identifier.cred_def_id.0)));
+        }
+
+        if let Some(ref query) = restrictions {
+            let mut identifiers: HashMap<String, Identifier> = HashMap::new();
+            identifiers.insert(referent.to_string(), identifier.clone());
+
+            if let Err(err) = Verifier::_gather_filter_info(referent, &identifiers)
+                .and_then(|filter| Verifier::_process_operator(name, query, &filter, revealed_value)) {
+                failures.push(VerificationFailure::new(VerificationCheck::Restrictions, Verifier::_failure_reason(&err)));
+            }
+        }
+
+        if let (Some(interval), None) = (get_non_revoc_interval(&proof_req.non_revoked, non_revoked), identifier.timestamp) {
+            failures.push(VerificationFailure::new(VerificationCheck::Timestamp,
+                                                   format!("Non-revocation is requested for interval {:?}, but the proof contains no timestamp for the credential", interval)));
+        }
+
+        if let Some(timestamp) = identifier.timestamp {
+            match identifier.rev_reg_id {
+                Some(ref rev_reg_id) => {
+                    if !rev_reg_defs.contains_key(rev_reg_id) {
+                        failures.push(VerificationFailure::new(VerificationCheck::Revocation,
+                                                               format!("RevocationRegistryDefinition not found for id: {:?}", rev_reg_id.0)));
+                    }
+
+                    if !rev_regs.get(rev_reg_id).map(|rev_regs| rev_regs.contains_key(&timestamp)).unwrap_or(false) {
+                        failures.push(VerificationFailure::new(VerificationCheck::Revocation,
+                                                               format!("RevocationRegistry not found for id: {:?} and timestamp: {}", rev_reg_id.0, timestamp)));
+                    }
+                }
+                None => {
+                    failures.push(VerificationFailure::new(VerificationCheck::Revocation,
+                                                           format!("Proof contains timestamp {} for the credential, but no Revocation Registry Id", timestamp)));
+                }
+            }
+        }
+    }
+
+    fn _add_referent_report(referents: &mut HashMap<String, ReferentVerificationReport>, referent: &str, report: ReferentVerificationReport) {
+        match referents.get_mut(referent) {
+            Some(existing) => {
+                existing.valid = existing.valid && report.valid;
+                existing.failures.extend(report.failures);
+            }
+            None => {
+                referents.insert(referent.to_string(), report);
+            }
+        }
+    }
+
+    // Non-revocation proofs are bound into the aggregated CL proof and can't be verified apart from it,
+    // so a referent is blamed only if its revocation data can't match the non-revocation proof on its own.
+    fn _add_non_revocation_failures(full_proof: &Proof,
+                                    cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                                    rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                                    referents: &mut HashMap<String, ReferentVerificationReport>) {
+        for referent in referents.values_mut() {
+            let reason = match referent.sub_proof_index {
+                Some(sub_proof_index) => Verifier::_check_non_revocation_data(full_proof, sub_proof_index, cred_defs, rev_reg_defs),
+                None => None
+            };
+
+            if let Some(reason) = reason {
+                referent.valid = false;
+                referent.failures.push(VerificationFailure::new(VerificationCheck::NonRevocation, reason));
+            }
+        }
+    }
+
+    fn _check_non_revocation_data(full_proof: &Proof,
+                                  sub_proof_index: u32,
+                                  cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                                  rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>) -> Option<String> {
+        let identifier = Verifier::_get_proof_identifier(full_proof, sub_proof_index).ok()?;
+
+        if identifier.timestamp.is_none() {
+            return None;
+        }
+
+        let rev_reg_id = identifier.rev_reg_id.as_ref()?;
+
+        if let Some(cred_def) = cred_defs.get(&identifier.cred_def_id) {
+            if cred_def.value.revocation.is_none() {
+                return Some(format!("CredentialDefinition {:?} doesn't contain revocation public key", identifier.cred_def_id.0));
+            }
+        }
+
+        if let Some(rev_reg_def) = rev_reg_defs.get(rev_reg_id) {
+            if rev_reg_def.cred_def_id.to_unqualified() != identifier.cred_def_id.to_unqualified() {
+                return Some(format!("RevocationRegistryDefinition {:?} belongs to CredentialDefinition {:?} but not to {:?}",
+                                    rev_reg_id.0, rev_reg_def.cred_def_id.0, identifier.cred_def_id.0));
+            }
+        }
+
+        None
+    }
+
+    fn _failure_reason(err: &IndyError) -> String {
+        err.to_string()
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    fn _verify_crypto_proof(full_proof: &Proof,
+                            proof_req: &ProofRequestPayload,
+                            schemas: &HashMap<SchemaId, SchemaV1>,
+                            cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                            rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                            rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
         let mut proof_verifier = CryptoVerifier::new_proof_verifier()?;
         let non_credential_schema = build_non_credential_schema()?;
 
@@ -118,8 +405,6 @@ impl Verifier {
 
         let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?;
 
-        trace!("verify <<< valid: {:?}", valid);
-
         Ok(valid)
     }
 
@@ -133,6 +418,234 @@ impl Verifier {
         Ok(nonce)
     }
 
+    pub fn validate_proof_request(&self, proof_req_json: &str) -> ProofRequestValidationReport {
+        trace!("validate_proof_request >>> proof_req_json: {:?}", proof_req_json);
+
+        let mut errors: Vec<ProofRequestIssue> = Vec::new();
+
+        Verifier::_check_proof_request(proof_req_json, &mut errors);
+
+        if errors.is_empty() {
+            // The request must be also accepted by `indy_verifier_verify_proof` and prover functions as is
+            if let Err(err) = ::serde_json::from_str::<ProofRequest>(proof_req_json)
+                .map_err(|err| err.to_string())
+                .and_then(|proof_req| proof_req.validate()) {
+                errors.push(ProofRequestIssue::new(None, err));
+            }
+        }
+
+        let res = ProofRequestValidationReport {
+            valid: errors.is_empty(),
+            errors
+        };
+
+        trace!("validate_proof_request <<< res: {:?}", res);
+
+        res
+    }
+
+    fn _check_proof_request(proof_req_json: &str, errors: &mut Vec<ProofRequestIssue>) {
+        let proof_req: Value = match ::serde_json::from_str(proof_req_json) {
+            Ok(proof_req) => proof_req,
+            Err(err) => return errors.push(ProofRequestIssue::new(None, format!("Proof Request is not a valid JSON: {}", err)))
+        };
+
+        let proof_req = match proof_req.as_object() {
+            Some(proof_req) => proof_req,
+            None => return errors.push(ProofRequestIssue::new(None, "Proof Request must be a JSON object".to_string()))
+        };
+
+        let version = match proof_req.get("ver") {
+            None | Some(Value::Null) => Some(ProofRequestsVersion::V1),
+            Some(Value::String(ref ver)) if ver == "1.0" => Some(ProofRequestsVersion::V1),
+            Some(Value::String(ref ver)) if ver == "2.0" => Some(ProofRequestsVersion::V2),
+            Some(ver) => {
+                errors.push(ProofRequestIssue::new(None, format!("Unsupported `ver` value: {}. Expected \"1.0\" or \"2.0\"", ver)));
+                None
+            }
+        };
+
+        match proof_req.get("nonce") {
+            Some(nonce) => {
+                if ::serde_json::from_value::<Nonce>(nonce.clone()).is_err() {
+                    errors.push(ProofRequestIssue::new(None, format!("`nonce` must be a decimal number string (see `indy_generate_nonce`), got: {}", nonce)));
+                }
+            }
+            None => errors.push(ProofRequestIssue::new(None, "`nonce` is missing".to_string()))
+        }
+
+        for field in &["name", "version"] {
+            match proof_req.get(*field) {
+                Some(Value::String(_)) => {}
+                Some(_) => errors.push(ProofRequestIssue::new(None, format!("`{}` must be a string", field))),
+                None => errors.push(ProofRequestIssue::new(None, format!("`{}` is missing", field)))
+            }
+        }
+
+        match proof_req.get("non_revoked") {
+            None | Some(Value::Null) => {}
+            Some(interval) => Verifier::_check_non_revoked_interval(None, interval, errors)
+        }
+
+        let mut sections_valid = true;
+        for field in &["requested_attributes", "requested_predicates"] {
+            match proof_req.get(*field) {
+                Some(Value::Object(_)) => {}
+                Some(_) => {
+                    errors.push(ProofRequestIssue::new(None, format!("`{}` must be a JSON object", field)));
+                    sections_valid = false;
+                }
+                None => {
+                    errors.push(ProofRequestIssue::new(None, format!("`{}` is missing", field)));
+                    sections_valid = false;
+                }
+            }
+        }
+
+        if !sections_valid {
+            return;
+        }
+
+        let referents: ProofRequestReferents = match ::serde_json::from_str(proof_req_json) {
+            Ok(referents) => referents,
+            Err(err) => return errors.push(ProofRequestIssue::new(None, format!("Cannot parse requested items: {}", err)))
+        };
+
+        let requested_attributes = referents.requested_attributes.map(|items| items.0).unwrap_or_default();
+        let requested_predicates = referents.requested_predicates.map(|items| items.0).unwrap_or_default();
+
+        if requested_attributes.is_empty() && requested_predicates.is_empty() {
+            errors.push(ProofRequestIssue::new(None, "both `requested_attributes` and `requested_predicates` are empty".to_string()));
+        }
+
+        let mut attr_referents: HashSet<&str> = HashSet::new();
+
+        for (referent, attr) in requested_attributes.iter() {
+            if !attr_referents.insert(referent) {
+                errors.push(ProofRequestIssue::new(Some(referent), "Referent is used more than once in `requested_attributes`".to_string()));
+            }
+
+            match ::serde_json::from_value::<AttributeInfo>(attr.clone()) {
+                Ok(info) => Verifier::_check_requested_item(referent, &info.name, &info.restrictions, &info.non_revoked, &version, errors),
+                Err(err) => errors.push(ProofRequestIssue::new(Some(referent), format!("Invalid requested attribute: {}", err)))
+            }
+        }
+
+        let mut predicate_referents: HashSet<&str> = HashSet::new();
+
+        for (referent, predicate) in requested_predicates.iter() {
+            if !predicate_referents.insert(referent) {
+                errors.push(ProofRequestIssue::new(Some(referent), "Referent is used more than once in `requested_predicates`".to_string()));
+            }
+
+            if attr_referents.contains(referent.as_str()) {
+                errors.push(ProofRequestIssue::new(Some(referent), "Referent is used in both `requested_attributes` and `requested_predicates`".to_string()));
+            }
+
+            if let Some(p_type) = predicate.get("p_type") {
+                if ::serde_json::from_value::<PredicateTypes>(p_type.clone()).is_err() {
+                    errors.push(ProofRequestIssue::new(Some(referent), format!("Unknown predicate type: {}. Expected one of \">=\", \"<=\", \">\", \"<\"", p_type)));
+                    continue;
+                }
+            }
+
+            if let Some(p_value) = predicate.get("p_value") {
+                if p_value.as_i64().map(|p_value| p_value < i32::min_value() as i64 || p_value > i32::max_value() as i64).unwrap_or(true) {
+                    errors.push(ProofRequestIssue::new(Some(referent), format!("`p_value` must be a 32-bit integer, got: {}", p_value)));
+                    continue;
+                }
+            }
+
+            match ::serde_json::from_value::<PredicateInfo>(predicate.clone()) {
+                Ok(info) => Verifier::_check_requested_item(referent, &info.name, &info.restrictions, &info.non_revoked, &version, errors),
+                Err(err) => errors.push(ProofRequestIssue::new(Some(referent), format!("Invalid requested predicate: {}", err)))
+            }
+        }
+    }
+
+    fn _check_requested_item(referent: &str,
+                             name: &str,
+                             restrictions: &Option<Query>,
+                             non_revoked: &Option<NonRevocedInterval>,
+                             version: &Option<ProofRequestsVersion>,
+                             errors: &mut Vec<ProofRequestIssue>) {
+        if name.is_empty() {
+            errors.push(ProofRequestIssue::new(Some(referent), "`name` must not be empty".to_string()));
+        }
+
+        if let Some(ref restrictions) = restrictions {
+            if let Some(ref version) = version {
+                if let Err(err) = validate_restrictions(restrictions, version) {
+                    errors.push(ProofRequestIssue::new(Some(referent), err));
+                }
+            }
+
+            for err in Verifier::_check_restriction_operators(name, restrictions) {
+                errors.push(ProofRequestIssue::new(Some(referent), err));
+            }
+        }
+
+        if let Some(ref interval) = non_revoked {
+            if let Err(err) = Verifier::_check_interval_bounds(interval) {
+                errors.push(ProofRequestIssue::new(Some(referent), err));
+            }
+        }
+    }
+
+    fn _check_non_revoked_interval(referent: Option<&str>, interval: &Value, errors: &mut Vec<ProofRequestIssue>) {
+        match ::serde_json::from_value::<NonRevocedInterval>(interval.clone()) {
+            Ok(interval) => {
+                if let Err(err) = Verifier::_check_interval_bounds(&interval) {
+                    errors.push(ProofRequestIssue::new(referent, err));
+                }
+            }
+            Err(err) => errors.push(ProofRequestIssue::new(referent, format!("Invalid `non_revoked` interval: {}", err)))
+        }
+    }
+
+    // Empty interval is accepted as no interval, the same way prover and verifier treat it.
+    fn _check_interval_bounds(interval: &NonRevocedInterval) -> Result<(), String> {
+        match (interval.from, interval.to) {
+            (Some(from), Some(to)) if from > to =>
+                Err(format!("Invalid `non_revoked` interval: `from` ({}) is greater than `to` ({})", from, to)),
+            _ => Ok(())
+        }
+    }
+
+    fn _check_restriction_operators(attr: &str, restriction_op: &Query) -> Vec<String> {
+        match restriction_op {
+            Query::Eq(ref tag_name, _) |
+            Query::Neq(ref tag_name, _) |
+            Query::In(ref tag_name, _) => {
+                Verifier::_check_restriction_tag(attr, tag_name).into_iter().collect()
+            }
+            Query::Gt(ref tag_name, _) |
+            Query::Gte(ref tag_name, _) |
+            Query::Lt(ref tag_name, _) |
+            Query::Lte(ref tag_name, _) |
+            Query::Like(ref tag_name, _) => {
+                vec![format!("Unsupported operator for tag \"{}\": only $eq, $neq, $in, $and, $or and $not can be used in restrictions", tag_name)]
+            }
+            Query::And(ref operators) | Query::Or(ref operators) => {
+                operators
+                    .iter()
+                    .flat_map(|operator| Verifier::_check_restriction_operators(attr, operator))
+                    .collect()
+            }
+            Query::Not(ref operator) => {
+                Verifier::_check_restriction_operators(attr, operator)
+            }
+        }
+    }
+
+    fn _check_restriction_tag(attr: &str, tag: &str) -> Option<String> {
+        match tag {
+            "schema_id" | "schema_issuer_did" | "schema_name" | "schema_version" | "cred_def_id" | "issuer_did" => None,
+            x if Verifier::_is_attr_internal_tag(x, attr) || Verifier::_is_attr_operator(x) => None,
+            x => Some(format!("Unknown restriction tag \"{}\"", x))
+        }
+    }
+
     fn _get_revealed_attributes_for_credential(sub_proof_index: usize,
                                                requested_proof: &RequestedProof,
                                                proof_req: &ProofRequestPayload) -> IndyResult<Vec<AttributeInfo>> {
@@ -303,28 +816,50 @@ impl Verifier {
     fn _verify_revealed_attribute_values(proof_req: &ProofRequestPayload,
                                          proof: &Proof) -> IndyResult<()> {
         for (attr_referent, attr_info) in proof.requested_proof.revealed_attrs.iter() {
-            let reveal_attr_encoded = attr_info.encoded.to_string();
-            let sub_proof_index = attr_info.sub_proof_index as usize;
-
-            let attr_name = proof_req.requested_attributes.get(attr_referent.as_str())
-                .as_ref()
-                .map(|attr_info| attr_info.name.as_str())
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
-
-            let crypto_proof_encoded = proof.proof.proofs
-                .get(sub_proof_index)
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("CryptoProof not found by index \"{}\"", sub_proof_index)))?
-                .revealed_attrs()?
-                .iter()
-                .find(|(key, _)| attr_common_view(&attr_name) == attr_common_view(&key))
-                .map(|(_, val)| val.to_string())
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with name \"{}\" not found in CryptoProof", attr_name)))?;
-
-            if reveal_attr_encoded != crypto_proof_encoded {
-                return Err(IndyError::from_msg(IndyErrorKind::ProofRejected,
-                                               format!("Encoded Values for \"{}\" are different in RequestedProof \"{}\" and CryptoProof \"{}\"", attr_name, reveal_attr_encoded, crypto_proof_encoded)));
-            }
+            Verifier::_verify_revealed_attribute_value(proof_req, proof, attr_referent, attr_info)?;
+        }
+        Ok(())
+    }
+
+    fn _verify_revealed_attribute_value(proof_req: &ProofRequestPayload,
+                                        proof: &Proof,
+                                        attr_referent: &str,
+                                        attr_info: &RevealedAttributeInfo) -> IndyResult<()> {
+        let reveal_attr_encoded = attr_info.encoded.to_string();
+        let sub_proof_index = attr_info.sub_proof_index as usize;
+
+        let attr_name = proof_req.requested_attributes.get(attr_referent)
+            .as_ref()
+            .map(|attr_info| attr_info.name.as_str())
+            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
+
+        let crypto_proof_encoded = proof.proof.proofs
+            .get(sub_proof_index)
+            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("CryptoProof not found by index \"{}\"", sub_proof_index)))?
+            .revealed_attrs()?
+            .iter()
+            .find(|(key, _)| attr_common_view(&attr_name) == attr_common_view(&key))
+            .map(|(_, val)| val.to_string())
+            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with name \"{}\" not found in CryptoProof", attr_name)))?;
+
+        if reveal_attr_encoded != crypto_proof_encoded {
+            return Err(IndyError::from_msg(IndyErrorKind::ProofRejected,
+                                           format!("Encoded Values for \"{}\" are different in RequestedProof \"{}\" and CryptoProof \"{}\"", attr_name, reveal_attr_encoded, crypto_proof_encoded)));
+        }
+
+        Ok(())
+    }
+
+    fn _verify_revealed_attribute_encoding(attr_referent: &str,
+                                           attr_info: &RevealedAttributeInfo) -> IndyResult<()> {
+        let expected_encoded = encode_attribute_value(&attr_info.raw)?;
+
+        if expected_encoded != attr_info.encoded {
+            return Err(IndyError::from_msg(IndyErrorKind::ProofRejected,
+                                           format!("Encoded value \"{}\" of revealed attribute with referent \"{}\" doesn't correspond to raw value \"{}\"",
+                                                   attr_info.encoded, attr_referent, attr_info.raw)));
         }
+
         Ok(())
     }
 
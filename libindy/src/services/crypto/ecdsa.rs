@@ -0,0 +1,96 @@
+use indy_api_types::errors::prelude::*;
+use sha2::{Digest, Sha256};
+
+use super::CryptoType;
+use crate::utils::crypto::ecdsa;
+use crate::utils::crypto::ed25519_box;
+use crate::utils::crypto::ed25519_sign;
+use crate::utils::crypto::xsalsa20;
+
+// ECDSA signatures over secp256k1 or NIST P-256 curve.
+// Box operations use ECDH over the same curve to derive xsalsa20poly1305 key.
+pub struct ECDSACryptoType {
+    curve: ecdsa::Curve
+}
+
+impl ECDSACryptoType {
+    pub fn new(curve: ecdsa::Curve) -> ECDSACryptoType {
+        ECDSACryptoType {
+            curve
+        }
+    }
+
+    fn derive_box_key(&self, sk: &[u8], vk: &[u8], context: &[&[u8]]) -> IndyResult<xsalsa20::Key> {
+        let shared_secret = ecdsa::ecdh(self.curve, sk, vk)?;
+
+        let mut hasher = Sha256::new();
+        hasher.input(&shared_secret);
+        for item in context {
+            hasher.input(item);
+        }
+
+        xsalsa20::Key::from_slice(&hasher.result())
+    }
+}
+
+impl CryptoType for ECDSACryptoType {
+    fn crypto_box(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>> {
+        let key = self.derive_box_key(sk, vk, &[])?;
+        let nonce = xsalsa20::Nonce::from_slice(&nonce[..])?;
+        Ok(xsalsa20::encrypt(&key, &nonce, doc))
+    }
+
+    fn crypto_box_open(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>> {
+        let key = self.derive_box_key(sk, vk, &[])?;
+        let nonce = xsalsa20::Nonce::from_slice(&nonce[..])?;
+        xsalsa20::decrypt(&key, &nonce, doc)
+    }
+
+    fn gen_nonce(&self) -> ed25519_box::Nonce {
+        ed25519_box::gen_nonce()
+    }
+
+    fn create_key(&self, seed: Option<&ed25519_sign::Seed>) -> IndyResult<(Vec<u8>, Vec<u8>)> {
+        ecdsa::create_key_pair(self.curve, seed.map(|seed| &seed[..]))
+    }
+
+    fn validate_key(&self, vk: &[u8]) -> IndyResult<()> {
+        ecdsa::validate_public_key(self.curve, vk)
+    }
+
+    fn sign(&self, sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>> {
+        ecdsa::sign(self.curve, sk, doc)
+    }
+
+    fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> IndyResult<bool> {
+        ecdsa::verify(self.curve, vk, doc, signature)
+    }
+
+    // Sealed box is ephemeral_vk || nonce || ciphertext
+    fn crypto_box_seal(&self, vk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>> {
+        let (ephemeral_vk, ephemeral_sk) = ecdsa::create_key_pair(self.curve, None)?;
+
+        let key = self.derive_box_key(&ephemeral_sk, vk, &[&ephemeral_vk, vk])?;
+        let nonce = xsalsa20::gen_nonce();
+
+        let mut res = ephemeral_vk;
+        res.extend_from_slice(&nonce[..]);
+        res.extend(xsalsa20::encrypt(&key, &nonce, doc));
+
+        Ok(res)
+    }
+
+    fn crypto_box_seal_open(&self, vk: &[u8], sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>> {
+        if doc.len() < ecdsa::PUBLICKEYBYTES + xsalsa20::NONCEBYTES {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Sealed message is too short"));
+        }
+
+        let (ephemeral_vk, doc) = doc.split_at(ecdsa::PUBLICKEYBYTES);
+        let (nonce, doc) = doc.split_at(xsalsa20::NONCEBYTES);
+
+        let key = self.derive_box_key(sk, ephemeral_vk, &[ephemeral_vk, vk])?;
+        let nonce = xsalsa20::Nonce::from_slice(nonce)?;
+
+        xsalsa20::decrypt(&key, &nonce, doc)
+    }
+}
@@ -0,0 +1,121 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::slice;
+
+use indy_api_types::ErrorCode;
+use indy_api_types::errors::prelude::*;
+use indy_api_types::signer::*;
+use libc::{c_char, c_void};
+
+pub struct ExternalSigner {
+    create_key_handler: SignerCreateKey,
+    sign_handler: SignerSign,
+    crypto_box_handler: SignerCryptoBox,
+    free_handler: SignerFree,
+}
+
+impl ExternalSigner {
+    pub fn new(create_key_handler: SignerCreateKey,
+               sign_handler: SignerSign,
+               crypto_box_handler: SignerCryptoBox,
+               free_handler: SignerFree) -> ExternalSigner {
+        ExternalSigner {
+            create_key_handler,
+            sign_handler,
+            crypto_box_handler,
+            free_handler,
+        }
+    }
+
+    // Returns key reference and base58 encoded verkey
+    pub fn create_key(&self, crypto_type: &str, config: &str) -> IndyResult<(String, String)> {
+        let crypto_type = CString::new(crypto_type)?;
+        let config = CString::new(config)?;
+
+        let mut key_ref_ptr: *const c_char = ptr::null();
+        let mut verkey_ptr: *const c_char = ptr::null();
+
+        let err = (self.create_key_handler)(crypto_type.as_ptr(),
+                                            config.as_ptr(),
+                                            &mut key_ref_ptr,
+                                            &mut verkey_ptr);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        let key_ref = self._take_string(key_ref_ptr);
+        let verkey = self._take_string(verkey_ptr);
+
+        Ok((key_ref?, verkey?))
+    }
+
+    pub fn sign(&self, key_ref: &str, doc: &[u8]) -> IndyResult<Vec<u8>> {
+        let key_ref = CString::new(key_ref)?;
+
+        let mut signature_ptr: *const u8 = ptr::null();
+        let mut signature_len: usize = 0;
+
+        let err = (self.sign_handler)(key_ref.as_ptr(),
+                                      doc.as_ptr(),
+                                      doc.len(),
+                                      &mut signature_ptr,
+                                      &mut signature_len);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        self._take_bytes(signature_ptr, signature_len)
+    }
+
+    pub fn crypto_box(&self, key_ref: &str, their_vk: &str, doc: &[u8], nonce: &[u8]) -> IndyResult<Vec<u8>> {
+        let key_ref = CString::new(key_ref)?;
+        let their_vk = CString::new(their_vk)?;
+
+        let mut encrypted_ptr: *const u8 = ptr::null();
+        let mut encrypted_len: usize = 0;
+
+        let err = (self.crypto_box_handler)(key_ref.as_ptr(),
+                                            their_vk.as_ptr(),
+                                            doc.as_ptr(),
+                                            doc.len(),
+                                            nonce.as_ptr(),
+                                            nonce.len(),
+                                            &mut encrypted_ptr,
+                                            &mut encrypted_len);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        self._take_bytes(encrypted_ptr, encrypted_len)
+    }
+
+    fn _take_string(&self, ptr: *const c_char) -> IndyResult<String> {
+        if ptr.is_null() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "External signer returned null pointer"));
+        }
+
+        let res = unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .map(str::to_owned)
+            .to_indy(IndyErrorKind::InvalidState, "External signer returned non-utf8 string");
+
+        (self.free_handler)(ptr as *const c_void);
+
+        res
+    }
+
+    fn _take_bytes(&self, ptr: *const u8, len: usize) -> IndyResult<Vec<u8>> {
+        if ptr.is_null() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "External signer returned null pointer"));
+        }
+
+        let res = unsafe { slice::from_raw_parts(ptr, len) }.to_vec();
+
+        (self.free_handler)(ptr as *const c_void);
+
+        Ok(res)
+    }
+}
@@ -1,55 +1,90 @@
 extern crate hex;
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::str;
 
 use crate::domain::crypto::combo_box::ComboBox;
 use crate::domain::crypto::did::{Did, DidValue, MyDidInfo, TheirDid, TheirDidInfo};
+use crate::domain::crypto::did_doc::{ED25519_VERIFICATION_KEY_2018, SECP256K1_VERIFICATION_KEY_2019, SECP256R1_VERIFICATION_KEY_2019};
+use crate::domain::crypto::did_key;
 use crate::domain::crypto::key::{Key, KeyInfo};
+use crate::domain::crypto::pack::*;
 use indy_api_types::errors::prelude::*;
 use crate::utils::crypto::base64;
 use crate::utils::crypto::ed25519_box;
 use crate::utils::crypto::chacha20poly1305_ietf;
 use crate::utils::crypto::chacha20poly1305_ietf::gen_nonce_and_encrypt_detached;
 use crate::utils::crypto::ed25519_sign;
+use crate::utils::crypto::aes;
+use crate::utils::crypto::xchacha20poly1305_ietf;
 use crate::utils::crypto::verkey_builder::{build_full_verkey, split_verkey, verkey_get_cryptoname};
+use crate::utils::timestamp::timestamp_tag_value;
 
+use crate::utils::crypto::ecdsa::Curve;
+use self::ecdsa::ECDSACryptoType;
 use self::ed25519::ED25519CryptoType;
+pub use self::external::ExternalSigner;
 use self::hex::FromHex;
 use rust_base58::{FromBase58, ToBase58};
+use sha2::{Digest, Sha256};
 
+mod ecdsa;
 mod ed25519;
+mod external;
 
 pub const DEFAULT_CRYPTO_TYPE: &str = "ed25519";
+pub const SECP256K1_CRYPTO_TYPE: &str = "secp256k1";
+pub const P256_CRYPTO_TYPE: &str = "p256";
 
-//TODO fix this crypto trait so it matches the functions below
 //TODO create a second crypto trait for additional functions
+// Keys and signatures are passed as raw bytes as their sizes depend on crypto type
 trait CryptoType {
-    fn crypto_box(&self, sk: &ed25519_sign::SecretKey, vk: &ed25519_sign::PublicKey, doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>>;
-    fn crypto_box_open(&self, sk: &ed25519_sign::SecretKey, vk: &ed25519_sign::PublicKey, doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>>;
+    fn crypto_box(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>>;
+    fn crypto_box_open(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &ed25519_box::Nonce) -> IndyResult<Vec<u8>>;
     fn gen_nonce(&self) -> ed25519_box::Nonce;
-    fn create_key(&self, seed: Option<&ed25519_sign::Seed>) -> IndyResult<(ed25519_sign::PublicKey, ed25519_sign::SecretKey)>;
-    fn validate_key(&self, _vk: &ed25519_sign::PublicKey) -> IndyResult<()>;
-    fn sign(&self, sk: &ed25519_sign::SecretKey, doc: &[u8]) -> IndyResult<ed25519_sign::Signature>;
-    fn verify(&self, vk: &ed25519_sign::PublicKey, doc: &[u8], signature: &ed25519_sign::Signature) -> IndyResult<bool>;
-    fn crypto_box_seal(&self, vk: &ed25519_sign::PublicKey, doc: &[u8]) -> IndyResult<Vec<u8>>;
-    fn crypto_box_seal_open(&self, vk: &ed25519_sign::PublicKey, sk: &ed25519_sign::SecretKey, doc: &[u8]) -> IndyResult<Vec<u8>>;
+    fn create_key(&self, seed: Option<&ed25519_sign::Seed>) -> IndyResult<(Vec<u8>, Vec<u8>)>;
+    fn validate_key(&self, vk: &[u8]) -> IndyResult<()>;
+    fn sign(&self, sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>>;
+    fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> IndyResult<bool>;
+    fn crypto_box_seal(&self, vk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>>;
+    fn crypto_box_seal_open(&self, vk: &[u8], sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>>;
 }
 
 pub struct CryptoService {
-    crypto_types: HashMap<&'static str, Box<dyn CryptoType>>
+    crypto_types: HashMap<&'static str, Box<dyn CryptoType>>,
+    external_signers: RefCell<HashMap<String, ExternalSigner>>,
 }
 
 impl CryptoService {
     pub fn new() -> CryptoService {
         let mut crypto_types: HashMap<&str, Box<dyn CryptoType>> = HashMap::new();
         crypto_types.insert(DEFAULT_CRYPTO_TYPE, Box::new(ED25519CryptoType::new()));
+        crypto_types.insert(SECP256K1_CRYPTO_TYPE, Box::new(ECDSACryptoType::new(Curve::Secp256k1)));
+        crypto_types.insert(P256_CRYPTO_TYPE, Box::new(ECDSACryptoType::new(Curve::P256)));
 
         CryptoService {
-            crypto_types
+            crypto_types,
+            external_signers: RefCell::new(HashMap::new()),
         }
     }
 
+    pub fn register_external_signer(&self, signer_name: &str, signer: ExternalSigner) -> IndyResult<()> {
+        trace!("register_external_signer >>> signer_name: {:?}", signer_name);
+
+        let mut external_signers = self.external_signers.borrow_mut();
+
+        if external_signers.contains_key(signer_name) {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("External signer already registered: {}", signer_name)));
+        }
+
+        external_signers.insert(signer_name.to_string(), signer);
+
+        trace!("register_external_signer <<<");
+
+        Ok(())
+    }
+
     pub fn create_key(&self, key_info: &KeyInfo) -> IndyResult<Key> {
         trace!("create_key >>> key_info: {:?}", secret!(key_info));
 
@@ -62,6 +97,10 @@ impl CryptoService {
             return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("KeyInfo contains unknown crypto: {}", crypto_type_name)));
         }
 
+        if let Some(ref signer_name) = key_info.signer {
+            return self._create_external_key(signer_name, crypto_type_name, key_info);
+        }
+
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
         let seed = self.convert_seed(key_info.seed.as_ref().map(String::as_ref))?;
@@ -80,6 +119,36 @@ impl CryptoService {
         Ok(key)
     }
 
+    fn _create_external_key(&self, signer_name: &str, crypto_type_name: &str, key_info: &KeyInfo) -> IndyResult<Key> {
+        if key_info.seed.is_some() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Seed can't be used for keys created by external signer"));
+        }
+
+        let external_signers = self.external_signers.borrow();
+        let signer = self._get_external_signer(&external_signers, signer_name)?;
+
+        let config = key_info.signer_config
+            .as_ref()
+            .map(|config| config.to_string())
+            .unwrap_or_else(|| "{}".to_string());
+
+        let (key_ref, vk) = signer.create_key(crypto_type_name, &config)?;
+
+        let vk = if crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
+            vk
+        } else {
+            format!("{}:{}", vk, crypto_type_name)
+        };
+
+        self.validate_key(&vk)?;
+
+        let key = Key::new_external(vk, key_ref, signer_name.to_string());
+
+        trace!("create_key <<< key: {:?}", key);
+
+        Ok(key)
+    }
+
     pub fn create_my_did(&self, my_did_info: &MyDidInfo) -> IndyResult<(Did, Key)> {
         trace!("create_my_did >>> my_did_info: {:?}", secret!(my_did_info));
 
@@ -96,12 +165,15 @@ impl CryptoService {
 
         let seed = self.convert_seed(my_did_info.seed.as_ref().map(String::as_ref))?;
         let (vk, sk) = crypto_type.create_key(seed.as_ref())?;
+        let method = my_did_info.method_name.as_ref().map(|method| method.0.as_str());
         let did = match my_did_info.did {
             Some(ref did) => did.clone(),
+            _ if method == Some(did_key::DID_KEY_METHOD) || method == Some(did_key::DID_PEER_METHOD) =>
+                self._build_self_certifying_did(crypto_type_name, &vk[..], my_did_info)?,
             _ if my_did_info.cid == Some(true) =>
-                DidValue::new(&vk[..].to_vec().to_base58(), my_did_info.method_name.as_ref().map(|method| method.0.as_str())),
+                DidValue::new(&vk[..].to_vec().to_base58(), method),
             _ =>
-                DidValue::new(&vk[0..16].to_vec().to_base58(), my_did_info.method_name.as_ref().map(|method| method.0.as_str()))
+                DidValue::new(&vk[0..16].to_vec().to_base58(), method)
         };
 
         let mut vk = vk[..].to_base58();
@@ -119,14 +191,40 @@ impl CryptoService {
         Ok(did)
     }
 
+    fn _build_self_certifying_did(&self, crypto_type_name: &str, vk: &[u8], my_did_info: &MyDidInfo) -> IndyResult<DidValue> {
+        if crypto_type_name != DEFAULT_CRYPTO_TYPE {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("did:key and did:peer DIDs aren't supported for crypto type: {}", crypto_type_name)));
+        }
+
+        let is_peer = my_did_info.method_name.as_ref().map(|method| method.0 == did_key::DID_PEER_METHOD).unwrap_or(false);
+
+        let did = match my_did_info.peer_numalgo {
+            _ if !is_peer => did_key::build_did_key(vk),
+            Some(did_key::PEER_NUMALGO_MULTIPLE_KEYS) => {
+                let agreement_key = ed25519_sign::vk_to_curve25519(&ed25519_sign::PublicKey::from_slice(vk)?)?;
+                did_key::build_did_peer_2(vk, &agreement_key[..])
+            }
+            _ => did_key::build_did_peer_0(vk)
+        };
+
+        Ok(did)
+    }
+
     pub fn create_their_did(&self, their_did_info: &TheirDidInfo) -> IndyResult<TheirDid> {
         trace!("create_their_did >>> their_did_info: {:?}", their_did_info);
 
         // Check did is correct Base58
         let _ = self.validate_did(&their_did_info.did)?;
 
-        let verkey = build_full_verkey(&their_did_info.did.to_unqualified().0,
-                                       their_did_info.verkey.as_ref().map(String::as_str))?;
+        let verkey = match (did_key::resolve_verkey(&their_did_info.did)?, their_did_info.verkey.as_ref()) {
+            (Some(ref did_verkey), Some(verkey)) if did_verkey != split_verkey(verkey).0 =>
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Verkey doesn't match the key encoded in DID: {}", their_did_info.did.0))),
+            (Some(did_verkey), _) => did_verkey,
+            (None, verkey) => build_full_verkey(&their_did_info.did.to_unqualified().0,
+                                                verkey.map(String::as_str))?
+        };
 
         self.validate_key(&verkey)?;
 
@@ -146,10 +244,19 @@ impl CryptoService {
             return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Trying to sign message with unknown crypto: {}", crypto_type_name)));
         }
 
-        let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
+        let signature = match my_key.signer {
+            Some(ref signer_name) => {
+                let external_signers = self.external_signers.borrow();
+                self._get_external_signer(&external_signers, signer_name)?
+                    .sign(&my_key.signkey, doc)?
+            }
+            None => {
+                let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let my_sk = ed25519_sign::SecretKey::from_slice(&my_key.signkey.as_str().from_base58()?.as_slice())?;
-        let signature = crypto_type.sign(&my_sk, doc)?[..].to_vec();
+                let my_sk = my_key.signkey.as_str().from_base58()?;
+                crypto_type.sign(&my_sk, doc)?
+            }
+        };
 
         trace!("sign <<< signature: {:?}", signature);
 
@@ -167,10 +274,9 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let their_vk = ed25519_sign::PublicKey::from_slice(&their_vk.from_base58()?)?;
-        let signature = ed25519_sign::Signature::from_slice(&signature)?;
+        let their_vk = their_vk.from_base58()?;
 
-        let valid = crypto_type.verify(&their_vk, msg, &signature)?;
+        let valid = crypto_type.verify(&their_vk, msg, signature)?;
 
         trace!("verify <<< valid: {:?}", valid);
 
@@ -214,11 +320,21 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(&crypto_type_name).unwrap();
 
-        let my_sk = ed25519_sign::SecretKey::from_slice(my_key.signkey.as_str().from_base58()?.as_slice())?;
-        let their_vk = ed25519_sign::PublicKey::from_slice(their_vk.from_base58()?.as_slice())?;
         let nonce = crypto_type.gen_nonce();
 
-        let encrypted_doc = crypto_type.crypto_box(&my_sk, &their_vk, doc, &nonce)?;
+        let encrypted_doc = match my_key.signer {
+            Some(ref signer_name) => {
+                let external_signers = self.external_signers.borrow();
+                self._get_external_signer(&external_signers, signer_name)?
+                    .crypto_box(&my_key.signkey, their_vk, doc, &nonce[..])?
+            }
+            None => {
+                let my_sk = my_key.signkey.as_str().from_base58()?;
+                let their_vk = their_vk.from_base58()?;
+
+                crypto_type.crypto_box(&my_sk, &their_vk, doc, &nonce)?
+            }
+        };
         let nonce = nonce[..].to_vec();
 
         trace!("crypto_box <<< encrypted_doc: {:?}, nonce: {:?}", encrypted_doc, nonce);
@@ -246,10 +362,12 @@ impl CryptoService {
                                        their_crypto_type_name)));
         }
 
+        self._check_not_external(my_key)?;
+
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let my_sk = ed25519_sign::SecretKey::from_slice(&my_key.signkey.from_base58()?.as_slice())?;
-        let their_vk = ed25519_sign::PublicKey::from_slice(their_vk.from_base58()?.as_slice())?;
+        let my_sk = my_key.signkey.from_base58()?;
+        let their_vk = their_vk.from_base58()?;
         let nonce = ed25519_box::Nonce::from_slice(&nonce)?;
 
         let decrypted_doc = crypto_type.crypto_box_open(&my_sk, &their_vk, &doc, &nonce)?;
@@ -270,7 +388,7 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let their_vk = ed25519_sign::PublicKey::from_slice(their_vk.from_base58()?.as_slice())?;
+        let their_vk = their_vk.from_base58()?;
 
         let encrypted_doc = crypto_type.crypto_box_seal(&their_vk, doc)?;
 
@@ -289,10 +407,12 @@ impl CryptoService {
                                format!("Trying to crypto_box_open sealed message with unknown crypto: {}", crypto_type_name)));
         }
 
+        self._check_not_external(my_key)?;
+
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let my_vk = ed25519_sign::PublicKey::from_slice(my_vk.from_base58()?.as_slice())?;
-        let my_sk = ed25519_sign::SecretKey::from_slice(my_key.signkey.as_str().from_base58()?.as_slice())?;
+        let my_vk = my_vk.from_base58()?;
+        let my_sk = my_key.signkey.as_str().from_base58()?;
 
         let decrypted_doc = crypto_type.crypto_box_seal_open(&my_vk, &my_sk, doc)?;
 
@@ -301,6 +421,22 @@ impl CryptoService {
         Ok(decrypted_doc)
     }
 
+    fn _get_external_signer<'a>(&self, external_signers: &'a HashMap<String, ExternalSigner>, signer_name: &str) -> IndyResult<&'a ExternalSigner> {
+        external_signers
+            .get(signer_name)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownCrypto, format!("Unknown external signer: {}", signer_name)))
+    }
+
+    // Decryption isn't supported by external signer interface yet
+    fn _check_not_external(&self, my_key: &Key) -> IndyResult<()> {
+        match my_key.signer {
+            Some(ref signer_name) =>
+                Err(err_msg(IndyErrorKind::InvalidStructure,
+                            format!("Decryption isn't supported for keys of external signer: {}", signer_name))),
+            None => Ok(())
+        }
+    }
+
     pub fn convert_seed(&self, seed: Option<&str>) -> IndyResult<Option<ed25519_sign::Seed>> {
         trace!("convert_seed >>> seed: {:?}", secret!(seed));
 
@@ -356,8 +492,7 @@ impl CryptoService {
         if vk.starts_with('~') {
             let _ = vk[1..].from_base58()?; // TODO: proper validate abbreviated verkey
         } else {
-            let vk = ed25519_sign::PublicKey::from_slice(vk.from_base58()?.as_slice())?;
-            crypto_type.validate_key(&vk)?;
+            crypto_type.validate_key(&vk.from_base58()?)?;
         };
 
         trace!("validate_key <<<");
@@ -365,6 +500,29 @@ impl CryptoService {
         Ok(())
     }
 
+    pub fn build_key_tags(&self, key: &Key, created_at: u64) -> HashMap<String, String> {
+        trace!("build_key_tags >>> verkey: {:?}, created_at: {:?}", key.verkey, created_at);
+
+        let mut res: HashMap<String, String> = HashMap::new();
+
+        res.insert(Key::CREATED_AT_TAG.to_string(), timestamp_tag_value(created_at));
+        res.insert(Key::CRYPTO_TYPE_TAG.to_string(), verkey_get_cryptoname(&key.verkey).to_string());
+
+        trace!("build_key_tags <<< res: {:?}", res);
+
+        res
+    }
+
+    pub fn verification_method_type(&self, vk: &str) -> IndyResult<&'static str> {
+        match verkey_get_cryptoname(vk) {
+            DEFAULT_CRYPTO_TYPE => Ok(ED25519_VERIFICATION_KEY_2018),
+            SECP256K1_CRYPTO_TYPE => Ok(SECP256K1_VERIFICATION_KEY_2019),
+            P256_CRYPTO_TYPE => Ok(SECP256R1_VERIFICATION_KEY_2019),
+            crypto_type_name => Err(err_msg(IndyErrorKind::UnknownCrypto,
+                                            format!("DID Document doesn't support keys of crypto type: {}", crypto_type_name)))
+        }
+    }
+
     pub fn validate_did(&self, did: &DidValue) -> IndyResult<()> {
         trace!("validate_did >>> did: {:?}", did);
         // Useful method, huh?
@@ -440,12 +598,200 @@ impl CryptoService {
             err_msg(IndyErrorKind::InvalidStructure, format!("Failed to convert message to UTF-8 {}", err))
         })
     }
+
+    /* DIDComm v2 helper functions*/
+    pub fn didcomm_gen_cek(&self, enc: &str) -> IndyResult<Vec<u8>> {
+        match enc {
+            DIDCOMM_V2_ENC_A256CBC_HS512 => Ok(aes::gen_cbc_hs512_key()),
+            DIDCOMM_V2_ENC_A256GCM => Ok(aes::gen_key()),
+            DIDCOMM_V2_ENC_XC20P => Ok(xchacha20poly1305_ietf::gen_key()),
+            _ => Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Unsupported DIDComm content encryption: {}", enc)))
+        }
+    }
+
+    // Returns (ciphertext, iv, tag)
+    pub fn didcomm_encrypt_content(&self, enc: &str, plaintext: &[u8], aad: &str, cek: &[u8]) -> IndyResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+        match enc {
+            DIDCOMM_V2_ENC_A256CBC_HS512 => aes::cbc_hs512_gen_iv_and_encrypt_detached(plaintext, aad.as_bytes(), cek),
+            DIDCOMM_V2_ENC_A256GCM => aes::gen_nonce_and_encrypt_detached(plaintext, aad.as_bytes(), cek),
+            DIDCOMM_V2_ENC_XC20P => xchacha20poly1305_ietf::gen_nonce_and_encrypt_detached(plaintext, aad.as_bytes(), cek),
+            _ => Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Unsupported DIDComm content encryption: {}", enc)))
+        }
+    }
+
+    pub fn didcomm_decrypt_content(&self, enc: &str, ciphertext: &[u8], aad: &str, iv: &[u8], tag: &[u8], cek: &[u8]) -> IndyResult<Vec<u8>> {
+        match enc {
+            DIDCOMM_V2_ENC_A256CBC_HS512 => aes::cbc_hs512_decrypt_detached(ciphertext, cek, iv, tag, aad.as_bytes()),
+            DIDCOMM_V2_ENC_A256GCM => aes::decrypt_detached(ciphertext, cek, iv, tag, aad.as_bytes()),
+            DIDCOMM_V2_ENC_XC20P => xchacha20poly1305_ietf::decrypt_detached(ciphertext, cek, iv, tag, aad.as_bytes()),
+            _ => Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Unsupported DIDComm content encryption: {}", enc)))
+        }
+    }
+
+    pub fn didcomm_gen_ephemeral_key(&self) -> (EphemeralKey, ed25519_box::SecretKey) {
+        let (epk, epk_sk) = ed25519_box::create_key_pair();
+
+        let epk = EphemeralKey {
+            kty: EphemeralKey::KTY_OKP.to_string(),
+            crv: EphemeralKey::CRV_X25519.to_string(),
+            x: base64::encode_urlsafe_nopad(&epk[..]),
+        };
+
+        (epk, epk_sk)
+    }
+
+    // Agreement PartyVInfo: hash of sorted recipient key ids
+    pub fn didcomm_apv(&self, kids: &[String]) -> String {
+        let mut kids = kids.to_vec();
+        kids.sort();
+
+        base64::encode_urlsafe_nopad(&Sha256::digest(kids.join(".").as_bytes()))
+    }
+
+    // Wraps content encryption key for single recipient.
+    // Sender key is used for ECDH-1PU only, tag is bound to key encryption key for ECDH-1PU as well.
+    pub fn didcomm_wrap_cek(&self,
+                            protected: &DidCommProtected,
+                            epk_sk: &ed25519_box::SecretKey,
+                            my_key: Option<&Key>,
+                            their_vk: &str,
+                            tag: &[u8],
+                            cek: &[u8]) -> IndyResult<Vec<u8>> {
+        trace!("didcomm_wrap_cek >>> protected: {:?}, my_key: {:?}, their_vk: {:?}", protected, my_key, their_vk);
+
+        let their_pk = self._x25519_public_key(their_vk)?;
+
+        let mut z = ed25519_box::ecdh(epk_sk, &their_pk)?;
+
+        if protected.alg == DIDCOMM_V2_ALG_AUTH {
+            let my_key = my_key
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Sender key is required for ECDH-1PU"))?;
+
+            z.extend(ed25519_box::ecdh(&self._x25519_secret_key(my_key)?, &their_pk)?);
+        }
+
+        let kek = self._didcomm_kek(protected, &z, tag)?;
+        let res = aes::wrap_key(&kek, cek)?;
+
+        trace!("didcomm_wrap_cek <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn didcomm_unwrap_cek(&self,
+                              protected: &DidCommProtected,
+                              my_key: &Key,
+                              their_vk: Option<&str>,
+                              tag: &[u8],
+                              encrypted_key: &[u8]) -> IndyResult<Vec<u8>> {
+        trace!("didcomm_unwrap_cek >>> protected: {:?}, my_key: {:?}, their_vk: {:?}", protected, my_key, their_vk);
+
+        if protected.epk.kty != EphemeralKey::KTY_OKP || protected.epk.crv != EphemeralKey::CRV_X25519 {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("Unsupported DIDComm ephemeral key: {} {}", protected.epk.kty, protected.epk.crv)));
+        }
+
+        let epk = ed25519_box::PublicKey::from_slice(&base64::decode_urlsafe_nopad(&protected.epk.x)?)?;
+        let my_sk = self._x25519_secret_key(my_key)?;
+
+        let mut z = ed25519_box::ecdh(&my_sk, &epk)?;
+
+        if protected.alg == DIDCOMM_V2_ALG_AUTH {
+            let their_vk = their_vk
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Sender key is required for ECDH-1PU"))?;
+
+            z.extend(ed25519_box::ecdh(&my_sk, &self._x25519_public_key(their_vk)?)?);
+        }
+
+        let kek = self._didcomm_kek(protected, &z, tag)?;
+        let res = aes::unwrap_key(&kek, encrypted_key)?;
+
+        trace!("didcomm_unwrap_cek <<< res: {:?}", secret!(&res));
+
+        Ok(res)
+    }
+
+    fn _didcomm_kek(&self, protected: &DidCommProtected, z: &[u8], tag: &[u8]) -> IndyResult<Vec<u8>> {
+        let apu = match protected.apu {
+            Some(ref apu) => base64::decode_urlsafe_nopad(apu)?,
+            None => Vec::new()
+        };
+        let apv = base64::decode_urlsafe_nopad(&protected.apv)?;
+
+        // ECDH-1PU in Key Agreement with Key Wrapping mode also binds content tag
+        let tag = match protected.alg.as_str() {
+            DIDCOMM_V2_ALG_ANON => None,
+            DIDCOMM_V2_ALG_AUTH => Some(tag),
+            alg => return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Unsupported DIDComm key agreement: {}", alg)))
+        };
+
+        Ok(concat_kdf(z, protected.alg.as_bytes(), &apu, &apv, tag, aes::KEYBYTES))
+    }
+
+    // DIDComm v2 key agreement is defined over X25519 keys converted from ed25519 keys only
+    fn _x25519_public_key(&self, vk: &str) -> IndyResult<ed25519_box::PublicKey> {
+        let (vk, crypto_type_name) = split_verkey(vk);
+
+        if crypto_type_name != DEFAULT_CRYPTO_TYPE {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("DIDComm v2 encryption isn't supported for crypto type: {}", crypto_type_name)));
+        }
+
+        let vk = ed25519_sign::PublicKey::from_slice(&vk.from_base58()?)?;
+        ed25519_sign::vk_to_curve25519(&vk)
+    }
+
+    fn _x25519_secret_key(&self, my_key: &Key) -> IndyResult<ed25519_box::SecretKey> {
+        let crypto_type_name = verkey_get_cryptoname(&my_key.verkey);
+
+        if crypto_type_name != DEFAULT_CRYPTO_TYPE {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto,
+                               format!("DIDComm v2 encryption isn't supported for crypto type: {}", crypto_type_name)));
+        }
+
+        self._check_not_external(my_key)?;
+
+        let sk = ed25519_sign::SecretKey::from_slice(&my_key.signkey.from_base58()?)?;
+        ed25519_sign::sk_to_curve25519(&sk)
+    }
 }
 
+// Concat KDF (NIST SP 800-56A) with SHA-256 as used by JWA ECDH key agreement.
+// Optional tag is appended to SuppPubInfo as cctag of ECDH-1PU.
+fn concat_kdf(z: &[u8], alg: &[u8], apu: &[u8], apv: &[u8], tag: Option<&[u8]>, key_len: usize) -> Vec<u8> {
+    fn _len_prefixed(out: &mut Vec<u8>, value: &[u8]) {
+        out.extend_from_slice(&(value.len() as u32).to_be_bytes());
+        out.extend_from_slice(value);
+    }
+
+    let mut info = Vec::new();
+    _len_prefixed(&mut info, alg);
+    _len_prefixed(&mut info, apu);
+    _len_prefixed(&mut info, apv);
+    info.extend_from_slice(&((key_len * 8) as u32).to_be_bytes());
+    if let Some(tag) = tag {
+        _len_prefixed(&mut info, tag);
+    }
+
+    let mut res = Vec::with_capacity(key_len);
+    let mut counter: u32 = 1;
+
+    while res.len() < key_len {
+        let mut round = counter.to_be_bytes().to_vec();
+        round.extend_from_slice(z);
+        round.extend_from_slice(&info);
+
+        res.extend_from_slice(&Sha256::digest(&round));
+        counter += 1;
+    }
+
+    res.truncate(key_len);
+    res
+}
 
 #[cfg(test)]
 mod tests {
-    use crate::domain::crypto::did::MyDidInfo;
+    use crate::domain::crypto::did::{DidMethod, MyDidInfo};
     use crate::utils::crypto::chacha20poly1305_ietf::gen_key;
 
     use super::*;
@@ -453,7 +799,7 @@ mod tests {
     #[test]
     fn create_my_did_with_works_for_empty_info() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
         let my_did = service.create_my_did(&did_info);
         assert!(my_did.is_ok());
     }
@@ -463,7 +809,7 @@ mod tests {
         let service = CryptoService::new();
 
         let did = DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string());
-        let did_info = MyDidInfo { did: Some(did.clone()), cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: Some(did.clone()), cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
 
         let (my_did, _) = service.create_my_did(&did_info).unwrap();
         assert_eq!(did, my_did.did);
@@ -476,7 +822,7 @@ mod tests {
         let did = DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string());
         let crypto_type = Some("type".to_string());
 
-        let did_info = MyDidInfo { did: Some(did), cid: None, seed: None, crypto_type, method_name: None };
+        let did_info = MyDidInfo { did: Some(did), cid: None, seed: None, crypto_type, method_name: None, peer_numalgo: None };
 
         assert!(service.create_my_did(&did_info).is_err());
     }
@@ -488,8 +834,8 @@ mod tests {
         let did = DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string());
         let seed = Some("00000000000000000000000000000My1".to_string());
 
-        let did_info_with_seed = MyDidInfo { did: Some(did.clone()), cid: None, seed, crypto_type: None, method_name: None };
-        let did_info_without_seed = MyDidInfo { did: Some(did.clone()), cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info_with_seed = MyDidInfo { did: Some(did.clone()), cid: None, seed, crypto_type: None, method_name: None, peer_numalgo: None };
+        let did_info_without_seed = MyDidInfo { did: Some(did.clone()), cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
 
         let (did_with_seed, _) = service.create_my_did(&did_info_with_seed).unwrap();
         let (did_without_seed, _) = service.create_my_did(&did_info_without_seed).unwrap();
@@ -497,6 +843,81 @@ mod tests {
         assert_ne!(did_with_seed.verkey, did_without_seed.verkey)
     }
 
+    #[test]
+    fn create_my_did_works_for_did_key_method() {
+        let service = CryptoService::new();
+
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: Some(DidMethod("key".to_string())), peer_numalgo: None };
+
+        let (my_did, _) = service.create_my_did(&did_info).unwrap();
+        assert!(my_did.did.0.starts_with("did:key:z6Mk"));
+        assert_eq!(my_did.verkey, did_key::resolve_verkey(&my_did.did).unwrap().unwrap());
+    }
+
+    #[test]
+    fn create_my_did_works_for_did_peer_method() {
+        let service = CryptoService::new();
+
+        for numalgo in [None, Some(0), Some(2)].iter() {
+            let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: Some(DidMethod("peer".to_string())), peer_numalgo: *numalgo };
+
+            let (my_did, _) = service.create_my_did(&did_info).unwrap();
+            assert!(my_did.did.0.starts_with(if *numalgo == Some(2) { "did:peer:2.Ez6LS" } else { "did:peer:0z6Mk" }));
+            assert_eq!(my_did.verkey, did_key::resolve_verkey(&my_did.did).unwrap().unwrap());
+        }
+    }
+
+    #[test]
+    fn create_my_did_not_works_for_did_key_method_and_ecdsa_crypto_type() {
+        let service = CryptoService::new();
+
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: Some(SECP256K1_CRYPTO_TYPE.to_string()), method_name: Some(DidMethod("key".to_string())), peer_numalgo: None };
+
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.create_my_did(&did_info));
+    }
+
+    #[test]
+    fn create_their_did_works_for_did_key_without_verkey() {
+        let service = CryptoService::new();
+        let did = DidValue("did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH".to_string());
+
+        let their_did = service.create_their_did(&TheirDidInfo::new(did.clone(), None)).unwrap();
+
+        assert_eq!(did, their_did.did);
+        assert_eq!("B12NYF8RrR3h41TDCTJojY59usg3mbtbjnFs7Eud1Y6u", their_did.verkey);
+    }
+
+    #[test]
+    fn create_their_did_not_works_for_did_key_and_other_verkey() {
+        let service = CryptoService::new();
+        let did = DidValue("did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH".to_string());
+
+        let their_did_info = TheirDidInfo::new(did, Some("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp".to_string()));
+
+        assert_kind!(IndyErrorKind::InvalidStructure, service.create_their_did(&their_did_info));
+    }
+
+    #[test]
+    fn build_key_tags_works() {
+        let service = CryptoService::new();
+
+        let key = Key::new("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW:secp256k1".to_string(), String::new());
+        let tags = service.build_key_tags(&key, 1500000000);
+
+        assert_eq!("00000000001500000000", tags[Key::CREATED_AT_TAG]);
+        assert_eq!(SECP256K1_CRYPTO_TYPE, tags[Key::CRYPTO_TYPE_TAG]);
+    }
+
+    #[test]
+    fn verification_method_type_works() {
+        let service = CryptoService::new();
+
+        assert_eq!(ED25519_VERIFICATION_KEY_2018, service.verification_method_type("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW").unwrap());
+        assert_eq!(SECP256K1_VERIFICATION_KEY_2019, service.verification_method_type("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW:secp256k1").unwrap());
+        assert_eq!(SECP256R1_VERIFICATION_KEY_2019, service.verification_method_type("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW:p256").unwrap());
+        assert_kind!(IndyErrorKind::UnknownCrypto, service.verification_method_type("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW:unknown"));
+    }
+
     #[test]
     fn create_their_did_works_without_verkey() {
         let service = CryptoService::new();
@@ -536,7 +957,7 @@ mod tests {
     #[test]
     fn sign_works() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
 
         let message = r#"message"#;
         let (_, my_key) = service.create_my_did(&did_info).unwrap();
@@ -555,7 +976,7 @@ mod tests {
     #[test]
     fn sign_verify_works() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
         let message = r#"message"#;
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
         let signature = service.sign(&my_key, message.as_bytes()).unwrap();
@@ -566,7 +987,7 @@ mod tests {
     #[test]
     fn sign_verify_works_for_verkey_contained_crypto_type() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
         let message = r#"message"#;
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
         let signature = service.sign(&my_key, message.as_bytes()).unwrap();
@@ -579,7 +1000,7 @@ mod tests {
     #[test]
     fn sign_verify_works_for_verkey_contained_invalid_crypto_type() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
         let message = r#"message"#;
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
         let signature = service.sign(&my_key, message.as_bytes()).unwrap();
@@ -590,7 +1011,7 @@ mod tests {
     #[test]
     fn verify_not_works_for_invalid_verkey() {
         let service = CryptoService::new();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
         let message = r#"message"#;
         let (_, my_key) = service.create_my_did(&did_info).unwrap();
         let signature = service.sign(&my_key, message.as_bytes()).unwrap();
@@ -603,7 +1024,7 @@ mod tests {
     fn crypto_box_works() {
         let service = CryptoService::new();
         let msg = "some message";
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
         let (_, my_key) = service.create_my_did(&did_info).unwrap();
         let (their_did, _) = service.create_my_did(&did_info.clone()).unwrap();
         let their_did = Did::new(their_did.did, their_did.verkey);
@@ -617,7 +1038,7 @@ mod tests {
 
         let msg = "some message";
 
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
 
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
 
@@ -645,7 +1066,7 @@ mod tests {
 
         let msg = "some message";
 
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
 
         let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
 
@@ -671,7 +1092,7 @@ mod tests {
     fn crypto_box_seal_works() {
         let service = CryptoService::new();
         let msg = "some message";
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
         let (did, _) = service.create_my_did(&did_info.clone()).unwrap();
         let did = Did::new(did.did, did.verkey);
         let encrypted_message = service.crypto_box_seal(&did.verkey, msg.as_bytes());
@@ -682,7 +1103,7 @@ mod tests {
     fn crypto_box_seal_and_crypto_box_seal_open_works() {
         let service = CryptoService::new();
         let msg = "some message".as_bytes();
-        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
         let (did, key) = service.create_my_did(&did_info.clone()).unwrap();
         let encrypt_did = Did::new(did.did.clone(), did.verkey.clone());
         let encrypted_message = service.crypto_box_seal(&encrypt_did.verkey, msg).unwrap();
@@ -690,6 +1111,86 @@ mod tests {
         assert_eq!(msg, decrypted_message.as_slice());
     }
 
+    #[test]
+    fn create_my_did_works_for_ecdsa_crypto_types() {
+        let service = CryptoService::new();
+
+        for crypto_type in &[SECP256K1_CRYPTO_TYPE, P256_CRYPTO_TYPE] {
+            let seed = Some("00000000000000000000000000000My1".to_string());
+            let did_info = MyDidInfo { did: None, cid: None, seed, crypto_type: Some(crypto_type.to_string()), method_name: None, peer_numalgo: None };
+
+            let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
+            let (my_did_2, _) = service.create_my_did(&did_info).unwrap();
+
+            assert_eq!(my_did.verkey, my_did_2.verkey);
+            assert!(my_did.verkey.ends_with(&format!(":{}", crypto_type)));
+            assert_eq!(my_did.verkey, my_key.verkey);
+            service.validate_key(&my_did.verkey).unwrap();
+        }
+    }
+
+    #[test]
+    fn sign_verify_works_for_ecdsa_crypto_types() {
+        let service = CryptoService::new();
+        let message = r#"message"#;
+
+        for crypto_type in &[SECP256K1_CRYPTO_TYPE, P256_CRYPTO_TYPE] {
+            let key_info = KeyInfo { seed: None, crypto_type: Some(crypto_type.to_string()), signer: None, signer_config: None };
+            let key = service.create_key(&key_info).unwrap();
+
+            let signature = service.sign(&key, message.as_bytes()).unwrap();
+
+            assert!(service.verify(&key.verkey, message.as_bytes(), &signature).unwrap());
+            assert!(!service.verify(&key.verkey, "other message".as_bytes(), &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn crypto_box_and_crypto_box_open_works_for_ecdsa_crypto_types() {
+        let service = CryptoService::new();
+        let msg = "some message";
+
+        for crypto_type in &[SECP256K1_CRYPTO_TYPE, P256_CRYPTO_TYPE] {
+            let key_info = KeyInfo { seed: None, crypto_type: Some(crypto_type.to_string()), signer: None, signer_config: None };
+            let my_key = service.create_key(&key_info).unwrap();
+            let their_key = service.create_key(&key_info).unwrap();
+
+            let (encrypted_message, nonce) = service.crypto_box(&my_key, &their_key.verkey, msg.as_bytes()).unwrap();
+            let decrypted_message = service.crypto_box_open(&their_key, &my_key.verkey, &encrypted_message, &nonce).unwrap();
+
+            assert_eq!(msg.as_bytes().to_vec(), decrypted_message);
+        }
+    }
+
+    #[test]
+    fn crypto_box_not_works_for_different_crypto_types() {
+        let service = CryptoService::new();
+
+        let my_key = service.create_key(&KeyInfo { seed: None, crypto_type: Some(P256_CRYPTO_TYPE.to_string()), signer: None, signer_config: None }).unwrap();
+        let their_key = service.create_key(&KeyInfo { seed: None, crypto_type: Some(SECP256K1_CRYPTO_TYPE.to_string()), signer: None, signer_config: None }).unwrap();
+
+        assert!(service.crypto_box(&my_key, &their_key.verkey, "some message".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn crypto_box_seal_and_crypto_box_seal_open_works_for_ecdsa_crypto_types() {
+        let service = CryptoService::new();
+        let msg = "some message".as_bytes();
+
+        for crypto_type in &[SECP256K1_CRYPTO_TYPE, P256_CRYPTO_TYPE] {
+            let key_info = KeyInfo { seed: None, crypto_type: Some(crypto_type.to_string()), signer: None, signer_config: None };
+            let key = service.create_key(&key_info).unwrap();
+            let other_key = service.create_key(&key_info).unwrap();
+
+            let encrypted_message = service.crypto_box_seal(&key.verkey, msg).unwrap();
+
+            let decrypted_message = service.crypto_box_seal_open(&key, &encrypted_message).unwrap();
+            assert_eq!(msg, decrypted_message.as_slice());
+
+            assert!(service.crypto_box_seal_open(&other_key, &encrypted_message).is_err());
+        }
+    }
+
     #[test]
     pub fn test_encrypt_plaintext_and_decrypt_ciphertext_works() {
         let service: CryptoService = CryptoService::new();
@@ -824,4 +1325,78 @@ mod tests {
             .decrypt_ciphertext(&expected_ciphertext, &iv_encoded, &tag, bad_aad, &cek);
         assert!(expected_error.is_err());
     }
+
+    fn _didcomm_protected(service: &CryptoService, alg: &str, epk: EphemeralKey) -> DidCommProtected {
+        DidCommProtected {
+            typ: DIDCOMM_V2_TYP.to_string(),
+            alg: alg.to_string(),
+            enc: DidCommPackOptions::default().enc(alg).to_string(),
+            skid: None,
+            apu: Some(base64::encode_urlsafe_nopad(b"did:sov:NcYxiDXkpYi6ov5FcYDi1e#key-1")),
+            apv: service.didcomm_apv(&["did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1".to_string()]),
+            epk,
+        }
+    }
+
+    #[test]
+    fn concat_kdf_works_for_rfc7518_vector() {
+        // RFC 7518, Appendix C. Example ECDH-ES Key Agreement Computation
+        let z = [158, 86, 217, 29, 129, 113, 53, 211, 114, 131, 66, 131, 191, 132, 38, 156,
+            251, 49, 110, 163, 218, 128, 106, 72, 246, 218, 167, 121, 140, 254, 144, 196];
+
+        let key = concat_kdf(&z, b"A128GCM", b"Alice", b"Bob", None, 16);
+
+        assert_eq!("VqqN6vgjbSBcIijNcacQGg", base64::encode_urlsafe_nopad(&key));
+    }
+
+    #[test]
+    fn didcomm_wrap_cek_and_unwrap_cek_works() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
+        let (my_did, my_key) = service.create_my_did(&did_info.clone()).unwrap();
+        let (their_did, their_key) = service.create_my_did(&did_info.clone()).unwrap();
+
+        for alg in &[DIDCOMM_V2_ALG_ANON, DIDCOMM_V2_ALG_AUTH] {
+            let (epk, epk_sk) = service.didcomm_gen_ephemeral_key();
+            let protected = _didcomm_protected(&service, alg, epk);
+            let cek = service.didcomm_gen_cek(&protected.enc).unwrap();
+            let tag = [1u8; 16];
+
+            let encrypted_key = service.didcomm_wrap_cek(&protected, &epk_sk, Some(&my_key), &their_did.verkey, &tag, &cek).unwrap();
+            let decrypted_key = service.didcomm_unwrap_cek(&protected, &their_key, Some(&my_did.verkey), &tag, &encrypted_key).unwrap();
+
+            assert_eq!(cek, decrypted_key);
+        }
+    }
+
+    #[test]
+    fn didcomm_unwrap_cek_fails_for_other_tag_in_authcrypt() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None, peer_numalgo: None };
+        let (my_did, my_key) = service.create_my_did(&did_info.clone()).unwrap();
+        let (their_did, their_key) = service.create_my_did(&did_info.clone()).unwrap();
+
+        let (epk, epk_sk) = service.didcomm_gen_ephemeral_key();
+        let protected = _didcomm_protected(&service, DIDCOMM_V2_ALG_AUTH, epk);
+        let cek = service.didcomm_gen_cek(&protected.enc).unwrap();
+
+        let encrypted_key = service.didcomm_wrap_cek(&protected, &epk_sk, Some(&my_key), &their_did.verkey, &[1u8; 16], &cek).unwrap();
+
+        assert!(service.didcomm_unwrap_cek(&protected, &their_key, Some(&my_did.verkey), &[2u8; 16], &encrypted_key).is_err());
+    }
+
+    #[test]
+    fn didcomm_encrypt_content_and_decrypt_content_works() {
+        let service = CryptoService::new();
+
+        for enc in &[DIDCOMM_V2_ENC_A256CBC_HS512, DIDCOMM_V2_ENC_A256GCM, DIDCOMM_V2_ENC_XC20P] {
+            let cek = service.didcomm_gen_cek(enc).unwrap();
+            let (ciphertext, iv, tag) = service.didcomm_encrypt_content(enc, b"Hello World", "protected", &cek).unwrap();
+
+            let plaintext = service.didcomm_decrypt_content(enc, &ciphertext, "protected", &iv, &tag, &cek).unwrap();
+            assert_eq!(b"Hello World".to_vec(), plaintext);
+
+            assert!(service.didcomm_decrypt_content(enc, &ciphertext, "other", &iv, &tag, &cek).is_err());
+        }
+    }
 }
\ No newline at end of file
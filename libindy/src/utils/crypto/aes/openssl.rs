@@ -0,0 +1,219 @@
+use indy_api_types::errors::prelude::*;
+use openssl::aes::{self, AesKey};
+use openssl::hash::MessageDigest;
+use openssl::memcmp;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+use openssl::symm::{self, Cipher};
+
+use super::randombytes::randombytes;
+
+pub const KEYBYTES: usize = 32;
+pub const GCM_NONCEBYTES: usize = 12;
+pub const GCM_TAGBYTES: usize = 16;
+// AES_256_CBC_HMAC_SHA_512 (RFC 7518, 5.2.5): MAC key followed by encryption key
+pub const CBC_HS512_KEYBYTES: usize = 64;
+pub const CBC_HS512_IVBYTES: usize = 16;
+pub const CBC_HS512_TAGBYTES: usize = 32;
+// RFC 3394 wrapping adds single 64-bit block
+const KW_OVERHEADBYTES: usize = 8;
+
+pub fn gen_key() -> Vec<u8> {
+    randombytes(KEYBYTES)
+}
+
+// AES-256 key wrap (RFC 3394) with default IV
+pub fn wrap_key(kek: &[u8], key: &[u8]) -> IndyResult<Vec<u8>> {
+    _check_key_len(kek)?;
+
+    let kek = AesKey::new_encrypt(kek)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Invalid key encryption key"))?;
+
+    let mut res = vec![0u8; key.len() + KW_OVERHEADBYTES];
+
+    aes::wrap_key(&kek, None, &mut res, key)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Unable to wrap key"))?;
+
+    Ok(res)
+}
+
+pub fn unwrap_key(kek: &[u8], wrapped: &[u8]) -> IndyResult<Vec<u8>> {
+    _check_key_len(kek)?;
+
+    if wrapped.len() < 2 * KW_OVERHEADBYTES || wrapped.len() % KW_OVERHEADBYTES != 0 {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid wrapped key length"));
+    }
+
+    let kek = AesKey::new_decrypt(kek)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Invalid key encryption key"))?;
+
+    let mut res = vec![0u8; wrapped.len() - KW_OVERHEADBYTES];
+
+    aes::unwrap_key(&kek, None, &mut res, wrapped)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Unable to unwrap key"))?;
+
+    Ok(res)
+}
+
+// AES-256-GCM, returns (ciphertext, nonce, tag)
+pub fn gen_nonce_and_encrypt_detached(data: &[u8], aad: &[u8], key: &[u8]) -> IndyResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    _check_key_len(key)?;
+
+    let nonce = randombytes(GCM_NONCEBYTES);
+    let mut tag = vec![0u8; GCM_TAGBYTES];
+
+    let ciphertext = symm::encrypt_aead(Cipher::aes_256_gcm(), key, Some(&nonce), aad, data, &mut tag)?;
+
+    Ok((ciphertext, nonce, tag))
+}
+
+pub fn decrypt_detached(data: &[u8], key: &[u8], nonce: &[u8], tag: &[u8], aad: &[u8]) -> IndyResult<Vec<u8>> {
+    _check_key_len(key)?;
+
+    if nonce.len() != GCM_NONCEBYTES || tag.len() != GCM_TAGBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid AES-GCM nonce or tag length"));
+    }
+
+    symm::decrypt_aead(Cipher::aes_256_gcm(), key, Some(nonce), aad, data, tag)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Unable to decrypt data"))
+}
+
+pub fn gen_cbc_hs512_key() -> Vec<u8> {
+    randombytes(CBC_HS512_KEYBYTES)
+}
+
+// AES-256-CBC with HMAC-SHA-512 authentication, returns (ciphertext, iv, tag)
+pub fn cbc_hs512_gen_iv_and_encrypt_detached(data: &[u8], aad: &[u8], key: &[u8]) -> IndyResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    _cbc_hs512_encrypt_detached(data, aad, key, randombytes(CBC_HS512_IVBYTES))
+}
+
+pub fn cbc_hs512_decrypt_detached(data: &[u8], key: &[u8], iv: &[u8], tag: &[u8], aad: &[u8]) -> IndyResult<Vec<u8>> {
+    _check_cbc_hs512_key_len(key)?;
+
+    if iv.len() != CBC_HS512_IVBYTES || tag.len() != CBC_HS512_TAGBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid AES-CBC-HMAC-SHA2 iv or tag length"));
+    }
+
+    let (mac_key, enc_key) = key.split_at(CBC_HS512_KEYBYTES / 2);
+
+    if !memcmp::eq(&_cbc_hs512_tag(mac_key, aad, iv, data)?, tag) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Unable to decrypt data"));
+    }
+
+    symm::decrypt(Cipher::aes_256_cbc(), enc_key, Some(iv), data)
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Unable to decrypt data"))
+}
+
+fn _cbc_hs512_encrypt_detached(data: &[u8], aad: &[u8], key: &[u8], iv: Vec<u8>) -> IndyResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    _check_cbc_hs512_key_len(key)?;
+
+    let (mac_key, enc_key) = key.split_at(CBC_HS512_KEYBYTES / 2);
+
+    let ciphertext = symm::encrypt(Cipher::aes_256_cbc(), enc_key, Some(&iv), data)?;
+    let tag = _cbc_hs512_tag(mac_key, aad, &iv, &ciphertext)?;
+
+    Ok((ciphertext, iv, tag))
+}
+
+// First half of HMAC-SHA-512 over AAD || IV || ciphertext || AAD length in bits
+fn _cbc_hs512_tag(mac_key: &[u8], aad: &[u8], iv: &[u8], ciphertext: &[u8]) -> IndyResult<Vec<u8>> {
+    let mac_key = PKey::hmac(mac_key)?;
+    let mut signer = Signer::new(MessageDigest::sha512(), &mac_key)?;
+
+    signer.update(aad)?;
+    signer.update(iv)?;
+    signer.update(ciphertext)?;
+    signer.update(&((aad.len() as u64) * 8).to_be_bytes())?;
+
+    let mut tag = signer.sign_to_vec()?;
+    tag.truncate(CBC_HS512_TAGBYTES);
+
+    Ok(tag)
+}
+
+fn _check_cbc_hs512_key_len(key: &[u8]) -> IndyResult<()> {
+    if key.len() != CBC_HS512_KEYBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Invalid AES-CBC-HMAC-SHA2 key length, expected: {}, provided: {}", CBC_HS512_KEYBYTES, key.len())));
+    }
+
+    Ok(())
+}
+
+fn _check_key_len(key: &[u8]) -> IndyResult<()> {
+    if key.len() != KEYBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Invalid AES key length, expected: {}, provided: {}", KEYBYTES, key.len())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate hex;
+
+    use self::hex::FromHex;
+
+    #[test]
+    fn wrap_key_works_for_rfc3394_vector() {
+        // RFC 3394, 4.6 Wrap 256 bits of Key Data with a 256-bit KEK
+        let kek = Vec::from_hex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F").unwrap();
+        let key = Vec::from_hex("00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F").unwrap();
+        let expected = Vec::from_hex("28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21").unwrap();
+
+        let wrapped = wrap_key(&kek, &key).unwrap();
+        assert_eq!(expected, wrapped);
+
+        let unwrapped = unwrap_key(&kek, &wrapped).unwrap();
+        assert_eq!(key, unwrapped);
+    }
+
+    #[test]
+    fn unwrap_key_fails_for_wrong_kek() {
+        let wrapped = wrap_key(&gen_key(), &gen_key()).unwrap();
+        assert!(unwrap_key(&gen_key(), &wrapped).is_err());
+    }
+
+    #[test]
+    fn encrypt_decrypt_detached_works() {
+        let key = gen_key();
+        let (ciphertext, nonce, tag) = gen_nonce_and_encrypt_detached(b"message", b"aad", &key).unwrap();
+
+        assert_eq!(b"message".to_vec(), decrypt_detached(&ciphertext, &key, &nonce, &tag, b"aad").unwrap());
+        assert!(decrypt_detached(&ciphertext, &key, &nonce, &tag, b"bad").is_err());
+    }
+
+    #[test]
+    fn cbc_hs512_encrypt_works_for_rfc7518_vector() {
+        // RFC 7518, B.3 Test Cases for AES_256_CBC_HMAC_SHA_512
+        let key = Vec::from_hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f\
+                                 202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f").unwrap();
+        let plaintext = b"A cipher system must not be required to be secret, and it must be able to fall into the hands of the enemy without inconvenience";
+        let iv = Vec::from_hex("1af38c2dc2b96ffdd86694092341bc04").unwrap();
+        let aad = b"The second principle of Auguste Kerckhoffs";
+        let expected_ciphertext = Vec::from_hex("4affaaadb78c31c5da4b1b590d10ffbd3dd8d5d302423526912da037ecbcc7bd\
+                                                 822c301dd67c373bccb584ad3e9279c2e6d12a1374b77f077553df829410446b\
+                                                 36ebd97066296ae6427ea75c2e0846a11a09ccf5370dc80bfecbad28c73f09b3\
+                                                 a3b75e662a2594410ae496b2e2e6609e31e6e02cc837f053d21f37ff4f51950b\
+                                                 be2638d09dd7a4930930806d0703b1f6").unwrap();
+        let expected_tag = Vec::from_hex("4dd3b4c088a7f45c216839645b2012bf2e6269a8c56a816dbc1b267761955bc5").unwrap();
+
+        let (ciphertext, iv, tag) = _cbc_hs512_encrypt_detached(plaintext, aad, &key, iv).unwrap();
+        assert_eq!(expected_ciphertext, ciphertext);
+        assert_eq!(expected_tag, tag);
+
+        assert_eq!(plaintext.to_vec(), cbc_hs512_decrypt_detached(&ciphertext, &key, &iv, &tag, aad).unwrap());
+    }
+
+    #[test]
+    fn cbc_hs512_decrypt_fails_for_other_aad() {
+        let key = gen_cbc_hs512_key();
+        let (ciphertext, iv, tag) = cbc_hs512_gen_iv_and_encrypt_detached(b"message", b"aad", &key).unwrap();
+
+        assert_eq!(b"message".to_vec(), cbc_hs512_decrypt_detached(&ciphertext, &key, &iv, &tag, b"aad").unwrap());
+        assert!(cbc_hs512_decrypt_detached(&ciphertext, &key, &iv, &tag, b"bad").is_err());
+    }
+}
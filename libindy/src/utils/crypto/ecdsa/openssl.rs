@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+
+use indy_api_types::errors::prelude::*;
+use openssl::bn::{BigNum, BigNumContext, BigNumRef};
+use openssl::derive::Deriver;
+use openssl::ec::{EcGroup, EcGroupRef, EcKey, EcPoint, PointConversionForm};
+use openssl::ecdsa::EcdsaSig;
+use openssl::nid::Nid;
+use openssl::pkey::{PKey, Private, Public};
+use sha2::{Digest, Sha256};
+
+use super::randombytes::randombytes;
+
+pub const SEEDBYTES: usize = 32;
+pub const SECRETKEYBYTES: usize = 32;
+// Public keys are SEC1 encoded compressed points
+pub const PUBLICKEYBYTES: usize = 33;
+// Signatures are r || s (each 32 bytes big-endian) of SHA-256 digest of the message
+pub const SIGNATUREBYTES: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Curve {
+    Secp256k1,
+    P256,
+}
+
+impl Curve {
+    fn group(&self) -> IndyResult<EcGroup> {
+        let nid = match self {
+            Curve::Secp256k1 => Nid::SECP256K1,
+            Curve::P256 => Nid::X9_62_PRIME256V1,
+        };
+
+        Ok(EcGroup::from_curve_name(nid)?)
+    }
+}
+
+pub fn create_key_pair(curve: Curve, seed: Option<&[u8]>) -> IndyResult<(Vec<u8>, Vec<u8>)> {
+    let group = curve.group()?;
+    let mut ctx = BigNumContext::new()?;
+
+    let seed = match seed {
+        Some(seed) => seed.to_vec(),
+        None => randombytes(SEEDBYTES)
+    };
+
+    // Map seed to [1, order - 1] range, so the same seed always gives the same key
+    let mut order = BigNum::new()?;
+    group.order(&mut order, &mut ctx)?;
+    order.sub_word(1)?;
+
+    let seed = BigNum::from_slice(&seed)?;
+
+    let mut sk = BigNum::new()?;
+    sk.nnmod(&seed, &order, &mut ctx)?;
+    sk.add_word(1)?;
+
+    let key = _private_key(&group, &sk)?;
+    let vk = key.public_key().to_bytes(&group, PointConversionForm::COMPRESSED, &mut ctx)?;
+
+    Ok((vk, _to_fixed_bytes(&sk)))
+}
+
+pub fn sign(curve: Curve, sk: &[u8], doc: &[u8]) -> IndyResult<Vec<u8>> {
+    let group = curve.group()?;
+    let key = _secret_key(&group, sk)?;
+
+    let signature = EcdsaSig::sign(&Sha256::digest(doc), &key)?;
+    let s = _normalize_s(&group, signature.s())?;
+
+    let mut res = _to_fixed_bytes(signature.r());
+    res.extend(_to_fixed_bytes(&s));
+
+    Ok(res)
+}
+
+pub fn verify(curve: Curve, vk: &[u8], doc: &[u8], signature: &[u8]) -> IndyResult<bool> {
+    if signature.len() != SIGNATUREBYTES {
+        return Ok(false);
+    }
+
+    let group = curve.group()?;
+    let key = _public_key(&group, vk)?;
+
+    let (r, s) = signature.split_at(SIGNATUREBYTES / 2);
+    let signature = EcdsaSig::from_private_components(BigNum::from_slice(r)?, BigNum::from_slice(s)?)?;
+
+    Ok(signature.verify(&Sha256::digest(doc), &key).unwrap_or(false))
+}
+
+pub fn ecdh(curve: Curve, sk: &[u8], vk: &[u8]) -> IndyResult<Vec<u8>> {
+    let group = curve.group()?;
+
+    let sk = PKey::from_ec_key(_secret_key(&group, sk)?)?;
+    let vk = PKey::from_ec_key(_public_key(&group, vk)?)?;
+
+    let mut deriver = Deriver::new(&sk)?;
+    deriver.set_peer(&vk)?;
+
+    Ok(deriver.derive_to_vec()?)
+}
+
+pub fn validate_public_key(curve: Curve, vk: &[u8]) -> IndyResult<()> {
+    let group = curve.group()?;
+    _public_key(&group, vk).map(|_| ())
+}
+
+fn _private_key(group: &EcGroupRef, sk: &BigNumRef) -> IndyResult<EcKey<Private>> {
+    let ctx = BigNumContext::new()?;
+
+    let mut vk = EcPoint::new(group)?;
+    vk.mul_generator(group, sk, &ctx)?;
+
+    Ok(EcKey::from_private_components(group, sk, &vk)?)
+}
+
+fn _secret_key(group: &EcGroupRef, sk: &[u8]) -> IndyResult<EcKey<Private>> {
+    if sk.len() != SECRETKEYBYTES {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Invalid secret key length, expected: {}, provided: {}", SECRETKEYBYTES, sk.len())));
+    }
+
+    let sk = BigNum::from_slice(sk)?;
+    let key = _private_key(group, &sk)?;
+
+    key.check_key()
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid secret key")?;
+
+    Ok(key)
+}
+
+fn _public_key(group: &EcGroupRef, vk: &[u8]) -> IndyResult<EcKey<Public>> {
+    let mut ctx = BigNumContext::new()?;
+
+    let vk = EcPoint::from_bytes(group, vk, &mut ctx)
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid public key")?;
+
+    let key = EcKey::from_public_key(group, &vk)?;
+
+    key.check_key()
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid public key")?;
+
+    Ok(key)
+}
+
+// Use low-S form of signature (as Bitcoin and Ethereum require) to prevent signature malleability
+fn _normalize_s(group: &EcGroupRef, s: &BigNumRef) -> IndyResult<BigNum> {
+    let mut ctx = BigNumContext::new()?;
+
+    let mut order = BigNum::new()?;
+    group.order(&mut order, &mut ctx)?;
+
+    let mut half_order = BigNum::new()?;
+    half_order.rshift1(&order)?;
+
+    if s.ucmp(&half_order) == Ordering::Greater {
+        let mut res = BigNum::new()?;
+        res.checked_sub(&order, s)?;
+        Ok(res)
+    } else {
+        Ok(s.to_owned()?)
+    }
+}
+
+fn _to_fixed_bytes(n: &BigNumRef) -> Vec<u8> {
+    let bytes = n.to_vec();
+    let mut res = vec![0u8; SECRETKEYBYTES.saturating_sub(bytes.len())];
+    res.extend(bytes);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: &[u8; 32] = b"00000000000000000000000000000My1";
+
+    #[test]
+    fn create_key_pair_works_for_seed() {
+        for curve in &[Curve::Secp256k1, Curve::P256] {
+            let (vk, sk) = create_key_pair(*curve, Some(SEED)).unwrap();
+            let (vk2, sk2) = create_key_pair(*curve, Some(SEED)).unwrap();
+
+            assert_eq!(PUBLICKEYBYTES, vk.len());
+            assert_eq!(SECRETKEYBYTES, sk.len());
+            assert_eq!(vk, vk2);
+            assert_eq!(sk, sk2);
+        }
+    }
+
+    #[test]
+    fn sign_verify_works() {
+        for curve in &[Curve::Secp256k1, Curve::P256] {
+            let (vk, sk) = create_key_pair(*curve, None).unwrap();
+
+            let signature = sign(*curve, &sk, b"message").unwrap();
+            assert_eq!(SIGNATUREBYTES, signature.len());
+
+            assert!(verify(*curve, &vk, b"message", &signature).unwrap());
+            assert!(!verify(*curve, &vk, b"other message", &signature).unwrap());
+        }
+    }
+
+    #[test]
+    fn verify_fails_for_key_of_other_curve() {
+        let (vk, _) = create_key_pair(Curve::P256, Some(SEED)).unwrap();
+        let (_, sk) = create_key_pair(Curve::Secp256k1, Some(SEED)).unwrap();
+
+        let signature = sign(Curve::Secp256k1, &sk, b"message").unwrap();
+
+        assert!(!verify(Curve::P256, &vk, b"message", &signature).unwrap());
+    }
+
+    #[test]
+    fn ecdh_works() {
+        for curve in &[Curve::Secp256k1, Curve::P256] {
+            let (alice_vk, alice_sk) = create_key_pair(*curve, None).unwrap();
+            let (bob_vk, bob_sk) = create_key_pair(*curve, None).unwrap();
+
+            assert_eq!(ecdh(*curve, &alice_sk, &bob_vk).unwrap(),
+                       ecdh(*curve, &bob_sk, &alice_vk).unwrap());
+        }
+    }
+
+    #[test]
+    fn validate_public_key_fails_for_invalid_point() {
+        assert!(validate_public_key(Curve::Secp256k1, &[5u8; PUBLICKEYBYTES]).is_err());
+        assert!(validate_public_key(Curve::P256, &[2u8; 10]).is_err());
+    }
+}
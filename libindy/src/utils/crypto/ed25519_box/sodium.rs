@@ -1,6 +1,7 @@
 extern crate sodiumoxide;
 
 use indy_api_types::errors::prelude::*;
+use libc::{c_int, c_uchar};
 use self::sodiumoxide::crypto::box_;
 
 
@@ -12,6 +13,35 @@ sodium_type!(Nonce, box_::Nonce, NONCEBYTES);
 sodium_type!(PublicKey, box_::PublicKey, PUBLICKEYBYTES);
 sodium_type!(SecretKey, box_::SecretKey, SECRETKEYBYTES);
 
+pub const SHAREDSECRETBYTES: usize = 32;
+
+extern {
+    // TODO: fix hack:
+    // this functions isn't included to sodiumoxide rust wrappers,
+    // temporary local binding is used to call libsodium-sys function
+    fn crypto_scalarmult(q: *mut c_uchar, n: *const c_uchar, p: *const c_uchar) -> c_int;
+}
+
+pub fn create_key_pair() -> (PublicKey, SecretKey) {
+    let (public_key, secret_key) = box_::gen_keypair();
+    (PublicKey(public_key), SecretKey(secret_key))
+}
+
+// X25519 Diffie-Hellman over the same keys
+pub fn ecdh(secret_key: &SecretKey, public_key: &PublicKey) -> Result<Vec<u8>, IndyError> {
+    let mut shared = vec![0u8; SHAREDSECRETBYTES];
+
+    let res = unsafe {
+        crypto_scalarmult(shared.as_mut_ptr(), (secret_key.0).0.as_ptr(), (public_key.0).0.as_ptr())
+    };
+
+    if res != 0 {
+        return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Unable to compute X25519 shared secret"));
+    }
+
+    Ok(shared)
+}
+
 pub fn encrypt(secret_key: &SecretKey, public_key: &PublicKey, doc: &[u8], nonce: &Nonce) -> Result<Vec<u8>, IndyError> {
     Ok(box_::seal(
         doc,
@@ -66,4 +96,16 @@ mod tests {
         assert!(alice_decrypted_text.is_ok());
         assert_eq!(text, alice_decrypted_text.unwrap());
     }
+
+    #[test]
+    fn ecdh_works() {
+        let (alice_pk, alice_sk) = create_key_pair();
+        let (bob_pk, bob_sk) = create_key_pair();
+
+        let alice_shared = ecdh(&alice_sk, &bob_pk).unwrap();
+        let bob_shared = ecdh(&bob_sk, &alice_pk).unwrap();
+
+        assert_eq!(SHAREDSECRETBYTES, alice_shared.len());
+        assert_eq!(alice_shared, bob_shared);
+    }
 }
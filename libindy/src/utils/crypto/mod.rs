@@ -9,6 +9,11 @@ pub mod ed25519_box;
 
 pub use indy_utils::crypto::base64;
 
+#[cfg(feature = "ecdsa_openssl")]
+#[path = "ecdsa/openssl.rs"]
+// Also provides ECDH over the same curves
+pub mod ecdsa;
+
 #[allow(dead_code)] /* FIXME Do we really need this module? */
 #[cfg(feature = "xsalsa20_sodium")]
 #[path = "xsalsa20/sodium.rs"]
@@ -16,6 +21,14 @@ pub mod xsalsa20;
 
 pub use indy_utils::crypto::chacha20poly1305_ietf;
 
+#[cfg(feature = "xchacha20poly1305_ietf_sodium")]
+#[path = "xchacha20poly1305_ietf/sodium.rs"]
+pub mod xchacha20poly1305_ietf;
+
+#[cfg(feature = "aes_openssl")]
+#[path = "aes/openssl.rs"]
+pub mod aes;
+
 pub mod signature_serializer;
 
 pub mod verkey_builder;
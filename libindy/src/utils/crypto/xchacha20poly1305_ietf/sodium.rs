@@ -0,0 +1,128 @@
+use indy_api_types::errors::prelude::*;
+use libc::{c_int, c_uchar, c_ulonglong};
+
+use std::ptr;
+
+use super::randombytes::randombytes;
+
+pub const KEYBYTES: usize = 32;
+pub const NONCEBYTES: usize = 24;
+pub const TAGBYTES: usize = 16;
+
+extern {
+    // TODO: fix hack:
+    // this functions isn't included to sodiumoxide rust wrappers,
+    // temporary local binding is used to call libsodium-sys function
+    fn crypto_aead_xchacha20poly1305_ietf_encrypt_detached(
+        c: *mut c_uchar,
+        mac: *mut c_uchar,
+        maclen_p: *mut c_ulonglong,
+        m: *const c_uchar,
+        mlen: c_ulonglong,
+        ad: *const c_uchar,
+        adlen: c_ulonglong,
+        nsec: *const c_uchar,
+        npub: *const c_uchar,
+        k: *const c_uchar) -> c_int;
+    fn crypto_aead_xchacha20poly1305_ietf_decrypt_detached(
+        m: *mut c_uchar,
+        nsec: *mut c_uchar,
+        c: *const c_uchar,
+        clen: c_ulonglong,
+        mac: *const c_uchar,
+        ad: *const c_uchar,
+        adlen: c_ulonglong,
+        npub: *const c_uchar,
+        k: *const c_uchar) -> c_int;
+}
+
+pub fn gen_key() -> Vec<u8> {
+    randombytes(KEYBYTES)
+}
+
+// XChaCha20-Poly1305 (XC20P), returns (ciphertext, nonce, tag)
+pub fn gen_nonce_and_encrypt_detached(data: &[u8], aad: &[u8], key: &[u8]) -> IndyResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    _check_len("key", key, KEYBYTES)?;
+
+    let nonce = randombytes(NONCEBYTES);
+    let mut ciphertext = vec![0u8; data.len()];
+    let mut tag = vec![0u8; TAGBYTES];
+
+    let res = unsafe {
+        crypto_aead_xchacha20poly1305_ietf_encrypt_detached(
+            ciphertext.as_mut_ptr(),
+            tag.as_mut_ptr(),
+            ptr::null_mut(),
+            data.as_ptr(),
+            data.len() as c_ulonglong,
+            aad.as_ptr(),
+            aad.len() as c_ulonglong,
+            ptr::null(),
+            nonce.as_ptr(),
+            key.as_ptr())
+    };
+
+    if res != 0 {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Unable to encrypt data"));
+    }
+
+    Ok((ciphertext, nonce, tag))
+}
+
+pub fn decrypt_detached(data: &[u8], key: &[u8], nonce: &[u8], tag: &[u8], aad: &[u8]) -> IndyResult<Vec<u8>> {
+    _check_len("key", key, KEYBYTES)?;
+    _check_len("nonce", nonce, NONCEBYTES)?;
+    _check_len("tag", tag, TAGBYTES)?;
+
+    let mut plaintext = vec![0u8; data.len()];
+
+    let res = unsafe {
+        crypto_aead_xchacha20poly1305_ietf_decrypt_detached(
+            plaintext.as_mut_ptr(),
+            ptr::null_mut(),
+            data.as_ptr(),
+            data.len() as c_ulonglong,
+            tag.as_ptr(),
+            aad.as_ptr(),
+            aad.len() as c_ulonglong,
+            nonce.as_ptr(),
+            key.as_ptr())
+    };
+
+    if res != 0 {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Unable to decrypt data"));
+    }
+
+    Ok(plaintext)
+}
+
+fn _check_len(name: &str, value: &[u8], expected: usize) -> IndyResult<()> {
+    if value.len() != expected {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Invalid XChaCha20-Poly1305 {} length, expected: {}, provided: {}", name, expected, value.len())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_detached_works() {
+        let key = gen_key();
+        let (ciphertext, nonce, tag) = gen_nonce_and_encrypt_detached(b"message", b"aad", &key).unwrap();
+
+        assert_eq!(NONCEBYTES, nonce.len());
+        assert_eq!(b"message".to_vec(), decrypt_detached(&ciphertext, &key, &nonce, &tag, b"aad").unwrap());
+    }
+
+    #[test]
+    fn decrypt_detached_fails_for_wrong_aad() {
+        let key = gen_key();
+        let (ciphertext, nonce, tag) = gen_nonce_and_encrypt_detached(b"message", b"aad", &key).unwrap();
+
+        assert!(decrypt_detached(&ciphertext, &key, &nonce, &tag, b"bad").is_err());
+    }
+}
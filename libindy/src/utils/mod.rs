@@ -23,3 +23,5 @@ pub use indy_utils::wql;
 
 #[macro_use]
 pub mod qualifier;
+
+pub mod timestamp;
@@ -0,0 +1,36 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Unencrypted wallet tag values are compared as strings,
+// so timestamps are zero-padded to the length of the max u64 value to keep range queries numeric.
+const TAG_VALUE_WIDTH: usize = 20;
+
+pub fn current_timestamp() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(ts) => ts.as_secs(),
+        Err(err) => {
+            warn!("Cannot get time: {:?}", err);
+            0
+        }
+    }
+}
+
+pub fn timestamp_tag_value(timestamp: u64) -> String {
+    format!("{:0width$}", timestamp, width = TAG_VALUE_WIDTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_tag_value_works() {
+        assert_eq!("00000000001500000000", timestamp_tag_value(1500000000));
+        assert_eq!("00000000000000000200", timestamp_tag_value(200));
+        assert_eq!("18446744073709551615", timestamp_tag_value(u64::max_value()));
+    }
+
+    #[test]
+    fn timestamp_tag_value_keeps_numeric_order() {
+        assert!(timestamp_tag_value(999) < timestamp_tag_value(1000));
+    }
+}
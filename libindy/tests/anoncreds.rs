@@ -88,6 +88,71 @@ mod high_cases {
         fn issuer_create_credential_works() {
             anoncreds::init_common_wallet();
         }
+
+        #[test]
+        fn issuer_create_credential_works_for_values_without_encoded() {
+            let (_, credential_offer, credential_req, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let cred_values = json!({
+                "sex": {"raw": "male"},
+                "name": {"raw": "Alex", "encoded": "1139481716457488690172217916278103335"},
+                "height": {"raw": "175"},
+                "age": {"raw": "28"}
+            }).to_string();
+
+            let (credential_json, _, _) = anoncreds::issuer_create_credential(wallet_handle,
+                                                                              &credential_offer,
+                                                                              &credential_req,
+                                                                              &cred_values,
+                                                                              None,
+                                                                              None).unwrap();
+            let credential: serde_json::Value = serde_json::from_str(&credential_json).unwrap();
+
+            assert_eq!(json!({"raw": "male", "encoded": "5944657099558967239210949258394887428692050081607692519917050011144233115103"}), credential["values"]["sex"]);
+            assert_eq!(json!({"raw": "Alex", "encoded": "1139481716457488690172217916278103335"}), credential["values"]["name"]);
+            assert_eq!(json!({"raw": "175", "encoded": "175"}), credential["values"]["height"]);
+            assert_eq!(json!({"raw": "28", "encoded": "28"}), credential["values"]["age"]);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod encode_credential_values {
+        use super::*;
+
+        #[test]
+        fn encode_credential_values_works() {
+            let cred_values = json!({
+                "name": {"raw": "Alex"},
+                "age": {"raw": "28"},
+                "zip": {"raw": "2147483648"},
+                "sex": {"raw": "male", "encoded": "1"}
+            }).to_string();
+
+            let cred_values_json = anoncreds::encode_credential_values(&cred_values).unwrap();
+            let cred_values: serde_json::Value = serde_json::from_str(&cred_values_json).unwrap();
+
+            assert_eq!(json!({
+                "name": {"raw": "Alex", "encoded": "99262857098057710338306967609588410025648622308394250666849665532448612202874"},
+                "age": {"raw": "28", "encoded": "28"},
+                "zip": {"raw": "2147483648", "encoded": "26221484005389514539852548961319751347124425277437769688639924217837557266135"},
+                "sex": {"raw": "male", "encoded": "1"}
+            }), cred_values);
+        }
+
+        #[test]
+        fn encode_credential_values_works_for_empty_values() {
+            let res = anoncreds::encode_credential_values("{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn encode_credential_values_works_for_missed_raw() {
+            let res = anoncreds::encode_credential_values(r#"{"name": {"encoded": "1"}}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
     mod prover_store_credential {
@@ -3052,6 +3117,279 @@ mod high_cases {
         }
     }
 
+    mod verifier_verify_proof_with_config {
+        use super::*;
+
+        #[test]
+        fn verifier_verify_proof_with_config_works_for_default_config() {
+            let valid = anoncreds::verifier_verify_proof_with_config(&anoncreds::proof_request_attr(),
+                                                                     &anoncreds::proof_json(),
+                                                                     &anoncreds::schemas_for_proof(),
+                                                                     &anoncreds::cred_defs_for_proof(),
+                                                                     "{}",
+                                                                     "{}",
+                                                                     "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_config_works_for_not_canonical_encoding() {
+            let res = anoncreds::verifier_verify_proof_with_config(&anoncreds::proof_request_attr(),
+                                                                   &anoncreds::proof_json(),
+                                                                   &anoncreds::schemas_for_proof(),
+                                                                   &anoncreds::cred_defs_for_proof(),
+                                                                   "{}",
+                                                                   "{}",
+                                                                   r#"{"check_raw_encoding": true}"#);
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_config_works_for_disabled_encoding_check() {
+            let valid = anoncreds::verifier_verify_proof_with_config(&anoncreds::proof_request_attr(),
+                                                                     &anoncreds::proof_json(),
+                                                                     &anoncreds::schemas_for_proof(),
+                                                                     &anoncreds::cred_defs_for_proof(),
+                                                                     "{}",
+                                                                     "{}",
+                                                                     r#"{"check_raw_encoding": false}"#).unwrap();
+            assert!(valid);
+        }
+    }
+
+    mod verifier_verify_proof_detailed {
+        use super::*;
+        use crate::utils::domain::anoncreds::proof::{ProofVerificationReport, ReferentKind, VerificationCheck};
+
+        fn verify_detailed(proof_request_json: &str, proof_json: &str) -> ProofVerificationReport {
+            let report_json = anoncreds::verifier_verify_proof_detailed(proof_request_json,
+                                                                        proof_json,
+                                                                        &anoncreds::schemas_for_proof(),
+                                                                        &anoncreds::cred_defs_for_proof(),
+                                                                        "{}",
+                                                                        "{}").unwrap();
+            serde_json::from_str(&report_json).unwrap()
+        }
+
+        #[test]
+        fn verifier_verify_proof_detailed_works_for_correct_proof() {
+            let report = verify_detailed(&anoncreds::proof_request_attr(), &anoncreds::proof_json());
+            assert!(report.valid);
+            assert!(report.failures.is_empty());
+
+            let referent = &report.referents["attr1_referent"];
+            assert!(referent.valid);
+            assert_eq!(ReferentKind::RevealedAttribute, referent.kind);
+            assert_eq!(Some(0), referent.sub_proof_index);
+        }
+
+        #[test]
+        fn verifier_verify_proof_detailed_works_for_wrong_encoded() {
+            let proof_json = anoncreds::proof_json().replace(r#"encoded":"1139481716457488690172217916278103335"#, r#"encoded":"1111111111111111111111111111111111111"#);
+
+            let report = verify_detailed(&anoncreds::proof_request_attr(), &proof_json);
+            assert!(!report.valid);
+
+            let referent = &report.referents["attr1_referent"];
+            assert!(!referent.valid);
+            assert_eq!(VerificationCheck::Encoding, referent.failures[0].check);
+        }
+
+        #[test]
+        fn verifier_verify_proof_detailed_works_for_proof_does_not_correspond_to_request() {
+            let proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": {
+                   "attr2_referent": { "name":"sex" }
+               },
+               "requested_predicates": {},
+            }).to_string();
+
+            let report = verify_detailed(&proof_req_json, &anoncreds::proof_json());
+            assert!(!report.valid);
+
+            let missing = &report.referents["attr2_referent"];
+            assert_eq!(ReferentKind::Missing, missing.kind);
+            assert_eq!(VerificationCheck::Structure, missing.failures[0].check);
+
+            let unexpected = &report.referents["attr1_referent"];
+            assert_eq!(ReferentKind::RevealedAttribute, unexpected.kind);
+            assert_eq!(VerificationCheck::Structure, unexpected.failures[0].check);
+        }
+
+        #[test]
+        fn verifier_verify_proof_detailed_works_for_restrictions_and_timestamp_mismatch() {
+            let proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": {
+                   "attr1_referent": {
+                       "name":"name",
+                       "restrictions": { "issuer_did": "NO DID" },
+                       "non_revoked": { "to": 100 }
+                   }
+               },
+               "requested_predicates": {},
+            }).to_string();
+
+            let report = verify_detailed(&proof_req_json, &anoncreds::proof_json());
+            assert!(!report.valid);
+
+            let checks: Vec<VerificationCheck> = report.referents["attr1_referent"].failures.iter().map(|failure| failure.check).collect();
+            assert_eq!(vec![VerificationCheck::Restrictions, VerificationCheck::Timestamp], checks);
+        }
+    }
+
+    mod verifier_validate_proof_request {
+        use super::*;
+        use indy::anoncreds::{ProofRequestBuilder, ProofRequestVersion, AttributeRequest, PredicateRequest, PredicateType, NonRevokedInterval};
+        use crate::utils::domain::anoncreds::proof_request::ProofRequestValidationReport;
+
+        fn validate(proof_request_json: &str) -> ProofRequestValidationReport {
+            let report_json = anoncreds::verifier_validate_proof_request(proof_request_json).unwrap();
+            serde_json::from_str(&report_json).unwrap()
+        }
+
+        fn referent_errors(report: &ProofRequestValidationReport, referent: &str) -> Vec<String> {
+            report.errors.iter()
+                .filter(|error| error.referent.as_ref().map(String::as_str) == Some(referent))
+                .map(|error| error.message.clone())
+                .collect()
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works() {
+            let report = validate(&anoncreds::proof_request_attr());
+            assert!(report.valid);
+            assert!(report.errors.is_empty());
+
+            let report = validate(&anoncreds::proof_request_attr_and_predicate());
+            assert!(report.valid);
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_builder() {
+            let nonce = anoncreds::generate_nonce().unwrap();
+
+            let proof_request_json = ProofRequestBuilder::new(&nonce, "proof_req_1", "0.1")
+                .ver(ProofRequestVersion::V2)
+                .requested_attribute("attr1_referent", AttributeRequest::new("name")
+                    .restrictions(json!({"cred_def_id": anoncreds::local_gvt_cred_def_id_fully_qualified()})))
+                .requested_predicate("predicate1_referent", PredicateRequest::new("age", PredicateType::GE, 18)
+                    .non_revoked(NonRevokedInterval { from: Some(10), to: Some(20) }))
+                .build()
+                .unwrap();
+
+            let report = validate(&proof_request_json);
+            assert!(report.valid, "{:?}", report.errors);
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_builder_rejects_duplicate_referents() {
+            let res = ProofRequestBuilder::new("123432421212", "proof_req_1", "0.1")
+                .requested_attribute("referent", AttributeRequest::new("name"))
+                .requested_predicate("referent", PredicateRequest::new("age", PredicateType::GE, 18))
+                .build();
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_invalid_json() {
+            let report = validate("{not a json");
+            assert!(!report.valid);
+            assert_eq!(1, report.errors.len());
+            assert_eq!(None, report.errors[0].referent);
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_duplicate_referents() {
+            let proof_req_json = r#"{
+                "nonce":"123432421212",
+                "name":"proof_req_1",
+                "version":"0.1",
+                "requested_attributes":{
+                    "attr1_referent":{"name":"name"},
+                    "attr1_referent":{"name":"sex"}
+                },
+                "requested_predicates":{
+                    "attr1_referent":{"name":"age","p_type":">=","p_value":18}
+                }
+            }"#;
+
+            let report = validate(proof_req_json);
+            assert!(!report.valid);
+            assert_eq!(2, referent_errors(&report, "attr1_referent").len());
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_invalid_items() {
+            let proof_req_json = json!({
+                "nonce":"123432421212",
+                "name":"proof_req_1",
+                "version":"0.1",
+                "requested_attributes":{
+                    "attr1_referent":{"name":"name", "restrictions":{"schema_name":{"$like":"gvt%"}}},
+                    "attr2_referent":{"name":"sex", "restrictions":{"unknown_tag":"value"}},
+                    "attr3_referent":{"name":"height", "non_revoked":{"from":20, "to":10}},
+                    "attr4_referent":{"name":"status", "restrictions":{"cred_def_id": anoncreds::local_gvt_cred_def_id_fully_qualified()}},
+                    "attr5_referent":{"name":"phone"}
+                },
+                "requested_predicates":{
+                    "predicate1_referent":{"name":"age","p_type":"=>","p_value":18},
+                    "predicate2_referent":{"name":"age","p_type":">=","p_value":"18"}
+                }
+            }).to_string();
+
+            let report = validate(&proof_req_json);
+            assert!(!report.valid);
+
+            for referent in &["attr1_referent", "attr2_referent", "attr3_referent", "attr4_referent", "predicate1_referent", "predicate2_referent"] {
+                assert_eq!(1, referent_errors(&report, referent).len(), "{}: {:?}", referent, report.errors);
+            }
+            assert!(referent_errors(&report, "attr5_referent").is_empty());
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_invalid_proof_request_fields() {
+            let proof_req_json = json!({
+                "ver":"3.0",
+                "nonce":"not a nonce",
+                "version":"0.1",
+                "requested_attributes":{},
+                "requested_predicates":{},
+                "non_revoked":{}
+            }).to_string();
+
+            let report = validate(&proof_req_json);
+            assert!(!report.valid);
+            assert_eq!(4, report.errors.len(), "{:?}", report.errors);
+            assert!(report.errors.iter().all(|error| error.referent.is_none()));
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_empty_non_revoked_interval() {
+            let proof_req_json = json!({
+                "nonce":"123432421212",
+                "name":"proof_req_1",
+                "version":"0.1",
+                "requested_attributes":{
+                    "attr1_referent":{"name":"name", "non_revoked":{}}
+                },
+                "requested_predicates":{
+                    "predicate1_referent":{"name":"age","p_type":">=","p_value":18, "non_revoked":{}}
+                },
+                "non_revoked":{}
+            }).to_string();
+
+            let report = validate(&proof_req_json);
+            assert!(report.valid, "{:?}", report.errors);
+            assert!(report.errors.is_empty());
+        }
+    }
+
     mod verifier_verify_proof_with_proof_req_restrictions {
         use super::*;
 
@@ -23,10 +23,11 @@ mod demos {
     use crate::utils::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
     use crate::utils::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinition;
     use crate::utils::domain::anoncreds::credential_for_proof_request::CredentialsForProofRequest;
-    use crate::utils::domain::anoncreds::proof::Proof;
+    use crate::utils::domain::anoncreds::proof::{Proof, ProofVerificationReport, VerificationCheck};
     use crate::utils::domain::anoncreds::revocation_state::RevocationState;
     use crate::utils::domain::anoncreds::revocation_registry::RevocationRegistry;
     use crate::utils::domain::anoncreds::credential_offer::CredentialOffer;
+    use crate::utils::domain::anoncreds::credential::CredentialInfo;
 
     #[test]
     fn anoncreds_works_for_single_issuer_single_prover() {
@@ -905,6 +906,133 @@ mod demos {
         wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
     }
 
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_verify_proof_detailed_for_corrupted_primary_proof_with_timestamp() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_verify_proof_detailed_for_corrupted_primary_proof_with_timestamp").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_verify_proof_detailed_for_corrupted_primary_proof_with_timestamp").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (schema_id, schema_json,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, revoc_reg_entry_json,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        let (cred_rev_id, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //5. Proof Request
+        let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({}),
+           "non_revoked": json!({ "from":80, "to":100 })
+        }).to_string();
+
+        //6. Prover creates Revocation State
+        let timestamp = 100;
+
+        let rev_state_json = anoncreds::create_revocation_state(blob_storage_reader_handle,
+                                                                &revoc_reg_def_json,
+                                                                &revoc_reg_entry_json,
+                                                                timestamp,
+                                                                &cred_rev_id).unwrap();
+
+        //7. Prover creates Proof
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": timestamp,  "revealed":true })
+             }),
+             "requested_predicates": json!({})
+        }).to_string();
+
+        let schemas_json = json!({
+            schema_id.clone(): serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
+
+        let credential_defs_json = json!({
+            cred_def_id.clone(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        let rev_states_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationState>(&rev_state_json).unwrap()
+            })
+        }).to_string();
+
+        let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                        &proof_request,
+                                                        &requested_credentials_json,
+                                                        COMMON_MASTER_SECRET,
+                                                        &schemas_json,
+                                                        &credential_defs_json,
+                                                        &rev_states_json).unwrap();
+
+        //8. Primary proof of the credential is corrupted
+        let mut proof: serde_json::Value = serde_json::from_str(&proof_json).unwrap();
+        proof["proof"]["proofs"][0]["primary_proof"]["eq_proof"]["e"] = json!("1234567890");
+        let proof_json = proof.to_string();
+
+        //9. Verifier reports invalid CL proof without blaming non-revocation of the credential
+        let rev_reg_defs_json = json!({
+            rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
+        }).to_string();
+
+        let rev_regs_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationRegistry>(&revoc_reg_entry_json).unwrap()
+            })
+        }).to_string();
+
+        let report_json = anoncreds::verifier_verify_proof_detailed(&proof_request,
+                                                                    &proof_json,
+                                                                    &schemas_json,
+                                                                    &credential_defs_json,
+                                                                    &rev_reg_defs_json,
+                                                                    &rev_regs_json).unwrap();
+        let report: ProofVerificationReport = serde_json::from_str(&report_json).unwrap();
+
+        assert!(!report.valid);
+        assert_eq!(1, report.failures.len());
+        assert_eq!(VerificationCheck::Signature, report.failures[0].check);
+
+        let referent = &report.referents["attr1_referent"];
+        assert!(referent.valid);
+        assert!(referent.failures.is_empty());
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
     #[cfg(feature = "revocation_tests")]
     #[test]
     fn anoncreds_works_for_revocation_proof_for_issuance_and_proving_three_credential() {
@@ -3453,4 +3581,440 @@ mod demos {
         wallet::close_and_delete_wallet(issuer_gvt_wallet_handle, &issuer_gvt_wallet_config).unwrap();
         wallet::close_and_delete_wallet(issuer_xyz_wallet_handle, &issuer_xyz_wallet_config).unwrap();
     }
+
+    #[test]
+    fn anoncreds_works_for_credential_lifecycle_tags() {
+        Setup::empty();
+
+        //1. Create Issuer wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_credential_lifecycle_tags").unwrap();
+
+        //2. Create Prover wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_credential_lifecycle_tags").unwrap();
+
+        //3. Issuer creates Schema and Credential Definition with expiry attribute
+        let (_schema_id, _schema_json, cred_def_id, cred_def_json) = anoncreds::multi_steps_issuer_preparation(issuer_wallet_handle,
+                                                                                                               ISSUER_DID,
+                                                                                                               "license",
+                                                                                                               r#"["name", "expiry"]"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance credentials for Prover
+        anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET,
+                                                 prover_wallet_handle,
+                                                 issuer_wallet_handle,
+                                                 CREDENTIAL1_ID,
+                                                 &json!({"name": {"raw": "Alex"}, "expiry": {"raw": "1500000000"}}).to_string(),
+                                                 &cred_def_id,
+                                                 &cred_def_json);
+
+        anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET,
+                                                 prover_wallet_handle,
+                                                 issuer_wallet_handle,
+                                                 CREDENTIAL2_ID,
+                                                 &json!({"name": {"raw": "Alex"}, "expiry": {"raw": "2000000000"}}).to_string(),
+                                                 &cred_def_id,
+                                                 &cred_def_json);
+
+        //6. Prover searches for received credentials
+        let filter_json = json!({"~received_at": {"$gt": "00000000001000000000"}}).to_string();
+
+        let (search_handle, count) = anoncreds::prover_search_credentials(prover_wallet_handle, &filter_json).unwrap();
+        assert_eq!(count, 2);
+        anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+        //7. Prover searches for expired credentials
+        let filter_json = json!({"~expires_at": {"$lt": "00000000001600000000"}}).to_string();
+
+        let (search_handle, count) = anoncreds::prover_search_credentials(prover_wallet_handle, &filter_json).unwrap();
+        assert_eq!(count, 1);
+
+        let credentials: Vec<CredentialInfo> = serde_json::from_str(&anoncreds::prover_fetch_credentials(search_handle, 10).unwrap()).unwrap();
+        assert_eq!(CREDENTIAL1_ID, credentials[0].referent);
+        anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+        //8. Prover sets credential attr tag policy retroactively and lifecycle tags are kept
+        anoncreds::prover_set_credential_attr_tag_policy(prover_wallet_handle, &cred_def_id, Some(r#"["name"]"#), true).unwrap();
+
+        let (search_handle, count) = anoncreds::prover_search_credentials(prover_wallet_handle, &filter_json).unwrap();
+        assert_eq!(count, 1);
+        anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+        //9. Non revocable credentials don't have revocation status
+        let filter_json = json!({"~revocation_status": "unknown"}).to_string();
+
+        let (search_handle, count) = anoncreds::prover_search_credentials(prover_wallet_handle, &filter_json).unwrap();
+        assert_eq!(count, 0);
+        anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_check_revocation_status() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_check_revocation_status").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_check_revocation_status").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, revoc_reg_entry_json,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance Credential
+        let (cred_rev_id, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //6. Revocation status of stored credential is unknown
+        let filter_json = json!({"~revocation_status": "unknown"}).to_string();
+
+        let (search_handle, count) = anoncreds::prover_search_credentials(prover_wallet_handle, &filter_json).unwrap();
+        assert_eq!(count, 1);
+        anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+        //7. Prover checks revocation status
+        let revocation_info_json = anoncreds::prover_check_revocation_status(prover_wallet_handle, CREDENTIAL1_ID, &revoc_reg_def_json, &revoc_reg_entry_json, 100).unwrap();
+        let revocation_info: serde_json::Value = serde_json::from_str(&revocation_info_json).unwrap();
+        assert_eq!(json!({
+            "referent": CREDENTIAL1_ID,
+            "rev_reg_id": rev_reg_id,
+            "cred_rev_id": cred_rev_id,
+            "status": "active",
+            "checked_at": 100
+        }), revocation_info);
+
+        //8. Issuer revokes credential
+        let revoc_reg_delta_json = anoncreds::issuer_revoke_credential(issuer_wallet_handle,
+                                                                       blob_storage_reader_handle,
+                                                                       &rev_reg_id,
+                                                                       &cred_rev_id).unwrap();
+
+        let revoc_reg_delta_json = anoncreds::issuer_merge_revocation_registry_deltas(&revoc_reg_entry_json, &revoc_reg_delta_json).unwrap();
+
+        //9. Prover checks revocation status again
+        let revocation_info_json = anoncreds::prover_check_revocation_status(prover_wallet_handle, CREDENTIAL1_ID, &revoc_reg_def_json, &revoc_reg_delta_json, 200).unwrap();
+        let revocation_info: serde_json::Value = serde_json::from_str(&revocation_info_json).unwrap();
+        assert_eq!(json!("revoked"), revocation_info["status"]);
+
+        //10. Prover searches revoked credentials checked after revocation
+        let filter_json = json!({"~revocation_status": "revoked", "~revocation_checked_at": {"$gte": "00000000000000000200"}}).to_string();
+
+        let (search_handle, count) = anoncreds::prover_search_credentials(prover_wallet_handle, &filter_json).unwrap();
+        assert_eq!(count, 1);
+        anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+        //11. Timestamp tags are compared in numeric order
+        let filter_json = json!({"~revocation_checked_at": {"$lt": "00000000000000001000"}}).to_string();
+
+        let (search_handle, count) = anoncreds::prover_search_credentials(prover_wallet_handle, &filter_json).unwrap();
+        assert_eq!(count, 1);
+        anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+        //12. Prover can't check revocation status using Revocation Registry of another credential
+        let mut other_revoc_reg_def: serde_json::Value = serde_json::from_str(&revoc_reg_def_json).unwrap();
+        other_revoc_reg_def["id"] = json!(rev_reg_id.replace(TAG_1, "OTHER_TAG"));
+
+        let res = anoncreds::prover_check_revocation_status(prover_wallet_handle, CREDENTIAL1_ID, &other_revoc_reg_def.to_string(), &revoc_reg_delta_json, 300);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_check_revocation_status_for_partial_delta() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_check_revocation_status_for_partial_delta").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_check_revocation_status_for_partial_delta").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance Credential
+        let (cred_rev_id, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //6. Issuer revokes credential
+        let revoc_reg_delta_json = anoncreds::issuer_revoke_credential(issuer_wallet_handle,
+                                                                       blob_storage_reader_handle,
+                                                                       &rev_reg_id,
+                                                                       &cred_rev_id).unwrap();
+
+        //7. Prover can't check revocation status using delta which isn't accumulated from the registry creation
+        let res = anoncreds::prover_check_revocation_status(prover_wallet_handle, CREDENTIAL1_ID, &revoc_reg_def_json, &revoc_reg_delta_json, 100);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_check_revocation_status_for_issuance_on_demand() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_check_revocation_status_for_issuance_on_demand").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_check_revocation_status_for_issuance_on_demand").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, revoc_reg_entry_json,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance Credential
+        let (_, revoc_reg_delta_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //6. Credential isn't valid until its issuance is in the delta
+        let revocation_info_json = anoncreds::prover_check_revocation_status(prover_wallet_handle, CREDENTIAL1_ID, &revoc_reg_def_json, &revoc_reg_entry_json, 100).unwrap();
+        let revocation_info: serde_json::Value = serde_json::from_str(&revocation_info_json).unwrap();
+        assert_eq!(json!("revoked"), revocation_info["status"]);
+
+        //7. Prover checks revocation status using delta containing issuance
+        let revoc_reg_delta_json = anoncreds::issuer_merge_revocation_registry_deltas(&revoc_reg_entry_json, &revoc_reg_delta_json.unwrap()).unwrap();
+
+        let revocation_info_json = anoncreds::prover_check_revocation_status(prover_wallet_handle, CREDENTIAL1_ID, &revoc_reg_def_json, &revoc_reg_delta_json, 200).unwrap();
+        let revocation_info: serde_json::Value = serde_json::from_str(&revocation_info_json).unwrap();
+        assert_eq!(json!("active"), revocation_info["status"]);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_cached_revocation_states() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_cached_revocation_states").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_cached_revocation_states").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (schema_id, schema_json,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, revoc_reg_entry_json,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance Credentials
+        let (cred_rev_id, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        let (cred_rev_id_2, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL2_ID,
+            &anoncreds::gvt2_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //6. Prover caches Revocation State for the first Credential
+        let rev_state_json = anoncreds::prover_update_cached_revocation_state(prover_wallet_handle,
+                                                                              blob_storage_reader_handle,
+                                                                              &revoc_reg_def_json,
+                                                                              &revoc_reg_entry_json,
+                                                                              100,
+                                                                              &cred_rev_id).unwrap();
+        let rev_state: RevocationState = serde_json::from_str(&rev_state_json).unwrap();
+        assert_eq!(100, rev_state.timestamp);
+
+        //7. Issuer revokes the second Credential
+        let revoc_reg_delta_json = anoncreds::issuer_revoke_credential(issuer_wallet_handle,
+                                                                       blob_storage_reader_handle,
+                                                                       &rev_reg_id,
+                                                                       &cred_rev_id_2).unwrap();
+
+        //8. Prover updates cached Revocation State with delta
+        let timestamp = 200;
+
+        anoncreds::prover_update_cached_revocation_state(prover_wallet_handle,
+                                                         blob_storage_reader_handle,
+                                                         &revoc_reg_def_json,
+                                                         &revoc_reg_delta_json,
+                                                         timestamp,
+                                                         &cred_rev_id).unwrap();
+
+        let rev_state_json = anoncreds::prover_get_cached_revocation_state(prover_wallet_handle, &rev_reg_id, &cred_rev_id).unwrap();
+        let rev_state: RevocationState = serde_json::from_str(&rev_state_json).unwrap();
+        assert_eq!(timestamp, rev_state.timestamp);
+
+        //9. Proof Request
+        let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({}),
+           "non_revoked": json!({ "from":150, "to":200 })
+        }).to_string();
+
+        //10. Prover creates Proof without passing Revocation States
+        let schemas_json = json!({
+            schema_id.clone(): serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
+
+        let credential_defs_json = json!({
+            cred_def_id.clone(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": timestamp,  "revealed":true })
+             }),
+             "requested_predicates": json!({})
+        }).to_string();
+
+        let proof_json = anoncreds::prover_create_proof_with_cached_rev_states(prover_wallet_handle,
+                                                                               &proof_request,
+                                                                               &requested_credentials_json,
+                                                                               COMMON_MASTER_SECRET,
+                                                                               &schemas_json,
+                                                                               &credential_defs_json).unwrap();
+
+        //11. Verifier verifies proof
+        let rev_reg_defs_json = json!({
+            rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
+        }).to_string();
+
+        let rev_regs_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationRegistry>(&revoc_reg_delta_json).unwrap()
+            })
+        }).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&proof_request,
+                                                     &proof_json,
+                                                     &schemas_json,
+                                                     &credential_defs_json,
+                                                     &rev_reg_defs_json,
+                                                     &rev_regs_json).unwrap();
+        assert!(valid);
+
+        //12. Prover cannot create Proof for timestamp not matching cached Revocation State
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": 100,  "revealed":true })
+             }),
+             "requested_predicates": json!({})
+        }).to_string();
+
+        let res = anoncreds::prover_create_proof_with_cached_rev_states(prover_wallet_handle,
+                                                                        &proof_request,
+                                                                        &requested_credentials_json,
+                                                                        COMMON_MASTER_SECRET,
+                                                                        &schemas_json,
+                                                                        &credential_defs_json);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //13. Deleting Credential drops its cached Revocation State
+        anoncreds::prover_delete_credential(prover_wallet_handle, CREDENTIAL1_ID).unwrap();
+
+        let res = anoncreds::prover_get_cached_revocation_state(prover_wallet_handle, &rev_reg_id, &cred_rev_id);
+        assert_code!(ErrorCode::WalletItemNotFound, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
 }
@@ -9,6 +9,7 @@ extern crate indyrs as api;
 use crate::utils::crypto;
 use crate::utils::constants::*;
 use crate::utils::Setup;
+use crate::utils::software_signer::SOFTWARE_SIGNER;
 
 use self::indy::ErrorCode;
 
@@ -35,6 +36,36 @@ mod high_cases {
             let verkey = crypto::create_key(setup.wallet_handle, None).unwrap();
             assert_eq!(verkey.from_base58().unwrap().len(), 32);
         }
+
+        #[test]
+        fn indy_create_key_works_for_ecdsa_crypto_types() {
+            let setup = Setup::wallet();
+
+            for crypto_type in &["secp256k1", "p256"] {
+                let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, Some(MY1_SEED), crypto_type).unwrap();
+
+                let (verkey, verkey_crypto_type) = verkey.split_at(verkey.find(':').unwrap());
+                assert_eq!(format!(":{}", crypto_type), verkey_crypto_type);
+                assert_eq!(verkey.from_base58().unwrap().len(), 33);
+            }
+        }
+
+        #[test]
+        fn indy_create_key_works_for_external_signer() {
+            let setup = Setup::wallet();
+            crypto::register_software_signer();
+
+            let verkey = crypto::create_external_key(setup.wallet_handle, SOFTWARE_SIGNER).unwrap();
+            assert_eq!(verkey.from_base58().unwrap().len(), 32);
+        }
+
+        #[test]
+        fn indy_create_key_works_for_unknown_external_signer() {
+            let setup = Setup::wallet();
+
+            let res = crypto::create_external_key(setup.wallet_handle, "unknown_signer");
+            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+        }
     }
 
     mod set_key_metadata {
@@ -83,6 +114,104 @@ mod high_cases {
         }
     }
 
+    mod list_keys {
+        use super::*;
+
+        #[test]
+        fn indy_list_keys_works() {
+            let setup = Setup::did();
+
+            crypto::set_key_metadata(setup.wallet_handle, &setup.verkey, METADATA).unwrap();
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "secp256k1").unwrap();
+
+            let keys = crypto::list_keys(setup.wallet_handle, None).unwrap();
+            let keys: Vec<serde_json::Value> = serde_json::from_str(&keys).unwrap();
+            assert_eq!(2, keys.len());
+
+            let did_key = keys.iter().find(|key| key["verkey"] == setup.verkey).unwrap();
+            assert_eq!("ed25519", did_key["crypto_type"]);
+            assert_eq!(METADATA, did_key["metadata"]);
+            assert!(did_key["created_at"].as_u64().unwrap() > 1500000000);
+
+            let key = keys.iter().find(|key| key["verkey"] == verkey).unwrap();
+            assert_eq!("secp256k1", key["crypto_type"]);
+            assert!(key["metadata"].is_null());
+        }
+
+        #[test]
+        fn indy_list_keys_works_for_query() {
+            let setup = Setup::did();
+
+            let verkey = crypto::create_key_with_crypto_type(setup.wallet_handle, None, "secp256k1").unwrap();
+
+            let keys = crypto::list_keys(setup.wallet_handle, Some(r#"{"~crypto_type": "secp256k1"}"#)).unwrap();
+            let keys: Vec<serde_json::Value> = serde_json::from_str(&keys).unwrap();
+            assert_eq!(1, keys.len());
+            assert_eq!(verkey, keys[0]["verkey"]);
+
+            let keys = crypto::list_keys(setup.wallet_handle, Some(r#"{"~created_at": {"$lt": "00000000001500000000"}}"#)).unwrap();
+            let keys: Vec<serde_json::Value> = serde_json::from_str(&keys).unwrap();
+            assert_eq!(0, keys.len());
+        }
+
+        #[test]
+        fn indy_list_keys_works_for_empty_wallet() {
+            let setup = Setup::wallet();
+
+            let keys = crypto::list_keys(setup.wallet_handle, None).unwrap();
+            assert_eq!("[]", keys);
+        }
+    }
+
+    mod delete_key {
+        use super::*;
+
+        #[test]
+        fn indy_delete_key_works() {
+            let setup = Setup::wallet();
+
+            let verkey = crypto::create_key(setup.wallet_handle, None).unwrap();
+            crypto::set_key_metadata(setup.wallet_handle, &verkey, METADATA).unwrap();
+
+            crypto::delete_key(setup.wallet_handle, &verkey).unwrap();
+
+            let res = crypto::sign(setup.wallet_handle, &verkey, MESSAGE.as_bytes());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            let res = crypto::get_key_metadata(setup.wallet_handle, &verkey);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_delete_key_works_for_key_used_by_did() {
+            let setup = Setup::did();
+
+            let res = crypto::delete_key(setup.wallet_handle, &setup.verkey);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            crypto::sign(setup.wallet_handle, &setup.verkey, MESSAGE.as_bytes()).unwrap();
+        }
+
+        #[test]
+        fn indy_delete_key_works_for_key_used_by_pairwise() {
+            let setup = Setup::did();
+
+            crate::utils::did::store_their_did_from_parts(setup.wallet_handle, DID, VERKEY).unwrap();
+            crate::utils::pairwise::create_pairwise(setup.wallet_handle, DID, &setup.did, None).unwrap();
+
+            let res = crypto::delete_key(setup.wallet_handle, &setup.verkey);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[test]
+        fn indy_delete_key_works_for_unknown_key() {
+            let setup = Setup::wallet();
+
+            let res = crypto::delete_key(setup.wallet_handle, VERKEY);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
     mod crypto_sign {
         use super::*;
 
@@ -96,6 +225,32 @@ mod high_cases {
             assert_eq!(SIGNATURE.to_vec(), signature);
         }
 
+        #[test]
+        fn indy_crypto_sign_works_for_ecdsa_crypto_types() {
+            let setup = Setup::wallet();
+
+            for crypto_type in &["secp256k1", "p256"] {
+                let my_vk = crypto::create_key_with_crypto_type(setup.wallet_handle, None, crypto_type).unwrap();
+
+                let signature = crypto::sign(setup.wallet_handle, &my_vk, MESSAGE.as_bytes()).unwrap();
+                assert_eq!(64, signature.len());
+
+                assert!(crypto::verify(&my_vk, MESSAGE.as_bytes(), &signature).unwrap());
+                assert!(!crypto::verify(&my_vk, "other message".as_bytes(), &signature).unwrap());
+            }
+        }
+
+        #[test]
+        fn indy_crypto_sign_works_for_external_signer() {
+            let setup = Setup::wallet();
+            crypto::register_software_signer();
+
+            let my_vk = crypto::create_external_key(setup.wallet_handle, SOFTWARE_SIGNER).unwrap();
+
+            let signature = crypto::sign(setup.wallet_handle, &my_vk, MESSAGE.as_bytes()).unwrap();
+            assert!(crypto::verify(&my_vk, MESSAGE.as_bytes(), &signature).unwrap());
+        }
+
         #[test]
         fn indy_crypto_sign_works_for_unknown_signer() {
             let setup = Setup::wallet();
@@ -269,6 +424,46 @@ mod high_cases {
             assert_eq!(res_serialized.recipient_verkey, receiver_setup.verkey);
         }
 
+        #[test]
+        fn indy_unpack_message_authcrypt_works_for_external_signer() {
+            let sender_setup = Setup::wallet();
+            let receiver_setup = Setup::key();
+            crypto::register_software_signer();
+
+            let sender_vk = crypto::create_external_key(sender_setup.wallet_handle, SOFTWARE_SIGNER).unwrap();
+
+            let receiver_keys = serde_json::to_string(&vec![&receiver_setup.verkey]).unwrap();
+            let pack_message = crypto::pack_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_keys, Some(&sender_vk)).unwrap();
+
+            let res = crypto::unpack_message(receiver_setup.wallet_handle, pack_message.as_slice()).unwrap();
+            let res_serialized: UnpackMessage = serde_json::from_slice(res.as_slice()).unwrap();
+
+            assert_eq!(res_serialized.message, AGENT_MESSAGE.to_string());
+            assert_eq!(res_serialized.sender_verkey, sender_vk);
+            assert_eq!(res_serialized.recipient_verkey, receiver_setup.verkey);
+        }
+
+        #[test]
+        fn indy_unpack_message_authcrypt_works_for_ecdsa_crypto_types() {
+            for crypto_type in &["secp256k1", "p256"] {
+                let sender_setup = Setup::wallet();
+                let receiver_setup = Setup::wallet();
+
+                let sender_verkey = crypto::create_key_with_crypto_type(sender_setup.wallet_handle, None, crypto_type).unwrap();
+                let receiver_verkey = crypto::create_key_with_crypto_type(receiver_setup.wallet_handle, None, crypto_type).unwrap();
+
+                let receiver_keys = serde_json::to_string(&vec![&receiver_verkey]).unwrap();
+                let pack_message = crypto::pack_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receiver_keys, Some(&sender_verkey)).unwrap();
+
+                let res = crypto::unpack_message(receiver_setup.wallet_handle, pack_message.as_slice()).unwrap();
+                let res_serialized: UnpackMessage = serde_json::from_slice(res.as_slice()).unwrap();
+
+                assert_eq!(res_serialized.message, AGENT_MESSAGE.to_string());
+                assert_eq!(res_serialized.sender_verkey, sender_verkey);
+                assert_eq!(res_serialized.recipient_verkey, receiver_verkey);
+            }
+        }
+
         #[test]
         fn indy_unpack_message_authcrypt_fails_no_matching_key() {
             //Test Setup
@@ -326,6 +521,295 @@ mod high_cases {
             assert_code!(ErrorCode::WalletItemNotFound, res);
         }
     }
+
+    mod pack_file {
+        use super::*;
+        use crate::utils::environment;
+
+        use std::fs;
+        use std::path::PathBuf;
+
+        // Larger than single chunk
+        const PAYLOAD_SIZE: usize = 200 * 1024 + 17;
+
+        fn _paths(name: &str) -> (PathBuf, PathBuf, PathBuf) {
+            let input = environment::tmp_file_path(&format!("{}.plain", name));
+            let packed = environment::tmp_file_path(&format!("{}.packed", name));
+            let unpacked = environment::tmp_file_path(&format!("{}.unpacked", name));
+
+            for path in &[&input, &packed, &unpacked] {
+                fs::remove_file(path).ok();
+            }
+
+            fs::create_dir_all(input.parent().unwrap()).unwrap();
+
+            let payload: Vec<u8> = (0..PAYLOAD_SIZE).map(|i| (i % 251) as u8).collect();
+            fs::write(&input, &payload).unwrap();
+
+            (input, packed, unpacked)
+        }
+
+        #[test]
+        fn indy_pack_file_authcrypt_works() {
+            let sender_setup = Setup::key();
+            let receiver_setup = Setup::key();
+            let (input, packed, unpacked) = _paths(&sender_setup.name);
+
+            let receiver_keys = serde_json::to_string(&vec![VERKEY_TRUSTEE, &receiver_setup.verkey]).unwrap();
+            crypto::pack_file(sender_setup.wallet_handle, input.to_str().unwrap(), packed.to_str().unwrap(), &receiver_keys, Some(&sender_setup.verkey)).unwrap();
+
+            let res = crypto::unpack_file(receiver_setup.wallet_handle, packed.to_str().unwrap(), unpacked.to_str().unwrap()).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            assert_eq!(json!({"sender_verkey": sender_setup.verkey, "recipient_verkey": receiver_setup.verkey}), res);
+            assert_eq!(fs::read(&input).unwrap(), fs::read(&unpacked).unwrap());
+        }
+
+        #[test]
+        fn indy_pack_file_anoncrypt_works() {
+            let sender_setup = Setup::wallet();
+            let receiver_setup = Setup::key();
+            let (input, packed, unpacked) = _paths(&receiver_setup.name);
+
+            let receiver_keys = serde_json::to_string(&vec![&receiver_setup.verkey]).unwrap();
+            crypto::pack_file(sender_setup.wallet_handle, input.to_str().unwrap(), packed.to_str().unwrap(), &receiver_keys, None).unwrap();
+
+            let res = crypto::unpack_file(receiver_setup.wallet_handle, packed.to_str().unwrap(), unpacked.to_str().unwrap()).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            assert_eq!(json!({"recipient_verkey": receiver_setup.verkey}), res);
+            assert_eq!(fs::read(&input).unwrap(), fs::read(&unpacked).unwrap());
+        }
+
+        #[test]
+        fn indy_unpack_file_works_for_truncated_file() {
+            let sender_setup = Setup::key();
+            let receiver_setup = Setup::key();
+            let (input, packed, unpacked) = _paths(&sender_setup.name);
+
+            let receiver_keys = serde_json::to_string(&vec![&receiver_setup.verkey]).unwrap();
+            crypto::pack_file(sender_setup.wallet_handle, input.to_str().unwrap(), packed.to_str().unwrap(), &receiver_keys, Some(&sender_setup.verkey)).unwrap();
+
+            let mut content = fs::read(&packed).unwrap();
+            let len = content.len();
+            content.truncate(len - 1024);
+            fs::write(&packed, &content).unwrap();
+
+            let res = crypto::unpack_file(receiver_setup.wallet_handle, packed.to_str().unwrap(), unpacked.to_str().unwrap());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+            assert!(!unpacked.exists());
+        }
+
+        #[test]
+        fn indy_unpack_file_works_for_unknown_recipient() {
+            let sender_setup = Setup::key();
+            let receiver_setup = Setup::wallet();
+            let (input, packed, unpacked) = _paths(&sender_setup.name);
+
+            let receiver_keys = serde_json::to_string(&vec![VERKEY_TRUSTEE]).unwrap();
+            crypto::pack_file(sender_setup.wallet_handle, input.to_str().unwrap(), packed.to_str().unwrap(), &receiver_keys, None).unwrap();
+
+            let res = crypto::unpack_file(receiver_setup.wallet_handle, packed.to_str().unwrap(), unpacked.to_str().unwrap());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod pack_didcomm_message {
+        use super::*;
+        use crate::utils::did;
+
+        #[derive(Deserialize, Debug)]
+        pub struct UnpackMessage {
+            pub message: String,
+            pub recipient_verkey: String,
+            pub recipient_kid: String,
+            pub sender_verkey: Option<String>,
+            pub sender_kid: Option<String>,
+        }
+
+        fn _exchange_dids(first: &Setup, second: &Setup) {
+            did::store_their_did_from_parts(first.wallet_handle, &second.did, &second.verkey).unwrap();
+            did::store_their_did_from_parts(second.wallet_handle, &first.did, &first.verkey).unwrap();
+        }
+
+        #[test]
+        fn indy_pack_didcomm_message_authcrypt_works() {
+            let sender_setup = Setup::did();
+            let receiver_setup = Setup::did();
+            _exchange_dids(&sender_setup, &receiver_setup);
+
+            let receivers = json!([receiver_setup.did]).to_string();
+            let packed = crypto::pack_didcomm_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receivers, Some(&sender_setup.did), None).unwrap();
+
+            let packed_json: serde_json::Value = serde_json::from_slice(&packed).unwrap();
+            assert_eq!(format!("did:sov:{}#key-1", receiver_setup.did), packed_json["recipients"][0]["header"]["kid"].as_str().unwrap());
+
+            let res = crypto::unpack_message(receiver_setup.wallet_handle, &packed).unwrap();
+            let res: UnpackMessage = serde_json::from_slice(&res).unwrap();
+
+            assert_eq!(AGENT_MESSAGE, res.message);
+            assert_eq!(receiver_setup.verkey, res.recipient_verkey);
+            assert_eq!(format!("did:sov:{}#key-1", receiver_setup.did), res.recipient_kid);
+            assert_eq!(Some(sender_setup.verkey.clone()), res.sender_verkey);
+            assert_eq!(Some(format!("did:sov:{}#key-1", sender_setup.did)), res.sender_kid);
+        }
+
+        #[test]
+        fn indy_pack_didcomm_message_authcrypt_works_for_a256cbc_hs512_by_default() {
+            let sender_setup = Setup::did();
+            let receiver_setup = Setup::did();
+            _exchange_dids(&sender_setup, &receiver_setup);
+
+            let receivers = json!([receiver_setup.did]).to_string();
+            let packed = crypto::pack_didcomm_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receivers, Some(&sender_setup.did), None).unwrap();
+
+            let packed_json: serde_json::Value = serde_json::from_slice(&packed).unwrap();
+            let protected = base64::decode_config(packed_json["protected"].as_str().unwrap(), base64::URL_SAFE_NO_PAD).unwrap();
+            let protected: serde_json::Value = serde_json::from_slice(&protected).unwrap();
+            assert_eq!("A256CBC-HS512", protected["enc"].as_str().unwrap());
+        }
+
+        #[test]
+        fn indy_pack_didcomm_message_authcrypt_fails_for_a256gcm() {
+            let sender_setup = Setup::did();
+            let receiver_setup = Setup::did();
+            _exchange_dids(&sender_setup, &receiver_setup);
+
+            let receivers = json!([receiver_setup.did]).to_string();
+            let res = crypto::pack_didcomm_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receivers, Some(&sender_setup.did), Some(r#"{"enc":"A256GCM"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_pack_didcomm_message_anoncrypt_works_for_xc20p() {
+            let sender_setup = Setup::did();
+            let receiver_setup = Setup::did();
+            _exchange_dids(&sender_setup, &receiver_setup);
+
+            let receivers = json!([format!("did:sov:{}#key-1", receiver_setup.did)]).to_string();
+            let packed = crypto::pack_didcomm_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receivers, None, Some(r#"{"enc":"XC20P"}"#)).unwrap();
+
+            let res = crypto::unpack_message(receiver_setup.wallet_handle, &packed).unwrap();
+            let res: UnpackMessage = serde_json::from_slice(&res).unwrap();
+
+            assert_eq!(AGENT_MESSAGE, res.message);
+            assert_eq!(receiver_setup.verkey, res.recipient_verkey);
+            assert_eq!(None, res.sender_verkey);
+            assert_eq!(None, res.sender_kid);
+        }
+
+        #[test]
+        fn indy_pack_didcomm_message_works_for_unknown_receiver() {
+            let sender_setup = Setup::did();
+            let receiver_setup = Setup::did();
+
+            let receivers = json!([receiver_setup.did]).to_string();
+            let res = crypto::pack_didcomm_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receivers, Some(&sender_setup.did), None);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_unpack_message_works_for_didcomm_message_to_other_receiver() {
+            let sender_setup = Setup::did();
+            let receiver_setup = Setup::did();
+            let other_setup = Setup::did();
+            _exchange_dids(&sender_setup, &receiver_setup);
+
+            let receivers = json!([receiver_setup.did]).to_string();
+            let packed = crypto::pack_didcomm_message(sender_setup.wallet_handle, AGENT_MESSAGE.as_bytes(), &receivers, None, None).unwrap();
+
+            let res = crypto::unpack_message(other_setup.wallet_handle, &packed);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod sign_jws {
+        use super::*;
+        use crate::utils::did;
+
+        #[test]
+        fn indy_crypto_sign_jws_works() {
+            let setup = Setup::did();
+
+            let jws = crypto::sign_jws(setup.wallet_handle, &setup.did, MESSAGE.as_bytes(), None).unwrap();
+            assert_eq!(3, jws.split('.').count());
+
+            assert!(crypto::verify_jws(setup.wallet_handle, &jws, None).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_sign_jws_works_for_detached_payload() {
+            let setup = Setup::did();
+
+            let jws = crypto::sign_jws(setup.wallet_handle, &setup.did, MESSAGE.as_bytes(), Some(r#"{"detached":true}"#)).unwrap();
+            assert!(jws.contains(".."));
+
+            assert!(crypto::verify_jws(setup.wallet_handle, &jws, Some(MESSAGE.as_bytes())).unwrap());
+            assert!(!crypto::verify_jws(setup.wallet_handle, &jws, Some("other message".as_bytes())).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_their_did() {
+            let signer_setup = Setup::did();
+            let verifier_setup = Setup::wallet();
+
+            let identity_json = json!({"did": signer_setup.did, "verkey": signer_setup.verkey}).to_string();
+            did::store_their_did(verifier_setup.wallet_handle, &identity_json).unwrap();
+
+            let jws = crypto::sign_jws(signer_setup.wallet_handle, &signer_setup.did, MESSAGE.as_bytes(), None).unwrap();
+
+            assert!(crypto::verify_jws(verifier_setup.wallet_handle, &jws, None).unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_unknown_did() {
+            let signer_setup = Setup::did();
+            let verifier_setup = Setup::wallet();
+
+            let jws = crypto::sign_jws(signer_setup.wallet_handle, &signer_setup.did, MESSAGE.as_bytes(), None).unwrap();
+
+            let res = crypto::verify_jws(verifier_setup.wallet_handle, &jws, None);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_crypto_verify_jws_works_for_missed_detached_payload() {
+            let setup = Setup::did();
+
+            let jws = crypto::sign_jws(setup.wallet_handle, &setup.did, MESSAGE.as_bytes(), Some(r#"{"detached":true}"#)).unwrap();
+
+            let res = crypto::verify_jws(setup.wallet_handle, &jws, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod sign_jwt {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_sign_jwt_works() {
+            let setup = Setup::did();
+
+            let jwt = crypto::sign_jwt(setup.wallet_handle, &setup.did, r#"{"sub":"alice"}"#, 300).unwrap();
+
+            let claims = crypto::verify_jwt(setup.wallet_handle, &jwt).unwrap();
+            let claims: serde_json::Value = serde_json::from_str(&claims).unwrap();
+
+            assert_eq!("alice", claims["sub"].as_str().unwrap());
+            assert_eq!(setup.did, claims["iss"].as_str().unwrap());
+            assert_eq!(claims["iat"].as_u64().unwrap() + 300, claims["exp"].as_u64().unwrap());
+        }
+
+        #[test]
+        fn indy_crypto_verify_jwt_works_for_expired_token() {
+            let setup = Setup::did();
+
+            let jwt = crypto::sign_jwt(setup.wallet_handle, &setup.did, r#"{"sub":"alice","exp":1}"#, 0).unwrap();
+
+            let res = crypto::verify_jwt(setup.wallet_handle, &jwt);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
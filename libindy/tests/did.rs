@@ -82,6 +82,14 @@ mod high_cases {
             let received_verkey = did::key_for_did(-1, setup.wallet_handle, DID_V1).unwrap();
             assert_eq!(VERKEY, received_verkey);
         }
+
+        #[test]
+        fn indy_key_for_did_works_for_unknown_did_key() {
+            let setup = Setup::wallet();
+
+            let received_verkey = did::key_for_did(-1, setup.wallet_handle, DID_KEY).unwrap();
+            assert_eq!(VERKEY_DID_KEY, received_verkey);
+        }
     }
 
     mod key_for_local_did {
@@ -120,6 +128,125 @@ mod high_cases {
             let received_verkey = did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap();
             assert_eq!(setup.verkey, received_verkey);
         }
+
+        #[test]
+        fn indy_key_for_local_did_works_for_unknown_did_key() {
+            let setup = Setup::wallet();
+
+            let received_verkey = did::key_for_local_did(setup.wallet_handle, DID_KEY).unwrap();
+            assert_eq!(VERKEY_DID_KEY, received_verkey);
+        }
+
+        #[test]
+        fn indy_key_for_local_did_works_for_invalid_did_key() {
+            let setup = Setup::wallet();
+
+            let res = did::key_for_local_did(setup.wallet_handle, "did:key:z6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc");
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+    }
+
+    mod resolve_did {
+        use super::*;
+
+        #[test]
+        fn indy_resolve_did_works_for_my_did() {
+            let setup = Setup::did();
+
+            did::set_endpoint_for_did(setup.wallet_handle, &setup.did, ENDPOINT, VERKEY).unwrap();
+
+            let result = did::resolve_did(-1, setup.wallet_handle, &setup.did).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            let did_doc = &result["didDocument"];
+            assert_eq!(json!(format!("did:sov:{}", setup.did)), did_doc["id"]);
+            assert_eq!(json!(setup.verkey), did_doc["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!("Ed25519VerificationKey2018"), did_doc["verificationMethod"][0]["type"]);
+            assert_eq!(json!([format!("did:sov:{}#key-1", setup.did)]), did_doc["authentication"]);
+            assert_eq!(json!(ENDPOINT), did_doc["service"][0]["serviceEndpoint"]);
+            assert_eq!(json!([VERKEY]), did_doc["service"][0]["routingKeys"]);
+            assert_eq!(json!("wallet"), result["didResolutionMetadata"]["source"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_their_did() {
+            let setup = Setup::wallet();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_V1, VERKEY).unwrap();
+
+            let result = did::resolve_did(-1, setup.wallet_handle, DID_V1).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            assert_eq!(json!(DID_V1), result["didDocument"]["id"]);
+            assert_eq!(json!(VERKEY), result["didDocument"]["verificationMethod"][0]["publicKeyBase58"]);
+            assert!(result["didDocument"]["service"].is_null());
+            assert_eq!(json!("wallet"), result["didResolutionMetadata"]["source"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_did_key() {
+            let setup = Setup::wallet();
+
+            let result = did::resolve_did(-1, setup.wallet_handle, DID_KEY).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            assert_eq!(json!(DID_KEY), result["didDocument"]["id"]);
+            assert_eq!(json!(VERKEY_DID_KEY), result["didDocument"]["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!("identifier"), result["didResolutionMetadata"]["source"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_did_peer_with_key_agreement() {
+            let setup = Setup::wallet();
+
+            let my_did_json = json!({"method_name": "peer", "peer_numalgo": 2}).to_string();
+            let (my_did, _) = did::create_my_did(setup.wallet_handle, &my_did_json).unwrap();
+
+            let result = did::resolve_did(-1, setup.wallet_handle, &my_did).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            assert_eq!(json!("X25519KeyAgreementKey2019"), result["didDocument"]["verificationMethod"][1]["type"]);
+            assert_eq!(json!([format!("{}#key-agreement-1", my_did)]), result["didDocument"]["keyAgreement"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_from_ledger() {
+            let setup = Setup::wallet_and_pool();
+
+            let result = did::resolve_did(setup.pool_handle, setup.wallet_handle, DID_TRUSTEE).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            assert_eq!(json!(VERKEY_TRUSTEE), result["didDocument"]["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!("ledger"), result["didResolutionMetadata"]["source"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_from_ledger_with_endpoint() {
+            let setup = Setup::new_identity();
+
+            let attrib_data = json!({"endpoint": {"ha": ENDPOINT}}).to_string();
+            let attrib_request = ledger::build_attrib_request(&setup.did, &setup.did, None, Some(&attrib_data), None).unwrap();
+            ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &attrib_request).unwrap();
+
+            thread::sleep(std::time::Duration::from_secs(1));
+
+            let other_setup = Setup::wallet();
+
+            let result = did::resolve_did(setup.pool_handle, other_setup.wallet_handle, &setup.did).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            assert_eq!(json!(setup.verkey), result["didDocument"]["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!(ENDPOINT), result["didDocument"]["service"][0]["serviceEndpoint"]);
+            assert_eq!(json!("ledger"), result["didResolutionMetadata"]["source"]);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_unknown_did() {
+            let setup = Setup::wallet_and_pool();
+
+            let res = did::resolve_did(setup.pool_handle, setup.wallet_handle, DID);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
     }
 
     mod set_endpoint_for_did {
@@ -456,6 +583,49 @@ mod high_cases {
             let res = did::create_my_did(setup.wallet_handle, &json!({"did": did}).to_string());
             assert_code!(ErrorCode::DidAlreadyExistsError, res);
         }
+
+        #[test]
+        fn indy_create_my_did_works_for_did_key_method() {
+            let setup = Setup::wallet();
+
+            let my_did_json = json!({"method_name": "key", "seed": MY1_SEED}).to_string();
+            let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, &my_did_json).unwrap();
+
+            assert!(my_did.starts_with("did:key:z6Mk"));
+            assert_eq!(VERKEY_MY1, my_verkey);
+            assert_eq!(my_verkey, did::key_for_local_did(setup.wallet_handle, &my_did).unwrap());
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_did_peer_method() {
+            let setup = Setup::wallet();
+
+            let my_did_json = json!({"method_name": "peer", "seed": MY1_SEED}).to_string();
+            let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, &my_did_json).unwrap();
+
+            assert!(my_did.starts_with("did:peer:0z6Mk"));
+            assert_eq!(my_verkey, did::key_for_local_did(setup.wallet_handle, &my_did).unwrap());
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_did_peer_method_and_numalgo_2() {
+            let setup = Setup::wallet();
+
+            let my_did_json = json!({"method_name": "peer", "peer_numalgo": 2, "seed": MY1_SEED}).to_string();
+            let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, &my_did_json).unwrap();
+
+            assert!(my_did.starts_with("did:peer:2.Ez6LS"));
+            assert_eq!(my_verkey, did::key_for_local_did(setup.wallet_handle, &my_did).unwrap());
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_unsupported_peer_numalgo() {
+            let setup = Setup::wallet();
+
+            let my_did_json = json!({"method_name": "peer", "peer_numalgo": 1}).to_string();
+            let res = did::create_my_did(setup.wallet_handle, &my_did_json);
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
     }
 
     mod replace_keys_start {
@@ -615,6 +785,92 @@ mod high_cases {
         }
     }
 
+    mod rotate_did_key {
+        use super::*;
+
+        #[test]
+        fn indy_rotate_did_key_works() {
+            let setup = Setup::new_identity();
+
+            let new_verkey = did::rotate_did_key(setup.wallet_handle, setup.pool_handle, &setup.did, "{}").unwrap();
+            assert_ne!(setup.verkey, new_verkey);
+
+            assert_eq!(new_verkey, did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap());
+
+            let did_with_meta = did::get_my_did_with_metadata(setup.wallet_handle, &setup.did).unwrap();
+            let did_with_meta: serde_json::Value = serde_json::from_str(&did_with_meta).unwrap();
+            assert!(did_with_meta["tempVerkey"].is_null());
+
+            let schema_request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+            let response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &schema_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+
+        #[test]
+        fn indy_rotate_did_key_works_for_rejected_nym() {
+            let setup = Setup::wallet_and_pool();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let res = did::rotate_did_key(setup.wallet_handle, setup.pool_handle, &my_did, "{}");
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+
+            assert_eq!(my_verkey, did::key_for_local_did(setup.wallet_handle, &my_did).unwrap());
+
+            let res = did::replace_keys_apply(setup.wallet_handle, &my_did);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_rotate_did_key_works_twice_after_unknown_state_for_not_written_nym() {
+            let setup = Setup::new_identity();
+
+            // Temporary DID is left in the wallet the same way as after the rotation with unknown ledger state
+            let temp_verkey = did::replace_keys_start(setup.wallet_handle, &setup.did, "{}").unwrap();
+
+            let new_verkey = did::rotate_did_key(setup.wallet_handle, setup.pool_handle, &setup.did, "{}").unwrap();
+            assert_ne!(temp_verkey, new_verkey);
+            assert_eq!(new_verkey, did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap());
+
+            let new_verkey = did::rotate_did_key(setup.wallet_handle, setup.pool_handle, &setup.did, "{}").unwrap();
+            assert_eq!(new_verkey, did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap());
+
+            let schema_request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+            let response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &schema_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+
+        #[test]
+        fn indy_rotate_did_key_works_twice_after_unknown_state_for_written_nym() {
+            let setup = Setup::new_identity();
+
+            // NYM with temporary key is written, but the key isn't applied in the wallet
+            let temp_verkey = did::replace_keys_start(setup.wallet_handle, &setup.did, "{}").unwrap();
+            let nym_request = ledger::build_nym_request(&setup.did, &setup.did, Some(&temp_verkey), None, None).unwrap();
+            let response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            let new_verkey = did::rotate_did_key(setup.wallet_handle, setup.pool_handle, &setup.did, "{}").unwrap();
+            assert_ne!(temp_verkey, new_verkey);
+            assert_eq!(new_verkey, did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap());
+
+            let new_verkey = did::rotate_did_key(setup.wallet_handle, setup.pool_handle, &setup.did, "{}").unwrap();
+            assert_eq!(new_verkey, did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap());
+
+            let schema_request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+            let response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &schema_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+
+        #[test]
+        fn indy_rotate_did_key_works_for_unknown_did() {
+            let setup = Setup::wallet_and_pool();
+
+            let res = did::rotate_did_key(setup.wallet_handle, setup.pool_handle, DID, "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
     mod abbreviate_verkey {
         use super::*;
 
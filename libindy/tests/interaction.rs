@@ -22,7 +22,7 @@ use crate::utils::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::utils::domain::anoncreds::credential::Credential;
 use crate::utils::domain::anoncreds::credential::CredentialInfo;
 use crate::utils::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinition;
-use crate::utils::domain::anoncreds::proof::Proof;
+use crate::utils::domain::anoncreds::proof::{Proof, ProofVerificationReport, VerificationCheck};
 use crate::utils::domain::anoncreds::revocation_state::RevocationState;
 use crate::utils::domain::anoncreds::revocation_registry::RevocationRegistry;
 
@@ -461,7 +461,34 @@ impl Verifier {
 
     pub fn verify(&self, pool: &Pool, proof_json: &str) -> bool
     {
+        let (schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json) = self.get_ledger_entities(pool, proof_json);
 
+        let valid = anoncreds::verifier_verify_proof(&self.proof_request,
+                                                     proof_json,
+                                                     &schemas_json,
+                                                     &cred_defs_json,
+                                                     &rev_reg_defs_json,
+                                                     &rev_regs_json).unwrap();
+
+        valid
+    }
+
+    pub fn verify_detailed(&self, pool: &Pool, proof_json: &str) -> ProofVerificationReport
+    {
+        let (schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json) = self.get_ledger_entities(pool, proof_json);
+
+        let report_json = anoncreds::verifier_verify_proof_detailed(&self.proof_request,
+                                                                    proof_json,
+                                                                    &schemas_json,
+                                                                    &cred_defs_json,
+                                                                    &rev_reg_defs_json,
+                                                                    &rev_regs_json).unwrap();
+
+        serde_json::from_str(&report_json).unwrap()
+    }
+
+    fn get_ledger_entities(&self, pool: &Pool, proof_json: &str) -> (String, String, String, String) /* schemas, cred_defs, rev_reg_defs, rev_regs */
+    {
         let proof: Proof = serde_json::from_str(&proof_json).unwrap();
         assert_eq!(1, proof.identifiers.len());
 
@@ -498,14 +525,7 @@ impl Verifier {
             })
         }).to_string();
 
-        let valid = anoncreds::verifier_verify_proof(&self.proof_request,
-                                                     proof_json,
-                                                     &schemas_json,
-                                                     &cred_defs_json,
-                                                     &rev_reg_defs_json,
-                                                     &rev_regs_json).unwrap();
-
-        valid
+        (schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json)
     }
 }
 
@@ -611,6 +631,12 @@ fn anoncreds_revocation_interaction_test_one_prover(revocation_registry_config:
     let valid = verifier.verify(&pool, &proof_json);
     assert!(!valid);
 
+    // Revoked credential can't be told apart from invalid CL proof
+    let report = verifier.verify_detailed(&pool, &proof_json);
+    assert!(!report.valid);
+    assert_eq!(VerificationCheck::Signature, report.failures[0].check);
+    assert!(report.referents.values().all(|referent| referent.valid));
+
 
     issuer.close();
     prover.close();
@@ -8,7 +8,8 @@ extern crate indyrs as api;
 
 use self::indy::ErrorCode;
 #[cfg(feature = "local_nodes_pool")]
-use crate::utils::{pool, ledger, did, anoncreds};
+use crate::utils::{pool, ledger, did, anoncreds, crypto};
+use crate::utils::software_signer::SOFTWARE_SIGNER;
 use crate::utils::types::*;
 use crate::utils::constants::*;
 use crate::utils::Setup;
@@ -195,6 +196,36 @@ mod high_cases {
             assert_eq!(request["signature"].as_str().unwrap(), "65hzs4nsdQsTUqLCLy2qisbKLfwYKZSWoyh1C6CU59p5pfG3EHQXGAsjW4Qw4QdwkrvjSgQuyv8qyABcXRBznFKW");
         }
 
+        #[test]
+        fn indy_sign_request_works_for_external_signer() {
+            use rust_base58::FromBase58;
+
+            let setup = Setup::wallet();
+            crypto::register_software_signer();
+
+            let (did, _) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let key_info = json!({"signer": SOFTWARE_SIGNER}).to_string();
+            let verkey = did::replace_keys_start(setup.wallet_handle, &did, &key_info).unwrap();
+            did::replace_keys_apply(setup.wallet_handle, &did).unwrap();
+
+            let request = ledger::sign_request(setup.wallet_handle, &did, REQUEST).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            let signature = request["signature"].as_str().unwrap().from_base58().unwrap();
+            assert_eq!(64, signature.len());
+            assert_eq!(verkey, did::key_for_local_did(setup.wallet_handle, &did).unwrap());
+
+            // signature input of REQUEST: sorted fields joined by "|" without signature
+            let signature_input = "identifier:GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL|\
+                                   operation:dest:VsKV7grR1BUE29mG2Fm2kX|type:1|verkey:GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa|\
+                                   reqId:1496822211362017764";
+            assert!(crypto::verify(&verkey, signature_input.as_bytes(), &signature).unwrap());
+
+            let tampered_signature_input = signature_input.replace("dest:VsKV7grR1BUE29mG2Fm2kX", &format!("dest:{}", DID_MY1));
+            assert!(!crypto::verify(&verkey, tampered_signature_input.as_bytes(), &signature).unwrap());
+        }
+
         #[test]
         fn indy_sign_works_for_unknown_signer() {
             let setup = Setup::wallet();
@@ -162,6 +162,135 @@ mod high_cases {
             assert_code!(ErrorCode::WalletItemNotFound, res);
         }
     }
+
+    mod search_pairwise {
+        use super::*;
+
+        #[test]
+        fn indy_search_pairwise_works() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, r#"{"label": "trustee", "state": "active"}"#).unwrap();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID, VERKEY).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID, &setup.did, None).unwrap();
+            pairwise::update_pairwise(setup.wallet_handle, DID, r#"{"label": "steward", "state": "invited"}"#).unwrap();
+
+            let list_pairwise_json = pairwise::search_pairwise(setup.wallet_handle, Some(r#"{"~state": "active"}"#)).unwrap();
+            let list_pairwise: Vec<String> = serde_json::from_str(&list_pairwise_json).unwrap();
+
+            assert_eq!(list_pairwise.len(), 1);
+            assert!(list_pairwise.contains(&format!(r#"{{"my_did":"{}","their_did":"{}"}}"#, setup.did, DID_TRUSTEE)));
+
+            let list_pairwise_json = pairwise::search_pairwise(setup.wallet_handle, Some(r#"{"~label": {"$like": "stew%"}}"#)).unwrap();
+            let list_pairwise: Vec<String> = serde_json::from_str(&list_pairwise_json).unwrap();
+
+            assert_eq!(list_pairwise.len(), 1);
+            assert!(list_pairwise.contains(&format!(r#"{{"my_did":"{}","their_did":"{}"}}"#, setup.did, DID)));
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_created_at() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            let list_pairwise_json = pairwise::search_pairwise(setup.wallet_handle, Some(r#"{"~created_at": {"$gt": "00000000001500000000"}}"#)).unwrap();
+            let list_pairwise: Vec<String> = serde_json::from_str(&list_pairwise_json).unwrap();
+            assert_eq!(list_pairwise.len(), 1);
+
+            let list_pairwise_json = pairwise::search_pairwise(setup.wallet_handle, Some(r#"{"~created_at": {"$lt": "00000000001500000000"}}"#)).unwrap();
+            let list_pairwise: Vec<String> = serde_json::from_str(&list_pairwise_json).unwrap();
+            assert_eq!(list_pairwise.len(), 0);
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_empty_query() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            let list_pairwise_json = pairwise::search_pairwise(setup.wallet_handle, None).unwrap();
+            let list_pairwise: Vec<String> = serde_json::from_str(&list_pairwise_json).unwrap();
+
+            assert_eq!(list_pairwise.len(), 1);
+        }
+    }
+
+    mod update_pairwise {
+        use super::*;
+
+        #[test]
+        fn indy_update_pairwise_works_for_my_did() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(METADATA)).unwrap();
+
+            let (new_did, _) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, &json!({"my_did": new_did}).to_string()).unwrap();
+
+            let pairwise_info_json = pairwise::get_pairwise(setup.wallet_handle, DID_TRUSTEE).unwrap();
+            assert_eq!(format!(r#"{{"my_did":"{}","metadata":"{}"}}"#, new_did, METADATA), pairwise_info_json);
+        }
+
+        #[test]
+        fn indy_update_pairwise_works_for_not_found_my_did() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            let res = pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, &json!({"my_did": DID}).to_string());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_update_pairwise_works_for_not_created_pairwise() {
+            let setup = Setup::wallet();
+
+            let res = pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, r#"{"state": "active"}"#);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_update_pairwise_works_for_empty_update() {
+            let setup = Setup::wallet();
+
+            let res = pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod delete_pairwise {
+        use super::*;
+
+        #[test]
+        fn indy_delete_pairwise_works() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            pairwise::delete_pairwise(setup.wallet_handle, DID_TRUSTEE).unwrap();
+
+            assert!(!pairwise::pairwise_exists(setup.wallet_handle, DID_TRUSTEE).unwrap());
+            did::key_for_local_did(setup.wallet_handle, DID_TRUSTEE).unwrap();
+        }
+
+        #[test]
+        fn indy_delete_pairwise_works_for_not_created_pairwise() {
+            let setup = Setup::wallet();
+
+            let res = pairwise::delete_pairwise(setup.wallet_handle, DID_TRUSTEE);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -86,6 +86,10 @@ pub fn issuer_merge_revocation_registry_deltas(rev_reg_delta: &str, other_rev_re
     anoncreds::issuer_merge_revocation_registry_deltas(rev_reg_delta, other_rev_reg_delta).wait()
 }
 
+pub fn encode_credential_values(cred_values_json: &str) -> Result<String, IndyError> {
+    anoncreds::encode_credential_values(cred_values_json).wait()
+}
+
 pub fn prover_create_master_secret(wallet_handle: i32, master_secret_id: &str) -> Result<String, IndyError> {
     anoncreds::prover_create_master_secret(wallet_handle, Some(master_secret_id)).wait()
 }
@@ -163,6 +167,16 @@ pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_verify_proof_with_config(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                         cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, config_json: &str) -> Result<bool, IndyError> {
+    anoncreds::verifier_verify_proof_with_config(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json, config_json).wait()
+}
+
+pub fn verifier_verify_proof_detailed(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                      cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_verify_proof_detailed(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -173,6 +187,29 @@ pub fn update_revocation_state(tails_reader_handle: i32, rev_state_json: &str, r
     anoncreds::update_revocation_state(tails_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
 }
 
+pub fn prover_create_proof_with_cached_rev_states(wallet_handle: i32, proof_req_json: &str, requested_credentials_json: &str,
+                                                  master_secret_name: &str, schemas_json: &str, cred_defs_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_create_proof_with_cached_rev_states(wallet_handle, proof_req_json, requested_credentials_json,
+                                                          master_secret_name, schemas_json, cred_defs_json).wait()
+}
+
+pub fn prover_update_cached_revocation_state(wallet_handle: i32, tails_reader_handle: i32, rev_reg_def_json: &str,
+                                             rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
+    anoncreds::prover_update_cached_revocation_state(wallet_handle, tails_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
+}
+
+pub fn prover_get_cached_revocation_state(wallet_handle: i32, rev_reg_id: &str, cred_rev_id: &str) -> Result<String, IndyError> {
+    anoncreds::prover_get_cached_revocation_state(wallet_handle, rev_reg_id, cred_rev_id).wait()
+}
+
+pub fn prover_check_revocation_status(wallet_handle: i32, cred_id: &str, rev_reg_def_json: &str, rev_reg_delta_json: &str, timestamp: u64) -> Result<String, IndyError> {
+    anoncreds::prover_check_revocation_status(wallet_handle, cred_id, rev_reg_def_json, rev_reg_delta_json, timestamp).wait()
+}
+
+pub fn verifier_validate_proof_request(proof_request_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_validate_proof_request(proof_request_json).wait()
+}
+
 pub fn generate_nonce() -> Result<String, IndyError> {
     anoncreds::generate_nonce().wait()
 }
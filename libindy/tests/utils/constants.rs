@@ -12,6 +12,7 @@ pub const ISSUER_DID_V1: &'static str = "did:sov:NcYxiDXkpYi6ov5FcYDi1e";
 pub const ISSUER_DID_2: &'static str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
 pub const DID: &'static str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
 pub const DID_V1: &'static str = "did:sov:CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+pub const DID_KEY: &'static str = "did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH";
 pub const DID_MY1: &'static str = "VsKV7grR1BUE29mG2Fm2kX";
 pub const DID_MY1_V1: &'static str = "did:sov:VsKV7grR1BUE29mG2Fm2kX";
 pub const DID_MY2: &'static str = "2PRyVHmkXQnQzJQKxHxnXC";
@@ -30,6 +31,7 @@ pub const XYZ_SCHEMA_ATTRIBUTES: &'static str = r#"["status", "period"]"#;
 pub const SCHEMA_DATA: &'static str = r#"{"id":"1", "name":"gvt","version":"1.0","attrNames":["name"],"ver":"1.0"}"#;
 pub const ENDPOINT: &'static str = "127.0.0.1:9700";
 pub const VERKEY: &'static str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+pub const VERKEY_DID_KEY: &'static str = "B12NYF8RrR3h41TDCTJojY59usg3mbtbjnFs7Eud1Y6u";
 pub const VERKEY_MY1: &'static str = "GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa";
 pub const INVALID_VERKEY_LENGTH: &'static str = "invalidVerkeyLength";
 pub const INVALID_BASE58_VERKEY: &'static str = "CnEDk___MnmiHXEV1WFgbV___eYnPqs___TdcZaNhFVW";
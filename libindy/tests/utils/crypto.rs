@@ -1,14 +1,62 @@
 extern crate futures;
 
-use indy::IndyError;
+use indy::{ErrorCode, IndyError};
 use indy::crypto;
 use self::futures::Future;
 
+use crate::utils::callback;
+use crate::utils::software_signer::{SoftwareSigner, SOFTWARE_SIGNER};
+
+use std::ffi::CString;
+use std::sync::Mutex;
+use super::libc::{c_char, c_void};
+
+pub fn register_software_signer() {
+    lazy_static! {
+        static ref REGISTERED: Mutex<bool> = Default::default();
+    }
+
+    let mut registered = REGISTERED.lock().unwrap();
+
+    if *registered {
+        return;
+    }
+
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let signer_name = CString::new(SOFTWARE_SIGNER).unwrap();
+
+    let err = unsafe {
+        indy_register_external_signer(command_handle,
+                                      signer_name.as_ptr(),
+                                      Some(SoftwareSigner::create_key),
+                                      Some(SoftwareSigner::sign),
+                                      Some(SoftwareSigner::crypto_box),
+                                      Some(SoftwareSigner::free),
+                                      cb)
+    };
+
+    assert_eq!(ErrorCode::Success, err);
+    assert_eq!(ErrorCode::Success, receiver.recv().unwrap());
+
+    *registered = true;
+}
+
+pub fn create_external_key(wallet_handle: i32, signer: &str) -> Result<String, IndyError> {
+    let key_json = json!({"signer": signer}).to_string();
+    crypto::create_key(wallet_handle, Some(&key_json)).wait()
+}
+
 pub fn create_key(wallet_handle: i32, seed: Option<&str>) -> Result<String, IndyError> {
     let key_json = json!({"seed": seed}).to_string();
     crypto::create_key(wallet_handle, Some(&key_json)).wait()
 }
 
+pub fn create_key_with_crypto_type(wallet_handle: i32, seed: Option<&str>, crypto_type: &str) -> Result<String, IndyError> {
+    let key_json = json!({"seed": seed, "crypto_type": crypto_type}).to_string();
+    crypto::create_key(wallet_handle, Some(&key_json)).wait()
+}
+
 pub fn set_key_metadata(wallet_handle: i32, verkey: &str, metadata: &str) -> Result<(), IndyError> {
     crypto::set_key_metadata(wallet_handle, verkey, metadata).wait()
 }
@@ -17,6 +65,14 @@ pub fn get_key_metadata(wallet_handle: i32, verkey: &str) -> Result<String, Indy
     crypto::get_key_metadata(wallet_handle, verkey).wait()
 }
 
+pub fn list_keys(wallet_handle: i32, query_json: Option<&str>) -> Result<String, IndyError> {
+    crypto::list_keys(wallet_handle, query_json).wait()
+}
+
+pub fn delete_key(wallet_handle: i32, verkey: &str) -> Result<(), IndyError> {
+    crypto::delete_key(wallet_handle, verkey).wait()
+}
+
 pub fn sign(wallet_handle: i32, my_vk: &str, msg: &[u8]) -> Result<Vec<u8>, IndyError> {
     crypto::sign(wallet_handle, my_vk, msg).wait()
 }
@@ -25,6 +81,22 @@ pub fn verify(their_vk: &str, msg: &[u8], signature: &[u8]) -> Result<bool, Indy
     crypto::verify(their_vk, msg, signature).wait()
 }
 
+pub fn sign_jws(wallet_handle: i32, signer_did: &str, payload: &[u8], options_json: Option<&str>) -> Result<String, IndyError> {
+    crypto::sign_jws(wallet_handle, signer_did, payload, options_json).wait()
+}
+
+pub fn verify_jws(wallet_handle: i32, jws: &str, detached_payload: Option<&[u8]>) -> Result<bool, IndyError> {
+    crypto::verify_jws(wallet_handle, jws, detached_payload).wait()
+}
+
+pub fn sign_jwt(wallet_handle: i32, signer_did: &str, claims_json: &str, expires_in: u64) -> Result<String, IndyError> {
+    crypto::sign_jwt(wallet_handle, signer_did, claims_json, expires_in).wait()
+}
+
+pub fn verify_jwt(wallet_handle: i32, jwt: &str) -> Result<String, IndyError> {
+    crypto::verify_jwt(wallet_handle, jwt).wait()
+}
+
 pub fn auth_crypt(wallet_handle: i32, my_vk: &str, their_vk: &str, msg: &[u8]) -> Result<Vec<u8>, IndyError> {
     crypto::auth_crypt(wallet_handle, my_vk, their_vk, msg).wait()
 }
@@ -45,6 +117,48 @@ pub fn pack_message(wallet_handle: i32, message: &[u8], receiver_keys: &str, sen
     crypto::pack_message(wallet_handle, message, receiver_keys, sender).wait()
 }
 
+pub fn pack_file(wallet_handle: i32, input_path: &str, output_path: &str, receiver_keys: &str, sender: Option<&str>) -> Result<(), IndyError> {
+    crypto::pack_file(wallet_handle, input_path, output_path, receiver_keys, sender).wait()
+}
+
+pub fn unpack_file(wallet_handle: i32, input_path: &str, output_path: &str) -> Result<String, IndyError> {
+    crypto::unpack_file(wallet_handle, input_path, output_path).wait()
+}
+
+pub fn pack_didcomm_message(wallet_handle: i32, message: &[u8], receivers: &str, sender: Option<&str>, options_json: Option<&str>) -> Result<Vec<u8>, IndyError> {
+    crypto::pack_didcomm_message(wallet_handle, message, receivers, sender, options_json).wait()
+}
+
 pub fn unpack_message(wallet_handle: i32, jwe: &[u8]) -> Result<Vec<u8>, IndyError> {
     crypto::unpack_message(wallet_handle, jwe).wait()
-}
\ No newline at end of file
+}
+
+extern {
+    #[no_mangle]
+    pub fn indy_register_external_signer(command_handle: i32,
+                                         signer_name: *const c_char,
+                                         create_key: Option<SignerCreateKey>,
+                                         sign: Option<SignerSign>,
+                                         crypto_box: Option<SignerCryptoBox>,
+                                         free: Option<SignerFree>,
+                                         cb: Option<extern fn(command_handle: i32, err: ErrorCode)>) -> ErrorCode;
+}
+
+pub type SignerCreateKey = extern fn(crypto_type: *const c_char,
+                                     config: *const c_char,
+                                     key_ref_p: *mut *const c_char,
+                                     verkey_p: *mut *const c_char) -> ErrorCode;
+pub type SignerSign = extern fn(key_ref: *const c_char,
+                                message_raw: *const u8,
+                                message_len: usize,
+                                signature_raw_p: *mut *const u8,
+                                signature_len_p: *mut usize) -> ErrorCode;
+pub type SignerCryptoBox = extern fn(key_ref: *const c_char,
+                                     their_vk: *const c_char,
+                                     message_raw: *const u8,
+                                     message_len: usize,
+                                     nonce_raw: *const u8,
+                                     nonce_len: usize,
+                                     encrypted_raw_p: *mut *const u8,
+                                     encrypted_len_p: *mut usize) -> ErrorCode;
+pub type SignerFree = extern fn(ptr: *const c_void) -> ErrorCode;
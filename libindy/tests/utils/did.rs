@@ -64,6 +64,10 @@ pub fn replace_keys_apply(wallet_handle: i32, did: &str) -> Result<(), IndyError
     did::replace_keys_apply(wallet_handle, did).wait()
 }
 
+pub fn rotate_did_key(wallet_handle: i32, pool_handle: PoolHandle, did: &str, key_info_json: &str) -> Result<String, IndyError> {
+    did::rotate_did_key(wallet_handle, pool_handle, did, key_info_json).wait()
+}
+
 pub fn replace_keys(pool_handle: PoolHandle, wallet_handle: i32, did: &str) -> Result<String, IndyError> {
     let verkey = did::replace_keys_start(wallet_handle, did, "{}").wait().unwrap();
 
@@ -83,6 +87,10 @@ pub fn key_for_local_did(wallet_handle: PoolHandle, did: &str) -> Result<String,
     did::key_for_local_did(wallet_handle, did).wait()
 }
 
+pub fn resolve_did(pool_handle: PoolHandle, wallet_handle: i32, did: &str) -> Result<String, IndyError> {
+    did::resolve_did(pool_handle, wallet_handle, did).wait()
+}
+
 pub fn set_endpoint_for_did(wallet_handle: i32, did: &str, address: &str, transport_key: &str) -> Result<(), IndyError> {
     did::set_endpoint_for_did(wallet_handle, did, address, transport_key).wait()
 }
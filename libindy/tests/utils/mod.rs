@@ -48,6 +48,8 @@ pub(crate) use indy::ErrorCode;
 #[path = "../../indy-utils/src/inmem_wallet.rs"]
 pub mod inmem_wallet;
 
+pub mod software_signer;
+
 #[path = "../../indy-utils/src/wql.rs"]
 pub mod wql;
 
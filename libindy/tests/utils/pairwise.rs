@@ -22,4 +22,15 @@ pub fn get_pairwise(wallet_handle: i32, their_did: &str) -> Result<String, IndyE
 
 pub fn set_pairwise_metadata(wallet_handle: i32, their_did: &str, metadata: Option<&str>) -> Result<(), IndyError> {
     pairwise::set_pairwise_metadata(wallet_handle, their_did, metadata).wait()
-}
\ No newline at end of file
+}
+pub fn search_pairwise(wallet_handle: i32, query_json: Option<&str>) -> Result<String, IndyError> {
+    pairwise::search_pairwise(wallet_handle, query_json).wait()
+}
+
+pub fn update_pairwise(wallet_handle: i32, their_did: &str, pairwise_update_json: &str) -> Result<(), IndyError> {
+    pairwise::update_pairwise(wallet_handle, their_did, pairwise_update_json).wait()
+}
+
+pub fn delete_pairwise(wallet_handle: i32, their_did: &str) -> Result<(), IndyError> {
+    pairwise::delete_pairwise(wallet_handle, their_did).wait()
+}
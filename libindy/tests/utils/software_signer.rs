@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::slice;
+use std::sync::Mutex;
+
+use super::libc::{c_char, c_int, c_void};
+use rust_base58::{FromBase58, ToBase58};
+use sodiumoxide::crypto::{box_, sign};
+
+use crate::utils::ErrorCode;
+use crate::utils::inmem_wallet::c_str_to_string;
+use crate::utils::sequence;
+
+pub const SOFTWARE_SIGNER: &str = "software";
+
+extern {
+    fn crypto_sign_ed25519_pk_to_curve25519(curve25519_pk: *mut [u8; box_::PUBLICKEYBYTES],
+                                            ed25519_pk: *const [u8; sign::PUBLICKEYBYTES]) -> c_int;
+    fn crypto_sign_ed25519_sk_to_curve25519(curve25519_sk: *mut [u8; box_::SECRETKEYBYTES],
+                                            ed25519_sk: *const [u8; sign::SECRETKEYBYTES]) -> c_int;
+}
+
+lazy_static! {
+    static ref SOFTWARE_KEYS: Mutex<HashMap<String, sign::SecretKey>> = Default::default();
+    static ref ALLOCATED: Mutex<HashMap<usize, Vec<u8>>> = Default::default();
+}
+
+/// Local stand-in for HSM/KMS backed external signer.
+/// Keeps ed25519 keys in memory and hands out data until libindy frees it.
+pub struct SoftwareSigner {}
+
+impl SoftwareSigner {
+    pub extern "C" fn create_key(crypto_type: *const c_char,
+                                 _config: *const c_char,
+                                 key_ref_p: *mut *const c_char,
+                                 verkey_p: *mut *const c_char) -> ErrorCode {
+        match c_str_to_string(crypto_type) {
+            Ok(Some("ed25519")) => {}
+            _ => return ErrorCode::UnknownCryptoTypeError
+        }
+
+        let (vk, sk) = sign::gen_keypair();
+        let key_ref = format!("software-key-{}", sequence::get_next_id());
+
+        SOFTWARE_KEYS.lock().unwrap().insert(key_ref.clone(), sk);
+
+        unsafe {
+            *key_ref_p = _alloc_string(&key_ref);
+            *verkey_p = _alloc_string(&vk[..].to_base58());
+        }
+
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn sign(key_ref: *const c_char,
+                           message_raw: *const u8,
+                           message_len: usize,
+                           signature_raw_p: *mut *const u8,
+                           signature_len_p: *mut usize) -> ErrorCode {
+        let sk = match _get_key(key_ref) {
+            Some(sk) => sk,
+            None => return ErrorCode::WalletItemNotFound
+        };
+
+        let message = unsafe { slice::from_raw_parts(message_raw, message_len) };
+        let signature = sign::sign_detached(message, &sk);
+
+        unsafe {
+            *signature_raw_p = _alloc_bytes(signature[..].to_vec());
+            *signature_len_p = sign::SIGNATUREBYTES;
+        }
+
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn crypto_box(key_ref: *const c_char,
+                                 their_vk: *const c_char,
+                                 message_raw: *const u8,
+                                 message_len: usize,
+                                 nonce_raw: *const u8,
+                                 nonce_len: usize,
+                                 encrypted_raw_p: *mut *const u8,
+                                 encrypted_len_p: *mut usize) -> ErrorCode {
+        let sk = match _get_key(key_ref) {
+            Some(sk) => sk,
+            None => return ErrorCode::WalletItemNotFound
+        };
+
+        let their_vk = match c_str_to_string(their_vk) {
+            Ok(Some(their_vk)) => their_vk.from_base58().ok().and_then(|vk| sign::PublicKey::from_slice(&vk)),
+            _ => None
+        };
+
+        let their_vk = match their_vk {
+            Some(their_vk) => their_vk,
+            None => return ErrorCode::CommonInvalidStructure
+        };
+
+        let nonce = match box_::Nonce::from_slice(unsafe { slice::from_raw_parts(nonce_raw, nonce_len) }) {
+            Some(nonce) => nonce,
+            None => return ErrorCode::CommonInvalidStructure
+        };
+
+        let mut curve_sk = [0u8; box_::SECRETKEYBYTES];
+        let mut curve_pk = [0u8; box_::PUBLICKEYBYTES];
+
+        unsafe {
+            crypto_sign_ed25519_sk_to_curve25519(&mut curve_sk, &sk.0);
+            crypto_sign_ed25519_pk_to_curve25519(&mut curve_pk, &their_vk.0);
+        }
+
+        let message = unsafe { slice::from_raw_parts(message_raw, message_len) };
+        let encrypted = box_::seal(message, &nonce, &box_::PublicKey(curve_pk), &box_::SecretKey(curve_sk));
+
+        unsafe {
+            *encrypted_len_p = encrypted.len();
+            *encrypted_raw_p = _alloc_bytes(encrypted);
+        }
+
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn free(ptr: *const c_void) -> ErrorCode {
+        match ALLOCATED.lock().unwrap().remove(&(ptr as usize)) {
+            Some(_) => ErrorCode::Success,
+            None => ErrorCode::CommonInvalidState
+        }
+    }
+}
+
+fn _get_key(key_ref: *const c_char) -> Option<sign::SecretKey> {
+    match c_str_to_string(key_ref) {
+        Ok(Some(key_ref)) => SOFTWARE_KEYS.lock().unwrap().get(key_ref).cloned(),
+        _ => None
+    }
+}
+
+fn _alloc_string(value: &str) -> *const c_char {
+    _alloc_bytes(CString::new(value).unwrap().into_bytes_with_nul()) as *const c_char
+}
+
+fn _alloc_bytes(value: Vec<u8>) -> *const u8 {
+    let ptr = value.as_ptr();
+    ALLOCATED.lock().unwrap().insert(ptr as usize, value);
+    ptr
+}
@@ -76,6 +76,11 @@ extern {
                                                         other_rev_reg_delta_json: CString,
                                                         cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_encode_credential_values(command_handle: CommandHandle,
+                                         cred_values_json: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_prover_create_master_secret(command_handle: CommandHandle,
                                             wallet_handle: WalletHandle,
@@ -196,6 +201,27 @@ extern {
                                       rev_regs_json: CString,
                                       cb: Option<ResponseBoolCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_verifier_verify_proof_with_config(command_handle: CommandHandle,
+                                                  proof_request_json: CString,
+                                                  proof_json: CString,
+                                                  schemas_json: CString,
+                                                  credential_defs_json: CString,
+                                                  rev_reg_defs_json: CString,
+                                                  rev_regs_json: CString,
+                                                  config_json: CString,
+                                                  cb: Option<ResponseBoolCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_verifier_verify_proof_detailed(command_handle: CommandHandle,
+                                               proof_request_json: CString,
+                                               proof_json: CString,
+                                               schemas_json: CString,
+                                               credential_defs_json: CString,
+                                               rev_reg_defs_json: CString,
+                                               rev_regs_json: CString,
+                                               cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_create_revocation_state(command_handle: CommandHandle,
                                         blob_storage_reader_handle: BlobStorageReaderHandle,
@@ -215,10 +241,40 @@ extern {
                                         cred_rev_id: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_prover_update_cached_revocation_state(command_handle: CommandHandle,
+                                                      wallet_handle: WalletHandle,
+                                                      blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                      rev_reg_def_json: CString,
+                                                      rev_reg_delta_json: CString,
+                                                      timestamp: u64,
+                                                      cred_rev_id: CString,
+                                                      cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_prover_get_cached_revocation_state(command_handle: CommandHandle,
+                                                   wallet_handle: WalletHandle,
+                                                   rev_reg_id: CString,
+                                                   cred_rev_id: CString,
+                                                   cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_prover_check_revocation_status(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               cred_id: CString,
+                                               rev_reg_def_json: CString,
+                                               rev_reg_delta_json: CString,
+                                               timestamp: u64,
+                                               cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_generate_nonce(command_handle: CommandHandle,
                                cb: Option<ResponseStringCB>) -> Error;
     #[no_mangle]
+    pub fn indy_verifier_validate_proof_request(command_handle: CommandHandle,
+                                                proof_request_json: CString,
+                                                cb: Option<ResponseStringCB>) -> Error;
+    #[no_mangle]
     pub fn indy_to_unqualified(command_handle: CommandHandle,
                                entity: CString,
                                cb: Option<ResponseStringCB>) -> Error;
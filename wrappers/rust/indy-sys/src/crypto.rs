@@ -4,6 +4,15 @@ use {BString, CString, Error, CommandHandle, WalletHandle};
 
 extern {
 
+    #[no_mangle]
+    pub fn indy_register_external_signer(command_handle: CommandHandle,
+                                         signer_name: CString,
+                                         create_key: Option<SignerCreateKey>,
+                                         sign: Option<SignerSign>,
+                                         crypto_box: Option<SignerCryptoBox>,
+                                         free: Option<SignerFree>,
+                                         cb: Option<ResponseEmptyCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_create_key(command_handle: CommandHandle,
                            wallet_handle: WalletHandle,
@@ -23,6 +32,18 @@ extern {
                                  verkey: CString,
                                  cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_list_keys(command_handle: CommandHandle,
+                          wallet_handle: WalletHandle,
+                          query_json: CString,
+                          cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_delete_key(command_handle: CommandHandle,
+                           wallet_handle: WalletHandle,
+                           verkey: CString,
+                           cb: Option<ResponseEmptyCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_crypto_sign(command_handle: CommandHandle,
                             wallet_handle: WalletHandle,
@@ -40,6 +61,37 @@ extern {
                               signature_len: u32,
                               cb: Option<ResponseBoolCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_crypto_sign_jws(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                signer_did: CString,
+                                payload_raw: BString,
+                                payload_len: u32,
+                                options_json: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_crypto_verify_jws(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  jws: CString,
+                                  detached_payload_raw: BString,
+                                  detached_payload_len: u32,
+                                  cb: Option<ResponseBoolCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_crypto_sign_jwt(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                signer_did: CString,
+                                claims_json: CString,
+                                expires_in: u64,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_crypto_verify_jwt(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  jwt: CString,
+                                  cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_crypto_auth_crypt(command_handle: CommandHandle,
                                   wallet_handle: WalletHandle,
@@ -81,6 +133,32 @@ extern {
                              sender: CString,
                              cb: Option<ResponseSliceCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_pack_file(command_handle: CommandHandle,
+                          wallet_handle: WalletHandle,
+                          input_path: CString,
+                          output_path: CString,
+                          receiver_keys: CString,
+                          sender: CString,
+                          cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_unpack_file(command_handle: CommandHandle,
+                            wallet_handle: WalletHandle,
+                            input_path: CString,
+                            output_path: CString,
+                            cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_pack_didcomm_message(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     message: BString,
+                                     message_len: u32,
+                                     receivers: CString,
+                                     sender: CString,
+                                     options_json: CString,
+                                     cb: Option<ResponseSliceCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_unpack_message(command_handle: CommandHandle,
                                wallet_handle: WalletHandle,
@@ -89,3 +167,21 @@ extern {
                                cb: Option<ResponseSliceCB>) -> Error;
 }
 
+pub type SignerCreateKey = extern fn(crypto_type: CString,
+                                     config: CString,
+                                     key_ref_p: *mut CString,
+                                     verkey_p: *mut CString) -> Error;
+pub type SignerSign = extern fn(key_ref: CString,
+                                message_raw: BString,
+                                message_len: usize,
+                                signature_raw_p: *mut BString,
+                                signature_len_p: *mut usize) -> Error;
+pub type SignerCryptoBox = extern fn(key_ref: CString,
+                                     their_vk: CString,
+                                     message_raw: BString,
+                                     message_len: usize,
+                                     nonce_raw: BString,
+                                     nonce_len: usize,
+                                     encrypted_raw_p: *mut BString,
+                                     encrypted_len_p: *mut usize) -> Error;
+pub type SignerFree = extern fn(ptr: *const CVoid) -> Error;
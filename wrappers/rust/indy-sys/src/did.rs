@@ -22,6 +22,14 @@ extern {
                                    did: CString,
                                    cb: Option<ResponseEmptyCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_rotate_did_key(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               pool_handle: PoolHandle,
+                               did: CString,
+                               key_info: CString,
+                               cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_store_their_did(command_handle: CommandHandle,
                                 wallet_handle: WalletHandle,
@@ -41,6 +49,13 @@ extern {
                                   did: CString,
                                   cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_resolve_did(command_handle: CommandHandle,
+                            pool_handle: PoolHandle,
+                            wallet_handle: WalletHandle,
+                            did: CString,
+                            cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_set_endpoint_for_did(command_handle: CommandHandle,
                                      wallet_handle: WalletHandle,
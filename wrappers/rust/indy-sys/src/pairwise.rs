@@ -23,6 +23,12 @@ extern {
                               wallet_handle: WalletHandle,
                               cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_search_pairwise(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                query_json: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_get_pairwise(command_handle: CommandHandle,
                              wallet_handle: WalletHandle,
@@ -35,5 +41,17 @@ extern {
                                       their_did: CString,
                                       metadata: CString,
                                       cb: Option<ResponseEmptyCB>) -> Error;
-}
 
+    #[no_mangle]
+    pub fn indy_update_pairwise(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                their_did: CString,
+                                pairwise_update_json: CString,
+                                cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_delete_pairwise(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                their_did: CString,
+                                cb: Option<ResponseEmptyCB>) -> Error;
+}
@@ -18,6 +18,10 @@ use ffi::{ResponseStringStringCB,
 use {CommandHandle, WalletHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
 use ffi::BlobStorageReaderCfgHandle;
 
+use std::collections::HashSet;
+
+use serde_json::{Map, Value};
+
 /*
 These functions wrap the Ursa algorithm as documented in this paper:
 https://github.com/hyperledger/ursa/blob/master/libursa/docs/AnonCred.pdf
@@ -352,6 +356,8 @@ fn _issuer_create_credential_offer(command_handle: CommandHandle, wallet_handle:
 ///      "attr1" : {"raw": "value1", "encoded": "value1_as_int" },
 ///      "attr2" : {"raw": "value1", "encoded": "value1_as_int" }
 ///     }
+///     `encoded` is optional. If omitted it is filled in with the canonical encoding of `raw`
+///     (see `encode_credential_values`).
 /// * `rev_reg_id`: id of revocation registry stored in the wallet
 /// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
 ///
@@ -469,6 +475,34 @@ fn _issuer_merge_revocation_registry_deltas(command_handle: CommandHandle, rev_r
     })
 }
 
+/// Fills in `encoded` values of credential attributes using the canonical encoding:
+/// a 32-bit signed integer in canonical decimal form is kept as is, any other value is encoded as decimal representation of its SHA-256 hash.
+///
+/// # Arguments
+/// * `cred_values_json`: credential attribute values, `encoded` is optional
+///     {
+///      "attr1" : {"raw": "value1"},
+///      "attr2" : {"raw": "value2", "encoded": "value2_as_int" }
+///     }
+///
+/// # Returns
+/// * `cred_values_json`: credential attribute values with `encoded` set for each attribute
+pub fn encode_credential_values(cred_values_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _encode_credential_values(command_handle, cred_values_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _encode_credential_values(command_handle: CommandHandle, cred_values_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_values_json = c_str!(cred_values_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_encode_credential_values(command_handle, cred_values_json.as_ptr(), cb)
+    })
+}
+
 
 /// Creates a master secret with a given id and stores it in the wallet.
 /// The id must be unique.
@@ -1216,21 +1250,34 @@ fn _prover_close_credentials_search_for_proof_req(command_handle: CommandHandle,
 pub fn prover_create_proof(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _prover_create_proof(command_handle, wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, cb);
+    let err = _prover_create_proof(command_handle, wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, Some(rev_states_json), cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
 
-fn _prover_create_proof(command_handle: CommandHandle, wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+/// Creates a proof the same way as `prover_create_proof` does, but takes revocation states
+/// from the wallet cache maintained by `prover_update_cached_revocation_state`.
+///
+/// The cached state of each revocable credential must correspond to the timestamp
+/// specified for it in `requested_credentials_json`.
+pub fn prover_create_proof_with_cached_rev_states(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_create_proof(command_handle, wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, None, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_create_proof(command_handle: CommandHandle, wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, rev_states_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
     let proof_req_json = c_str!(proof_req_json);
     let requested_credentials_json = c_str!(requested_credentials_json);
     let master_secret_id = c_str!(master_secret_id);
     let schemas_json = c_str!(schemas_json);
     let credential_defs_json = c_str!(credential_defs_json);
-    let rev_states_json = c_str!(rev_states_json);
+    let rev_states_json_str = opt_c_str!(rev_states_json);
 
     ErrorCode::from(unsafe {
-        anoncreds::indy_prover_create_proof(command_handle, wallet_handle, proof_req_json.as_ptr(), requested_credentials_json.as_ptr(), master_secret_id.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_states_json.as_ptr(), cb)
+        anoncreds::indy_prover_create_proof(command_handle, wallet_handle, proof_req_json.as_ptr(), requested_credentials_json.as_ptr(), master_secret_id.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), opt_c_ptr!(rev_states_json, rev_states_json_str), cb)
     })
 }
 
@@ -1344,6 +1391,82 @@ fn _verifier_verify_proof(command_handle: CommandHandle, proof_request_json: &st
     })
 }
 
+/// Verifies a proof like `verifier_verify_proof` applying additional checks.
+///
+/// # Arguments
+/// * `proof_request_json`, `proof_json`, `schemas_json`, `credential_defs_json`, `rev_reg_defs_json`, `rev_regs_json`:
+///     the same as for `verifier_verify_proof`
+/// * `config_json`: verification configuration json
+///     {
+///         "check_raw_encoding": Optional<bool> - whether `encoded` value of every revealed attribute must be
+///                 the canonical encoding of its `raw` value (see `encode_credential_values`). false by default.
+///     }
+///
+/// # Returns
+/// * `valid`: true - if signature is valid, false - otherwise
+pub fn verifier_verify_proof_with_config(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, config_json: &str) -> Box<dyn Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verifier_verify_proof_with_config(command_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, config_json, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_with_config(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, config_json: &str, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+    let config_json = c_str!(config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_with_config(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), config_json.as_ptr(), cb)
+    })
+}
+
+
+/// Verifies a proof like `verifier_verify_proof`, but returns a report explaining which checks failed for every referent.
+///
+/// # Arguments
+/// * `proof_request_json`, `proof_json`, `schemas_json`, `credential_defs_json`, `rev_reg_defs_json`, `rev_regs_json`:
+///     the same as for `verifier_verify_proof`
+///
+/// # Returns
+/// * `report_json`: verification report json
+/// {
+///     "valid": bool, - the same as result of `verifier_verify_proof`
+///     "referents": {
+///         "<referent>": {
+///             "kind": "revealed_attribute" | "unrevealed_attribute" | "self_attested_attribute" | "predicate" | "missing",
+///             "sub_proof_index": Optional<int>,
+///             "valid": bool,
+///             "failures": [{"check": "structure" | "restrictions" | "encoding" | "timestamp" | "revocation" | "non_revocation", "reason": string}]
+///         }
+///     },
+///     "failures": [{"check": "signature", "reason": string}] - proof level failures
+/// }
+pub fn verifier_verify_proof_detailed(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_verify_proof_detailed(command_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_detailed(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_detailed(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), cb)
+    })
+}
 
 /// Create revocation state for a credential in the particular time moment.
 ///
@@ -1418,6 +1541,107 @@ fn _update_revocation_state(command_handle: CommandHandle, blob_storage_reader_h
     })
 }
 
+/// Creates or incrementally updates revocation state of a credential cached in the wallet.
+/// Cached states are used by `prover_create_proof_with_cached_rev_states`.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `rev_reg_def_json`: revocation registry definition json
+/// * `rev_reg_delta_json`: revocation registry delta json: accumulated from the registry creation
+///     if there is no cached state yet, or starting from the timestamp of the cached state otherwise
+/// * `timestamp`: time represented as a total number of seconds from Unix Epoch the delta corresponds to
+/// * `cred_rev_id`: user credential revocation id in revocation registry
+///
+/// # Returns
+/// * `revocation_state_json`:
+/// {
+///     "rev_reg": <revocation registry>,
+///     "witness": <witness>,  (opaque type that contains data structures internal to Ursa.
+///                            It should not be parsed and are likely to change in future versions).
+///     "timestamp" : integer
+/// }
+pub fn prover_update_cached_revocation_state(wallet_handle: WalletHandle, blob_storage_reader_handle: BlobStorageReaderHandle, rev_reg_def_json: &str, rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_update_cached_revocation_state(command_handle, wallet_handle, blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_update_cached_revocation_state(command_handle: CommandHandle, wallet_handle: WalletHandle, blob_storage_reader_handle: BlobStorageReaderHandle, rev_reg_def_json: &str, rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let rev_reg_def_json = c_str!(rev_reg_def_json);
+    let rev_reg_delta_json = c_str!(rev_reg_delta_json);
+    let cred_rev_id = c_str!(cred_rev_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_update_cached_revocation_state(command_handle, wallet_handle, blob_storage_reader_handle, rev_reg_def_json.as_ptr(), rev_reg_delta_json.as_ptr(), timestamp, cred_rev_id.as_ptr(), cb)
+    })
+}
+
+/// Gets revocation state of a credential cached in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `rev_reg_id`: identifier of revocation registry definition
+/// * `cred_rev_id`: user credential revocation id in revocation registry
+///
+/// # Returns
+/// * `revocation_state_json`
+pub fn prover_get_cached_revocation_state(wallet_handle: WalletHandle, rev_reg_id: &str, cred_rev_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_get_cached_revocation_state(command_handle, wallet_handle, rev_reg_id, cred_rev_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_get_cached_revocation_state(command_handle: CommandHandle, wallet_handle: WalletHandle, rev_reg_id: &str, cred_rev_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let rev_reg_id = c_str!(rev_reg_id);
+    let cred_rev_id = c_str!(cred_rev_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_get_cached_revocation_state(command_handle, wallet_handle, rev_reg_id.as_ptr(), cred_rev_id.as_ptr(), cb)
+    })
+}
+
+/// Checks revocation status of the credential stored in the wallet using revocation registry delta
+/// and updates `~revocation_status` and `~revocation_checked_at` tags of the credential.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `cred_id`: identifier by which requested credential is stored in the wallet
+/// * `rev_reg_def_json`: revocation registry definition json related to `rev_reg_id` of the credential
+/// * `rev_reg_delta_json`: revocation registry delta json accumulated from the registry creation
+/// * `timestamp`: time represented as a total number of seconds from Unix Epoch the delta corresponds to
+///
+/// # Returns
+/// * `revocation_info_json`:
+/// {
+///     "referent": string, - id of credential in the wallet
+///     "rev_reg_id": string, - identifier of revocation registry definition
+///     "cred_rev_id": string, - identifier of credential in the revocation registry definition
+///     "status": "active" | "revoked", - credential of ISSUANCE_ON_DEMAND registry which issuance isn't in the delta is reported as revoked
+///     "checked_at": int - timestamp the status corresponds to
+/// }
+pub fn prover_check_revocation_status(wallet_handle: WalletHandle, cred_id: &str, rev_reg_def_json: &str, rev_reg_delta_json: &str, timestamp: u64) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_check_revocation_status(command_handle, wallet_handle, cred_id, rev_reg_def_json, rev_reg_delta_json, timestamp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_check_revocation_status(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_id: &str, rev_reg_def_json: &str, rev_reg_delta_json: &str, timestamp: u64, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_id = c_str!(cred_id);
+    let rev_reg_def_json = c_str!(rev_reg_def_json);
+    let rev_reg_delta_json = c_str!(rev_reg_delta_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_check_revocation_status(command_handle, wallet_handle, cred_id.as_ptr(), rev_reg_def_json.as_ptr(), rev_reg_delta_json.as_ptr(), timestamp, cb)
+    })
+}
+
 /// Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
 /// # Arguments
@@ -1444,6 +1668,40 @@ fn _generate_nonce(command_handle: CommandHandle, cb: Option<ResponseStringCB>)
     })
 }
 
+/// Validates proof request json prepared by a verifier before sending it to a prover.
+///
+/// Performs all checks done by `verifier_verify_proof` and prover functions for the proof request
+/// (restrictions WQL, versioning of identifiers, duplicate referents, `non_revoked` intervals, predicate types)
+/// and reports all found problems at once.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json to validate
+///
+/// # Returns
+/// * `report_json`: validation report json
+///     {
+///         "valid": bool, - true if proof request can be used as is
+///         "errors": [{
+///             "referent": Optional<string>, - attribute or predicate referent (null for proof request level problems)
+///             "message": string - problem description
+///         }]
+///     }
+pub fn verifier_validate_proof_request(proof_request_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_validate_proof_request(command_handle, proof_request_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_validate_proof_request(command_handle: CommandHandle, proof_request_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_validate_proof_request(command_handle, proof_request_json.as_ptr(), cb)
+    })
+}
+
 /// Get unqualified form (short form without method) of a fully qualified entity like DID.
 ///
 /// This function should be used to the proper casting of fully qualified entity to unqualified form in the following cases:
@@ -1481,3 +1739,227 @@ fn _to_unqualified(command_handle: CommandHandle, entity: &str, cb: Option<Respo
         anoncreds::indy_to_unqualified(command_handle, entity.as_ptr(), cb)
     })
 }
+
+/// Format version of a proof request (`ver` field).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofRequestVersion {
+    /// Restrictions must use unqualified identifiers
+    V1,
+    /// Restrictions can use fully qualified identifiers
+    V2,
+}
+
+impl ProofRequestVersion {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            ProofRequestVersion::V1 => "1.0",
+            ProofRequestVersion::V2 => "2.0",
+        }
+    }
+}
+
+/// Type of requested predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum PredicateType {
+    #[serde(rename = ">=")]
+    GE,
+    #[serde(rename = "<=")]
+    LE,
+    #[serde(rename = ">")]
+    GT,
+    #[serde(rename = "<")]
+    LT,
+}
+
+/// Time interval (seconds from Unix Epoch) the credential must be non-revoked in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NonRevokedInterval {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<u64>,
+}
+
+/// Requested attribute of a proof request.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AttributeRequest {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    restrictions: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    non_revoked: Option<NonRevokedInterval>,
+}
+
+impl AttributeRequest {
+    /// * `name`: attribute name (case insensitive and ignore spaces)
+    pub fn new(name: &str) -> AttributeRequest {
+        AttributeRequest {
+            name: name.to_string(),
+            restrictions: None,
+            non_revoked: None,
+        }
+    }
+
+    /// * `restrictions`: WQL query on credential tags (see `prover_get_credentials_for_proof_req` for supported tags)
+    pub fn restrictions(mut self, restrictions: Value) -> AttributeRequest {
+        self.restrictions = Some(restrictions);
+        self
+    }
+
+    /// * `non_revoked`: interval that overrides the proof request level one for this attribute
+    pub fn non_revoked(mut self, non_revoked: NonRevokedInterval) -> AttributeRequest {
+        self.non_revoked = Some(non_revoked);
+        self
+    }
+}
+
+/// Requested predicate of a proof request.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PredicateRequest {
+    name: String,
+    p_type: PredicateType,
+    p_value: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    restrictions: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    non_revoked: Option<NonRevokedInterval>,
+}
+
+impl PredicateRequest {
+    /// * `name`: attribute name (case insensitive and ignore spaces)
+    /// * `p_type`: predicate type
+    /// * `p_value`: predicate value
+    pub fn new(name: &str, p_type: PredicateType, p_value: i32) -> PredicateRequest {
+        PredicateRequest {
+            name: name.to_string(),
+            p_type,
+            p_value,
+            restrictions: None,
+            non_revoked: None,
+        }
+    }
+
+    /// * `restrictions`: WQL query on credential tags (see `prover_get_credentials_for_proof_req` for supported tags)
+    pub fn restrictions(mut self, restrictions: Value) -> PredicateRequest {
+        self.restrictions = Some(restrictions);
+        self
+    }
+
+    /// * `non_revoked`: interval that overrides the proof request level one for this predicate
+    pub fn non_revoked(mut self, non_revoked: NonRevokedInterval) -> PredicateRequest {
+        self.non_revoked = Some(non_revoked);
+        self
+    }
+}
+
+/// Builder of proof request json accepted by `prover_*` and `verifier_verify_proof` functions.
+///
+/// # Example
+/// ```ignore
+/// let nonce = anoncreds::generate_nonce().wait()?;
+/// let proof_request_json = ProofRequestBuilder::new(&nonce, "proof_req_1", "0.1")
+///     .requested_attribute("attr1_referent", AttributeRequest::new("name"))
+///     .requested_predicate("predicate1_referent", PredicateRequest::new("age", PredicateType::GE, 18))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProofRequestBuilder {
+    ver: ProofRequestVersion,
+    nonce: String,
+    name: String,
+    version: String,
+    requested_attributes: Vec<(String, AttributeRequest)>,
+    requested_predicates: Vec<(String, PredicateRequest)>,
+    non_revoked: Option<NonRevokedInterval>,
+}
+
+impl ProofRequestBuilder {
+    /// * `nonce`: nonce generated by `generate_nonce`
+    /// * `name`: proof request name
+    /// * `version`: proof request version
+    pub fn new(nonce: &str, name: &str, version: &str) -> ProofRequestBuilder {
+        ProofRequestBuilder {
+            ver: ProofRequestVersion::V1,
+            nonce: nonce.to_string(),
+            name: name.to_string(),
+            version: version.to_string(),
+            requested_attributes: Vec::new(),
+            requested_predicates: Vec::new(),
+            non_revoked: None,
+        }
+    }
+
+    /// Sets format version of proof request (`V1` by default).
+    pub fn ver(mut self, ver: ProofRequestVersion) -> ProofRequestBuilder {
+        self.ver = ver;
+        self
+    }
+
+    pub fn requested_attribute(mut self, referent: &str, attribute: AttributeRequest) -> ProofRequestBuilder {
+        self.requested_attributes.push((referent.to_string(), attribute));
+        self
+    }
+
+    pub fn requested_predicate(mut self, referent: &str, predicate: PredicateRequest) -> ProofRequestBuilder {
+        self.requested_predicates.push((referent.to_string(), predicate));
+        self
+    }
+
+    /// Sets interval applied to all requested attributes and predicates without their own one.
+    pub fn non_revoked(mut self, non_revoked: NonRevokedInterval) -> ProofRequestBuilder {
+        self.non_revoked = Some(non_revoked);
+        self
+    }
+
+    /// Builds proof request json.
+    ///
+    /// Fails with `CommonInvalidStructure` if the same referent is used more than once, as it would be silently lost in json.
+    /// Use `verifier_validate_proof_request` to get complete diagnostics for the result.
+    pub fn build(&self) -> Result<String, IndyError> {
+        let mut referents: HashSet<&str> = HashSet::new();
+        let duplicates: Vec<&str> = self.requested_attributes.iter().map(|&(ref referent, _)| referent.as_str())
+            .chain(self.requested_predicates.iter().map(|&(ref referent, _)| referent.as_str()))
+            .filter(|referent| !referents.insert(referent))
+            .collect();
+
+        if !duplicates.is_empty() {
+            return Err(IndyError {
+                error_code: ErrorCode::CommonInvalidStructure,
+                message: format!("Referents are used more than once: {:?}", duplicates),
+                indy_backtrace: None,
+            });
+        }
+
+        let mut requested_attributes = Map::new();
+        for &(ref referent, ref attribute) in self.requested_attributes.iter() {
+            requested_attributes.insert(referent.to_string(), _to_value(attribute)?);
+        }
+
+        let mut requested_predicates = Map::new();
+        for &(ref referent, ref predicate) in self.requested_predicates.iter() {
+            requested_predicates.insert(referent.to_string(), _to_value(predicate)?);
+        }
+
+        let mut proof_request = Map::new();
+        proof_request.insert("ver".to_string(), Value::String(self.ver.as_str().to_string()));
+        proof_request.insert("nonce".to_string(), Value::String(self.nonce.clone()));
+        proof_request.insert("name".to_string(), Value::String(self.name.clone()));
+        proof_request.insert("version".to_string(), Value::String(self.version.clone()));
+        proof_request.insert("requested_attributes".to_string(), Value::Object(requested_attributes));
+        proof_request.insert("requested_predicates".to_string(), Value::Object(requested_predicates));
+        if let Some(ref non_revoked) = self.non_revoked {
+            proof_request.insert("non_revoked".to_string(), _to_value(non_revoked)?);
+        }
+
+        Ok(Value::Object(proof_request).to_string())
+    }
+}
+
+fn _to_value<T: ::serde::Serialize>(value: &T) -> Result<Value, IndyError> {
+    ::serde_json::to_value(value)
+        .map_err(|err| IndyError {
+            error_code: ErrorCode::CommonInvalidState,
+            message: format!("Cannot serialize proof request: {}", err),
+            indy_backtrace: None,
+        })
+}
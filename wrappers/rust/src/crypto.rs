@@ -14,6 +14,43 @@ use {ErrorCode, IndyError};
 use utils::callbacks::{ClosureHandler, ResultHandler};
 use {WalletHandle, CommandHandle};
 
+/// Registers external signer implementation (For example, HSM or KMS backed one).
+///
+/// Keys created with `signer` field of key info set to the registered name keep
+/// private part in the external signer and libindy delegates private key operations to it.
+///
+/// # Arguments
+/// * `signer_name` - Signer name.
+/// * `create_key` - Signer create key operation handler
+/// * `sign` - Signer sign operation handler
+/// * `crypto_box` - Signer crypto box operation handler
+/// * `free` - Handler that allows to de-allocate data returned by signer handlers
+pub fn register_external_signer(signer_name: &str,
+                                create_key: Option<crypto::SignerCreateKey>,
+                                sign: Option<crypto::SignerSign>,
+                                crypto_box: Option<crypto::SignerCryptoBox>,
+                                free: Option<crypto::SignerFree>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_external_signer(command_handle, signer_name, create_key, sign, crypto_box, free, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_external_signer(command_handle: CommandHandle,
+                             signer_name: &str,
+                             create_key: Option<crypto::SignerCreateKey>,
+                             sign: Option<crypto::SignerSign>,
+                             crypto_box: Option<crypto::SignerCryptoBox>,
+                             free: Option<crypto::SignerFree>,
+                             cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let signer_name = c_str!(signer_name);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_register_external_signer(command_handle, signer_name.as_ptr(), create_key, sign, crypto_box, free, cb)
+    })
+}
+
 /// Creates key pair in wallet
 /// # Arguments
 /// * `wallet_handle` - wallet handle (created by Wallet::open)
@@ -24,7 +61,9 @@ use {WalletHandle, CommandHandle};
 /// {
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
-///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Supported values: 'ed25519', 'secp256k1', 'p256'.
+///     "signer": string, // Optional name of external signer (see register_external_signer) that will hold the private key.
+///     "signer_config": object, // Optional signer specific key config passed to the signer as is.
 /// }
 /// # Returns
 /// verkey of generated key pair, also used as key identifier
@@ -82,6 +121,45 @@ fn _get_key_metadata(command_handle: CommandHandle, wallet_handle: WalletHandle,
     ErrorCode::from(unsafe { crypto::indy_get_key_metadata(command_handle, wallet_handle, verkey.as_ptr(), cb) })
 }
 
+/// Lists keys stored in the wallet
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `query_json` - optional wallet query (WQL) over key tags `~created_at` and `~crypto_type`
+/// # Returns
+/// json list of keys with their crypto type, metadata and creation time
+pub fn list_keys(wallet_handle: WalletHandle, query_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _list_keys(command_handle, wallet_handle, query_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _list_keys(command_handle: CommandHandle, wallet_handle: WalletHandle, query_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+
+    ErrorCode::from(unsafe { crypto::indy_list_keys(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), cb) })
+}
+
+/// Deletes the key and its metadata from the wallet.
+/// The key can't be deleted while it's used by my DID or pairwise
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `verkey` - the public key or key id to delete
+pub fn delete_key(wallet_handle: WalletHandle, verkey: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _delete_key(command_handle, wallet_handle, verkey, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _delete_key(command_handle: CommandHandle, wallet_handle: WalletHandle, verkey: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let verkey = c_str!(verkey);
+
+    ErrorCode::from(unsafe { crypto::indy_delete_key(command_handle, wallet_handle, verkey.as_ptr(), cb) })
+}
+
 /// Signs a message with a key
 /// # Arguments
 /// * `wallet_handle` - wallet handle (created by Wallet::open)
@@ -133,6 +211,114 @@ fn _verify(command_handle: CommandHandle, signer_vk: &str, message: &[u8], signa
     })
 }
 
+/// Signs a payload as JWS with the key of DID stored in the wallet
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `signer_did` - DID of the signer
+/// * `payload` - the data to be signed
+/// * `options_json` - Optional signing options as json. If none then defaults are used.
+///
+/// # Example
+/// options_json
+/// {
+///     "detached": bool, (optional, false by default) omit payload from the result
+/// }
+/// # Returns
+/// JWS in compact serialization
+pub fn sign_jws(wallet_handle: WalletHandle, signer_did: &str, payload: &[u8], options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_jws(command_handle, wallet_handle, signer_did, payload, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_jws(command_handle: CommandHandle, wallet_handle: WalletHandle, signer_did: &str, payload: &[u8], options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let signer_did = c_str!(signer_did);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_crypto_sign_jws(command_handle, wallet_handle, signer_did.as_ptr(),
+                                     payload.as_ptr() as *const u8, payload.len() as u32,
+                                     opt_c_ptr!(options_json, options_json_str), cb)
+    })
+}
+
+/// Verifies JWS in compact serialization. Signer key is found by `kid` DID in the wallet
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `jws` - JWS in compact serialization
+/// * `detached_payload` - payload for JWS with detached payload
+/// # Returns
+/// true if signature is valid, false otherwise
+pub fn verify_jws(wallet_handle: WalletHandle, jws: &str, detached_payload: Option<&[u8]>) -> Box<dyn Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verify_jws(command_handle, wallet_handle, jws, detached_payload, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verify_jws(command_handle: CommandHandle, wallet_handle: WalletHandle, jws: &str, detached_payload: Option<&[u8]>, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let jws = c_str!(jws);
+    let (detached_payload_raw, detached_payload_len) = match detached_payload {
+        Some(payload) => (payload.as_ptr() as *const u8, payload.len() as u32),
+        None => (null(), 0)
+    };
+
+    ErrorCode::from(unsafe {
+        crypto::indy_crypto_verify_jws(command_handle, wallet_handle, jws.as_ptr(),
+                                       detached_payload_raw, detached_payload_len, cb)
+    })
+}
+
+/// Builds and signs JWT with the key of DID stored in the wallet
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `signer_did` - DID of the signer
+/// * `claims_json` - JWT claims as json object
+/// * `expires_in` - token lifetime in seconds (0 - token without expiry)
+/// # Returns
+/// signed JWT
+pub fn sign_jwt(wallet_handle: WalletHandle, signer_did: &str, claims_json: &str, expires_in: u64) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _sign_jwt(command_handle, wallet_handle, signer_did, claims_json, expires_in, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _sign_jwt(command_handle: CommandHandle, wallet_handle: WalletHandle, signer_did: &str, claims_json: &str, expires_in: u64, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let signer_did = c_str!(signer_did);
+    let claims_json = c_str!(claims_json);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_crypto_sign_jwt(command_handle, wallet_handle, signer_did.as_ptr(), claims_json.as_ptr(), expires_in, cb)
+    })
+}
+
+/// Verifies JWT signature and validates `exp` and `nbf` claims
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `jwt` - signed JWT
+/// # Returns
+/// JWT claims as json object
+pub fn verify_jwt(wallet_handle: WalletHandle, jwt: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verify_jwt(command_handle, wallet_handle, jwt, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verify_jwt(command_handle: CommandHandle, wallet_handle: WalletHandle, jwt: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let jwt = c_str!(jwt);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_crypto_verify_jwt(command_handle, wallet_handle, jwt.as_ptr(), cb)
+    })
+}
+
 /// **** THIS FUNCTION WILL BE DEPRECATED USE pack_message INSTEAD ****
 /// Encrypt a message by authenticated-encryption scheme.
 ///
@@ -317,8 +503,106 @@ fn _pack_message(command_handle: CommandHandle, wallet_handle: WalletHandle, mes
 
 }
 
+/// Packs a file for the receivers by encrypting its content in chunks (Experimental)
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open).
+/// * `input_path`: path of the file to be packed
+/// * `output_path`: path of the packed file to be created (must not exist)
+/// * `receiver_keys`: a JSON array as a string containing a list of the receivers verkey's
+/// * `sender` : a string of the sender's verkey When None is used in this parameter, anoncrypt is used
+pub fn pack_file(wallet_handle: WalletHandle, input_path: &str, output_path: &str, receiver_keys: &str, sender: Option<&str>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _pack_file(command_handle, wallet_handle, input_path, output_path, receiver_keys, sender, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _pack_file(command_handle: CommandHandle, wallet_handle: WalletHandle, input_path: &str, output_path: &str, receiver_keys: &str, sender: Option<&str>, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let input_path = c_str!(input_path);
+    let output_path = c_str!(output_path);
+    let receiver_keys = c_str!(receiver_keys);
+    let sender_str = opt_c_str!(sender);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_pack_file(command_handle,
+                               wallet_handle,
+                               input_path.as_ptr(),
+                               output_path.as_ptr(),
+                               receiver_keys.as_ptr(),
+                               opt_c_ptr!(sender, sender_str),
+                               cb)
+    })
+}
+
+/// Unpacks a file packed by pack_file (Experimental)
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open).
+/// * `input_path`: path of the packed file
+/// * `output_path`: path of the unpacked file to be created (must not exist)
+/// # Returns
+/// a json structure that contains recipient_verkey and sender_verkey if packed with authcrypt
+pub fn unpack_file(wallet_handle: WalletHandle, input_path: &str, output_path: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _unpack_file(command_handle, wallet_handle, input_path, output_path, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _unpack_file(command_handle: CommandHandle, wallet_handle: WalletHandle, input_path: &str, output_path: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let input_path = c_str!(input_path);
+    let output_path = c_str!(output_path);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_unpack_file(command_handle,
+                                 wallet_handle,
+                                 input_path.as_ptr(),
+                                 output_path.as_ptr(),
+                                 cb)
+    })
+}
+
+/// Packs a message as DIDComm v2 encrypted message (Experimental)
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open).
+/// * `message`: a pointer to the first byte of the message to be encrypted
+/// * `receivers`: a JSON array as a string containing a list of the receivers DIDs or key ids (DID URLs)
+/// * `sender` : DID or key id of the sender. When None is used in this parameter, ECDH-ES (anonymous) is used
+/// * `options_json`: (optional) packing options: {"enc": "A256CBC-HS512", "A256GCM" or "XC20P"}.
+///   Authcrypt supports "A256CBC-HS512" only and uses it by default, anoncrypt uses "A256GCM" by default.
+/// # Returns
+/// a json structure in the form of a JWE in General JSON Serialization
+pub fn pack_didcomm_message(wallet_handle: WalletHandle, message: &[u8], receivers: &str, sender: Option<&str>, options_json: Option<&str>) -> Box<dyn Future<Item=Vec<u8>, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _pack_didcomm_message(command_handle, wallet_handle, message, receivers, sender, options_json, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _pack_didcomm_message(command_handle: CommandHandle, wallet_handle: WalletHandle, message: &[u8], receivers: &str, sender: Option<&str>, options_json: Option<&str>, cb: Option<ResponseSliceCB>) -> ErrorCode {
+    let receivers = c_str!(receivers);
+    let sender_str = opt_c_str!(sender);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        crypto::indy_pack_didcomm_message(command_handle,
+                                          wallet_handle,
+                                          message.as_ptr() as *const u8,
+                                          message.len() as u32,
+                                          receivers.as_ptr(),
+                                          opt_c_ptr!(sender, sender_str),
+                                          opt_c_ptr!(options_json, options_json_str),
+                                          cb)
+    })
+}
+
 /// Unpacks a message packed using indy_pack_message which follows the wire message format HIPE
-/// (Experimental)
+/// or using indy_pack_didcomm_message (Experimental)
 ///
 ///
 /// # Arguments
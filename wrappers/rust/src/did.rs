@@ -32,9 +32,13 @@ use {CommandHandle, WalletHandle, PoolHandle};
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               supported values: 'ed25519', 'secp256k1', 'p256')
 ///     "cid": bool, (optional; if not set then false is used;)
 ///     "method_name": string, (optional) method name to create fully qualified did.
+///                   `key` and `peer` methods build self-certifying did:key and did:peer identifiers
+///                   from the ed25519 verkey, so the key can be resolved from the DID without a ledger.
+///     "peer_numalgo": int, (optional; if not set then 0 is used) did:peer generation algorithm:
+///                   0 - inception key, 2 - multiple keys (X25519 key agreement and ed25519 verification keys).
 /// }
 ///
 /// # Returns
@@ -67,7 +71,7 @@ fn _create_and_store_my_did(command_handle: CommandHandle, wallet_handle: Wallet
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-///               currently only 'ed25519' value is supported for this field)
+///               supported values: 'ed25519', 'secp256k1', 'p256')
 /// }
 ///
 /// # Returns
@@ -106,6 +110,41 @@ fn _replace_keys_apply(command_handle: CommandHandle, wallet_handle: WalletHandl
     ErrorCode::from(unsafe { did::indy_replace_keys_apply(command_handle, wallet_handle, tgt_did.as_ptr(), cb) })
 }
 
+/// Rotates keys for an existing DID (owned by the caller of the library) on the ledger and in the wallet.
+///
+/// Generates temporary keys, sends NYM transaction with the new verkey signed with the current key,
+/// checks that the ledger shows the new verkey and applies the new keys.
+/// Temporary keys are removed if the ledger doesn't apply NYM transaction.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `tgt_did` - DID stored in the wallet
+/// * `identity_json` - key information as json. Example:
+/// {
+///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
+///                                Can be UTF-8, base64 or hex string.
+///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
+///               supported values: 'ed25519', 'secp256k1', 'p256')
+/// }
+///
+/// # Returns
+/// * `verkey` - The DIDs new verification key
+pub fn rotate_did_key(wallet_handle: WalletHandle, pool_handle: PoolHandle, tgt_did: &str, identity_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _rotate_did_key(command_handle, wallet_handle, pool_handle, tgt_did, identity_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _rotate_did_key(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_handle: PoolHandle, tgt_did: &str, identity_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let tgt_did = c_str!(tgt_did);
+    let identity_json = c_str!(identity_json);
+
+    ErrorCode::from(unsafe { did::indy_rotate_did_key(command_handle, wallet_handle, pool_handle, tgt_did.as_ptr(), identity_json.as_ptr(), cb) })
+}
+
 /// Saves their DID for a pairwise connection in a secured Wallet,
 /// so that it can be used to verify transaction.
 ///
@@ -142,6 +181,9 @@ fn _store_their_did(command_handle: CommandHandle, wallet_handle: WalletHandle,
 /// "get_ver_key" call instead that will look only to the local wallet and skip
 /// freshness checking.
 ///
+/// did:key and did:peer DIDs are resolved from the identifier itself without wallet
+/// or ledger lookup.
+///
 /// Note that "new" makes similar wallet record as "Key::create_key".
 /// As result we can use returned ver key in all generic crypto and messaging functions.
 ///
@@ -174,6 +216,9 @@ fn _key_for_did(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_h
 /// Note if you want to get fresh data from the ledger you can use "get_ver_key" call
 /// instead.
 ///
+/// did:key and did:peer DIDs are resolved from the identifier itself without wallet
+/// or ledger lookup.
+///
 /// Note that "new" makes similar wallet record as "Key::create_key".
 /// As result we can use returned ver key in all generic crypto and messaging functions.
 ///
@@ -197,6 +242,37 @@ fn _key_for_local_did(command_handle: CommandHandle, wallet_handle: WalletHandle
     ErrorCode::from(unsafe { did::indy_key_for_local_did(command_handle, wallet_handle, did.as_ptr(), cb) })
 }
 
+/// Resolves the given DID into W3C DID Document.
+///
+/// DIDs stored in the wallet are resolved locally, other DIDs are resolved from the ledger
+/// (GET_NYM and `endpoint` GET_ATTRIB) and cached in the wallet.
+/// did:key and did:peer DIDs are resolved from the identifier itself.
+///
+/// # Arguments
+/// * `pool_handle` - Pool handle (created by Pool::open).
+/// * `wallet_handle` - Wallet handle (created by Wallet::open).
+/// * `did` - The DID to resolve.
+///
+/// # Returns
+/// * `resolution_result_json` - DID resolution result:
+///   {
+///     "didDocument": W3C DID Document with verification methods and `did-communication` service,
+///     "didResolutionMetadata": {"contentType": "application/did+ld+json", "source": "identifier" | "wallet" | "ledger"}
+///   }
+pub fn resolve_did(pool_handle: PoolHandle, wallet_handle: WalletHandle, did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _resolve_did(command_handle, pool_handle, wallet_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _resolve_did(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_handle: WalletHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_resolve_did(command_handle, pool_handle, wallet_handle, did.as_ptr(), cb) })
+}
+
 /// Set/replaces endpoint information for the given DID.
 ///
 /// # Arguments
@@ -61,6 +61,22 @@ fn _list_pairwise(command_handle: CommandHandle, wallet_handle: WalletHandle, cb
     })
 }
 
+pub fn search_pairwise(wallet_handle: WalletHandle, query_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _search_pairwise(command_handle, wallet_handle, query_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _search_pairwise(command_handle: CommandHandle, wallet_handle: WalletHandle, query_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_search_pairwise(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), cb)
+    })
+}
+
 pub fn get_pairwise(wallet_handle: WalletHandle, their_did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
@@ -93,3 +109,36 @@ fn _set_pairwise_metadata(command_handle: CommandHandle, wallet_handle: WalletHa
         pairwise::indy_set_pairwise_metadata(command_handle, wallet_handle, their_did.as_ptr(), opt_c_ptr!(metadata, metadata_str), cb)
     })
 }
+
+pub fn update_pairwise(wallet_handle: WalletHandle, their_did: &str, pairwise_update_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _update_pairwise(command_handle, wallet_handle, their_did, pairwise_update_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _update_pairwise(command_handle: CommandHandle, wallet_handle: WalletHandle, their_did: &str, pairwise_update_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+    let pairwise_update_json = c_str!(pairwise_update_json);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_update_pairwise(command_handle, wallet_handle, their_did.as_ptr(), pairwise_update_json.as_ptr(), cb)
+    })
+}
+
+pub fn delete_pairwise(wallet_handle: WalletHandle, their_did: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _delete_pairwise(command_handle, wallet_handle, their_did, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _delete_pairwise(command_handle: CommandHandle, wallet_handle: WalletHandle, their_did: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_delete_pairwise(command_handle, wallet_handle, their_did.as_ptr(), cb)
+    })
+}